@@ -1,4 +1,7 @@
 //! Script show error msg for missing `BECH_32_MAIN_PREFIX` env
+//!
+//! Prefix set at compile time is only a default. Applications could override it at runtime
+//! with `address::set_bech32_prefixes`.
 
 use std::{env, error::Error};
 fn main() -> Result<(), Box<dyn Error>> {
@@ -47,9 +47,22 @@ impl<T: AddressKind> BaseAddress<T> {
     /// assert!(addr.is_ok())
     /// ```
     pub fn from_bech32(address: &str) -> Result<Self, AddressError> {
-        let (hrp, data, variant) = bech32::decode(address)?;
+        Self::from_bech32_with_prefix(address, T::prefix())
+    }
 
-        let prefix = T::prefix();
+    /// Parse address from [bech32](https://en.bitcoin.it/wiki/Bech32) string with custom prefix
+    /// instead of one used by application
+    ///
+    /// # Example
+    /// ```rust
+    /// use address::AccAddress;
+    ///
+    /// let addr = AccAddress::from_bech32_with_prefix("osmo1syavy2npfyt9tcncdtsdzf7kny9lh777fxyv25", "osmo");
+    ///
+    /// assert!(addr.is_ok())
+    /// ```
+    pub fn from_bech32_with_prefix(address: &str, prefix: &str) -> Result<Self, AddressError> {
+        let (hrp, data, variant) = bech32::decode(address)?;
 
         if hrp != prefix {
             return Err(AddressError::InvalidPrefix {
@@ -73,6 +86,16 @@ impl<T: AddressKind> BaseAddress<T> {
         Ok(Self::new(address))
     }
 
+    /// Encode address to [bech32](https://en.bitcoin.it/wiki/Bech32) string with custom prefix
+    /// instead of one used by application
+    pub fn to_bech32_with_prefix(&self, prefix: &str) -> Result<String, AddressError> {
+        Ok(bech32::encode(
+            prefix,
+            self.bytes.to_base32(),
+            Variant::Bech32,
+        )?)
+    }
+
     /// Return length of bytes in address
     #[allow(clippy::len_without_is_empty)]
     pub fn len(&self) -> u8 {
@@ -418,6 +441,36 @@ mod tests {
         )
     }
 
+    #[test]
+    fn with_prefix_roundtrip() {
+        let addr = "osmo1syavy2npfyt9tcncdtsdzf7kny9lh777fxyv25";
+
+        let acc_addr = AccAddress::from_bech32_with_prefix(addr, "osmo").unwrap_test();
+
+        assert_eq!(
+            acc_addr,
+            AccAddress::from_bech32("cosmos1syavy2npfyt9tcncdtsdzf7kny9lh777pahuux").unwrap_test()
+        );
+        assert_eq!(acc_addr.to_bech32_with_prefix("osmo").unwrap_test(), addr);
+        assert!(AccAddress::from_bech32(addr).is_err());
+    }
+
+    #[test]
+    fn prefixes_from_main_prefix() {
+        assert_eq!(
+            Bech32Prefixes::from_main_prefix(BECH_32_PREFIX_ACC_ADDR),
+            Bech32Prefixes::default()
+        );
+        assert_eq!(
+            Bech32Prefixes::from_main_prefix("osmo"),
+            Bech32Prefixes {
+                account: "osmo".to_owned(),
+                validator: "osmovaloper".to_owned(),
+                consensus: "osmovalcons".to_owned(),
+            }
+        );
+    }
+
     #[test]
     fn prefix_len_bytes_works() {
         let addr = vec![0x00, 0x01, 0x02];
@@ -1,3 +1,5 @@
+use std::sync::OnceLock;
+
 const PREFIX_VALIDATOR: &str = "val";
 const PREFIX_OPERATOR: &str = "oper";
 const PREFIX_CONSENSUS: &str = "cons";
@@ -14,6 +16,55 @@ pub(crate) const BECH_32_PREFIX_CONS_ADDR: &str = constcat::concat!(
     PREFIX_CONSENSUS
 );
 
+static BECH_32_PREFIXES: OnceLock<Bech32Prefixes> = OnceLock::new();
+
+/// Bech32 prefixes of all address kinds
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Bech32Prefixes {
+    /// prefix of [Account] addresses
+    pub account: String,
+    /// prefix of [Validator] addresses
+    pub validator: String,
+    /// prefix of [Consensus] addresses
+    pub consensus: String,
+}
+
+impl Bech32Prefixes {
+    /// Derive prefixes from main (account) prefix the same way as cosmos sdk does.
+    /// `cosmos` gives `cosmos`, `cosmosvaloper` and `cosmosvalcons`
+    pub fn from_main_prefix(main: &str) -> Self {
+        Self {
+            account: main.to_owned(),
+            validator: format!("{main}{PREFIX_VALIDATOR}{PREFIX_OPERATOR}"),
+            consensus: format!("{main}{PREFIX_VALIDATOR}{PREFIX_CONSENSUS}"),
+        }
+    }
+}
+
+impl Default for Bech32Prefixes {
+    /// Prefixes set at compile time with `BECH_32_MAIN_PREFIX` env
+    fn default() -> Self {
+        Self {
+            account: BECH_32_PREFIX_ACC_ADDR.to_owned(),
+            validator: BECH_32_PREFIX_VAL_ADDR.to_owned(),
+            consensus: BECH_32_PREFIX_CONS_ADDR.to_owned(),
+        }
+    }
+}
+
+/// Set prefixes used by all addresses. Prefixes could be set only once and should be set
+/// at start of application before any address was parsed or displayed.
+///
+/// Returns passed prefixes as error if prefixes already set.
+pub fn set_bech32_prefixes(prefixes: Bech32Prefixes) -> Result<(), Bech32Prefixes> {
+    BECH_32_PREFIXES.set(prefixes)
+}
+
+/// Prefixes used by all addresses. Defaults to prefixes set at compile time
+pub fn bech32_prefixes() -> &'static Bech32Prefixes {
+    BECH_32_PREFIXES.get_or_init(Bech32Prefixes::default)
+}
+
 /// Account kind of address
 #[derive(Debug, Clone, Default, Hash, PartialEq, Eq, PartialOrd, Ord)]
 pub struct Account;
@@ -34,19 +85,19 @@ pub trait AddressKind: sealed::Sealed {
 
 impl AddressKind for Account {
     fn prefix() -> &'static str {
-        BECH_32_PREFIX_ACC_ADDR
+        &bech32_prefixes().account
     }
 }
 
 impl AddressKind for Validator {
     fn prefix() -> &'static str {
-        BECH_32_PREFIX_VAL_ADDR
+        &bech32_prefixes().validator
     }
 }
 
 impl AddressKind for Consensus {
     fn prefix() -> &'static str {
-        BECH_32_PREFIX_CONS_ADDR
+        &bech32_prefixes().consensus
     }
 }
 
@@ -1,2 +1,3 @@
 pub use ibc_proto::cosmos::tx::v1beta1::SignDoc;
+pub use ibc_proto::cosmos::tx::v1beta1::SignDocDirectAux;
 pub use ibc_proto::cosmos::tx::v1beta1::SignerInfo;
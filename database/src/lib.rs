@@ -13,7 +13,7 @@ pub mod rocks;
 #[cfg(feature = "sled")]
 pub mod sled;
 
-use std::fmt::Debug;
+use std::{fmt::Debug, ops::Bound};
 
 pub use memory::*;
 
@@ -21,6 +21,15 @@ pub use memory::*;
 #[derive(Debug, Clone, Default)]
 pub struct DBBuilder;
 
+/// Operation of write batch, see [`Database::write_batch`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BatchOp {
+    /// Add or overwrite key - value
+    Put(Vec<u8>, Vec<u8>),
+    /// Remove key and its value
+    Delete(Vec<u8>),
+}
+
 /// Interface which all key value database implements
 pub trait Database: Clone + Send + Sync + 'static {
     /// Return value of specific key
@@ -29,12 +38,68 @@ pub trait Database: Clone + Send + Sync + 'static {
     /// Add or overwrite new key - value
     fn put(&self, key: Vec<u8>, value: Vec<u8>);
 
+    /// Remove key and its value. Does nothing if key doesn't exist
+    fn delete(&self, key: &[u8]);
+
     /// Iterate over values in database. Uses lexicographical order
     fn iterator(&self) -> impl Iterator<Item = (Box<[u8]>, Box<[u8]>)> + '_;
 
     /// Iterate over values in database which starts with prefix. Uses lexicographical order
     fn prefix_iterator(&self, prefix: Vec<u8>)
         -> impl Iterator<Item = (Box<[u8]>, Box<[u8]>)> + '_;
+
+    /// Iterate over values with keys between `start` and `end`. Uses lexicographical order.
+    /// Iteration starts at `start` and stops at `end`, keys out of range aren't read
+    fn range_iterator(
+        &self,
+        start: Bound<Vec<u8>>,
+        end: Bound<Vec<u8>>,
+    ) -> impl Iterator<Item = (Box<[u8]>, Box<[u8]>)> + '_;
+
+    /// Same as [`Database::range_iterator`], but in reverse lexicographical order
+    fn rev_range_iterator(
+        &self,
+        start: Bound<Vec<u8>>,
+        end: Bound<Vec<u8>>,
+    ) -> impl Iterator<Item = (Box<[u8]>, Box<[u8]>)> + '_;
+
+    /// Apply all operations atomically, either all of them are written or none
+    fn write_batch(&self, batch: Vec<BatchOp>);
+}
+
+/// Returns the Bound on a range query for a given prefix
+///
+/// That is the smallest x such that, prefix + y < x for all y. If
+/// no such x exists (i.e. prefix = vec![255; N]; for some N) it returns Bound::Unbounded
+pub fn prefix_end_bound(mut prefix: Vec<u8>) -> Bound<Vec<u8>> {
+    loop {
+        let last = prefix.last_mut();
+
+        match last {
+            None => return Bound::Unbounded,
+            Some(last) => {
+                if *last != 255 {
+                    *last += 1;
+                    return Bound::Excluded(prefix);
+                }
+                prefix.pop();
+            }
+        }
+    }
+}
+
+/// Whether there is no key between `start` and `end`
+pub(crate) fn is_empty_range(start: &Bound<Vec<u8>>, end: &Bound<Vec<u8>>) -> bool {
+    match (start, end) {
+        (Bound::Included(start), Bound::Included(end)) => start > end,
+        (Bound::Included(start), Bound::Excluded(end))
+        | (Bound::Excluded(start), Bound::Included(end)) => start >= end,
+        // the only key after `start` is `start` followed by zero byte
+        (Bound::Excluded(start), Bound::Excluded(end)) => {
+            start >= end || [start.as_slice(), &[0]].concat() == *end
+        }
+        (Bound::Unbounded, _) | (_, Bound::Unbounded) => false,
+    }
 }
 
 /// Builder for database
@@ -4,7 +4,7 @@ use std::{
     sync::{Arc, RwLock},
 };
 
-use crate::Database;
+use crate::{is_empty_range, prefix_end_bound, BatchOp, Database};
 
 /// Database which stores data in memory
 #[derive(Debug, Clone)]
@@ -25,6 +25,24 @@ impl MemDB {
             store: Arc::new(RwLock::new(BTreeMap::new())),
         }
     }
+
+    /// Copy of pairs in range, lock isn't held by returned iterators
+    fn range_pairs(
+        &self,
+        start: Bound<Vec<u8>>,
+        end: Bound<Vec<u8>>,
+    ) -> Vec<(Box<[u8]>, Box<[u8]>)> {
+        if is_empty_range(&start, &end) {
+            return Vec::new();
+        }
+
+        self.store
+            .read()
+            .expect("poisoned lock")
+            .range((start, end))
+            .map(|(k, v)| (k.clone().into_boxed_slice(), v.clone().into_boxed_slice()))
+            .collect()
+    }
 }
 
 impl Database for MemDB {
@@ -39,6 +57,10 @@ impl Database for MemDB {
             .insert(key, value);
     }
 
+    fn delete(&self, key: &[u8]) {
+        self.store.write().expect("poisoned lock").remove(key);
+    }
+
     fn iterator(&self) -> impl Iterator<Item = (Box<[u8]>, Box<[u8]>)> + '_ {
         self.store
             .read()
@@ -70,24 +92,33 @@ impl Database for MemDB {
 
         pairs.into_iter()
     }
-}
 
-/// Returns the Bound on a range query for a given prefix
-///
-/// That is the smallest x such that, prefix + y < x for all y. If
-/// no such x exists (i.e. prefix = vec![255; N]; for some N) it returns Bound::Unbounded
-fn prefix_end_bound(mut prefix: Vec<u8>) -> Bound<Vec<u8>> {
-    loop {
-        let last = prefix.last_mut();
-
-        match last {
-            None => return Bound::Unbounded,
-            Some(last) => {
-                if *last != 255 {
-                    *last += 1;
-                    return Bound::Excluded(prefix);
+    fn range_iterator(
+        &self,
+        start: Bound<Vec<u8>>,
+        end: Bound<Vec<u8>>,
+    ) -> impl Iterator<Item = (Box<[u8]>, Box<[u8]>)> + '_ {
+        self.range_pairs(start, end).into_iter()
+    }
+
+    fn rev_range_iterator(
+        &self,
+        start: Bound<Vec<u8>>,
+        end: Bound<Vec<u8>>,
+    ) -> impl Iterator<Item = (Box<[u8]>, Box<[u8]>)> + '_ {
+        self.range_pairs(start, end).into_iter().rev()
+    }
+
+    fn write_batch(&self, batch: Vec<BatchOp>) {
+        let mut store = self.store.write().expect("poisoned lock");
+        for op in batch {
+            match op {
+                BatchOp::Put(key, value) => {
+                    store.insert(key, value);
+                }
+                BatchOp::Delete(key) => {
+                    store.remove(&key);
                 }
-                prefix.pop();
             }
         }
     }
@@ -114,6 +145,19 @@ mod tests {
         assert!(got_pairs.iter().all(|e| { expected_pairs.contains(e) }));
     }
 
+    #[test]
+    fn delete_works() {
+        let db = MemDB::new();
+        db.put(vec![1], vec![1]);
+        db.put(vec![2], vec![2]);
+
+        db.delete(&[1]);
+        db.delete(&[3]);
+
+        assert_eq!(db.get(&[1]), None);
+        assert_eq!(db.get(&[2]), Some(vec![2]));
+    }
+
     #[test]
     fn prefix_iterator_works() {
         let db = MemDB::new();
@@ -132,4 +176,56 @@ mod tests {
         assert_eq!(expected_pairs.len(), got_pairs.len());
         assert!(got_pairs.iter().all(|e| { expected_pairs.contains(e) }));
     }
+
+    #[test]
+    fn range_iterator_works() {
+        let db = MemDB::new();
+        db.put(vec![1], vec![1]);
+        db.put(vec![2], vec![2]);
+        db.put(vec![2, 1], vec![3]);
+        db.put(vec![3], vec![4]);
+
+        let keys = |pairs: Vec<(Box<[u8]>, Box<[u8]>)>| {
+            pairs
+                .into_iter()
+                .map(|(k, _)| k.into_vec())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(
+            keys(
+                db.range_iterator(Bound::Excluded(vec![1]), Bound::Included(vec![3]))
+                    .collect()
+            ),
+            vec![vec![2], vec![2, 1], vec![3]]
+        );
+        assert_eq!(
+            keys(
+                db.rev_range_iterator(Bound::Included(vec![2]), Bound::Excluded(vec![3]))
+                    .collect()
+            ),
+            vec![vec![2, 1], vec![2]]
+        );
+        assert_eq!(
+            keys(
+                db.range_iterator(Bound::Excluded(vec![2]), Bound::Excluded(vec![2, 0]))
+                    .collect()
+            ),
+            Vec::<Vec<u8>>::new()
+        );
+    }
+
+    #[test]
+    fn write_batch_works() {
+        let db = MemDB::new();
+        db.put(vec![1], vec![1]);
+
+        db.write_batch(vec![
+            BatchOp::Put(vec![2], vec![2]),
+            BatchOp::Delete(vec![1]),
+        ]);
+
+        assert_eq!(db.get(&[1]), None);
+        assert_eq!(db.get(&[2]), Some(vec![2]));
+    }
 }
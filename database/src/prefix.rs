@@ -1,8 +1,8 @@
 //! Prefixed database
 
-use std::sync::Arc;
+use std::{ops::Bound, sync::Arc};
 
-use crate::Database;
+use crate::{prefix_end_bound, BatchOp, Database};
 
 /// Struct to automatically add prefix to any key passed to db
 #[derive(Debug, Clone)]
@@ -16,6 +16,30 @@ impl<T: Database> PrefixDB<T> {
     pub fn new(db: Arc<T>, prefix: Vec<u8>) -> Self {
         PrefixDB { db, prefix }
     }
+
+    fn prefixed_key(&self, key: &[u8]) -> Vec<u8> {
+        [&self.prefix, key].concat()
+    }
+
+    /// Bounds of range in underlying database, unbounded ends are limited by own prefix
+    fn prefixed_bounds(
+        &self,
+        start: Bound<Vec<u8>>,
+        end: Bound<Vec<u8>>,
+    ) -> (Bound<Vec<u8>>, Bound<Vec<u8>>) {
+        let start = match start {
+            Bound::Included(start) => Bound::Included(self.prefixed_key(&start)),
+            Bound::Excluded(start) => Bound::Excluded(self.prefixed_key(&start)),
+            Bound::Unbounded => Bound::Included(self.prefix.clone()),
+        };
+        let end = match end {
+            Bound::Included(end) => Bound::Included(self.prefixed_key(&end)),
+            Bound::Excluded(end) => Bound::Excluded(self.prefixed_key(&end)),
+            Bound::Unbounded => prefix_end_bound(self.prefix.clone()),
+        };
+
+        (start, end)
+    }
 }
 impl<T: Database> Database for PrefixDB<T> {
     fn get(&self, key: &[u8]) -> Option<Vec<u8>> {
@@ -28,6 +52,11 @@ impl<T: Database> Database for PrefixDB<T> {
         self.db.put(key, value)
     }
 
+    fn delete(&self, key: &[u8]) {
+        let key = [&self.prefix, key].concat();
+        self.db.delete(&key)
+    }
+
     fn iterator(&self) -> impl Iterator<Item = (Box<[u8]>, Box<[u8]>)> + '_ {
         let prefix_length = self.prefix.len();
 
@@ -43,14 +72,55 @@ impl<T: Database> Database for PrefixDB<T> {
         &self,
         prefix: Vec<u8>,
     ) -> impl Iterator<Item = (Box<[u8]>, Box<[u8]>)> + '_ {
+        // Keys keep the requested prefix as other databases do, only own prefix is stripped
+        let prefix_length = self.prefix.len();
         let prefix = [self.prefix.clone(), prefix].concat();
-        let prefix_length = prefix.len();
 
         self.db.prefix_iterator(prefix).map(move |(k, v)| {
             let key = k[prefix_length..].to_vec();
             (key.into_boxed_slice(), v)
         })
     }
+
+    fn range_iterator(
+        &self,
+        start: Bound<Vec<u8>>,
+        end: Bound<Vec<u8>>,
+    ) -> impl Iterator<Item = (Box<[u8]>, Box<[u8]>)> + '_ {
+        let prefix_length = self.prefix.len();
+        let (start, end) = self.prefixed_bounds(start, end);
+
+        self.db.range_iterator(start, end).map(move |(k, v)| {
+            let key = k[prefix_length..].to_vec();
+            (key.into_boxed_slice(), v)
+        })
+    }
+
+    fn rev_range_iterator(
+        &self,
+        start: Bound<Vec<u8>>,
+        end: Bound<Vec<u8>>,
+    ) -> impl Iterator<Item = (Box<[u8]>, Box<[u8]>)> + '_ {
+        let prefix_length = self.prefix.len();
+        let (start, end) = self.prefixed_bounds(start, end);
+
+        self.db.rev_range_iterator(start, end).map(move |(k, v)| {
+            let key = k[prefix_length..].to_vec();
+            (key.into_boxed_slice(), v)
+        })
+    }
+
+    fn write_batch(&self, batch: Vec<BatchOp>) {
+        self.db.write_batch(
+            batch
+                .into_iter()
+                .map(|op| match op {
+                    BatchOp::Put(key, value) => BatchOp::Put(self.prefixed_key(&key), value),
+                    BatchOp::Delete(key) => BatchOp::Delete(self.prefixed_key(&key)),
+                })
+                .collect(),
+        )
+    }
 }
 
 #[cfg(test)]
@@ -113,11 +183,33 @@ mod tests {
         let got_pairs: Vec<(Box<[u8]>, Box<[u8]>)> = prefix_db.prefix_iterator(vec![2]).collect();
 
         let expected_pairs: Vec<(Box<[u8]>, Box<[u8]>)> = vec![
-            (vec![3].into_boxed_slice(), vec![2].into_boxed_slice()),
-            (vec![4].into_boxed_slice(), vec![6].into_boxed_slice()),
+            (vec![2, 3].into_boxed_slice(), vec![2].into_boxed_slice()),
+            (vec![2, 4].into_boxed_slice(), vec![6].into_boxed_slice()),
         ];
 
         assert_eq!(expected_pairs.len(), got_pairs.len());
         assert!(got_pairs.iter().all(|e| { expected_pairs.contains(e) }));
     }
+
+    #[test]
+    fn range_iterator_works() {
+        let db = MemDB::new();
+        db.put(vec![1, 1], vec![1]);
+        db.put(vec![2, 1], vec![2]);
+        db.put(vec![2, 2], vec![3]);
+        db.put(vec![3, 1], vec![4]);
+
+        let prefix_db = PrefixDB::new(Arc::new(db), vec![2]);
+
+        let got_pairs: Vec<(Box<[u8]>, Box<[u8]>)> = prefix_db
+            .rev_range_iterator(Bound::Unbounded, Bound::Unbounded)
+            .collect();
+
+        let expected_pairs: Vec<(Box<[u8]>, Box<[u8]>)> = vec![
+            (vec![2].into_boxed_slice(), vec![3].into_boxed_slice()),
+            (vec![1].into_boxed_slice(), vec![2].into_boxed_slice()),
+        ];
+
+        assert_eq!(expected_pairs, got_pairs);
+    }
 }
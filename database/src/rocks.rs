@@ -1,9 +1,9 @@
 //! RocksDB database implementation
 
-use crate::{error::DatabaseError, DBBuilder, Database, DatabaseBuilder};
-use std::{path::Path, sync::Arc};
+use crate::{error::DatabaseError, is_empty_range, BatchOp, DBBuilder, Database, DatabaseBuilder};
+use std::{ops::Bound, path::Path, sync::Arc};
 
-use rocksdb::{DBWithThreadMode, SingleThreaded};
+use rocksdb::{DBWithThreadMode, IteratorMode, ReadOptions, SingleThreaded, WriteBatch};
 
 impl DatabaseBuilder<RocksDB> for DBBuilder {
     type Err = DatabaseError;
@@ -31,6 +31,43 @@ impl RocksDB {
             db: Arc::new(rocksdb::DB::open_default(path)?),
         })
     }
+
+    /// Iterate in range starting from `mode`. Bounds are set on rocksdb iterator,
+    /// so it seeks to the start and doesn't read keys out of range
+    fn bounded_iterator(
+        &self,
+        start: Bound<Vec<u8>>,
+        end: Bound<Vec<u8>>,
+        mode: IteratorMode<'_>,
+    ) -> impl Iterator<Item = (Box<[u8]>, Box<[u8]>)> + '_ {
+        let is_empty = is_empty_range(&start, &end);
+
+        // rocksdb bounds are inclusive lower and exclusive upper, the next key
+        // after `key` is `key` followed by zero byte
+        let mut options = ReadOptions::default();
+        match start {
+            Bound::Included(start) => options.set_iterate_lower_bound(start),
+            Bound::Excluded(mut start) => {
+                start.push(0);
+                options.set_iterate_lower_bound(start)
+            }
+            Bound::Unbounded => (),
+        }
+        match end {
+            Bound::Included(mut end) => {
+                end.push(0);
+                options.set_iterate_upper_bound(end)
+            }
+            Bound::Excluded(end) => options.set_iterate_upper_bound(end),
+            Bound::Unbounded => (),
+        }
+
+        (!is_empty)
+            .then(|| self.db.iterator_opt(mode, options))
+            .into_iter()
+            .flatten()
+            .map(|res| res.unwrap_or_else(|e| panic!("unrecoverable database error {}", e)))
+    }
 }
 
 impl Database for RocksDB {
@@ -46,6 +83,12 @@ impl Database for RocksDB {
             .unwrap_or_else(|e| panic!("unrecoverable database error {}", e))
     }
 
+    fn delete(&self, key: &[u8]) {
+        self.db
+            .delete(key)
+            .unwrap_or_else(|e| panic!("unrecoverable database error {}", e))
+    }
+
     fn iterator(&self) -> impl Iterator<Item = (Box<[u8]>, Box<[u8]>)> + '_ {
         self.db
             .iterator(rocksdb::IteratorMode::Start)
@@ -61,6 +104,36 @@ impl Database for RocksDB {
             .map(|res| res.unwrap_or_else(|e| panic!("unrecoverable database error {}", e)))
             .take_while(move |(k, _)| k.starts_with(&prefix)) //rocks db returns keys beyond the prefix see https://github.com/rust-rocksdb/rust-rocksdb/issues/577
     }
+
+    fn range_iterator(
+        &self,
+        start: Bound<Vec<u8>>,
+        end: Bound<Vec<u8>>,
+    ) -> impl Iterator<Item = (Box<[u8]>, Box<[u8]>)> + '_ {
+        self.bounded_iterator(start, end, IteratorMode::Start)
+    }
+
+    fn rev_range_iterator(
+        &self,
+        start: Bound<Vec<u8>>,
+        end: Bound<Vec<u8>>,
+    ) -> impl Iterator<Item = (Box<[u8]>, Box<[u8]>)> + '_ {
+        self.bounded_iterator(start, end, IteratorMode::End)
+    }
+
+    fn write_batch(&self, batch: Vec<BatchOp>) {
+        let mut write_batch = WriteBatch::default();
+        for op in batch {
+            match op {
+                BatchOp::Put(key, value) => write_batch.put(key, value),
+                BatchOp::Delete(key) => write_batch.delete(key),
+            }
+        }
+
+        self.db
+            .write(write_batch)
+            .unwrap_or_else(|e| panic!("unrecoverable database error {}", e))
+    }
 }
 
 #[cfg(test)]
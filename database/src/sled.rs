@@ -1,8 +1,10 @@
 //! sled database implementation
 
+use std::ops::Bound;
+
 use extensions::corruption::UnwrapCorrupt;
 
-use crate::{error::DatabaseError, DBBuilder, Database, DatabaseBuilder};
+use crate::{error::DatabaseError, is_empty_range, BatchOp, DBBuilder, Database, DatabaseBuilder};
 
 impl DatabaseBuilder<SledDb> for DBBuilder {
     type Err = DatabaseError;
@@ -21,6 +23,11 @@ impl SledDb {
     pub fn new<P: AsRef<std::path::Path>>(path: P) -> Result<Self, DatabaseError> {
         Ok(Self(::sled::open(path.as_ref())?))
     }
+
+    /// Iterator over range, `None` if range doesn't contain any key
+    fn range(&self, start: Bound<Vec<u8>>, end: Bound<Vec<u8>>) -> Option<::sled::Iter> {
+        (!is_empty_range(&start, &end)).then(|| self.0.range((start, end)))
+    }
 }
 
 impl Database for SledDb {
@@ -35,6 +42,10 @@ impl Database for SledDb {
         let _ = self.0.insert(key, value).unwrap_or_corrupt();
     }
 
+    fn delete(&self, key: &[u8]) {
+        let _ = self.0.remove(key).unwrap_or_corrupt();
+    }
+
     fn iterator(&self) -> impl Iterator<Item = (Box<[u8]>, Box<[u8]>)> + '_ {
         self.0
             .iter()
@@ -61,4 +72,50 @@ impl Database for SledDb {
                 )
             })
     }
+
+    fn range_iterator(
+        &self,
+        start: Bound<Vec<u8>>,
+        end: Bound<Vec<u8>>,
+    ) -> impl Iterator<Item = (Box<[u8]>, Box<[u8]>)> + '_ {
+        self.range(start, end)
+            .into_iter()
+            .flatten()
+            .map(|this| this.unwrap_or_corrupt())
+            .map(|(key, value)| {
+                (
+                    key.to_vec().into_boxed_slice(),
+                    value.to_vec().into_boxed_slice(),
+                )
+            })
+    }
+
+    fn rev_range_iterator(
+        &self,
+        start: Bound<Vec<u8>>,
+        end: Bound<Vec<u8>>,
+    ) -> impl Iterator<Item = (Box<[u8]>, Box<[u8]>)> + '_ {
+        self.range(start, end)
+            .into_iter()
+            .flat_map(|range| range.rev())
+            .map(|this| this.unwrap_or_corrupt())
+            .map(|(key, value)| {
+                (
+                    key.to_vec().into_boxed_slice(),
+                    value.to_vec().into_boxed_slice(),
+                )
+            })
+    }
+
+    fn write_batch(&self, batch: Vec<BatchOp>) {
+        let mut sled_batch = ::sled::Batch::default();
+        for op in batch {
+            match op {
+                BatchOp::Put(key, value) => sled_batch.insert(key, value),
+                BatchOp::Delete(key) => sled_batch.remove(key),
+            }
+        }
+
+        self.0.apply_batch(sled_batch).unwrap_or_corrupt();
+    }
 }
@@ -6,11 +6,23 @@ use crate::{
     store_keys::{GaiaParamsStoreKey, GaiaStoreKey},
     GaiaNodeQueryRequest, GaiaNodeQueryResponse,
 };
+use crisis::{abci_handler::CrisisAbciHandler, keeper::CrisisKeeper};
+use gears::store::upgrade::StoreUpgrades;
 use gears::tendermint::types::request::query::RequestQuery;
 use gears::types::tx::raw::TxWithRaw;
-use gears::{application::handlers::node::ABCIHandler, x::ante::BaseAnteHandler};
+use gears::x::module::Module;
+use gears::{
+    application::handlers::node::ABCIHandler,
+    x::ante::{BaseAnteHandler, TxAnteHandler},
+};
 use gears::{application::handlers::node::ModuleInfo, context::init::InitContext};
-use gears::{application::handlers::node::TxError, config::Config};
+use gears::{
+    application::handlers::node::TxError, commands::node::run::NodeRuntime, config::Config,
+};
+use gears::{
+    application::handlers::node::{InvariantError, InvariantRoute},
+    context::QueryableContext,
+};
 use gears::{baseapp::errors::QueryError, context::query::QueryContext};
 use gears::{baseapp::BaseAppParamsKeeper, store::database::Database};
 use gears::{context::tx::TxContext, x::ante::DefaultSignGasConsumer};
@@ -20,7 +32,11 @@ use gov::{
     keeper::GovKeeper,
     proposal::{Proposals, ProposalsHandler},
 };
-use upgrade::{dummy::NullUpgradeHandler, keeper::UpgradeKeeper};
+use mint::{abci_handler::MintAbciHandler, keeper::MintKeeper};
+use staking::CreateValidator;
+use std::collections::HashMap;
+use strum::IntoEnumIterator;
+use upgrade::{abci_handler::UpgradeAbciHandler, dummy::NullUpgradeHandler, keeper::UpgradeKeeper};
 
 #[derive(Debug, Clone)]
 struct BankModuleInfo;
@@ -51,7 +67,66 @@ impl ModuleInfo for GovModuleInfo {
 }
 
 #[derive(Debug, Clone)]
-pub struct GaiaABCIHandler {
+struct CrisisModuleInfo;
+
+impl ModuleInfo for CrisisModuleInfo {
+    const NAME: &'static str = "crisis";
+}
+
+#[derive(Debug, Clone)]
+struct UpgradeModuleInfo;
+
+impl ModuleInfo for UpgradeModuleInfo {
+    const NAME: &'static str = "upgrade";
+}
+
+#[derive(Debug, Clone)]
+struct DistributionModuleInfo;
+
+impl ModuleInfo for DistributionModuleInfo {
+    const NAME: &'static str = "distribution";
+}
+
+#[derive(Debug, Clone)]
+struct SlashingModuleInfo;
+
+impl ModuleInfo for SlashingModuleInfo {
+    const NAME: &'static str = "slashing";
+}
+
+#[derive(Debug, Clone)]
+struct EvidenceModuleInfo;
+
+impl ModuleInfo for EvidenceModuleInfo {
+    const NAME: &'static str = "evidence";
+}
+
+#[derive(Debug, Clone)]
+struct MintModuleInfo;
+
+impl ModuleInfo for MintModuleInfo {
+    const NAME: &'static str = "mint";
+}
+
+/// Ante handler with default chain of decorators
+pub type GaiaAnteHandler = BaseAnteHandler<
+    bank::Keeper<
+        GaiaStoreKey,
+        GaiaParamsStoreKey,
+        auth::Keeper<GaiaStoreKey, GaiaParamsStoreKey, GaiaModules>,
+        GaiaModules,
+    >,
+    auth::Keeper<GaiaStoreKey, GaiaParamsStoreKey, GaiaModules>,
+    GaiaStoreKey,
+    DefaultSignGasConsumer,
+    GaiaModules,
+>;
+
+#[derive(Debug, Clone)]
+pub struct GaiaABCIHandler<AH = GaiaAnteHandler>
+where
+    AH: TxAnteHandler<GaiaStoreKey, Message> + TxAnteHandler<GaiaStoreKey, CreateValidator>,
+{
     bank_abci_handler: bank::BankABCIHandler<
         GaiaStoreKey,
         GaiaParamsStoreKey,
@@ -79,18 +154,7 @@ pub struct GaiaABCIHandler {
         StakingModuleInfo,
     >,
     ibc_abci_handler: ibc_rs::ABCIHandler<GaiaStoreKey, GaiaParamsStoreKey, IbcModuleInfo>,
-    ante_handler: BaseAnteHandler<
-        bank::Keeper<
-            GaiaStoreKey,
-            GaiaParamsStoreKey,
-            auth::Keeper<GaiaStoreKey, GaiaParamsStoreKey, GaiaModules>,
-            GaiaModules,
-        >,
-        auth::Keeper<GaiaStoreKey, GaiaParamsStoreKey, GaiaModules>,
-        GaiaStoreKey,
-        DefaultSignGasConsumer,
-        GaiaModules,
-    >,
+    ante_handler: AH,
     genutil_handler: GenutilAbciHandler<
         GaiaStoreKey,
         GaiaParamsStoreKey,
@@ -107,7 +171,7 @@ pub struct GaiaABCIHandler {
             GaiaModules,
         >,
         GaiaModules,
-        DefaultSignGasConsumer,
+        AH,
     >,
     gov_handler: GovAbciHandler<
         GaiaStoreKey,
@@ -144,13 +208,219 @@ pub struct GaiaABCIHandler {
             BaseAppParamsKeeper<GaiaParamsStoreKey>,
             GaiaXmodules,
             NullUpgradeHandler, // Note: this is actual handler for upgrade handling. Not upgrade proposal
+            distribution::Keeper<
+                GaiaStoreKey,
+                GaiaParamsStoreKey,
+                auth::Keeper<GaiaStoreKey, GaiaParamsStoreKey, GaiaModules>,
+                bank::Keeper<
+                    GaiaStoreKey,
+                    GaiaParamsStoreKey,
+                    auth::Keeper<GaiaStoreKey, GaiaParamsStoreKey, GaiaModules>,
+                    GaiaModules,
+                >,
+                staking::Keeper<
+                    GaiaStoreKey,
+                    GaiaParamsStoreKey,
+                    auth::Keeper<GaiaStoreKey, GaiaParamsStoreKey, GaiaModules>,
+                    bank::Keeper<
+                        GaiaStoreKey,
+                        GaiaParamsStoreKey,
+                        auth::Keeper<GaiaStoreKey, GaiaParamsStoreKey, GaiaModules>,
+                        GaiaModules,
+                    >,
+                    staking::MockHookKeeper<
+                        GaiaStoreKey,
+                        auth::Keeper<GaiaStoreKey, GaiaParamsStoreKey, GaiaModules>,
+                        GaiaModules,
+                    >,
+                    GaiaModules,
+                >,
+                GaiaModules,
+            >,
         >,
         GovModuleInfo,
     >,
+    crisis_handler: CrisisAbciHandler<
+        GaiaStoreKey,
+        GaiaParamsStoreKey,
+        bank::Keeper<
+            GaiaStoreKey,
+            GaiaParamsStoreKey,
+            auth::Keeper<GaiaStoreKey, GaiaParamsStoreKey, GaiaModules>,
+            GaiaModules,
+        >,
+        GaiaModules,
+        CrisisModuleInfo,
+    >,
+    upgrade_handler: UpgradeAbciHandler<
+        GaiaStoreKey,
+        GaiaParamsStoreKey,
+        GaiaXmodules,
+        NullUpgradeHandler,
+        UpgradeModuleInfo,
+    >,
+    mint_handler: MintAbciHandler<
+        GaiaStoreKey,
+        GaiaParamsStoreKey,
+        bank::Keeper<
+            GaiaStoreKey,
+            GaiaParamsStoreKey,
+            auth::Keeper<GaiaStoreKey, GaiaParamsStoreKey, GaiaModules>,
+            GaiaModules,
+        >,
+        staking::Keeper<
+            GaiaStoreKey,
+            GaiaParamsStoreKey,
+            auth::Keeper<GaiaStoreKey, GaiaParamsStoreKey, GaiaModules>,
+            bank::Keeper<
+                GaiaStoreKey,
+                GaiaParamsStoreKey,
+                auth::Keeper<GaiaStoreKey, GaiaParamsStoreKey, GaiaModules>,
+                GaiaModules,
+            >,
+            staking::MockHookKeeper<
+                GaiaStoreKey,
+                auth::Keeper<GaiaStoreKey, GaiaParamsStoreKey, GaiaModules>,
+                GaiaModules,
+            >,
+            GaiaModules,
+        >,
+        GaiaModules,
+        MintModuleInfo,
+    >,
+    distribution_handler: distribution::ABCIHandler<
+        GaiaStoreKey,
+        GaiaParamsStoreKey,
+        auth::Keeper<GaiaStoreKey, GaiaParamsStoreKey, GaiaModules>,
+        bank::Keeper<
+            GaiaStoreKey,
+            GaiaParamsStoreKey,
+            auth::Keeper<GaiaStoreKey, GaiaParamsStoreKey, GaiaModules>,
+            GaiaModules,
+        >,
+        staking::Keeper<
+            GaiaStoreKey,
+            GaiaParamsStoreKey,
+            auth::Keeper<GaiaStoreKey, GaiaParamsStoreKey, GaiaModules>,
+            bank::Keeper<
+                GaiaStoreKey,
+                GaiaParamsStoreKey,
+                auth::Keeper<GaiaStoreKey, GaiaParamsStoreKey, GaiaModules>,
+                GaiaModules,
+            >,
+            staking::MockHookKeeper<
+                GaiaStoreKey,
+                auth::Keeper<GaiaStoreKey, GaiaParamsStoreKey, GaiaModules>,
+                GaiaModules,
+            >,
+            GaiaModules,
+        >,
+        GaiaModules,
+    >,
+    slashing_handler: slashing::ABCIHandler<
+        GaiaStoreKey,
+        GaiaParamsStoreKey,
+        staking::Keeper<
+            GaiaStoreKey,
+            GaiaParamsStoreKey,
+            auth::Keeper<GaiaStoreKey, GaiaParamsStoreKey, GaiaModules>,
+            bank::Keeper<
+                GaiaStoreKey,
+                GaiaParamsStoreKey,
+                auth::Keeper<GaiaStoreKey, GaiaParamsStoreKey, GaiaModules>,
+                GaiaModules,
+            >,
+            staking::MockHookKeeper<
+                GaiaStoreKey,
+                auth::Keeper<GaiaStoreKey, GaiaParamsStoreKey, GaiaModules>,
+                GaiaModules,
+            >,
+            GaiaModules,
+        >,
+        GaiaModules,
+    >,
+    evidence_handler: evidence::ABCIHandler<
+        GaiaStoreKey,
+        staking::Keeper<
+            GaiaStoreKey,
+            GaiaParamsStoreKey,
+            auth::Keeper<GaiaStoreKey, GaiaParamsStoreKey, GaiaModules>,
+            bank::Keeper<
+                GaiaStoreKey,
+                GaiaParamsStoreKey,
+                auth::Keeper<GaiaStoreKey, GaiaParamsStoreKey, GaiaModules>,
+                GaiaModules,
+            >,
+            staking::MockHookKeeper<
+                GaiaStoreKey,
+                auth::Keeper<GaiaStoreKey, GaiaParamsStoreKey, GaiaModules>,
+                GaiaModules,
+            >,
+            GaiaModules,
+        >,
+        slashing::Keeper<
+            GaiaStoreKey,
+            GaiaParamsStoreKey,
+            staking::Keeper<
+                GaiaStoreKey,
+                GaiaParamsStoreKey,
+                auth::Keeper<GaiaStoreKey, GaiaParamsStoreKey, GaiaModules>,
+                bank::Keeper<
+                    GaiaStoreKey,
+                    GaiaParamsStoreKey,
+                    auth::Keeper<GaiaStoreKey, GaiaParamsStoreKey, GaiaModules>,
+                    GaiaModules,
+                >,
+                staking::MockHookKeeper<
+                    GaiaStoreKey,
+                    auth::Keeper<GaiaStoreKey, GaiaParamsStoreKey, GaiaModules>,
+                    GaiaModules,
+                >,
+                GaiaModules,
+            >,
+            GaiaModules,
+        >,
+        evidence::RawEquivocation,
+        GaiaModules,
+    >,
 }
 
 impl GaiaABCIHandler {
-    pub fn new(_cfg: Config<AppConfig>) -> GaiaABCIHandler {
+    pub fn new(cfg: Config<AppConfig>, runtime: NodeRuntime) -> GaiaABCIHandler {
+        let ante_handler = BaseAnteHandler::new(
+            auth::Keeper::new(
+                GaiaStoreKey::Auth,
+                GaiaParamsStoreKey::Auth,
+                GaiaModules::FeeCollector,
+            ),
+            bank::Keeper::new(
+                GaiaStoreKey::Bank,
+                GaiaParamsStoreKey::Bank,
+                auth::Keeper::new(
+                    GaiaStoreKey::Auth,
+                    GaiaParamsStoreKey::Auth,
+                    GaiaModules::FeeCollector,
+                ),
+            ),
+            DefaultSignGasConsumer,
+            GaiaModules::FeeCollector,
+        );
+
+        Self::with_ante_handler(cfg, runtime, ante_handler)
+    }
+}
+
+impl<AH> GaiaABCIHandler<AH>
+where
+    AH: TxAnteHandler<GaiaStoreKey, Message> + TxAnteHandler<GaiaStoreKey, CreateValidator>,
+{
+    /// Create handler which runs ante checks of txs with `ante_handler`,
+    /// e.g. one built with [gears::x::ante::AnteHandlerBuilder]
+    pub fn with_ante_handler(
+        _cfg: Config<AppConfig>,
+        runtime: NodeRuntime,
+        ante_handler: AH,
+    ) -> Self {
         let auth_keeper = auth::Keeper::new(
             GaiaStoreKey::Auth,
             GaiaParamsStoreKey::Auth,
@@ -183,11 +453,41 @@ impl GaiaABCIHandler {
         );
 
         let ibc_keeper = ibc_rs::keeper::Keeper::new(GaiaStoreKey::IBC, GaiaParamsStoreKey::IBC);
-        let ante_handler = BaseAnteHandler::new(
+        let crisis_keeper = CrisisKeeper::new(
+            GaiaParamsStoreKey::Crisis,
+            bank_keeper.clone(),
+            GaiaModules::FeeCollector,
+        );
+
+        let upgrade_keeper = UpgradeKeeper::<GaiaStoreKey, GaiaXmodules, NullUpgradeHandler>::new(
+            GaiaStoreKey::Upgrade,
+            runtime.home,
+            runtime.unsafe_skip_upgrades,
+        );
+
+        let mint_keeper = MintKeeper::new(
+            GaiaStoreKey::Mint,
+            staking_keeper.clone(),
+            bank_keeper.clone(),
+            GaiaModules::Mint,
+            GaiaModules::FeeCollector,
+        );
+
+        let blocked_addrs = GaiaModules::iter()
+            .filter(|module| *module != GaiaModules::Distribution)
+            .map(|module| (module.address().to_string(), true))
+            .collect::<HashMap<_, _>>();
+
+        let distribution_keeper = distribution::Keeper::new(
+            GaiaStoreKey::Distribution,
+            GaiaParamsStoreKey::Distribution,
             auth_keeper.clone(),
             bank_keeper.clone(),
-            DefaultSignGasConsumer,
+            staking_keeper.clone(),
             GaiaModules::FeeCollector,
+            GaiaModules::Distribution,
+            GaiaModules::Gov,
+            blocked_addrs,
         );
 
         let gov_keeper = GovKeeper::new(
@@ -203,11 +503,21 @@ impl GaiaABCIHandler {
                 BaseAppParamsKeeper<GaiaParamsStoreKey>,
                 GaiaXmodules,
                 NullUpgradeHandler,
-            >::new(UpgradeKeeper::<
-                GaiaStoreKey,
-                GaiaXmodules,
-                NullUpgradeHandler,
-            >::new(GaiaStoreKey::Upgrade, [])),
+                _,
+            >::new(upgrade_keeper.clone(), distribution_keeper.clone()),
+        );
+
+        let slashing_keeper = slashing::Keeper::new(
+            GaiaStoreKey::Slashing,
+            GaiaParamsStoreKey::Slashing,
+            staking_keeper.clone(),
+        );
+
+        let evidence_keeper = evidence::Keeper::new(
+            GaiaStoreKey::Evidence,
+            staking_keeper.clone(),
+            slashing_keeper.clone(),
+            None,
         );
 
         GaiaABCIHandler {
@@ -218,11 +528,20 @@ impl GaiaABCIHandler {
             ibc_abci_handler: ibc_rs::ABCIHandler::new(ibc_keeper.clone()),
             ante_handler,
             gov_handler: GovAbciHandler::new(gov_keeper),
+            crisis_handler: CrisisAbciHandler::new(crisis_keeper),
+            upgrade_handler: UpgradeAbciHandler::new(upgrade_keeper),
+            mint_handler: MintAbciHandler::new(mint_keeper, GaiaParamsStoreKey::Mint),
+            distribution_handler: distribution::ABCIHandler::new(distribution_keeper),
+            slashing_handler: slashing::ABCIHandler::new(slashing_keeper),
+            evidence_handler: evidence::ABCIHandler::new(evidence_keeper),
         }
     }
 }
 
-impl ABCIHandler for GaiaABCIHandler {
+impl<AH> ABCIHandler for GaiaABCIHandler<AH>
+where
+    AH: TxAnteHandler<GaiaStoreKey, Message> + TxAnteHandler<GaiaStoreKey, CreateValidator>,
+{
     type Message = Message;
     type Genesis = GenesisState;
     type StoreKey = GaiaStoreKey;
@@ -238,15 +557,58 @@ impl ABCIHandler for GaiaABCIHandler {
             Message::Bank(msg) => self.bank_abci_handler.msg(ctx, msg),
             Message::Staking(msg) => self.staking_abci_handler.msg(ctx, msg),
             Message::IBC(msg) => self.ibc_abci_handler.msg(ctx, msg.clone()),
+            Message::Crisis(msg) => self.crisis_handler.msg(ctx, msg, self),
+            Message::Gov(msg) => self.gov_handler.msg::<_, Message>(ctx, msg),
+            Message::Distribution(msg) => self
+                .distribution_handler
+                .tx(ctx, msg)
+                .map_err(|e| e.into::<DistributionModuleInfo>()),
+            Message::Slashing(msg) => self
+                .slashing_handler
+                .tx(ctx, msg)
+                .map_err(|e| e.into::<SlashingModuleInfo>()),
+            Message::Evidence(msg) => self
+                .evidence_handler
+                .tx(ctx, msg)
+                .map_err(|e| e.into::<EvidenceModuleInfo>()),
         }
     }
 
+    fn invariant_routes(&self) -> Vec<InvariantRoute> {
+        let mut routes = self.bank_abci_handler.invariant_routes();
+        routes.extend(self.staking_abci_handler.invariant_routes());
+        routes.extend(
+            self.distribution_handler
+                .invariant_routes::<DistributionModuleInfo>(),
+        );
+        routes
+    }
+
+    fn check_invariant<DB: Database, CTX: QueryableContext<DB, Self::StoreKey>>(
+        &self,
+        ctx: &CTX,
+        route: &InvariantRoute,
+    ) -> Result<(), InvariantError> {
+        match route.module {
+            BankModuleInfo::NAME => self.bank_abci_handler.check_invariant(ctx, route),
+            StakingModuleInfo::NAME => self.staking_abci_handler.check_invariant(ctx, route),
+            DistributionModuleInfo::NAME => self.distribution_handler.check_invariant(ctx, route),
+            _ => Err(InvariantError::NotRegistered),
+        }
+    }
+
+    fn store_upgrades(&self, plan_name: &str) -> StoreUpgrades {
+        self.upgrade_handler.store_upgrades(plan_name)
+    }
+
     fn begin_block<DB: Database>(
         &self,
         ctx: &mut gears::context::block::BlockContext<'_, DB, Self::StoreKey>,
         request: gears::tendermint::request::RequestBeginBlock,
     ) {
-        // TODO: need to add call to upgrade begin_block here
+        // NOTE: mint, distribution, slashing and evidence begin blockers stay disabled until
+        // staking hooks of distribution and slashing are registered in staking keeper
+        self.upgrade_handler.begin_block(ctx, request.clone());
         self.staking_abci_handler.begin_block(ctx, request);
     }
 
@@ -255,7 +617,7 @@ impl ABCIHandler for GaiaABCIHandler {
         ctx: &mut gears::context::block::BlockContext<'_, DB, Self::StoreKey>,
         request: gears::tendermint::request::RequestEndBlock,
     ) -> Vec<gears::tendermint::types::proto::validator::ValidatorUpdate> {
-        self.gov_handler.end_block(ctx, request.clone());
+        self.gov_handler.end_block(ctx, request.clone(), self);
         self.staking_abci_handler.end_block(ctx, request)
     }
 
@@ -269,6 +631,16 @@ impl ABCIHandler for GaiaABCIHandler {
         self.gov_handler.init_genesis(ctx, genesis.gov);
         self.ibc_abci_handler.genesis(ctx, genesis.ibc);
         self.auth_abci_handler.init_genesis(ctx, genesis.auth);
+        if let Some(crisis) = genesis.crisis {
+            self.crisis_handler.init_genesis(ctx, crisis);
+        }
+        self.mint_handler.init_genesis(ctx, genesis.mint);
+        if let Some(distribution) = genesis.distribution {
+            self.distribution_handler.genesis(ctx, distribution);
+        }
+        // slashing genesis stores public keys of validators so it runs after staking genesis
+        self.slashing_handler.genesis(ctx, genesis.slashing);
+        self.evidence_handler.genesis(ctx, genesis.evidence);
         let genutil_updates = self.genutil_handler.init_genesis(ctx, genesis.genutil);
 
         match (genutil_updates.is_empty(), staking_updates.is_empty()) {
@@ -294,6 +666,24 @@ impl ABCIHandler for GaiaABCIHandler {
             self.staking_abci_handler.query(ctx, query)
         } else if query.path.starts_with("/ibc.core.client") {
             self.ibc_abci_handler.query(ctx, query)
+        } else if query.path.starts_with("/cosmos.gov") {
+            self.gov_handler.query(ctx, query)
+        } else if query.path.starts_with("/cosmos.upgrade") {
+            self.upgrade_handler.query(ctx, query)
+        } else if query.path.starts_with("/cosmos.mint") {
+            self.mint_handler.query(ctx, query)
+        } else if query.path.starts_with("/cosmos.distribution") {
+            self.distribution_handler
+                .query(ctx, query)
+                .map(|res| res.into())
+        } else if query.path.starts_with("/cosmos.slashing") {
+            self.slashing_handler
+                .query(ctx, query)
+                .map(|res| res.into())
+        } else if query.path.starts_with("/cosmos.evidence") {
+            self.evidence_handler
+                .query(ctx, query)
+                .map(|res| res.into())
         } else {
             Err(QueryError::PathNotFound)
         }
@@ -305,7 +695,8 @@ impl ABCIHandler for GaiaABCIHandler {
         tx: &TxWithRaw<Message>,
         is_check: bool,
     ) -> Result<(), TxError> {
-        self.ante_handler.run(
+        TxAnteHandler::<GaiaStoreKey, Message>::run(
+            &self.ante_handler,
             ctx,
             tx,
             is_check,
@@ -329,20 +720,12 @@ impl ABCIHandler for GaiaABCIHandler {
             GaiaNodeQueryRequest::Staking(req) => {
                 GaiaNodeQueryResponse::Staking(self.staking_abci_handler.typed_query(ctx, req))
             }
-            // TODO: replace handler
-            GaiaNodeQueryRequest::Slashing(_req) => GaiaNodeQueryResponse::Slashing(
-                slashing::SlashingNodeQueryResponse::Params(slashing::QueryParamsResponse {
-                    params: slashing::SlashingParams::default(),
-                }),
-            ),
-            // TODO: replace handler
-            GaiaNodeQueryRequest::Distribution(_req) => GaiaNodeQueryResponse::Distribution(
-                distribution::DistributionNodeQueryResponse::Params(
-                    distribution::QueryParamsResponse {
-                        params: distribution::DistributionParams::default(),
-                    },
-                ),
-            ),
+            GaiaNodeQueryRequest::Slashing(req) => {
+                GaiaNodeQueryResponse::Slashing(self.slashing_handler.typed_query(ctx, req))
+            }
+            GaiaNodeQueryRequest::Distribution(req) => {
+                GaiaNodeQueryResponse::Distribution(self.distribution_handler.typed_query(ctx, req))
+            }
         }
     }
 }
@@ -5,18 +5,37 @@ use bank::cli::{
     tx::{run_bank_tx_command, BankTxCli},
 };
 use clap::{Args, Subcommand};
+use crisis::client::cli::tx::{run_crisis_tx_command, CrisisTxCli};
+use distribution::cli::{
+    query::DistributionQueryCli,
+    tx::{run_staking_tx_command as run_distribution_tx_command, DistributionTxCli},
+};
+use evidence::cli::{
+    query::EvidenceQueryCli,
+    tx::{run_tx_command as run_evidence_tx_command, EvidenceTxCli},
+};
 use gears::{
     commands::client::tx::ClientTxContext,
     types::{address::AccAddress, tx::Messages},
 };
+use gov::client::{
+    cli::{query::GovQueryCli, tx::GovTxCli},
+    tx_handler::run_gov_tx_command,
+};
 use ibc_rs::client::cli::{
     query::IbcQueryCli,
     tx::{run_ibc_tx_command, IbcTxCli},
 };
+use mint::client::cli::query::MintQueryCli;
+use slashing::cli::{
+    query::SlashingQueryCli,
+    tx::{run_staking_tx_command as run_slashing_tx_command, SlashingTxCli},
+};
 use staking::cli::{
     query::StakingQueryCli,
     tx::{run_staking_tx_command, StakingTxCli},
 };
+use upgrade::client::cli::query::UpgradeQueryCli;
 
 use crate::message::Message;
 
@@ -34,10 +53,20 @@ pub enum GaiaTxCommands {
     Staking(StakingTxCli),
     /// IBC transaction subcommands
     IBC(IbcTxCli),
+    /// Crisis transaction subcommands
+    Crisis(CrisisTxCli),
+    /// Distribution transaction subcommands
+    Distribution(DistributionTxCli),
+    /// Governance transaction subcommands
+    Gov(GovTxCli),
+    /// Slashing transaction subcommands
+    Slashing(SlashingTxCli),
+    /// Evidence transaction subcommands
+    Evidence(EvidenceTxCli),
 }
 
 pub fn tx_command_handler(
-    _ctx: &ClientTxContext,
+    ctx: &ClientTxContext,
     command: GaiaTxCommands,
     from_address: AccAddress,
 ) -> Result<Messages<Message>> {
@@ -51,6 +80,29 @@ pub fn tx_command_handler(
         GaiaTxCommands::IBC(args) => run_ibc_tx_command(args, from_address)
             .map(Message::IBC)
             .map(Into::into),
+        GaiaTxCommands::Crisis(args) => run_crisis_tx_command(args, from_address)
+            .map(Message::Crisis)
+            .map(Into::into),
+        GaiaTxCommands::Distribution(args) => {
+            Ok(run_distribution_tx_command(ctx, args, from_address)?
+                .into_msgs()
+                .into_iter()
+                .map(Message::Distribution)
+                .collect::<Vec<_>>()
+                .try_into()?)
+        }
+        GaiaTxCommands::Gov(args) => run_gov_tx_command(args, from_address)
+            .map(Message::Gov)
+            .map(Into::into),
+        GaiaTxCommands::Slashing(args) => run_slashing_tx_command(args, from_address)
+            .map(Message::Slashing)
+            .map(Into::into),
+        GaiaTxCommands::Evidence(args) => Ok(run_evidence_tx_command(args, from_address)?
+            .into_msgs()
+            .into_iter()
+            .map(Message::Evidence)
+            .collect::<Vec<_>>()
+            .try_into()?),
     }
 }
 
@@ -64,6 +116,18 @@ pub enum GaiaQueryCommands {
     Staking(StakingQueryCli),
     /// Querying commands for the ibc module
     Ibc(IbcQueryCli),
+    /// Querying commands for the distribution module
+    Distribution(DistributionQueryCli),
+    /// Querying commands for the gov module
+    Gov(GovQueryCli),
+    /// Querying commands for the slashing module
+    Slashing(SlashingQueryCli),
+    /// Querying commands for the evidence module
+    Evidence(EvidenceQueryCli),
+    /// Querying commands for the mint module
+    Mint(MintQueryCli),
+    /// Querying commands for the upgrade module
+    Upgrade(UpgradeQueryCli),
 }
 
 /// Wraps `GaiaTxCommands`. This structure exists to satisfy interface needs of TxHandler
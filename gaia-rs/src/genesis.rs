@@ -1,13 +1,19 @@
 use auth::GenesisState as AuthGenesis;
 use bank::GenesisState as BankGenesis;
+use crisis::genesis::CrisisGenesis;
+use distribution::GenesisState as DistributionGenesis;
+use evidence::{GenesisState as EvidenceGenesis, RawEquivocation};
 use gears::{
     baseapp::genesis::GenesisError,
+    params::gas::schedule::GasSchedule,
     types::{address::AccAddress, base::coins::UnsignedCoins},
 };
 use genutil::genesis::GenutilGenesis;
 use gov::{genesis::GovGenesisState, proposal::Proposals};
 use ibc_rs::GenesisState as IBCGenesis;
+use mint::genesis::MintGenesis;
 use serde::{Deserialize, Serialize};
+use slashing::GenesisState as SlashingGenesis;
 use staking::GenesisState as StakingGenesis;
 
 use crate::store_keys::GaiaParamsStoreKey;
@@ -21,6 +27,21 @@ pub struct GenesisState {
     pub ibc: IBCGenesis,
     pub genutil: GenutilGenesis,
     pub gov: GovGenesisState<Proposals<GaiaParamsStoreKey>>,
+    /// Optional so genesis files created before the crisis module keep working
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub crisis: Option<CrisisGenesis>,
+    #[serde(default)]
+    pub mint: MintGenesis,
+    /// Optional because the fee pool of the module can't be empty
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub distribution: Option<DistributionGenesis>,
+    #[serde(default)]
+    pub slashing: SlashingGenesis,
+    #[serde(default)]
+    pub evidence: EvidenceGenesis<RawEquivocation>,
+    /// Overrides default gas costs of stores
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gas_schedule: Option<GasSchedule>,
 }
 
 impl gears::baseapp::genesis::Genesis for GenesisState {
@@ -32,4 +53,8 @@ impl gears::baseapp::genesis::Genesis for GenesisState {
         self.bank.add_genesis_account(address.clone(), coins);
         self.auth.add_genesis_account(address)
     }
+
+    fn gas_schedule(&self) -> Option<GasSchedule> {
+        self.gas_schedule
+    }
 }
@@ -17,7 +17,11 @@ use bank::{
 };
 use clap::Subcommand;
 use client::{tx_command_handler, GaiaQueryCommands, WrappedGaiaQueryCommands};
-use distribution::{DistributionNodeQueryRequest, DistributionNodeQueryResponse};
+use distribution::{
+    cli::query::DistributionQueryHandler, DistributionNodeQueryRequest,
+    DistributionNodeQueryResponse,
+};
+use evidence::cli::query::EvidenceQueryHandler;
 use gears::{
     application::{
         client::Client,
@@ -36,18 +40,22 @@ use gears::{
     },
     core::Protobuf,
     crypto::public::PublicKey,
-    grpc::{health::health_server, tx::tx_server},
     rest::RestState,
     types::{address::AccAddress, tx::Messages},
 };
+use gov::{client::GovClientHandler, proposal::Proposals};
 use ibc_rs::client::cli::query::IbcQueryHandler;
+use mint::client::cli::MintClientHandler;
 use rest::get_router;
 use serde::Serialize;
-use slashing::{SlashingNodeQueryRequest, SlashingNodeQueryResponse};
+use slashing::{
+    cli::query::SlashingQueryHandler, SlashingNodeQueryRequest, SlashingNodeQueryResponse,
+};
 use staking::{cli::query::StakingQueryHandler, StakingNodeQueryRequest, StakingNodeQueryResponse};
 use tonic::transport::Server;
 use tonic::Status;
 use tower_layer::Identity;
+use upgrade::client::cli::UpgradeClientHandler;
 
 pub mod abci_handler;
 pub mod client;
@@ -111,6 +119,25 @@ impl QueryHandler for GaiaCoreClient {
             GaiaQueryCommands::Ibc(command) => {
                 Self::QueryRequest::Ibc(IbcQueryHandler.prepare_query_request(command)?)
             }
+            GaiaQueryCommands::Distribution(command) => Self::QueryRequest::Distribution(
+                DistributionQueryHandler.prepare_query_request(command)?,
+            ),
+            GaiaQueryCommands::Gov(command) => Self::QueryRequest::Gov(
+                GovClientHandler::<Proposals<GaiaParamsStoreKey>>::default()
+                    .prepare_query_request(command)?,
+            ),
+            GaiaQueryCommands::Slashing(command) => {
+                Self::QueryRequest::Slashing(SlashingQueryHandler.prepare_query_request(command)?)
+            }
+            GaiaQueryCommands::Evidence(command) => {
+                Self::QueryRequest::Evidence(EvidenceQueryHandler.prepare_query_request(command)?)
+            }
+            GaiaQueryCommands::Mint(command) => {
+                Self::QueryRequest::Mint(MintClientHandler.prepare_query_request(command)?)
+            }
+            GaiaQueryCommands::Upgrade(command) => {
+                Self::QueryRequest::Upgrade(UpgradeClientHandler.prepare_query_request(command)?)
+            }
         };
 
         Ok(res)
@@ -134,6 +161,25 @@ impl QueryHandler for GaiaCoreClient {
             GaiaQueryCommands::Ibc(command) => {
                 Self::QueryResponse::Ibc(IbcQueryHandler.handle_raw_response(query_bytes, command)?)
             }
+            GaiaQueryCommands::Distribution(command) => Self::QueryResponse::Distribution(
+                DistributionQueryHandler.handle_raw_response(query_bytes, command)?,
+            ),
+            GaiaQueryCommands::Gov(command) => Self::QueryResponse::Gov(
+                GovClientHandler::<Proposals<GaiaParamsStoreKey>>::default()
+                    .handle_raw_response(query_bytes, command)?,
+            ),
+            GaiaQueryCommands::Slashing(command) => Self::QueryResponse::Slashing(
+                SlashingQueryHandler.handle_raw_response(query_bytes, command)?,
+            ),
+            GaiaQueryCommands::Evidence(command) => Self::QueryResponse::Evidence(
+                EvidenceQueryHandler.handle_raw_response(query_bytes, command)?,
+            ),
+            GaiaQueryCommands::Mint(command) => Self::QueryResponse::Mint(
+                MintClientHandler.handle_raw_response(query_bytes, command)?,
+            ),
+            GaiaQueryCommands::Upgrade(command) => Self::QueryResponse::Upgrade(
+                UpgradeClientHandler.handle_raw_response(query_bytes, command)?,
+            ),
         };
 
         Ok(res)
@@ -351,9 +397,9 @@ impl RouterBuilder<GaiaNodeQueryRequest, GaiaNodeQueryResponse> for GaiaCore {
             .add_service(reflection_service)
             .add_service(staking::grpc::new(app.clone()))
             .add_service(auth::grpc::new(app.clone()))
+            .add_service(distribution::grpc::new(app.clone()))
+            .add_service(slashing::grpc::new(app.clone()))
             .add_service(bank::grpc::new(app))
-            .add_service(health_server())
-            .add_service(tx_server())
     }
 }
 
@@ -5,7 +5,6 @@ fn compile_check() {
     compile_error!("Can't use `sled` and `rocksdb` at one time. Chose only one DB")
 }
 
-use clap::Parser;
 use gaia_rs::abci_handler::GaiaABCIHandler;
 use gaia_rs::client::{GaiaQueryCommands, GaiaTxArgs};
 use gaia_rs::store_keys::GaiaParamsStoreKey;
@@ -29,7 +28,7 @@ type Args = CliApplicationArgs<
 >;
 
 fn main() -> anyhow::Result<()> {
-    let args = Args::parse();
+    let args = Args::parse_with_prefixes()?;
 
     args.execute_or_help(
         |command| {
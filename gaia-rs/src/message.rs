@@ -15,8 +15,18 @@ pub enum Message {
     Bank(bank::Message),
     #[msg(url(string = "/cosmos.staking.v1beta1"))]
     Staking(staking::Message),
+    #[msg(url(string = "/cosmos.crisis.v1beta1"))]
+    Crisis(crisis::message::Message),
     #[msg(url(string = "/ibc.core.client.v1"))]
     IBC(ibc_rs::message::Message),
+    #[msg(url(string = "/cosmos.distribution.v1beta1"))]
+    Distribution(distribution::Message),
+    #[msg(url(string = "/cosmos.gov.v1"))]
+    Gov(gov::msg::GovMsg),
+    #[msg(url(string = "/cosmos.slashing.v1beta1"))]
+    Slashing(slashing::Message),
+    #[msg(url(string = "/cosmos.evidence.v1beta1"))]
+    Evidence(evidence::Message),
 }
 
 impl ValueRenderer for Message {
@@ -24,7 +34,12 @@ impl ValueRenderer for Message {
         match self {
             Message::Bank(msg) => msg.format(get_metadata),
             Message::Staking(_) => Err(RenderError::NotImplemented),
+            Message::Crisis(_) => Err(RenderError::NotImplemented),
             Message::IBC(_) => Err(RenderError::NotImplemented),
+            Message::Distribution(_) => Err(RenderError::NotImplemented),
+            Message::Gov(msg) => msg.format(get_metadata),
+            Message::Slashing(_) => Err(RenderError::NotImplemented),
+            Message::Evidence(_) => Err(RenderError::NotImplemented),
         }
     }
 }
@@ -6,6 +6,8 @@ pub enum GaiaModules {
     BondedPool,
     NotBondedPool,
     Gov,
+    Mint,
+    Distribution,
 }
 
 impl Module for GaiaModules {
@@ -15,6 +17,8 @@ impl Module for GaiaModules {
             GaiaModules::BondedPool => staking::BONDED_POOL_NAME.into(),
             GaiaModules::NotBondedPool => staking::NOT_BONDED_POOL_NAME.into(),
             GaiaModules::Gov => "gov".into(),
+            GaiaModules::Mint => "mint".into(),
+            GaiaModules::Distribution => "distribution".into(),
         }
     }
 
@@ -24,6 +28,8 @@ impl Module for GaiaModules {
             GaiaModules::BondedPool => vec!["burner".into(), "staking".into()],
             GaiaModules::NotBondedPool => vec!["burner".into(), "staking".into()],
             GaiaModules::Gov => vec!["burner".into()],
+            GaiaModules::Mint => vec!["minter".into()],
+            GaiaModules::Distribution => Vec::new(),
         }
     }
 }
@@ -61,4 +67,12 @@ impl upgrade::Module for GaiaXmodules {
             GaiaXmodules::Staking => "staking",
         }
     }
+
+    fn consensus_version(&self) -> u64 {
+        match self {
+            GaiaXmodules::Auth => 1,
+            GaiaXmodules::Bank => bank::migrations::CONSENSUS_VERSION,
+            GaiaXmodules::Staking => 1,
+        }
+    }
 }
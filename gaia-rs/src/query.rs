@@ -1,16 +1,33 @@
 use auth::cli::query::{AuthQuery, AuthQueryResponse};
 use bank::cli::query::{BankQuery, BankQueryResponse};
+use distribution::cli::query::{DistributionQueryRequest, DistributionQueryResponse};
+use evidence::cli::query::{EvidenceQueryRequest, EvidenceQueryResponse};
 use gears::{baseapp::Query, derive::Query};
+use gov::{
+    proposal::Proposals,
+    query::{GovQuery, GovQueryResponse},
+};
 use ibc_rs::client::cli::query::{IbcQuery, IbcQueryResponse};
+use mint::types::query::{request::MintQueryRequest, response::MintQueryResponse};
 use serde::{Deserialize, Serialize};
+use slashing::cli::query::{SlashingQueryRequest, SlashingQueryResponse};
 use staking::cli::query::{StakingQuery, StakingQueryResponse};
+use upgrade::types::query::{UpgradeQueryRequest, UpgradeQueryResponse};
 
-#[derive(Clone, Debug, PartialEq)]
+use crate::store_keys::GaiaParamsStoreKey;
+
+#[derive(Clone, Debug)]
 pub enum GaiaQuery {
     Auth(AuthQuery),
     Bank(BankQuery),
     Staking(StakingQuery),
     Ibc(IbcQuery),
+    Distribution(DistributionQueryRequest),
+    Gov(GovQuery),
+    Slashing(SlashingQueryRequest),
+    Evidence(EvidenceQueryRequest),
+    Mint(MintQueryRequest),
+    Upgrade(UpgradeQueryRequest),
 }
 
 impl Query for GaiaQuery {
@@ -20,6 +37,12 @@ impl Query for GaiaQuery {
             GaiaQuery::Bank(var) => var.query_url(),
             GaiaQuery::Staking(var) => var.query_url(),
             GaiaQuery::Ibc(var) => var.query_url(),
+            GaiaQuery::Distribution(var) => var.query_url(),
+            GaiaQuery::Gov(var) => var.query_url(),
+            GaiaQuery::Slashing(var) => var.query_url(),
+            GaiaQuery::Evidence(var) => var.query_url(),
+            GaiaQuery::Mint(var) => var.query_url(),
+            GaiaQuery::Upgrade(var) => var.query_url(),
         }
     }
 
@@ -29,6 +52,12 @@ impl Query for GaiaQuery {
             GaiaQuery::Bank(var) => var.into_bytes(),
             GaiaQuery::Staking(var) => var.into_bytes(),
             GaiaQuery::Ibc(var) => var.into_bytes(),
+            GaiaQuery::Distribution(var) => var.into_bytes(),
+            GaiaQuery::Gov(var) => var.into_bytes(),
+            GaiaQuery::Slashing(var) => var.into_bytes(),
+            GaiaQuery::Evidence(var) => var.into_bytes(),
+            GaiaQuery::Mint(var) => var.into_bytes(),
+            GaiaQuery::Upgrade(var) => var.into_bytes(),
         }
     }
 }
@@ -40,4 +69,10 @@ pub enum GaiaQueryResponse {
     Bank(BankQueryResponse),
     Staking(StakingQueryResponse),
     Ibc(IbcQueryResponse),
+    Distribution(DistributionQueryResponse),
+    Gov(GovQueryResponse<Proposals<GaiaParamsStoreKey>>),
+    Slashing(SlashingQueryResponse),
+    Evidence(EvidenceQueryResponse),
+    Mint(MintQueryResponse),
+    Upgrade(UpgradeQueryResponse),
 }
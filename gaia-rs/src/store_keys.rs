@@ -21,6 +21,14 @@ pub enum GaiaStoreKey {
     Gov,
     #[skey(to_string = "upgrade")]
     Upgrade,
+    #[skey(to_string = "mint")]
+    Mint,
+    #[skey(to_string = "distribution")]
+    Distribution,
+    #[skey(to_string = "slashing")]
+    Slashing,
+    #[skey(to_string = "evidence")]
+    Evidence,
 }
 
 #[derive(EnumIter, Debug, PartialEq, Eq, Hash, Clone, ParamsKeys, Deserialize, Serialize)]
@@ -39,4 +47,12 @@ pub enum GaiaParamsStoreKey {
     Capability,
     #[pkey(to_string = "gov/")]
     Gov,
+    #[pkey(to_string = "crisis/")]
+    Crisis,
+    #[pkey(to_string = "mint/")]
+    Mint,
+    #[pkey(to_string = "distribution/")]
+    Distribution,
+    #[pkey(to_string = "slashing/")]
+    Slashing,
 }
@@ -0,0 +1,232 @@
+use distribution::{MsgCommunityPoolSpend, MsgCommunityPoolSpendRaw, MsgFundCommunityPool};
+use gaia_rs::{message::Message, modules::GaiaModules};
+use gears::{
+    tendermint::types::time::timestamp::Timestamp,
+    types::{address::AccAddress, decimal256::Decimal256, uint::Uint256},
+    utils::node::generate_tx,
+    x::module::Module,
+};
+use gov::{
+    msg::{
+        v1::MsgSubmitProposal,
+        vote::{Vote, VoteOption},
+        GovMsg,
+    },
+    proposal::community_pool::CommunityPoolSpendProposal,
+    types::proposal::ProposalStatus,
+};
+
+use crate::{
+    balance, community_pool, proposal, setup_mock_node, GaiaNode, AFTER_VOTING_PERIOD, GOV_GENESIS,
+    USER_0, USER_1,
+};
+
+const RECIPIENT: &str = "cosmos180tr8wmsk8ugt32yynj8efqwg3yglmpwp22rut";
+
+/// Funds community pool with `1000stake` and runs proposal which spends `amount` of community pool
+/// to `recipient` through all its periods
+fn spend_by_proposal(recipient: AccAddress, amount: &str) -> GaiaNode {
+    let (mut node, _) = setup_mock_node(Some(GOV_GENESIS));
+    let user_0 = crate::user(3, USER_0);
+    let user_1 = crate::user(4, USER_1);
+
+    node.step(vec![], Timestamp::UNIX_EPOCH);
+
+    let fund = Message::Distribution(distribution::Message::FundCommunityPool(
+        MsgFundCommunityPool {
+            amount: "1000stake".parse().expect("hardcoded is valid"),
+            depositor: user_1.address(),
+        },
+    ));
+    let spend = Message::Distribution(distribution::Message::CommunityPoolSpend(
+        MsgCommunityPoolSpend {
+            authority: GaiaModules::Gov.address(),
+            recipient,
+            amount: amount.parse().expect("hardcoded is valid"),
+        },
+    ));
+    let submit = Message::Gov(GovMsg::ProposalV1(MsgSubmitProposal {
+        messages: vec![spend.into()],
+        initial_deposit: "10000000uatom".parse().expect("hardcoded is valid"),
+        proposer: user_1.address(),
+        metadata: String::new(),
+        title: "Community pool spend".to_owned(),
+        summary: "Spend stake of community pool".to_owned(),
+        expedited: false,
+    }));
+
+    let step_response = node.step(
+        vec![
+            generate_tx(vec1::vec1![fund], 0, &user_1, node.chain_id().clone()),
+            generate_tx(vec1::vec1![submit], 1, &user_1, node.chain_id().clone()),
+        ],
+        Timestamp::UNIX_EPOCH,
+    );
+    for tx in step_response.tx_responses {
+        assert!(tx.code == 0, "{}", tx.log);
+    }
+    assert_eq!(
+        community_pool(&node, "stake"),
+        Decimal256::from_atomics(1000_u16, 0).expect("hardcoded is valid")
+    );
+
+    let vote = Message::Gov(GovMsg::Vote(Vote {
+        proposal_id: 1,
+        voter: user_0.address(),
+        option: VoteOption::Yes,
+    }));
+    let step_response = node.step(
+        vec![generate_tx(
+            vec1::vec1![vote],
+            1,
+            &user_0,
+            node.chain_id().clone(),
+        )],
+        Timestamp::UNIX_EPOCH,
+    );
+    assert!(
+        step_response.tx_responses[0].code == 0,
+        "{}",
+        step_response.tx_responses[0].log
+    );
+
+    node.step(
+        vec![],
+        Timestamp::try_new(AFTER_VOTING_PERIOD, 0).expect("hardcoded is valid"),
+    );
+
+    node
+}
+
+#[test]
+/// Passed proposal moves coins from community pool to recipient
+fn community_pool_spend() {
+    let recipient: AccAddress = RECIPIENT.parse().expect("hardcoded is valid");
+
+    let node = spend_by_proposal(recipient.clone(), "400stake");
+
+    let proposal = proposal(&node, 1);
+    assert_eq!(
+        proposal.status,
+        ProposalStatus::Passed,
+        "{}",
+        proposal.failed_reason
+    );
+    assert_eq!(
+        community_pool(&node, "stake"),
+        Decimal256::from_atomics(600_u16, 0).expect("hardcoded is valid")
+    );
+    assert_eq!(balance(&node, &recipient, "stake"), Uint256::from(400_u16));
+}
+
+#[test]
+/// Spend of more coins than community pool has fails proposal and leaves community pool unchanged
+fn community_pool_spend_insufficient_funds() {
+    let recipient: AccAddress = RECIPIENT.parse().expect("hardcoded is valid");
+
+    let node = spend_by_proposal(recipient.clone(), "2000stake");
+
+    let proposal = proposal(&node, 1);
+    assert_eq!(proposal.status, ProposalStatus::Failed);
+    assert!(
+        proposal
+            .failed_reason
+            .contains("community pool does not have sufficient coins to distribute"),
+        "{}",
+        proposal.failed_reason
+    );
+    assert_eq!(
+        community_pool(&node, "stake"),
+        Decimal256::from_atomics(1000_u16, 0).expect("hardcoded is valid")
+    );
+    assert_eq!(balance(&node, &recipient, "stake"), Uint256::zero());
+}
+
+#[test]
+/// Spend to module account which is not allowed to receive funds fails proposal
+fn community_pool_spend_blocked_recipient() {
+    let recipient = GaiaModules::FeeCollector.address();
+
+    let node = spend_by_proposal(recipient.clone(), "400stake");
+
+    let proposal = proposal(&node, 1);
+    assert_eq!(proposal.status, ProposalStatus::Failed);
+    assert!(
+        proposal
+            .failed_reason
+            .contains("is not allowed to receive external funds"),
+        "{}",
+        proposal.failed_reason
+    );
+    assert_eq!(
+        community_pool(&node, "stake"),
+        Decimal256::from_atomics(1000_u16, 0).expect("hardcoded is valid")
+    );
+    assert_eq!(balance(&node, &recipient, "stake"), Uint256::zero());
+}
+
+#[test]
+/// Only gov module account is allowed to spend community pool
+fn community_pool_spend_invalid_authority() {
+    let (mut node, _) = setup_mock_node(Some(GOV_GENESIS));
+    let user_1 = crate::user(4, USER_1);
+    let recipient: AccAddress = RECIPIENT.parse().expect("hardcoded is valid");
+
+    node.step(vec![], Timestamp::UNIX_EPOCH);
+
+    let fund = Message::Distribution(distribution::Message::FundCommunityPool(
+        MsgFundCommunityPool {
+            amount: "1000stake".parse().expect("hardcoded is valid"),
+            depositor: user_1.address(),
+        },
+    ));
+    let spend = Message::Distribution(distribution::Message::CommunityPoolSpend(
+        MsgCommunityPoolSpend {
+            authority: user_1.address(),
+            recipient: recipient.clone(),
+            amount: "400stake".parse().expect("hardcoded is valid"),
+        },
+    ));
+
+    let step_response = node.step(
+        vec![
+            generate_tx(vec1::vec1![fund], 0, &user_1, node.chain_id().clone()),
+            generate_tx(vec1::vec1![spend], 1, &user_1, node.chain_id().clone()),
+        ],
+        Timestamp::UNIX_EPOCH,
+    );
+
+    assert!(step_response.tx_responses[0].code == 0);
+    assert!(step_response.tx_responses[1].code != 0);
+    assert!(
+        step_response.tx_responses[1]
+            .log
+            .contains("invalid authority"),
+        "{}",
+        step_response.tx_responses[1].log
+    );
+    assert_eq!(
+        community_pool(&node, "stake"),
+        Decimal256::from_atomics(1000_u16, 0).expect("hardcoded is valid")
+    );
+    assert_eq!(balance(&node, &recipient, "stake"), Uint256::zero());
+}
+
+#[test]
+/// Neither spend message nor legacy spend proposal could be created without coins to spend
+fn community_pool_spend_empty_amount() {
+    let msg = MsgCommunityPoolSpend::try_from(MsgCommunityPoolSpendRaw {
+        authority: GaiaModules::Gov.address().to_string(),
+        recipient: RECIPIENT.to_owned(),
+        amount: Vec::new(),
+    });
+    assert!(msg.is_err());
+
+    let proposal = serde_json::from_value::<CommunityPoolSpendProposal>(serde_json::json!({
+        "title": "Community pool spend",
+        "description": "Spend nothing",
+        "recipient": RECIPIENT,
+        "amount": [],
+    }));
+    assert!(proposal.is_err());
+}
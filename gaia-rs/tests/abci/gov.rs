@@ -0,0 +1,329 @@
+use distribution::MsgFundCommunityPool;
+use gaia_rs::{message::Message, modules::GaiaModules};
+use gears::{
+    core::any::google::Any,
+    tendermint::types::{response::deliver_tx::ResponseDeliverTx, time::timestamp::Timestamp},
+    types::{address::AccAddress, decimal256::Decimal256, msg::send::MsgSend, uint::Uint256},
+    utils::node::{generate_tx, User},
+    x::module::Module,
+};
+use gov::{
+    msg::{
+        v1::{MsgExecLegacyContent, MsgSubmitProposal},
+        vote::{Vote, VoteOption},
+        GovMsg,
+    },
+    proposal::community_pool::CommunityPoolSpendProposal,
+    types::proposal::ProposalStatus,
+};
+
+use crate::{
+    balance, community_pool, proposal, setup_mock_node, GaiaNode, AFTER_VOTING_PERIOD, GOV_GENESIS,
+    USER_0, USER_1,
+};
+
+const RECIPIENT: &str = "cosmos180tr8wmsk8ugt32yynj8efqwg3yglmpwp22rut";
+// Expedited voting period by default plus a second
+const AFTER_EXPEDITED_VOTING_PERIOD: i64 = 86_401;
+
+/// Starts node where gov module account holds `500stake` and community pool holds `1000stake`.
+/// Returns validator and user who funded both accounts.
+fn setup_gov_node() -> (GaiaNode, User, User) {
+    let (mut node, _) = setup_mock_node(Some(GOV_GENESIS));
+    let user_0 = crate::user(3, USER_0);
+    let user_1 = crate::user(4, USER_1);
+
+    node.step(vec![], Timestamp::UNIX_EPOCH);
+
+    let fund_gov = Message::Bank(bank::Message::Send(MsgSend {
+        from_address: user_1.address(),
+        to_address: GaiaModules::Gov.address(),
+        amount: "500stake".parse().expect("hardcoded is valid"),
+    }));
+    let fund_community_pool = Message::Distribution(distribution::Message::FundCommunityPool(
+        MsgFundCommunityPool {
+            amount: "1000stake".parse().expect("hardcoded is valid"),
+            depositor: user_1.address(),
+        },
+    ));
+
+    let step_response = node.step(
+        vec![
+            generate_tx(vec1::vec1![fund_gov], 0, &user_1, node.chain_id().clone()),
+            generate_tx(
+                vec1::vec1![fund_community_pool],
+                1,
+                &user_1,
+                node.chain_id().clone(),
+            ),
+        ],
+        Timestamp::UNIX_EPOCH,
+    );
+    for tx in step_response.tx_responses {
+        assert!(tx.code == 0, "{}", tx.log);
+    }
+
+    (node, user_0, user_1)
+}
+
+/// Submits proposal with `messages` and deposit which is enough to start voting
+fn submit_proposal(
+    node: &mut GaiaNode,
+    proposer: &User,
+    messages: Vec<Any>,
+    title: String,
+    expedited: bool,
+) -> ResponseDeliverTx {
+    let initial_deposit = match expedited {
+        true => "50000000uatom",
+        false => "10000000uatom",
+    };
+    let msg = Message::Gov(GovMsg::ProposalV1(MsgSubmitProposal {
+        messages,
+        initial_deposit: initial_deposit.parse().expect("hardcoded is valid"),
+        proposer: proposer.address(),
+        metadata: String::new(),
+        title,
+        summary: "Summary of proposal".to_owned(),
+        expedited,
+    }));
+
+    let mut step_response = node.step(
+        vec![generate_tx(
+            vec1::vec1![msg],
+            2,
+            proposer,
+            node.chain_id().clone(),
+        )],
+        Timestamp::UNIX_EPOCH,
+    );
+
+    step_response.tx_responses.remove(0)
+}
+
+/// Votes on first proposal by the only validator
+fn vote(node: &mut GaiaNode, validator: &User, option: VoteOption) {
+    let msg = Message::Gov(GovMsg::Vote(Vote {
+        proposal_id: 1,
+        voter: validator.address(),
+        option,
+    }));
+
+    let step_response = node.step(
+        vec![generate_tx(
+            vec1::vec1![msg],
+            1,
+            validator,
+            node.chain_id().clone(),
+        )],
+        Timestamp::UNIX_EPOCH,
+    );
+    assert!(
+        step_response.tx_responses[0].code == 0,
+        "{}",
+        step_response.tx_responses[0].log
+    );
+}
+
+fn send_from_gov(amount: &str) -> Any {
+    Message::Bank(bank::Message::Send(MsgSend {
+        from_address: GaiaModules::Gov.address(),
+        to_address: RECIPIENT.parse().expect("hardcoded is valid"),
+        amount: amount.parse().expect("hardcoded is valid"),
+    }))
+    .into()
+}
+
+#[test]
+/// Passed proposal executes message with gov module account as signer
+fn proposal_executes_message() {
+    let (mut node, user_0, user_1) = setup_gov_node();
+    let recipient: AccAddress = RECIPIENT.parse().expect("hardcoded is valid");
+
+    let tx = submit_proposal(
+        &mut node,
+        &user_1,
+        vec![send_from_gov("100stake")],
+        "Send".to_owned(),
+        false,
+    );
+    assert!(tx.code == 0, "{}", tx.log);
+
+    vote(&mut node, &user_0, VoteOption::Yes);
+    node.step(
+        vec![],
+        Timestamp::try_new(AFTER_VOTING_PERIOD, 0).expect("hardcoded is valid"),
+    );
+
+    let proposal = proposal(&node, 1);
+    assert_eq!(
+        proposal.status,
+        ProposalStatus::Passed,
+        "{}",
+        proposal.failed_reason
+    );
+    assert_eq!(balance(&node, &recipient, "stake"), Uint256::from(100_u8));
+    assert_eq!(
+        balance(&node, &GaiaModules::Gov.address(), "stake"),
+        Uint256::from(400_u16)
+    );
+}
+
+#[test]
+/// Proposal with message signed by account other than gov module account can't be submitted
+fn proposal_with_foreign_signer_rejected() {
+    let (mut node, _, user_1) = setup_gov_node();
+
+    let msg = Message::Bank(bank::Message::Send(MsgSend {
+        from_address: user_1.address(),
+        to_address: RECIPIENT.parse().expect("hardcoded is valid"),
+        amount: "100stake".parse().expect("hardcoded is valid"),
+    }));
+
+    let tx = submit_proposal(
+        &mut node,
+        &user_1,
+        vec![msg.into()],
+        "Send".to_owned(),
+        false,
+    );
+    assert!(tx.code != 0);
+    assert!(
+        tx.log
+            .contains("expected gov account as only signer for proposal message"),
+        "{}",
+        tx.log
+    );
+}
+
+#[test]
+/// Proposal title has its own length limit
+fn proposal_with_long_title_rejected() {
+    let (mut node, _, user_1) = setup_gov_node();
+
+    let tx = submit_proposal(
+        &mut node,
+        &user_1,
+        vec![send_from_gov("100stake")],
+        "a".repeat(256),
+        false,
+    );
+    assert!(tx.code != 0);
+    assert!(tx.log.contains("title too long: 256 bytes"), "{}", tx.log);
+}
+
+#[test]
+/// Failed message reverts messages executed before it and fails proposal
+fn proposal_failed_message_reverts_state() {
+    let (mut node, user_0, user_1) = setup_gov_node();
+    let recipient: AccAddress = RECIPIENT.parse().expect("hardcoded is valid");
+
+    let tx = submit_proposal(
+        &mut node,
+        &user_1,
+        vec![send_from_gov("100stake"), send_from_gov("1000stake")],
+        "Send".to_owned(),
+        false,
+    );
+    assert!(tx.code == 0, "{}", tx.log);
+
+    vote(&mut node, &user_0, VoteOption::Yes);
+    node.step(
+        vec![],
+        Timestamp::try_new(AFTER_VOTING_PERIOD, 0).expect("hardcoded is valid"),
+    );
+
+    let proposal = proposal(&node, 1);
+    assert_eq!(proposal.status, ProposalStatus::Failed);
+    assert!(!proposal.failed_reason.is_empty());
+    assert_eq!(balance(&node, &recipient, "stake"), Uint256::zero());
+    assert_eq!(
+        balance(&node, &GaiaModules::Gov.address(), "stake"),
+        Uint256::from(500_u16)
+    );
+}
+
+#[test]
+/// Expedited proposal which didn't pass continues voting as a regular one
+fn expedited_proposal_converted_to_regular() {
+    let (mut node, user_0, user_1) = setup_gov_node();
+
+    let tx = submit_proposal(
+        &mut node,
+        &user_1,
+        vec![send_from_gov("100stake")],
+        "Send".to_owned(),
+        true,
+    );
+    assert!(tx.code == 0, "{}", tx.log);
+
+    vote(&mut node, &user_0, VoteOption::No);
+    node.step(
+        vec![],
+        Timestamp::try_new(AFTER_EXPEDITED_VOTING_PERIOD, 0).expect("hardcoded is valid"),
+    );
+
+    let proposal_after_expedited = proposal(&node, 1);
+    assert_eq!(
+        proposal_after_expedited.status,
+        ProposalStatus::VotingPeriod
+    );
+    assert!(!proposal_after_expedited.expedited);
+    assert_eq!(
+        proposal_after_expedited.voting_end_time,
+        Some(Timestamp::try_new(AFTER_VOTING_PERIOD - 1, 0).expect("hardcoded is valid"))
+    );
+
+    node.step(
+        vec![],
+        Timestamp::try_new(AFTER_VOTING_PERIOD, 0).expect("hardcoded is valid"),
+    );
+
+    assert_eq!(proposal(&node, 1).status, ProposalStatus::Rejected);
+}
+
+#[test]
+/// Legacy proposal content wrapped in `MsgExecLegacyContent` is executed by its handler
+fn proposal_executes_legacy_content() {
+    let (mut node, user_0, user_1) = setup_gov_node();
+    let recipient: AccAddress = RECIPIENT.parse().expect("hardcoded is valid");
+
+    let content = CommunityPoolSpendProposal {
+        title: "Community pool spend".to_owned(),
+        description: "Spend stake of community pool".to_owned(),
+        recipient: recipient.clone(),
+        amount: "400stake".parse().expect("hardcoded is valid"),
+    };
+    let msg = MsgExecLegacyContent {
+        content: content.into(),
+        authority: GaiaModules::Gov.address(),
+    };
+
+    let tx = submit_proposal(
+        &mut node,
+        &user_1,
+        vec![msg.into()],
+        "Legacy spend".to_owned(),
+        false,
+    );
+    assert!(tx.code == 0, "{}", tx.log);
+
+    vote(&mut node, &user_0, VoteOption::Yes);
+    node.step(
+        vec![],
+        Timestamp::try_new(AFTER_VOTING_PERIOD, 0).expect("hardcoded is valid"),
+    );
+
+    let proposal = proposal(&node, 1);
+    assert_eq!(
+        proposal.status,
+        ProposalStatus::Passed,
+        "{}",
+        proposal.failed_reason
+    );
+    assert_eq!(balance(&node, &recipient, "stake"), Uint256::from(400_u16));
+    assert_eq!(
+        community_pool(&node, "stake"),
+        Decimal256::from_atomics(600_u16, 0).expect("hardcoded is valid")
+    );
+}
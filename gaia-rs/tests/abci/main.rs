@@ -1,3 +1,4 @@
+use bank::types::query::{QueryBalanceRequest, QueryBalanceResponse};
 use gaia_rs::abci_handler::GaiaABCIHandler;
 use gaia_rs::config::AppConfig;
 use gaia_rs::genesis::GenesisState;
@@ -6,18 +7,34 @@ use gaia_rs::GaiaApplication;
 use gears::baseapp::genesis::Genesis;
 use gears::baseapp::options::NodeOptions;
 use gears::baseapp::BaseApp;
+use gears::commands::node::run::NodeRuntime;
 use gears::config::Config;
+use gears::core::Protobuf;
 use gears::crypto::keys::ReadAccAddress;
 use gears::store::database::MemDB;
 use gears::tendermint::types::chain_id::ChainId;
 use gears::tendermint::types::consensus::ConsensusParams;
 use gears::tendermint::types::proto::validator::{ValidatorUpdate, VotingPower};
+use gears::tendermint::types::request::query::RequestQuery;
+use gears::tendermint::types::response::ResponseQuery;
 use gears::tendermint::types::time::timestamp::Timestamp;
+use gears::types::address::AccAddress;
+use gears::types::decimal256::Decimal256;
+use gears::types::uint::Uint256;
 use gears::utils::node::{InitState, MockNode, User};
+use gov::proposal::Proposals;
+use gov::query::v1::{QueryProposalRequest, QueryProposalResponse};
+use gov::types::proposal::ProposalModel;
 use keyring::key::pair::KeyPair;
 use std::fs;
 use std::path::Path;
 
+#[cfg(test)]
+mod community_pool;
+#[cfg(test)]
+mod gov;
+#[cfg(test)]
+mod module_queries;
 mod scenario_1;
 mod scenario_2;
 mod scenario_3;
@@ -29,6 +46,11 @@ const USER_0: &str = "race draft rival universe maid cheese steel logic crowd fo
 // cosmos15qzm75pjh0jqsv3u40hzp2vzs2hdp47fkz7j5q
 const USER_1: &str = "unfair live spike near cushion blanket club salad poet cigar venue above north speak harbor salute curve tail appear obvious month end boss priority";
 
+// Genesis of scenario 3 where user 1 also has `stake` coins
+const GOV_GENESIS: &str = "./tests/abci/assets/gov_genesis.json";
+// Voting period of gov genesis plus a second
+const AFTER_VOTING_PERIOD: i64 = 172_801;
+
 // This is a helper function to create a user with a specific account number
 pub fn user(account_number: u64, mnemonic: &str) -> User {
     let mnemonic =
@@ -41,19 +63,17 @@ pub fn user(account_number: u64, mnemonic: &str) -> User {
     }
 }
 
-fn setup_mock_node(
-    genesis_path: Option<impl AsRef<Path>>,
-) -> (
-    MockNode<BaseApp<MemDB, GaiaParamsStoreKey, GaiaABCIHandler, GaiaApplication>, GenesisState>,
-    User,
-) {
+type GaiaNode =
+    MockNode<BaseApp<MemDB, GaiaParamsStoreKey, GaiaABCIHandler, GaiaApplication>, GenesisState>;
+
+fn setup_mock_node(genesis_path: Option<impl AsRef<Path>>) -> (GaiaNode, User) {
     let db = MemDB::new();
     let node_options = NodeOptions::default();
     let config: Config<AppConfig> = Config::default();
     let app: BaseApp<MemDB, GaiaParamsStoreKey, GaiaABCIHandler, GaiaApplication> = BaseApp::new(
         db,
         GaiaParamsStoreKey::BaseApp,
-        GaiaABCIHandler::new(config),
+        GaiaABCIHandler::new(config, NodeRuntime::default()),
         node_options,
     );
     let chain_id = ChainId::default();
@@ -102,3 +122,66 @@ fn setup_mock_node(
         },
     )
 }
+
+/// Returns balance of `address` in `denom` at the last committed height
+fn balance(node: &GaiaNode, address: &AccAddress, denom: &str) -> Uint256 {
+    let ResponseQuery {
+        code, value, log, ..
+    } = node.query(RequestQuery {
+        data: QueryBalanceRequest {
+            address: address.clone(),
+            denom: denom.parse().expect("hardcoded denom is valid"),
+        }
+        .encode_vec()
+        .into(),
+        path: QueryBalanceRequest::QUERY_URL.to_owned(),
+        height: 0,
+        prove: false,
+    });
+    assert!(code == 0, "{log}");
+
+    QueryBalanceResponse::decode_vec(&value)
+        .expect("response is valid")
+        .balance
+        .map(|coin| coin.amount)
+        .unwrap_or_default()
+}
+
+/// Returns amount of `denom` in community pool at the last committed height
+fn community_pool(node: &GaiaNode, denom: &str) -> Decimal256 {
+    let ResponseQuery {
+        code, value, log, ..
+    } = node.query(RequestQuery {
+        data: distribution::QueryCommunityPoolRequest {}
+            .encode_vec()
+            .into(),
+        path: "/cosmos.distribution.v1beta1.Query/CommunityPool".to_owned(),
+        height: 0,
+        prove: false,
+    });
+    assert!(code == 0, "{log}");
+
+    distribution::QueryCommunityPoolResponse::decode_vec(&value)
+        .expect("response is valid")
+        .pool
+        .map(|pool| pool.amount_of(&denom.parse().expect("hardcoded denom is valid")))
+        .unwrap_or_default()
+}
+
+/// Returns gov `v1` proposal at the last committed height
+fn proposal(node: &GaiaNode, proposal_id: u64) -> ProposalModel<Proposals<GaiaParamsStoreKey>> {
+    let ResponseQuery {
+        code, value, log, ..
+    } = node.query(RequestQuery {
+        data: QueryProposalRequest { proposal_id }.encode_vec().into(),
+        path: QueryProposalRequest::QUERY_URL.to_owned(),
+        height: 0,
+        prove: false,
+    });
+    assert!(code == 0, "{log}");
+
+    QueryProposalResponse::decode_vec(&value)
+        .expect("response is valid")
+        .proposal
+        .expect("proposal exists")
+}
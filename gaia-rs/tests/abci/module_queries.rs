@@ -0,0 +1,291 @@
+use gaia_rs::modules::GaiaModules;
+use gears::core::Protobuf;
+use gears::tendermint::types::request::query::RequestQuery;
+use gears::tendermint::types::response::ResponseQuery;
+use gears::tendermint::types::time::timestamp::Timestamp;
+use gears::types::account::Account;
+use gears::types::address::ValAddress;
+use gears::types::base::coin::UnsignedCoin;
+use gears::types::pagination::request::{PaginationKind, PaginationRequest};
+use gears::x::module::Module;
+
+use crate::{setup_mock_node, GOV_GENESIS};
+
+#[test]
+/// Mint params are set by genesis and available via gaia query router
+fn query_mint_params() {
+    let (mut node, _user) = setup_mock_node(None::<&str>);
+    node.step(vec![], Timestamp::UNIX_EPOCH);
+
+    let ResponseQuery {
+        code, value, log, ..
+    } = node.query(RequestQuery {
+        data: mint::types::query::request::QueryParamsRequest {}
+            .encode_vec()
+            .into(),
+        path: mint::types::query::request::QueryParamsRequest::QUERY_URL.to_owned(),
+        height: node.height() as i64,
+        prove: false,
+    });
+
+    assert!(code == 0, "{log}");
+
+    let mint::types::query::response::QueryParamsResponse { params } =
+        mint::types::query::response::QueryParamsResponse::decode_vec(&value)
+            .expect("response is valid");
+    assert_eq!(params, mint::params::MintParams::default());
+}
+
+#[test]
+/// Distribution params are available via gaia query router without distribution genesis
+fn query_distribution_params() {
+    let (mut node, _user) = setup_mock_node(None::<&str>);
+    node.step(vec![], Timestamp::UNIX_EPOCH);
+
+    let ResponseQuery {
+        code, value, log, ..
+    } = node.query(RequestQuery {
+        data: distribution::QueryParamsRequest {}.encode_vec().into(),
+        path: "/cosmos.distribution.v1beta1.Query/Params".to_owned(),
+        height: node.height() as i64,
+        prove: false,
+    });
+
+    assert!(code == 0, "{log}");
+
+    let distribution::QueryParamsResponse { params } =
+        distribution::QueryParamsResponse::decode_vec(&value).expect("response is valid");
+    assert_eq!(params, distribution::DistributionParams::default());
+}
+
+#[test]
+/// Slashing params are set by genesis and available via gaia query router
+fn query_slashing_params() {
+    let (mut node, _user) = setup_mock_node(None::<&str>);
+    node.step(vec![], Timestamp::UNIX_EPOCH);
+
+    let ResponseQuery {
+        code, value, log, ..
+    } = node.query(RequestQuery {
+        data: slashing::QueryParamsRequest {}.encode_vec().into(),
+        path: slashing::QueryParamsRequest::QUERY_URL.to_owned(),
+        height: node.height() as i64,
+        prove: false,
+    });
+
+    assert!(code == 0, "{log}");
+
+    let slashing::QueryParamsResponse { params } =
+        slashing::QueryParamsResponse::decode_vec(&value).expect("response is valid");
+    assert_eq!(params, slashing::SlashingParams::default());
+}
+
+#[test]
+/// Evidence store is empty after genesis and available via gaia query router
+fn query_all_evidence() {
+    let (mut node, _user) = setup_mock_node(None::<&str>);
+    node.step(vec![], Timestamp::UNIX_EPOCH);
+
+    let ResponseQuery {
+        code, value, log, ..
+    } = node.query(RequestQuery {
+        data: evidence::QueryAllEvidenceRequest {
+            pagination: PaginationRequest {
+                kind: PaginationKind::Offset { offset: 0 },
+                limit: 10,
+            },
+        }
+        .encode_vec()
+        .into(),
+        path: evidence::QueryAllEvidenceRequest::QUERY_URL.to_owned(),
+        height: node.height() as i64,
+        prove: false,
+    });
+
+    assert!(code == 0, "{log}");
+
+    let evidence::QueryAllEvidenceResponse { evidence, .. } =
+        evidence::QueryAllEvidenceResponse::decode_vec(&value).expect("response is valid");
+    assert!(evidence.is_empty());
+}
+
+#[test]
+/// Owners of denom from genesis are indexed and available via gaia query router
+fn query_denom_owners() {
+    let (mut node, user) = setup_mock_node(None::<&str>);
+    node.step(vec![], Timestamp::UNIX_EPOCH);
+
+    let ResponseQuery {
+        code, value, log, ..
+    } = node.query(RequestQuery {
+        data: bank::types::query::QueryDenomOwnersRequest {
+            denom: "uatom".parse().expect("hardcoded denom is valid"),
+            pagination: None,
+        }
+        .encode_vec()
+        .into(),
+        path: bank::types::query::QueryDenomOwnersRequest::QUERY_URL.to_owned(),
+        height: node.height() as i64,
+        prove: false,
+    });
+
+    assert!(code == 0, "{log}");
+
+    let bank::types::query::QueryDenomOwnersResponse { denom_owners, .. } =
+        bank::types::query::QueryDenomOwnersResponse::decode_vec(&value)
+            .expect("response is valid");
+    let denom_owners = denom_owners
+        .into_iter()
+        .map(|owner| (owner.address, owner.balance))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        denom_owners,
+        vec![(
+            user.address(),
+            "34uatom"
+                .parse::<UnsignedCoin>()
+                .expect("hardcoded coin is valid")
+        )]
+    );
+}
+
+#[test]
+/// Spendable balance of account is available via gaia query router
+fn query_spendable_balance_by_denom() {
+    let (mut node, user) = setup_mock_node(None::<&str>);
+    node.step(vec![], Timestamp::UNIX_EPOCH);
+
+    let ResponseQuery {
+        code, value, log, ..
+    } = node.query(RequestQuery {
+        data: bank::types::query::QuerySpendableBalanceByDenomRequest {
+            address: user.address(),
+            denom: "uatom".parse().expect("hardcoded denom is valid"),
+        }
+        .encode_vec()
+        .into(),
+        path: bank::types::query::QuerySpendableBalanceByDenomRequest::QUERY_URL.to_owned(),
+        height: node.height() as i64,
+        prove: false,
+    });
+
+    assert!(code == 0, "{log}");
+
+    let bank::types::query::QuerySpendableBalanceByDenomResponse { balance } =
+        bank::types::query::QuerySpendableBalanceByDenomResponse::decode_vec(&value)
+            .expect("response is valid");
+    assert_eq!(
+        balance,
+        Some("34uatom".parse().expect("hardcoded coin is valid"))
+    );
+}
+
+#[test]
+/// Rewards of delegation created by genesis are available via gaia query router
+fn query_delegation_rewards() {
+    let (mut node, user) = setup_mock_node(Some(GOV_GENESIS));
+    node.step(vec![], Timestamp::UNIX_EPOCH);
+
+    let ResponseQuery {
+        code, value, log, ..
+    } = node.query(RequestQuery {
+        data: distribution::QueryDelegationRewardsRequest {
+            delegator_address: user.address(),
+            validator_address: ValAddress::from(user.address()),
+        }
+        .encode_vec()
+        .into(),
+        path: "/cosmos.distribution.v1beta1.Query/DelegationRewards".to_owned(),
+        height: node.height() as i64,
+        prove: false,
+    });
+
+    assert!(code == 0, "{log}");
+
+    distribution::QueryDelegationRewardsResponse::decode_vec(&value).expect("response is valid");
+}
+
+#[test]
+/// Module account created by staking genesis is available via gaia query router
+fn query_module_account_by_name() {
+    let (mut node, _user) = setup_mock_node(None::<&str>);
+    node.step(vec![], Timestamp::UNIX_EPOCH);
+
+    let ResponseQuery {
+        code, value, log, ..
+    } = node.query(RequestQuery {
+        data: auth::types::query::QueryModuleAccountByNameRequest {
+            name: GaiaModules::BondedPool.name(),
+        }
+        .encode_vec()
+        .into(),
+        path: auth::types::query::QueryModuleAccountByNameRequest::QUERY_URL.to_owned(),
+        height: node.height() as i64,
+        prove: false,
+    });
+
+    assert!(code == 0, "{log}");
+
+    let auth::types::query::QueryModuleAccountByNameResponse { account } =
+        auth::types::query::QueryModuleAccountByNameResponse::decode_vec(&value)
+            .expect("response is valid");
+    match account {
+        Some(Account::Module(account)) => {
+            assert_eq!(account.name, GaiaModules::BondedPool.name());
+            assert_eq!(
+                account.base_account.address,
+                GaiaModules::BondedPool.address()
+            );
+        }
+        account => panic!("expected bonded pool module account, got {account:?}"),
+    }
+}
+
+#[test]
+/// Address conversions are available via gaia query router and reverse each other
+fn query_address_conversions() {
+    let (mut node, user) = setup_mock_node(None::<&str>);
+    node.step(vec![], Timestamp::UNIX_EPOCH);
+
+    let ResponseQuery {
+        code, value, log, ..
+    } = node.query(RequestQuery {
+        data: auth::types::query::AddressStringToBytesRequest {
+            address_string: user.address(),
+        }
+        .encode_vec()
+        .into(),
+        path: auth::types::query::AddressStringToBytesRequest::QUERY_URL.to_owned(),
+        height: node.height() as i64,
+        prove: false,
+    });
+
+    assert!(code == 0, "{log}");
+
+    let auth::types::query::AddressStringToBytesResponse { address_bytes } =
+        auth::types::query::AddressStringToBytesResponse::decode_vec(&value)
+            .expect("response is valid");
+    assert_eq!(address_bytes, Vec::<u8>::from(user.address()));
+
+    let ResponseQuery {
+        code, value, log, ..
+    } = node.query(RequestQuery {
+        data: auth::types::query::AddressBytesToStringRequest {
+            address_bytes: address_bytes
+                .try_into()
+                .expect("bytes of address are valid"),
+        }
+        .encode_vec()
+        .into(),
+        path: auth::types::query::AddressBytesToStringRequest::QUERY_URL.to_owned(),
+        height: node.height() as i64,
+        prove: false,
+    });
+
+    assert!(code == 0, "{log}");
+
+    let auth::types::query::AddressBytesToStringResponse { address_string } =
+        auth::types::query::AddressBytesToStringResponse::decode_vec(&value)
+            .expect("response is valid");
+    assert_eq!(address_string, user.address());
+}
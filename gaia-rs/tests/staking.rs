@@ -182,6 +182,8 @@ fn redelegate_failed_on_invalid_amount() -> anyhow::Result<()> {
         home: gaia.home(),
         keyring_backend: gears::commands::client::keys::KeyringBackend::Test,
         bip39_mnemonic: Some(mnemonic.to_owned()),
+        algo: Default::default(),
+        hd_path: Default::default(),
     }))?;
 
     // create destination validator
@@ -530,6 +532,8 @@ mod helpers {
             home: node.home(),
             keyring_backend: gears::commands::client::keys::KeyringBackend::Test,
             bip39_mnemonic: Some(mnemonic.to_owned()),
+            algo: Default::default(),
+            hd_path: Default::default(),
         }))?;
 
         // create destination validator
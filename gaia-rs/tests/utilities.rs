@@ -116,10 +116,15 @@ impl GaiaNode {
                 address: Some(proxy_addr),
                 rest_listen_addr: Some(rest_addr),
                 grpc_listen_addr: Some(grpc_addr),
+                metrics_listen_addr: None,
                 read_buf_size: 1048576,
                 log_level: LOG_LEVEL,
                 min_gas_prices: Default::default(),
                 tendermint_rpc_addr: Some(rpc_addr_moved.try_into().expect("invalid rpc addr")),
+                abci_version: Default::default(),
+                inv_check_period: 0,
+                tx_index: false,
+                unsafe_skip_upgrades: Vec::new(),
             };
 
             let _ = node
@@ -2,7 +2,8 @@ use std::sync::OnceLock;
 
 use super::FiniteGas;
 
-#[derive(Debug)]
+/// Gas costs of store operations
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GasConfig {
     pub has_cost: FiniteGas,
     pub delete_cost: FiniteGas,
@@ -14,6 +15,9 @@ pub struct GasConfig {
 }
 
 impl GasConfig {
+    /// Kv store costs of cosmos sdk. Note that transactions are charged by
+    /// `GasSchedule::default()` of gears which charges 1000 for kv write flat cost
+    /// instead of 2000 here to keep gas of existing chains unchanged.
     pub fn kv() -> &'static Self {
         static DEFAULT_KV_CONFIG: OnceLock<GasConfig> = OnceLock::new();
 
@@ -16,11 +16,15 @@ use super::{
 const GUARD_DESC: &str = "GasGuard";
 
 #[derive(Debug, Clone)]
-pub struct GasGuard(pub(super) Arc<RefCell<GasMeter<TxKind>>>);
+pub struct GasGuard(
+    pub(super) Arc<RefCell<GasMeter<TxKind>>>,
+    pub(super) GasConfig,
+);
 
 impl GasGuard {
-    pub fn new(inner: Arc<RefCell<GasMeter<TxKind>>>) -> Self {
-        Self(inner)
+    /// Create guard which charges costs from `config`
+    pub fn with_config(inner: Arc<RefCell<GasMeter<TxKind>>>, config: GasConfig) -> Self {
+        Self(inner, config)
     }
 
     pub fn get(
@@ -31,10 +35,10 @@ impl GasGuard {
     ) -> Result<(), GasStoreErrors> {
         let mut gas_meter = self.0.borrow_mut();
 
-        let read_cost_per_byte = GasConfig::kv().read_cost_per_byte;
+        let read_cost_per_byte = self.1.read_cost_per_byte;
 
         gas_meter
-            .consume_gas(GasConfig::kv().read_cost_flat, READ_COST_FLAT_DESC)
+            .consume_gas(self.1.read_cost_flat, READ_COST_FLAT_DESC)
             .map_err(|e| GasStoreErrors::new(get_key, e))?;
 
         gas_meter
@@ -71,10 +75,10 @@ impl GasGuard {
     pub fn set(&self, key: usize, value: usize, set_key: &[u8]) -> Result<(), GasStoreErrors> {
         let mut gas_meter = self.0.borrow_mut();
         gas_meter
-            .consume_gas(GasConfig::kv().read_cost_flat, WRITE_COST_FLAT_DESC)
+            .consume_gas(self.1.write_cost_flat, WRITE_COST_FLAT_DESC)
             .map_err(|e| GasStoreErrors::new(set_key, e))?;
 
-        let write_cost_per_byte = GasConfig::kv().write_cost_per_byte;
+        let write_cost_per_byte = self.1.write_cost_per_byte;
 
         gas_meter
             .consume_gas(
@@ -106,7 +110,7 @@ impl GasGuard {
     pub fn delete(&self, delete_key: &[u8]) -> Result<(), GasStoreErrors> {
         self.0
             .borrow_mut()
-            .consume_gas(GasConfig::kv().delete_cost, DELETE_DESC)
+            .consume_gas(self.1.delete_cost, DELETE_DESC)
             .map_err(|e| GasStoreErrors::new(delete_key, e))?;
 
         Ok(())
@@ -116,7 +120,7 @@ impl GasGuard {
         let mut gas_meter = self.0.borrow_mut();
 
         if let Some((key, value, get_key)) = key_value {
-            let read_cost_per_byte = GasConfig::kv().read_cost_per_byte;
+            let read_cost_per_byte = self.1.read_cost_per_byte;
 
             gas_meter
                 .consume_gas(
@@ -145,13 +149,43 @@ impl GasGuard {
                 .map_err(|e| GasStoreErrors::new(get_key, e))?;
 
             gas_meter
-                .consume_gas(
-                    GasConfig::kv().iter_next_cost_flat,
-                    ITER_NEXT_CAST_FLAT_DESC,
-                )
+                .consume_gas(self.1.iter_next_cost_flat, ITER_NEXT_CAST_FLAT_DESC)
                 .map_err(|e| GasStoreErrors::new(get_key, e))?; // I'm unsure how to handle such case
         }
 
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::metering::{basic_meter::BasicGasMeter, FiniteGas};
+
+    use super::*;
+
+    fn consumed_by_set(config: GasConfig) -> FiniteGas {
+        let meter = Arc::new(RefCell::new(GasMeter::new(Box::new(BasicGasMeter::new(
+            FiniteGas::from(1_000_000_u32),
+        )))));
+        let guard = GasGuard::with_config(meter.clone(), config);
+
+        guard.set(3, 4, b"key").expect("limit isn't reached");
+
+        let consumed = meter.borrow().consumed_or_limit();
+        consumed
+    }
+
+    #[test]
+    fn set_charges_write_cost_flat() {
+        let kv = *GasConfig::kv();
+
+        assert_eq!(consumed_by_set(kv), FiniteGas::from(2210_u32));
+        assert_eq!(
+            consumed_by_set(GasConfig {
+                write_cost_flat: FiniteGas::from(5000_u32),
+                ..kv
+            }),
+            FiniteGas::from(5210_u32)
+        );
+    }
+}
@@ -0,0 +1,81 @@
+//! Stand-in remote signer. Serves keys of a local keyring using the protocol
+//! described in `gears::crypto::remote_signer`.
+
+use std::{collections::HashMap, path::PathBuf};
+
+use anyhow::anyhow;
+use clap::Parser;
+use gears::{
+    commands::client::keys::KeyringBackend,
+    crypto::remote_signer::{server::RemoteSigner, SharedSecret},
+};
+
+#[derive(Debug, Parser)]
+#[command(about = "Remote signer serving keys of a local keyring")]
+struct Args {
+    /// directory which contains the keyring
+    #[arg(long)]
+    home: PathBuf,
+
+    /// select keyring's backend
+    #[arg(long = "keyring-backend", default_value_t = KeyringBackend::Test)]
+    keyring_backend: KeyringBackend,
+
+    /// names of keys to serve
+    #[arg(long = "key", required = true)]
+    keys: Vec<String>,
+
+    /// where to listen: http://<host>:<port> or unix:///<path to socket>
+    #[arg(long, default_value = "http://127.0.0.1:26660")]
+    listen: url::Url,
+
+    /// secret which clients must present in `Authorization: Bearer` header
+    #[arg(long, env = "GEARS_REMOTE_SIGNER_SECRET", hide_env_values = true)]
+    secret: Option<SharedSecret>,
+}
+
+fn main() -> anyhow::Result<()> {
+    let Args {
+        home,
+        keyring_backend,
+        keys,
+        listen,
+        secret,
+    } = Args::parse();
+
+    let keyring_home = home.join(keyring_backend.get_sub_dir());
+    let mut served = HashMap::with_capacity(keys.len());
+    for name in keys {
+        let key = keyring::key_by_name(&name, keyring_backend.to_keyring_backend(&keyring_home))?;
+        served.insert(name, key);
+    }
+
+    let mut signer = RemoteSigner::new(served);
+    if let Some(secret) = secret {
+        signer = signer.with_secret(secret);
+    }
+
+    tokio::runtime::Runtime::new()?.block_on(async move {
+        match listen.scheme() {
+            "http" => {
+                let host = listen
+                    .host_str()
+                    .ok_or_else(|| anyhow!("missing host: {listen}"))?;
+                let port = listen.port_or_known_default().unwrap_or(80);
+
+                let listener = tokio::net::TcpListener::bind((host, port)).await?;
+                println!("remote signer listening on {}", listener.local_addr()?);
+                signer.serve_tcp(listener).await?;
+            }
+            #[cfg(unix)]
+            "unix" => {
+                let listener = tokio::net::UnixListener::bind(listen.path())?;
+                println!("remote signer listening on {}", listen.path());
+                signer.serve_unix(listener).await?;
+            }
+            scheme => Err(anyhow!("unsupported scheme: {scheme}"))?,
+        }
+
+        Ok::<_, anyhow::Error>(())
+    })
+}
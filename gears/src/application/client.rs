@@ -3,7 +3,12 @@ use super::handlers::{
     AuxHandler,
 };
 use crate::{
-    commands::client::{keys::keys, query::run_query, tx::run_tx, ClientCommands},
+    commands::client::{
+        keys::keys,
+        query::run_query,
+        tx::{run_aux_to_fee, run_tx, RuntxResult},
+        ClientCommands,
+    },
     x::query::tx_query::{TxQueryHandler, TxsQueryHandler},
 };
 
@@ -34,15 +39,12 @@ impl<Core: Client, F: NodeFetcher + Clone> ClientApplication<Core, F> {
             ClientCommands::Tx(cmd) => {
                 let tx = run_tx(cmd, &self.core, &self.fetcher)?;
 
-                match tx {
-                    crate::commands::client::tx::RuntxResult::Broadcast(tx) => {
-                        println!("{}", serde_json::to_string_pretty(&tx)?);
-                    }
-                    crate::commands::client::tx::RuntxResult::File(file) => {
-                        println!("Saved to file: {}", file.to_string_lossy())
-                    }
-                    crate::commands::client::tx::RuntxResult::None => (),
-                }
+                print_tx_result(tx)?;
+            }
+            ClientCommands::AuxToFee(cmd) => {
+                let tx = run_aux_to_fee(cmd, &self.core, &self.fetcher)?;
+
+                print_tx_result(tx)?;
             }
             ClientCommands::Query(cmd) => {
                 let query = run_query(cmd, &self.core)?;
@@ -65,3 +67,20 @@ impl<Core: Client, F: NodeFetcher + Clone> ClientApplication<Core, F> {
         Ok(())
     }
 }
+
+fn print_tx_result(tx: RuntxResult) -> anyhow::Result<()> {
+    match tx {
+        RuntxResult::Broadcast(tx) => {
+            println!("{}", serde_json::to_string_pretty(&tx)?);
+        }
+        RuntxResult::File(file) => {
+            println!("Saved to file: {}", file.to_string_lossy())
+        }
+        RuntxResult::Aux(aux_signer_data) => {
+            println!("{}", serde_json::to_string_pretty(&aux_signer_data)?);
+        }
+        RuntxResult::None => (),
+    }
+
+    Ok(())
+}
@@ -4,7 +4,10 @@ use crate::{
     baseapp::Query,
     commands::client::tx::{broadcast_tx_commit, AccountProvider, ClientTxContext},
     crypto::{
-        info::{create_signed_transaction_direct, create_signed_transaction_textual, SigningInfo},
+        info::{
+            create_aux_signer_data, create_signed_transaction_direct,
+            create_signed_transaction_textual, create_signed_transaction_with_aux, SigningInfo,
+        },
         keys::{GearsPublicKey, ReadAccAddress, SigningKey},
         public::PublicKey,
     },
@@ -13,13 +16,17 @@ use crate::{
     types::{
         account::{Account, BaseAccount},
         address::AccAddress,
+        auth::tip::Tip,
         denom::Denom,
-        tx::{body::TxBody, metadata::Metadata, Messages, Tx, TxMessage},
+        tx::{
+            aux_signer::AuxSignerData, body::TxBody, metadata::Metadata, raw::TxWithRaw, Messages,
+            Tx, TxMessage,
+        },
     },
 };
 
 use anyhow::anyhow;
-use core_types::tx::mode_info::SignMode;
+use core_types::{tx::mode_info::SignMode, Protobuf};
 use serde::Serialize;
 
 use tendermint::{
@@ -148,17 +155,126 @@ pub trait TxHandler {
         }
     }
 
+    /// Sign messages with `SIGN_MODE_DIRECT_AUX`. Signer of messages becomes a tipper
+    /// if tip was set in context. Fee isn't included, it's set by fee payer later
+    fn sign_msg_aux<K: SigningKey + ReadAccAddress + GearsPublicKey, F: NodeFetcher + Clone>(
+        &self,
+        msgs: Messages<Self::Message>,
+        key: &K,
+        ctx: &mut ClientTxContext,
+        fetcher: &F,
+    ) -> anyhow::Result<AuxSignerData> {
+        let address = key.get_address();
+
+        let account = self
+            .account(address.to_owned(), ctx, fetcher)?
+            .ok_or_else(|| anyhow!("account not found: {}", address))?;
+
+        let signing_info = SigningInfo {
+            key,
+            sequence: account.get_sequence(),
+            account_number: account.get_account_number(),
+        };
+
+        let tx_body = TxBody {
+            messages: msgs.into_msgs(),
+            memo: ctx.memo.clone().unwrap_or_default(),
+            timeout_height: ctx.timeout_height.unwrap_or_default(),
+            extension_options: vec![], // TODO: remove hard coded
+            non_critical_extension_options: vec![], // TODO: remove hard coded
+        };
+
+        let tip = ctx.tip.clone().map(|amount| Tip {
+            amount: Some(amount),
+            tipper: address,
+        });
+
+        create_aux_signer_data(signing_info, ctx.chain_id.clone(), tip, tx_body)
+            .map_err(|e| anyhow!(e.to_string()))
+    }
+
+    /// Build tx from data signed by auxiliary signer. Key is used as fee payer of tx
+    fn sign_aux_signer_data<
+        K: SigningKey + ReadAccAddress + GearsPublicKey,
+        F: NodeFetcher + Clone,
+    >(
+        &self,
+        aux_signer_data: AuxSignerData,
+        key: &K,
+        ctx: &mut ClientTxContext,
+        fetcher: &F,
+    ) -> anyhow::Result<TxWithRaw<Self::Message>> {
+        if aux_signer_data.mode != SignMode::DirectAux {
+            Err(anyhow!(
+                "unsupported sign mode of auxiliary signer: {:?}",
+                aux_signer_data.mode
+            ))?
+        }
+
+        if aux_signer_data.sign_doc.chain_id != ctx.chain_id {
+            Err(anyhow!(
+                "auxiliary signer signed tx for chain {}, expected {}",
+                aux_signer_data.sign_doc.chain_id,
+                ctx.chain_id
+            ))?
+        }
+
+        let address = key.get_address();
+        if address == aux_signer_data.address {
+            Err(anyhow!("fee payer can't be the auxiliary signer"))?
+        }
+
+        let body = TxBody::<Self::Message>::decode_vec(&aux_signer_data.sign_doc.body_bytes)?;
+
+        if body
+            .messages
+            .iter()
+            .flat_map(|msg| msg.get_signers())
+            .any(|signer| signer != &aux_signer_data.address)
+        {
+            Err(anyhow!(
+                "messages must be signed only by auxiliary signer {}",
+                aux_signer_data.address
+            ))?
+        }
+
+        let account = self
+            .account(address.to_owned(), ctx, fetcher)?
+            .ok_or_else(|| anyhow!("account not found: {}", address))?;
+
+        let fee_payer = SigningInfo {
+            key,
+            sequence: account.get_sequence(),
+            account_number: account.get_account_number(),
+        };
+
+        let mut fee = ctx.fee.clone();
+        fee.payer = Some(address);
+
+        create_signed_transaction_with_aux(aux_signer_data, fee_payer, fee, body)
+            .map_err(|e| anyhow!(e.to_string()))
+    }
+
     fn handle_tx(
         &self,
         raw_tx: Tx<Self::Message>,
         client_tx_context: &mut ClientTxContext,
+    ) -> anyhow::Result<TxExecutionResult> {
+        self.handle_tx_with_raw(raw_tx.into(), client_tx_context)
+    }
+
+    /// Same as [Self::handle_tx], but broadcasts bytes of `raw` instead of encoding tx again
+    fn handle_tx_with_raw(
+        &self,
+        TxWithRaw { tx, raw, tx_len: _ }: TxWithRaw<Self::Message>,
+        client_tx_context: &mut ClientTxContext,
     ) -> anyhow::Result<TxExecutionResult> {
         match client_tx_context.account {
             AccountProvider::Offline {
                 sequence: _,
                 account_number: _,
             } => {
-                println!("{}", serde_json::to_string_pretty(&raw_tx)?);
+                println!("{}", serde_json::to_string_pretty(&tx)?);
 
                 Ok(TxExecutionResult::None)
             }
@@ -166,7 +282,7 @@ pub trait TxHandler {
                 let client = HttpClient::new(tendermint::rpc::url::Url::try_from(
                     client_tx_context.node.clone(),
                 )?)?;
-                broadcast_tx_commit(client, Into::into(&raw_tx)).map(Into::into)
+                broadcast_tx_commit(client, raw).map(Into::into)
             }
         }
     }
@@ -2,14 +2,28 @@ use std::{borrow::Cow, num::NonZero};
 
 use crate::{
     baseapp::{errors::QueryError, genesis::Genesis, QueryRequest, QueryResponse},
-    context::{block::BlockContext, init::InitContext, query::QueryContext, tx::TxContext},
+    context::{
+        block::BlockContext, init::InitContext, query::QueryContext, tx::TxContext,
+        QueryableContext,
+    },
     types::tx::{raw::TxWithRaw, TxMessage},
 };
 use database::Database;
-use kv_store::StoreKey;
+use gas::store::errors::GasStoreErrors;
+use kv_store::{upgrade::StoreUpgrades, StoreKey};
 use tendermint::types::{
     proto::validator::ValidatorUpdate,
-    request::{begin_block::RequestBeginBlock, end_block::RequestEndBlock, query::RequestQuery},
+    request::{
+        begin_block::RequestBeginBlock,
+        end_block::RequestEndBlock,
+        proposal::{RequestPrepareProposal, RequestProcessProposal},
+        query::RequestQuery,
+        vote_extension::{RequestExtendVote, RequestVerifyVoteExtension},
+    },
+    response::{
+        proposal::{ResponsePrepareProposal, ResponseProcessProposal},
+        vote_extension::{ResponseExtendVote, ResponseVerifyVoteExtension},
+    },
 };
 use thiserror::Error;
 
@@ -35,6 +49,35 @@ impl TxError {
     }
 }
 
+/// Route of an invariant registered by a module, e.g. `bank/total-supply`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct InvariantRoute {
+    pub module: &'static str,
+    pub route: &'static str,
+}
+
+impl InvariantRoute {
+    pub const fn new(module: &'static str, route: &'static str) -> Self {
+        Self { module, route }
+    }
+}
+
+impl std::fmt::Display for InvariantRoute {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}/{}", self.module, self.route)
+    }
+}
+
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum InvariantError {
+    #[error("{0}")]
+    Broken(String),
+    #[error("invariant is not registered")]
+    NotRegistered,
+    #[error("{0}")]
+    Gas(#[from] GasStoreErrors),
+}
+
 pub trait ABCIHandler: Clone + Send + Sync + 'static {
     type Message: TxMessage;
     type Genesis: Genesis;
@@ -86,6 +129,75 @@ pub trait ABCIHandler: Clone + Send + Sync + 'static {
         Vec::new()
     }
 
+    /// Build the transactions of a block which this node proposes. Called only
+    /// when the node runs with CometBFT 0.38. `ctx` reads the last committed state.
+    #[allow(unused_variables)]
+    fn prepare_proposal<DB: Database>(
+        &self,
+        ctx: &QueryContext<DB, Self::StoreKey>,
+        request: RequestPrepareProposal,
+    ) -> ResponsePrepareProposal {
+        ResponsePrepareProposal {
+            txs: request.txs_within_limit(),
+        }
+    }
+
+    /// Accept or reject a block proposed by another validator. Called only
+    /// when the node runs with CometBFT 0.38. `ctx` reads the last committed state.
+    #[allow(unused_variables)]
+    fn process_proposal<DB: Database>(
+        &self,
+        ctx: &QueryContext<DB, Self::StoreKey>,
+        request: RequestProcessProposal,
+    ) -> ResponseProcessProposal {
+        ResponseProcessProposal::accept()
+    }
+
+    /// Data attached to this validator's precommit. Called only when the node runs
+    /// with CometBFT 0.38 and vote extensions are enabled.
+    #[allow(unused_variables)]
+    fn extend_vote<DB: Database>(
+        &self,
+        ctx: &QueryContext<DB, Self::StoreKey>,
+        request: RequestExtendVote,
+    ) -> ResponseExtendVote {
+        ResponseExtendVote::default()
+    }
+
+    /// Validate a vote extension received from another validator. Called only when
+    /// the node runs with CometBFT 0.38 and vote extensions are enabled.
+    #[allow(unused_variables)]
+    fn verify_vote_extension<DB: Database>(
+        &self,
+        ctx: &QueryContext<DB, Self::StoreKey>,
+        request: RequestVerifyVoteExtension,
+    ) -> ResponseVerifyVoteExtension {
+        ResponseVerifyVoteExtension::accept()
+    }
+
+    /// Invariants of state registered by the handler. These are asserted every
+    /// `--inv-check-period` blocks and may be checked by anyone with `MsgVerifyInvariant`.
+    fn invariant_routes(&self) -> Vec<InvariantRoute> {
+        Vec::new()
+    }
+
+    /// Check invariant registered under `route`
+    #[allow(unused_variables)]
+    fn check_invariant<DB: Database, CTX: QueryableContext<DB, Self::StoreKey>>(
+        &self,
+        ctx: &CTX,
+        route: &InvariantRoute,
+    ) -> Result<(), InvariantError> {
+        Err(InvariantError::NotRegistered)
+    }
+
+    /// Stores added, renamed or deleted by the upgrade `plan_name`. These are applied
+    /// when the application is loaded right before the upgrade height.
+    #[allow(unused_variables)]
+    fn store_upgrades(&self, plan_name: &str) -> StoreUpgrades {
+        StoreUpgrades::default()
+    }
+
     fn init_genesis<DB: Database>(
         &self,
         ctx: &mut InitContext<'_, DB, Self::StoreKey>,
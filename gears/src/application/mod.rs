@@ -3,13 +3,35 @@ pub mod handlers;
 pub mod keepers;
 pub mod node;
 
+use crate::types::address::{bech32_prefixes, set_bech32_prefixes, Bech32Prefixes};
+
 pub trait ApplicationInfo: Clone + Sync + Send + 'static {
     const APP_NAME: &'static str = env!("CARGO_PKG_NAME");
     const APP_VERSION: &'static str = env!("CARGO_PKG_VERSION");
 
+    /// Bech32 prefixes of addresses. Set once at start of application.
+    /// Defaults to prefixes set at compile time with `BECH_32_MAIN_PREFIX` env
+    fn bech32_prefixes() -> Bech32Prefixes {
+        Bech32Prefixes::default()
+    }
+
     fn home_dir() -> std::path::PathBuf {
         dirs::home_dir()
             .expect("failed to get home dir")
             .join(format!(".{}/", Self::APP_NAME)) // TODO: what about using version as prefix?
     }
 }
+
+/// Set bech32 prefixes of application. Should be called at start of application
+/// before any address was parsed. Calling it again with the same prefixes is a no-op.
+pub fn init_bech32_prefixes<AI: ApplicationInfo>() -> anyhow::Result<()> {
+    let prefixes = AI::bech32_prefixes();
+    match set_bech32_prefixes(prefixes) {
+        Ok(()) => Ok(()),
+        Err(prefixes) if &prefixes == bech32_prefixes() => Ok(()),
+        Err(prefixes) => Err(anyhow::anyhow!(
+            "failed to set bech32 prefixes {prefixes:?}: already set to {:?}",
+            bech32_prefixes()
+        )),
+    }
+}
@@ -9,7 +9,8 @@ use super::{
 use crate::commands::node::{
     genesis::genesis_account_add,
     init::init,
-    run::{run, RouterBuilder},
+    rollback::rollback,
+    run::{run, NodeRuntime, RouterBuilder},
     AppCommands,
 };
 use crate::{
@@ -32,7 +33,7 @@ pub struct NodeApplication<
     Core: Node,
     DB: Database,
     DBO: DatabaseBuilder<DB>,
-    AHB: FnOnce(Config<Core::ApplicationConfig>) -> Core::Handler,
+    AHB: FnOnce(Config<Core::ApplicationConfig>, NodeRuntime) -> Core::Handler,
 > {
     core: Core,
     abci_handler_builder: AHB,
@@ -45,8 +46,8 @@ pub struct NodeApplication<
 impl<
         Core: Node,
         DB: Database,
-        DBO: DatabaseBuilder<DB>,
-        AHB: FnOnce(Config<Core::ApplicationConfig>) -> Core::Handler,
+        DBO: DatabaseBuilder<DB> + Clone,
+        AHB: FnOnce(Config<Core::ApplicationConfig>, NodeRuntime) -> Core::Handler,
     > NodeApplication<Core, DB, DBO, AHB>
 {
     pub fn new(
@@ -81,6 +82,11 @@ impl<
                 self.abci_handler_builder,
                 self.core,
             )?,
+            AppCommands::Rollback(cmd) => rollback::<
+                DB,
+                DBO,
+                <<Core as Node>::Handler as ABCIHandler>::StoreKey,
+            >(cmd, self.db_builder)?,
             AppCommands::GenesisAdd(cmd) => {
                 genesis_account_add::<<<Core as Node>::Handler as ABCIHandler>::Genesis>(cmd)?
             }
@@ -2,7 +2,9 @@ use super::{
     mode::{check::CheckTxMode, deliver::DeliverTxMode},
     BaseApp,
 };
+use crate::baseapp::genesis::Genesis;
 use crate::error::POISONED_LOCK;
+use crate::metrics::{self, metrics};
 use crate::params::ParamsSubspaceKey;
 use crate::{application::handlers::node::ABCIHandler, context::init::InitContext};
 use crate::{
@@ -14,19 +16,24 @@ use bytes::Bytes;
 use database::Database;
 use extensions::lock::AcquireRwLock;
 use gas::metering::Gas;
+use sha2::Digest;
 use tendermint::{
     application::ABCIApplication,
     types::{
+        proto::header::Header,
         request::{
             begin_block::RequestBeginBlock,
             check_tx::RequestCheckTx,
             deliver_tx::RequestDeliverTx,
             echo::RequestEcho,
             end_block::RequestEndBlock,
+            finalize_block::RequestFinalizeBlock,
             info::RequestInfo,
             init_chain::RequestInitChain,
+            proposal::{RequestPrepareProposal, RequestProcessProposal},
             query::RequestQuery,
             snapshot::{RequestApplySnapshotChunk, RequestLoadSnapshotChunk, RequestOfferSnapshot},
+            vote_extension::{RequestExtendVote, RequestVerifyVoteExtension},
         },
         response::{
             begin_block::ResponseBeginBlock,
@@ -34,13 +41,16 @@ use tendermint::{
             deliver_tx::ResponseDeliverTx,
             echo::ResponseEcho,
             end_block::ResponseEndBlock,
+            finalize_block::ResponseFinalizeBlock,
             info::ResponseInfo,
             init_chain::ResponseInitChain,
+            proposal::{ResponsePrepareProposal, ResponseProcessProposal},
             query::ResponseQuery,
             snapshot::{
                 ResponseApplySnapshotChunk, ResponseListSnapshots, ResponseLoadSnapshotChunk,
                 ResponseOfferSnapshot,
             },
+            vote_extension::{ResponseExtendVote, ResponseVerifyVoteExtension},
             ResponseCommit, ResponseFlush,
         },
     },
@@ -61,6 +71,10 @@ impl<DB: Database, PSK: ParamsSubspaceKey, H: ABCIHandler, AI: ApplicationInfo>
             ..
         }: RequestInitChain<H::Genesis>,
     ) -> ResponseInitChain {
+        let _timer = metrics().abci_timer("init_chain");
+
+        self.set_chain_id(chain_id.clone());
+
         let mut multi_store = self.multi_store.write().expect(POISONED_LOCK);
         let mut state = self.state.write().expect(POISONED_LOCK);
 
@@ -81,6 +95,11 @@ impl<DB: Database, PSK: ParamsSubspaceKey, H: ABCIHandler, AI: ApplicationInfo>
         self.baseapp_params_keeper
             .set_consensus_params(&mut ctx, consensus_params.clone().into());
 
+        if let Some(gas_schedule) = app_genesis.gas_schedule() {
+            self.baseapp_params_keeper
+                .set_gas_schedule(&mut ctx, gas_schedule);
+        }
+
         let val_updates = self
             .abci_handler
             .init_genesis(&mut ctx, app_genesis.clone()); //TODO: should also return consensus params
@@ -97,6 +116,8 @@ impl<DB: Database, PSK: ParamsSubspaceKey, H: ABCIHandler, AI: ApplicationInfo>
     }
 
     fn info(&self, _request: RequestInfo) -> ResponseInfo {
+        let _timer = metrics().abci_timer("info");
+
         let state = self.state.read().expect(POISONED_LOCK);
 
         ResponseInfo {
@@ -109,6 +130,8 @@ impl<DB: Database, PSK: ParamsSubspaceKey, H: ABCIHandler, AI: ApplicationInfo>
     }
 
     fn query(&self, request: RequestQuery) -> ResponseQuery {
+        let _timer = metrics().abci_timer("query");
+
         match self.run_query(&request) {
             Ok(res) => ResponseQuery {
                 code: 0,
@@ -136,6 +159,8 @@ impl<DB: Database, PSK: ParamsSubspaceKey, H: ABCIHandler, AI: ApplicationInfo>
     }
 
     fn check_tx(&self, RequestCheckTx { tx, r#type }: RequestCheckTx) -> ResponseCheckTx {
+        let _timer = metrics().abci_timer("check_tx");
+
         let mut state = self.state.acquire_write();
 
         let CheckTxMode {
@@ -189,6 +214,8 @@ impl<DB: Database, PSK: ParamsSubspaceKey, H: ABCIHandler, AI: ApplicationInfo>
     }
 
     fn deliver_tx(&self, RequestDeliverTx { tx }: RequestDeliverTx) -> ResponseDeliverTx {
+        let _timer = metrics().abci_timer("deliver_tx");
+
         let mut state = self.state.write().expect(POISONED_LOCK);
 
         let tx_index = state.tx_index;
@@ -201,7 +228,7 @@ impl<DB: Database, PSK: ParamsSubspaceKey, H: ABCIHandler, AI: ApplicationInfo>
             self.run_tx::<DeliverTxMode<_, _>>(tx.clone(), tx_index, multi_store, block_gas_meter);
         state.tx_index += 1;
 
-        match result {
+        let response = match result {
             Ok(RunTxInfo {
                 events,
                 gas_wanted,
@@ -229,17 +256,46 @@ impl<DB: Database, PSK: ParamsSubspaceKey, H: ABCIHandler, AI: ApplicationInfo>
                     codespace: e.codespace().to_string(),
                 }
             }
+        };
+
+        if metrics::enabled() {
+            self.record_tx_metrics(&tx, response.code);
+        }
+
+        if let Some(indexer) = &self.tx_indexer {
+            let header = self.get_block_header();
+            let hash = sha2::Sha256::digest(tx.as_ref()).into();
+            indexer.index_tx(header.height, tx_index, header.time, tx, hash, &response);
         }
+
+        response
     }
 
     fn commit(&self) -> ResponseCommit {
+        let _timer = metrics().abci_timer("commit");
+
         let mut multi_store = self.multi_store.write().expect(POISONED_LOCK);
         let mut state = self.state.write().expect(POISONED_LOCK);
 
-        let height = self.get_block_header().height;
+        let header = self.get_block_header();
+        let height = header.height;
+        let block_gas_used = state.deliver_mode.block_gas_meter.consumed_or_limit();
+
+        // index is written first, so it's restored by replay of the block if node stops
+        // before the state is committed
+        if let Some(indexer) = &self.tx_indexer {
+            indexer.commit(height, header.time);
+        }
 
         let hash = state.commit(&mut multi_store);
 
+        metrics().record_commit(
+            height,
+            block_gas_used.into(),
+            multi_store.cache_stats(),
+            kv_store::stats::store_stats(),
+        );
+
         info!(
             "Committed state, block height: {} app hash: {}",
             height,
@@ -259,6 +315,8 @@ impl<DB: Database, PSK: ParamsSubspaceKey, H: ABCIHandler, AI: ApplicationInfo>
     }
 
     fn begin_block(&self, request: RequestBeginBlock) -> ResponseBeginBlock {
+        let _timer = metrics().abci_timer("begin_block");
+
         //TODO: Cosmos SDK validates the request height here
 
         self.set_block_header(request.header.clone());
@@ -302,6 +360,8 @@ impl<DB: Database, PSK: ParamsSubspaceKey, H: ABCIHandler, AI: ApplicationInfo>
     }
 
     fn end_block(&self, request: RequestEndBlock) -> ResponseEndBlock {
+        let _timer = metrics().abci_timer("end_block");
+
         let mut state = self.state.write().expect(POISONED_LOCK);
         let mut multi_store = self.multi_store.write().expect(POISONED_LOCK);
 
@@ -328,6 +388,11 @@ impl<DB: Database, PSK: ParamsSubspaceKey, H: ABCIHandler, AI: ApplicationInfo>
 
         let validator_updates = self.abci_handler.end_block(&mut ctx, request);
 
+        let inv_check_period = self.options.inv_check_period();
+        if inv_check_period != 0 && header.height % inv_check_period == 0 {
+            self.assert_invariants(&ctx);
+        }
+
         let events = ctx.events;
 
         state.append_block_cache(&mut multi_store);
@@ -342,6 +407,124 @@ impl<DB: Database, PSK: ParamsSubspaceKey, H: ABCIHandler, AI: ApplicationInfo>
         }
     }
 
+    fn prepare_proposal(&self, request: RequestPrepareProposal) -> ResponsePrepareProposal {
+        let _timer = metrics().abci_timer("prepare_proposal");
+
+        match self.committed_query_context() {
+            Ok(ctx) => self.abci_handler.prepare_proposal(&ctx, request),
+            Err(e) => {
+                error!("Failed to read state for proposal, using mempool order: {e}");
+                ResponsePrepareProposal {
+                    txs: request.txs_within_limit(),
+                }
+            }
+        }
+    }
+
+    fn process_proposal(&self, request: RequestProcessProposal) -> ResponseProcessProposal {
+        let _timer = metrics().abci_timer("process_proposal");
+
+        match self.committed_query_context() {
+            Ok(ctx) => self.abci_handler.process_proposal(&ctx, request),
+            Err(e) => {
+                error!("Failed to read state for proposal processing: {e}");
+                ResponseProcessProposal::reject()
+            }
+        }
+    }
+
+    fn finalize_block(
+        &self,
+        RequestFinalizeBlock {
+            txs,
+            decided_last_commit,
+            misbehavior,
+            hash,
+            height,
+            time,
+            next_validators_hash,
+            proposer_address,
+        }: RequestFinalizeBlock,
+    ) -> ResponseFinalizeBlock {
+        let _timer = metrics().abci_timer("finalize_block");
+
+        // CometBFT 0.38 doesn't pass the header, so only the fields it supplies are set.
+        // Chain id is taken from genesis, app hash from the last commit like SDK does.
+        // Other fields would differ between nodes which restarted and which didn't.
+        let header = Header {
+            chain_id: self.get_block_header().chain_id,
+            height,
+            time,
+            next_validators_hash: next_validators_hash.to_vec(),
+            app_hash: self.state.read().expect(POISONED_LOCK).head_hash.to_vec(),
+            proposer_address: proposer_address.to_vec(),
+            ..Default::default()
+        };
+
+        let ResponseBeginBlock { mut events } = self.begin_block(RequestBeginBlock {
+            hash,
+            header,
+            last_commit_info: decided_last_commit,
+            byzantine_validators: misbehavior,
+        });
+
+        let tx_results = txs
+            .into_iter()
+            .map(|tx| self.deliver_tx(RequestDeliverTx { tx }))
+            .collect();
+
+        let ResponseEndBlock {
+            validator_updates,
+            consensus_param_updates,
+            events: end_block_events,
+        } = self.end_block(RequestEndBlock {
+            height: height.into(),
+        });
+        events.extend(end_block_events);
+
+        let app_hash = {
+            let mut multi_store = self.multi_store.write().expect(POISONED_LOCK);
+            let mut state = self.state.write().expect(POISONED_LOCK);
+
+            state.working_hash(&mut multi_store)
+        };
+
+        ResponseFinalizeBlock {
+            events,
+            tx_results,
+            validator_updates,
+            consensus_param_updates,
+            app_hash: app_hash.to_vec().into(),
+        }
+    }
+
+    fn extend_vote(&self, request: RequestExtendVote) -> ResponseExtendVote {
+        let _timer = metrics().abci_timer("extend_vote");
+
+        match self.committed_query_context() {
+            Ok(ctx) => self.abci_handler.extend_vote(&ctx, request),
+            Err(e) => {
+                error!("Failed to read state for vote extension: {e}");
+                ResponseExtendVote::default()
+            }
+        }
+    }
+
+    fn verify_vote_extension(
+        &self,
+        request: RequestVerifyVoteExtension,
+    ) -> ResponseVerifyVoteExtension {
+        let _timer = metrics().abci_timer("verify_vote_extension");
+
+        match self.committed_query_context() {
+            Ok(ctx) => self.abci_handler.verify_vote_extension(&ctx, request),
+            Err(e) => {
+                error!("Failed to read state for vote extension verification: {e}");
+                ResponseVerifyVoteExtension::reject()
+            }
+        }
+    }
+
     /// Signals that messages queued on the client should be flushed to the server.
     fn flush(&self) -> ResponseFlush {
         ResponseFlush {}
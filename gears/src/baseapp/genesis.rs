@@ -1,4 +1,7 @@
-use crate::types::{address::AccAddress, base::coins::UnsignedCoins};
+use crate::{
+    params::gas::schedule::GasSchedule,
+    types::{address::AccAddress, base::coins::UnsignedCoins},
+};
 use serde::{de::DeserializeOwned, Serialize};
 
 pub use null_genesis::NullGenesis;
@@ -23,6 +26,12 @@ pub trait Genesis:
     ) -> Result<(), GenesisError> {
         Ok(())
     }
+
+    /// Gas schedule which overrides default costs of stores at genesis.
+    /// `None` keeps [GasSchedule::default]
+    fn gas_schedule(&self) -> Option<GasSchedule> {
+        None
+    }
 }
 
 mod null_genesis {
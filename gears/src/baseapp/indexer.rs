@@ -0,0 +1,780 @@
+//! Application side index of delivered transactions. Tendermint `tx_search` doesn't work
+//! when indexing is disabled or pruned on the consensus node, so the application could
+//! keep its own index in a separate database and serve tx queries from it.
+
+use std::{
+    ops::Bound,
+    str::FromStr,
+    sync::{Arc, Mutex},
+};
+
+use bytes::Bytes;
+use database::{prefix_end_bound, BatchOp, Database};
+use extensions::pagination::PaginationByOffset;
+use serde::{Deserialize, Serialize};
+use tendermint::types::{
+    proto::event::Event, response::deliver_tx::ResponseDeliverTx, time::timestamp::Timestamp,
+};
+
+use crate::error::POISONED_LOCK;
+
+const TX_PREFIX: u8 = b't';
+const HASH_PREFIX: u8 = b'h';
+const EVENT_PREFIX: u8 = b'e';
+const BLOCK_PREFIX: u8 = b'b';
+
+/// Default amount of matched transactions which search counts before it stops, see
+/// [TxIndexer::with_max_search_total]
+pub const MAX_SEARCH_TOTAL: u64 = 10_000;
+
+/// Reserved keys which are indexed for every transaction
+pub const TX_HEIGHT_KEY: &str = "tx.height";
+pub const TX_HASH_KEY: &str = "tx.hash";
+
+/// Transaction with the result of its execution
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IndexedTx {
+    pub height: u32,
+    /// Position of transaction in block
+    pub index: u32,
+    pub hash: [u8; 32],
+    pub tx: Bytes,
+    pub code: u32,
+    pub codespace: String,
+    pub data: Bytes,
+    pub log: String,
+    pub info: String,
+    pub gas_wanted: i64,
+    pub gas_used: i64,
+    pub events: Vec<Event>,
+    /// Time of block which contains transaction
+    pub timestamp: Timestamp,
+}
+
+impl IndexedTx {
+    /// Time of block in RFC 3339 format, which is used in tx responses
+    pub fn timestamp_rfc3339(&self) -> Result<String, serde_json::Error> {
+        match serde_json::to_value(&self.timestamp)? {
+            serde_json::Value::String(timestamp) => Ok(timestamp),
+            _ => unreachable!("timestamp is serialized as string"),
+        }
+    }
+}
+
+/// Metadata of indexed block
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct IndexedBlock {
+    pub height: u32,
+    pub time: Timestamp,
+    pub num_txs: u32,
+}
+
+/// Order of transactions in search result
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TxOrder {
+    Ascending,
+    #[default]
+    Descending,
+}
+
+/// Page of transactions which match the query
+#[derive(Debug, Clone, PartialEq)]
+pub struct TxSearchResult {
+    /// Total amount of matched transactions. Counting stops once the page is read and the
+    /// total reaches the cap of index, so for broad queries it is the lower bound
+    pub total: u64,
+    pub txs: Vec<IndexedTx>,
+}
+
+/// Condition on event attribute, `{event_type}.{attribute_key}={value}`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EventCondition {
+    pub key: String,
+    pub value: String,
+}
+
+/// Conjunction of equality conditions in Tendermint query syntax:
+/// `transfer.recipient='cosmos1...' AND tx.height=5`.
+/// Other operators of Tendermint queries aren't supported.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TxSearchQuery(Vec<EventCondition>);
+
+impl TxSearchQuery {
+    pub fn conditions(&self) -> &[EventCondition] {
+        &self.0
+    }
+}
+
+/// Lexical token of Tendermint query
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum QueryToken {
+    /// Tag, number or keyword
+    Word(String),
+    /// Value in single quotes, quotes are stripped
+    Quoted(String),
+    /// Comparison operator, e.g. `=` or `<=`
+    Operator(String),
+}
+
+fn tokenize_query(s: &str) -> Result<Vec<QueryToken>, TxIndexError> {
+    const OPERATOR_CHARS: [char; 4] = ['=', '<', '>', '!'];
+
+    let mut tokens = Vec::new();
+    let mut chars = s.chars().peekable();
+    while let Some(&c) = chars.peek() {
+        if c.is_whitespace() {
+            chars.next();
+        } else if c == '\'' {
+            chars.next();
+
+            let mut value = String::new();
+            loop {
+                match chars.next() {
+                    Some('\'') => break,
+                    Some(c) => value.push(c),
+                    None => {
+                        return Err(TxIndexError::Query(format!(
+                            "unterminated string: '{value}"
+                        )))
+                    }
+                }
+            }
+
+            tokens.push(QueryToken::Quoted(value));
+        } else if OPERATOR_CHARS.contains(&c) {
+            let mut operator = String::new();
+            while let Some(c) = chars.next_if(|c| OPERATOR_CHARS.contains(c)) {
+                operator.push(c);
+            }
+
+            tokens.push(QueryToken::Operator(operator));
+        } else {
+            let mut word = String::new();
+            while let Some(c) =
+                chars.next_if(|c| !c.is_whitespace() && *c != '\'' && !OPERATOR_CHARS.contains(c))
+            {
+                word.push(c);
+            }
+
+            tokens.push(QueryToken::Word(word));
+        }
+    }
+
+    Ok(tokens)
+}
+
+impl FromStr for TxSearchQuery {
+    type Err = TxIndexError;
+
+    /// Parses `condition (AND condition)*` where condition is `tag = value`,
+    /// value is either quoted string or number
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut tokens = tokenize_query(s)?.into_iter();
+        let mut conditions = Vec::new();
+
+        loop {
+            let key = match tokens.next() {
+                Some(QueryToken::Word(key)) if key != "AND" => key,
+                Some(token) => {
+                    return Err(TxIndexError::Query(format!(
+                        "expected tag, found {token:?}"
+                    )))
+                }
+                None => return Err(TxIndexError::Query("expected tag".to_owned())),
+            };
+
+            match tokens.next() {
+                Some(QueryToken::Operator(operator)) if operator == "=" => (),
+                Some(QueryToken::Operator(operator)) => {
+                    return Err(TxIndexError::Query(format!(
+                        "unsupported operator: {operator}"
+                    )))
+                }
+                Some(QueryToken::Word(operator))
+                    if operator == "CONTAINS" || operator == "EXISTS" =>
+                {
+                    return Err(TxIndexError::Query(format!(
+                        "unsupported operator: {operator}"
+                    )))
+                }
+                _ => {
+                    return Err(TxIndexError::Query(format!(
+                        "expected operator after {key}"
+                    )))
+                }
+            }
+
+            let value = match tokens.next() {
+                Some(QueryToken::Quoted(value)) => value,
+                Some(QueryToken::Word(number)) if number.parse::<f64>().is_ok() => number,
+                _ => {
+                    return Err(TxIndexError::Query(format!(
+                        "expected quoted string or number as value of {key}"
+                    )))
+                }
+            };
+
+            conditions.push(EventCondition { key, value });
+
+            match tokens.next() {
+                None => break,
+                Some(QueryToken::Word(and)) if and == "AND" => (),
+                Some(token) => {
+                    return Err(TxIndexError::Query(format!(
+                        "expected AND, found {token:?}"
+                    )))
+                }
+            }
+        }
+
+        Ok(Self(conditions))
+    }
+}
+
+/// Read access to transaction index
+pub trait TxIndex: Send + Sync {
+    /// Return transaction by its hash
+    fn tx(&self, hash: &[u8; 32]) -> Result<Option<IndexedTx>, TxIndexError>;
+
+    /// Return transactions which match all conditions of query
+    fn search(
+        &self,
+        query: &TxSearchQuery,
+        pagination: PaginationByOffset,
+        order: TxOrder,
+    ) -> Result<TxSearchResult, TxIndexError>;
+
+    fn block(&self, height: u32) -> Result<Option<IndexedBlock>, TxIndexError>;
+}
+
+/// Index of transactions stored in own database.
+///
+/// Transactions are buffered during block execution and written right before the state is
+/// committed. If node stops between the two writes the block is replayed on restart and its
+/// transactions are written again under the same keys, so the index never misses a committed block.
+#[derive(Debug, Clone)]
+pub struct TxIndexer<DB> {
+    db: DB,
+    pending: Arc<Mutex<Vec<IndexedTx>>>,
+    max_search_total: u64,
+}
+
+impl<DB: Database> TxIndexer<DB> {
+    pub fn new(db: DB) -> Self {
+        Self {
+            db,
+            pending: Default::default(),
+            max_search_total: MAX_SEARCH_TOTAL,
+        }
+    }
+
+    /// Stop counting `total` of search result after `max_search_total` matched transactions
+    /// instead of walking the whole index for broad queries
+    pub fn with_max_search_total(mut self, max_search_total: u64) -> Self {
+        self.max_search_total = max_search_total;
+        self
+    }
+
+    /// Buffer result of delivered transaction until block is committed
+    pub(crate) fn index_tx(
+        &self,
+        height: u32,
+        index: u32,
+        timestamp: Timestamp,
+        tx: Bytes,
+        hash: [u8; 32],
+        response: &ResponseDeliverTx,
+    ) {
+        self.pending.lock().expect(POISONED_LOCK).push(IndexedTx {
+            height,
+            index,
+            hash,
+            tx,
+            code: response.code,
+            codespace: response.codespace.clone(),
+            data: response.data.clone(),
+            log: response.log.clone(),
+            info: response.info.clone(),
+            gas_wanted: response.gas_wanted,
+            gas_used: response.gas_used,
+            events: response.events.clone(),
+            timestamp,
+        });
+    }
+
+    /// Write all buffered transactions and metadata of block in one batch.
+    /// Must be called before the state of block is committed
+    pub(crate) fn commit(&self, height: u32, time: Timestamp) {
+        let txs = std::mem::take(&mut *self.pending.lock().expect(POISONED_LOCK));
+
+        let block = IndexedBlock {
+            height,
+            time,
+            num_txs: txs.iter().filter(|tx| tx.height == height).count() as u32,
+        };
+
+        let mut batch = Vec::new();
+        for tx in txs {
+            let position = position_key(tx.height, tx.index);
+
+            batch.push(BatchOp::Put(
+                event_key(TX_HEIGHT_KEY, &tx.height.to_string(), &position),
+                Vec::new(),
+            ));
+            batch.push(BatchOp::Put(
+                event_key(TX_HASH_KEY, &hex::encode_upper(tx.hash), &position),
+                Vec::new(),
+            ));
+            for event in &tx.events {
+                for attribute in event.attributes.iter().filter(|attribute| attribute.index) {
+                    let key = format!(
+                        "{}.{}",
+                        event.r#type,
+                        String::from_utf8_lossy(&attribute.key)
+                    );
+                    batch.push(BatchOp::Put(
+                        event_key(&key, &String::from_utf8_lossy(&attribute.value), &position),
+                        Vec::new(),
+                    ));
+                }
+            }
+
+            batch.push(BatchOp::Put(
+                [&[HASH_PREFIX], tx.hash.as_slice()].concat(),
+                position.to_vec(),
+            ));
+            batch.push(BatchOp::Put(
+                [&[TX_PREFIX], position.as_slice()].concat(),
+                serde_json::to_vec(&tx).expect("serialization of indexed tx is infallible"),
+            ));
+        }
+
+        batch.push(BatchOp::Put(
+            [&[BLOCK_PREFIX], height.to_be_bytes().as_slice()].concat(),
+            serde_json::to_vec(&block).expect("serialization of indexed block is infallible"),
+        ));
+
+        self.db.write_batch(batch);
+    }
+
+    fn tx_by_position(&self, position: &[u8]) -> Result<Option<IndexedTx>, TxIndexError> {
+        self.db
+            .get(&[&[TX_PREFIX], position].concat())
+            .map(|bytes| serde_json::from_slice(&bytes))
+            .transpose()
+            .map_err(Into::into)
+    }
+
+    /// Positions of transactions which match `condition` in `order`, read lazily from index
+    fn matching_positions(
+        &self,
+        condition: &EventCondition,
+        order: TxOrder,
+    ) -> Box<dyn Iterator<Item = [u8; 8]> + '_> {
+        let prefix = event_prefix(&condition.key, &normalized_value(condition));
+        let prefix_len = prefix.len();
+
+        let start = Bound::Included(prefix.clone());
+        let end = prefix_end_bound(prefix);
+        let keys: Box<dyn Iterator<Item = (Box<[u8]>, Box<[u8]>)> + '_> = match order {
+            TxOrder::Ascending => Box::new(self.db.range_iterator(start, end)),
+            TxOrder::Descending => Box::new(self.db.rev_range_iterator(start, end)),
+        };
+
+        Box::new(keys.filter_map(move |(key, _)| <[u8; 8]>::try_from(&key[prefix_len..]).ok()))
+    }
+
+    /// Whether transaction at `position` matches `condition`
+    fn matches(&self, condition: &EventCondition, position: &[u8; 8]) -> bool {
+        self.db
+            .get(&event_key(
+                &condition.key,
+                &normalized_value(condition),
+                position,
+            ))
+            .is_some()
+    }
+}
+
+impl<DB: Database> TxIndex for TxIndexer<DB> {
+    fn tx(&self, hash: &[u8; 32]) -> Result<Option<IndexedTx>, TxIndexError> {
+        match self.db.get(&[&[HASH_PREFIX], hash.as_slice()].concat()) {
+            Some(position) => self.tx_by_position(&position),
+            None => Ok(None),
+        }
+    }
+
+    fn search(
+        &self,
+        query: &TxSearchQuery,
+        PaginationByOffset { offset, limit }: PaginationByOffset,
+        order: TxOrder,
+    ) -> Result<TxSearchResult, TxIndexError> {
+        let (first, others) = query
+            .conditions()
+            .split_first()
+            .ok_or_else(|| TxIndexError::Query("query is empty".to_owned()))?;
+
+        // positions matched by the first condition are checked against the others one by one,
+        // only the requested page of transactions is read and counting stops at the cap
+        let mut total = 0;
+        let mut txs = Vec::new();
+        for position in self.matching_positions(first, order) {
+            if !others
+                .iter()
+                .all(|condition| self.matches(condition, &position))
+            {
+                continue;
+            }
+
+            if total >= offset as u64 && txs.len() < limit {
+                txs.extend(self.tx_by_position(&position)?);
+            }
+            total += 1;
+
+            if txs.len() >= limit && total >= self.max_search_total {
+                break;
+            }
+        }
+
+        Ok(TxSearchResult { total, txs })
+    }
+
+    fn block(&self, height: u32) -> Result<Option<IndexedBlock>, TxIndexError> {
+        self.db
+            .get(&[&[BLOCK_PREFIX], height.to_be_bytes().as_slice()].concat())
+            .map(|bytes| serde_json::from_slice(&bytes))
+            .transpose()
+            .map_err(Into::into)
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum TxIndexError {
+    #[error("invalid query: {0}")]
+    Query(String),
+    #[error("failed to decode indexed data: {0}")]
+    Decode(#[from] serde_json::Error),
+}
+
+/// Value of condition in the form it's indexed in
+fn normalized_value(condition: &EventCondition) -> String {
+    match condition.key.as_str() {
+        // heights are stored without leading zeros and hashes in upper case
+        TX_HEIGHT_KEY => condition
+            .value
+            .parse::<u32>()
+            .map(|height| height.to_string())
+            .unwrap_or_else(|_| condition.value.clone()),
+        TX_HASH_KEY => condition.value.to_uppercase(),
+        _ => condition.value.clone(),
+    }
+}
+
+/// Position of transaction in chain, sorts in the order of execution
+fn position_key(height: u32, index: u32) -> [u8; 8] {
+    let mut key = [0; 8];
+    key[..4].copy_from_slice(&height.to_be_bytes());
+    key[4..].copy_from_slice(&index.to_be_bytes());
+    key
+}
+
+fn event_prefix(key: &str, value: &str) -> Vec<u8> {
+    [
+        &[EVENT_PREFIX],
+        key.as_bytes(),
+        &[0],
+        value.as_bytes(),
+        &[0],
+    ]
+    .concat()
+}
+
+fn event_key(key: &str, value: &str, position: &[u8; 8]) -> Vec<u8> {
+    let mut event_key = event_prefix(key, value);
+    event_key.extend_from_slice(position);
+    event_key
+}
+
+#[cfg(test)]
+mod tests {
+    use database::MemDB;
+    use tendermint::types::proto::event::EventAttribute;
+
+    use super::*;
+
+    fn response(recipient: &str) -> ResponseDeliverTx {
+        ResponseDeliverTx {
+            events: vec![Event::new(
+                "transfer",
+                [EventAttribute::new(
+                    "recipient".into(),
+                    recipient.to_owned().into(),
+                    true,
+                )],
+            )],
+            ..Default::default()
+        }
+    }
+
+    fn indexer() -> TxIndexer<MemDB> {
+        let indexer = TxIndexer::new(MemDB::new());
+
+        indexer.index_tx(
+            1,
+            0,
+            Timestamp::UNIX_EPOCH,
+            Bytes::from_static(b"tx1"),
+            [1; 32],
+            &response("alice"),
+        );
+        indexer.commit(1, Timestamp::UNIX_EPOCH);
+
+        indexer.index_tx(
+            2,
+            0,
+            Timestamp::UNIX_EPOCH,
+            Bytes::from_static(b"tx2"),
+            [2; 32],
+            &response("bob"),
+        );
+        indexer.index_tx(
+            2,
+            1,
+            Timestamp::UNIX_EPOCH,
+            Bytes::from_static(b"tx3"),
+            [3; 32],
+            &response("alice"),
+        );
+        indexer.commit(2, Timestamp::UNIX_EPOCH);
+
+        indexer
+    }
+
+    fn search(indexer: &TxIndexer<MemDB>, query: &str, order: TxOrder) -> (u64, Vec<[u8; 32]>) {
+        let result = indexer
+            .search(
+                &query.parse().expect("valid query"),
+                PaginationByOffset::from((0, 10)),
+                order,
+            )
+            .expect("search succeeds");
+
+        (
+            result.total,
+            result.txs.into_iter().map(|tx| tx.hash).collect(),
+        )
+    }
+
+    #[test]
+    fn parse_query() {
+        let query: TxSearchQuery = "transfer.recipient='cosmos1' AND tx.height=5"
+            .parse()
+            .expect("valid query");
+
+        assert_eq!(
+            query.conditions(),
+            [
+                EventCondition {
+                    key: "transfer.recipient".to_owned(),
+                    value: "cosmos1".to_owned(),
+                },
+                EventCondition {
+                    key: "tx.height".to_owned(),
+                    value: "5".to_owned(),
+                },
+            ]
+        );
+
+        assert!("tx.height>5".parse::<TxSearchQuery>().is_err());
+        assert!("transfer.recipient".parse::<TxSearchQuery>().is_err());
+        assert!("transfer.recipient=alice".parse::<TxSearchQuery>().is_err());
+        assert!("transfer.recipient='alice"
+            .parse::<TxSearchQuery>()
+            .is_err());
+        assert!("tx.height=5 AND".parse::<TxSearchQuery>().is_err());
+        assert!("tx.height=5 tx.height=6".parse::<TxSearchQuery>().is_err());
+    }
+
+    #[test]
+    fn parse_query_with_keywords_in_values() {
+        let query: TxSearchQuery = "message.memo='a AND b=c' AND message.action='send'"
+            .parse()
+            .expect("valid query");
+
+        assert_eq!(
+            query.conditions(),
+            [
+                EventCondition {
+                    key: "message.memo".to_owned(),
+                    value: "a AND b=c".to_owned(),
+                },
+                EventCondition {
+                    key: "message.action".to_owned(),
+                    value: "send".to_owned(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn search_by_events() {
+        let indexer = indexer();
+
+        assert_eq!(
+            search(&indexer, "transfer.recipient='alice'", TxOrder::Descending),
+            (2, vec![[3; 32], [1; 32]])
+        );
+        assert_eq!(
+            search(&indexer, "transfer.recipient='alice'", TxOrder::Ascending),
+            (2, vec![[1; 32], [3; 32]])
+        );
+        assert_eq!(
+            search(
+                &indexer,
+                "transfer.recipient='alice' AND tx.height=2",
+                TxOrder::Ascending
+            ),
+            (1, vec![[3; 32]])
+        );
+        assert_eq!(
+            search(&indexer, "transfer.recipient='carol'", TxOrder::Ascending),
+            (0, vec![])
+        );
+    }
+
+    #[test]
+    fn search_paginates() {
+        let indexer = indexer();
+
+        let result = indexer
+            .search(
+                &"tx.height=2".parse().expect("valid query"),
+                PaginationByOffset::from((1, 1)),
+                TxOrder::Ascending,
+            )
+            .expect("search succeeds");
+
+        assert_eq!(result.total, 2);
+        assert_eq!(
+            result.txs.into_iter().map(|tx| tx.tx).collect::<Vec<_>>(),
+            vec![Bytes::from_static(b"tx3")]
+        );
+    }
+
+    #[test]
+    fn search_stops_counting_at_cap() {
+        let indexer = indexer().with_max_search_total(1);
+
+        let result = indexer
+            .search(
+                &"transfer.recipient='alice'".parse().expect("valid query"),
+                PaginationByOffset::from((0, 1)),
+                TxOrder::Ascending,
+            )
+            .expect("search succeeds");
+
+        assert_eq!(result.total, 1);
+        assert_eq!(
+            result.txs.into_iter().map(|tx| tx.hash).collect::<Vec<_>>(),
+            vec![[1; 32]]
+        );
+
+        // page after the cap is still read
+        let result = indexer
+            .search(
+                &"transfer.recipient='alice'".parse().expect("valid query"),
+                PaginationByOffset::from((1, 1)),
+                TxOrder::Ascending,
+            )
+            .expect("search succeeds");
+
+        assert_eq!(result.total, 2);
+        assert_eq!(
+            result.txs.into_iter().map(|tx| tx.hash).collect::<Vec<_>>(),
+            vec![[3; 32]]
+        );
+    }
+
+    #[test]
+    fn tx_by_hash_and_block() {
+        let indexer = indexer();
+
+        let tx = indexer
+            .tx(&[2; 32])
+            .expect("valid record")
+            .expect("tx exists");
+        assert_eq!((tx.height, tx.index), (2, 0));
+        assert_eq!(
+            search(
+                &indexer,
+                &format!("tx.hash='{}'", hex::encode([2; 32])),
+                TxOrder::Ascending
+            ),
+            (1, vec![[2; 32]])
+        );
+
+        assert_eq!(
+            indexer.block(2).expect("valid record"),
+            Some(IndexedBlock {
+                height: 2,
+                time: Timestamp::UNIX_EPOCH,
+                num_txs: 2,
+            })
+        );
+        assert_eq!(indexer.tx(&[4; 32]).expect("valid record"), None);
+    }
+
+    #[test]
+    fn only_indexed_attributes_are_searchable() {
+        let indexer = TxIndexer::new(MemDB::new());
+
+        indexer.index_tx(
+            1,
+            0,
+            Timestamp::UNIX_EPOCH,
+            Bytes::from_static(b"tx1"),
+            [1; 32],
+            &ResponseDeliverTx {
+                events: vec![Event::new(
+                    "transfer",
+                    [
+                        EventAttribute::new("recipient".into(), "alice".into(), true),
+                        EventAttribute::new("amount".into(), "10uatom".into(), false),
+                    ],
+                )],
+                ..Default::default()
+            },
+        );
+        indexer.commit(1, Timestamp::UNIX_EPOCH);
+
+        assert_eq!(
+            search(&indexer, "transfer.recipient='alice'", TxOrder::Ascending),
+            (1, vec![[1; 32]])
+        );
+        assert_eq!(
+            search(&indexer, "transfer.amount='10uatom'", TxOrder::Ascending),
+            (0, vec![])
+        );
+    }
+
+    #[test]
+    fn uncommitted_txs_are_not_visible() {
+        let indexer = TxIndexer::new(MemDB::new());
+
+        indexer.index_tx(
+            1,
+            0,
+            Timestamp::UNIX_EPOCH,
+            Bytes::from_static(b"tx1"),
+            [1; 32],
+            &response("alice"),
+        );
+
+        assert_eq!(indexer.tx(&[1; 32]).expect("valid record"), None);
+
+        indexer.commit(1, Timestamp::UNIX_EPOCH);
+
+        assert!(indexer.tx(&[1; 32]).expect("valid record").is_some());
+    }
+}
@@ -8,15 +8,21 @@ use std::{
 
 use crate::{
     application::{handlers::node::ABCIHandler, ApplicationInfo},
-    context::{query::QueryContext, simple::SimpleContext, tx::TxContext},
+    context::{
+        query::QueryContext, simple::SimpleContext, tx::TxContext, QueryableContext,
+        TransactionalContext,
+    },
     error::POISONED_LOCK,
     params::ParamsSubspaceKey,
-    types::tx::raw::TxWithRaw,
+    types::tx::{raw::TxWithRaw, TxMessage},
 };
 use bytes::Bytes;
 use database::Database;
 use errors::QueryError;
-use gas::metering::{descriptor::BLOCK_GAS_DESCRIPTOR, kind::BlockKind, FiniteGas, Gas, GasMeter};
+use gas::metering::{
+    descriptor::BLOCK_GAS_DESCRIPTOR, infinite_meter::InfiniteGasMeter, kind::BlockKind, FiniteGas,
+    Gas, GasMeter,
+};
 use kv_store::{
     bank::multi::{ApplicationMultiBank, TransactionMultiBank},
     query::QueryMultiStore,
@@ -30,16 +36,19 @@ use tendermint::types::{
 };
 
 use self::{
-    errors::RunTxError, mode::ExecutionMode, options::NodeOptions, state::ApplicationState,
+    errors::RunTxError, indexer::TxIndexer, mode::ExecutionMode, options::NodeOptions,
+    state::ApplicationState, upgrade::UpgradeInfo,
 };
 
 mod abci;
 pub mod errors;
 pub mod genesis;
+pub mod indexer;
 pub mod mode;
 mod params;
 mod query;
 pub mod state;
+pub mod upgrade;
 pub use params::{
     BaseAppParamsKeeper, BlockParams, ConsensusParams, EvidenceParams, ValidatorParams,
 };
@@ -60,6 +69,7 @@ pub struct BaseApp<DB: Database, PSK: ParamsSubspaceKey, H: ABCIHandler, AI: App
     block_header: Arc<RwLock<Header>>, // passed by Tendermint in call to begin_block
     baseapp_params_keeper: BaseAppParamsKeeper<PSK>,
     options: NodeOptions,
+    tx_indexer: Option<TxIndexer<DB>>,
     _info_marker: PhantomData<AI>,
 }
 
@@ -71,10 +81,21 @@ impl<DB: Database, PSK: ParamsSubspaceKey, H: ABCIHandler, AI: ApplicationInfo>
     ///
     /// See [BlockParams] for details on which values is used
     pub fn new(db: DB, params_subspace_key: PSK, abci_handler: H, options: NodeOptions) -> Self {
-        let multi_store = ApplicationMultiBank::new(Arc::new(db));
-        let mut multi_store = match multi_store {
-            Ok(ms) => ms,
-            Err(err) => panic!("Failed to init MultiStore with err: {err}"),
+        let db = Arc::new(db);
+        let mut multi_store = match options.upgrade_info() {
+            Some(UpgradeInfo { name, height, .. }) => {
+                let upgrades = abci_handler.store_upgrades(&name);
+                match ApplicationMultiBank::new_with_upgrades(db, height, &upgrades) {
+                    Ok(ms) => ms,
+                    Err(err) => {
+                        panic!("Failed to apply store upgrades of `{name}` with err: {err}")
+                    }
+                }
+            }
+            None => match ApplicationMultiBank::new(db) {
+                Ok(ms) => ms,
+                Err(err) => panic!("Failed to init MultiStore with err: {err}"),
+            },
         };
 
         let baseapp_params_keeper = BaseAppParamsKeeper {
@@ -99,10 +120,17 @@ impl<DB: Database, PSK: ParamsSubspaceKey, H: ABCIHandler, AI: ApplicationInfo>
             ))),
             multi_store: Arc::new(RwLock::new(multi_store)),
             options,
+            tx_indexer: None,
             _info_marker: PhantomData,
         }
     }
 
+    /// Index delivered transactions with `indexer`, see [TxIndexer]
+    pub fn with_tx_indexer(mut self, indexer: TxIndexer<DB>) -> Self {
+        self.tx_indexer = Some(indexer);
+        self
+    }
+
     fn get_block_header(&self) -> Header {
         self.block_header.read().expect(POISONED_LOCK).clone()
     }
@@ -112,6 +140,12 @@ impl<DB: Database, PSK: ParamsSubspaceKey, H: ABCIHandler, AI: ApplicationInfo>
         *current_header = header;
     }
 
+    /// Set chain id which is used for headers of blocks delivered with `FinalizeBlock`,
+    /// as CometBFT 0.38 no longer passes the header to the application.
+    pub fn set_chain_id(&self, chain_id: ChainId) {
+        self.block_header.write().expect(POISONED_LOCK).chain_id = chain_id;
+    }
+
     /// Returns the latest committed block height known to the application.
     pub fn current_height(&self) -> u32 {
         self.multi_store.read().expect(POISONED_LOCK).head_version()
@@ -137,6 +171,43 @@ impl<DB: Database, PSK: ParamsSubspaceKey, H: ABCIHandler, AI: ApplicationInfo>
             .map(Into::into)
     }
 
+    /// Context over the latest committed state
+    fn committed_query_context(&self) -> Result<QueryContext<DB, H::StoreKey>, QueryError> {
+        let store = self.multi_store.read().expect(POISONED_LOCK);
+        let height = store.head_version();
+
+        Ok(QueryContext::new(
+            QueryMultiStore::new(&*store, None)?,
+            height,
+        )?)
+    }
+
+    /// Check every invariant registered by the handler. A broken invariant means
+    /// that state is corrupted, so the node halts.
+    fn assert_invariants<CTX: QueryableContext<DB, H::StoreKey>>(&self, ctx: &CTX) {
+        for route in self.abci_handler.invariant_routes() {
+            if let Err(e) = self.abci_handler.check_invariant(ctx, &route) {
+                panic!(
+                    "invariant {route} is broken at height {}: {e}",
+                    ctx.height()
+                );
+            }
+        }
+
+        tracing::info!("asserted all invariants, height: {}", ctx.height());
+    }
+
+    /// Count delivered transaction by its result code and types of its messages
+    fn record_tx_metrics(&self, raw: &Bytes, code: u32) {
+        match TxWithRaw::<H::Message>::from_bytes(raw.clone()) {
+            Ok(tx_with_raw) => crate::metrics::metrics().record_tx(
+                code,
+                tx_with_raw.tx.get_msgs().iter().map(|msg| msg.type_url()),
+            ),
+            Err(_) => crate::metrics::metrics().record_tx(code, ["unknown"]),
+        }
+    }
+
     /// Execute transaction for specific mode
     fn run_tx<MD: ExecutionMode<DB, H>>(
         &self,
@@ -154,13 +225,13 @@ impl<DB: Database, PSK: ParamsSubspaceKey, H: ABCIHandler, AI: ApplicationInfo>
         let header = self.get_block_header();
         let height = header.height;
 
-        let consensus_params = {
-            self.baseapp_params_keeper
-                .consensus_params(&SimpleContext::new(
-                    multi_store.into(),
-                    height,
-                    header.chain_id.clone(),
-                ))
+        let (consensus_params, gas_schedule) = {
+            let ctx = SimpleContext::new(multi_store.into(), height, header.chain_id.clone());
+
+            (
+                self.baseapp_params_keeper.consensus_params(&ctx),
+                self.baseapp_params_keeper.gas_schedule(&ctx),
+            )
         };
 
         let mut ctx = TxContext::new(
@@ -168,6 +239,7 @@ impl<DB: Database, PSK: ParamsSubspaceKey, H: ABCIHandler, AI: ApplicationInfo>
             height,
             header,
             consensus_params,
+            gas_schedule,
             build_tx_gas_meter(height, Some(&tx_with_raw.tx.auth_info.fee)),
             gas_meter,
             self.options.clone(),
@@ -200,6 +272,70 @@ impl<DB: Database, PSK: ParamsSubspaceKey, H: ABCIHandler, AI: ApplicationInfo>
             gas_used,
         })
     }
+
+    /// Execute transaction on top of a snapshot of check state to estimate its gas.
+    /// Signatures aren't verified and all changes are discarded.
+    ///
+    /// State lock is held only to take the snapshot, so simulation doesn't block consensus.
+    pub fn simulate_tx(&self, raw: Bytes) -> Result<RunTxInfo, RunTxError> {
+        let tx_with_raw: TxWithRaw<H::Message> =
+            TxWithRaw::from_bytes(raw.clone()).map_err(|e: core_types::errors::CoreError| {
+                RunTxError::InvalidTransaction(e.to_string())
+            })?;
+        let tx_hash: [u8; 32] = sha2::Sha256::digest(raw.as_ref()).into();
+
+        let header = self.get_block_header();
+        let height = header.height;
+
+        let mut multi_store = self
+            .state
+            .read()
+            .expect(POISONED_LOCK)
+            .check_mode
+            .multi_store
+            .snapshot();
+        let mut block_gas_meter = GasMeter::new(Box::<InfiniteGasMeter>::default());
+
+        let (consensus_params, gas_schedule) = {
+            let ctx =
+                SimpleContext::new((&mut multi_store).into(), height, header.chain_id.clone());
+
+            (
+                self.baseapp_params_keeper.consensus_params(&ctx),
+                self.baseapp_params_keeper.gas_schedule(&ctx),
+            )
+        };
+
+        let mut ctx = TxContext::new(
+            &mut multi_store,
+            height,
+            header,
+            consensus_params,
+            gas_schedule,
+            // gas limit of simulated tx is unknown yet
+            GasMeter::new(Box::<InfiniteGasMeter>::default()),
+            &mut block_gas_meter,
+            self.options.clone(),
+            0,
+            tx_hash,
+        )
+        .with_simulate();
+
+        self.abci_handler
+            .run_ante_checks(&mut ctx, &tx_with_raw, true)?;
+
+        for msg in tx_with_raw.tx.get_msgs() {
+            self.abci_handler.msg(&mut ctx, msg)?;
+        }
+
+        let gas_used = ctx.gas_meter.borrow().consumed_or_limit();
+
+        Ok(RunTxInfo {
+            events: ctx.events_drain(),
+            gas_wanted: Gas::Finite(tx_with_raw.tx.auth_info.fee.gas_limit),
+            gas_used,
+        })
+    }
 }
 
 impl<DB: Database, PSK: ParamsSubspaceKey, H: ABCIHandler, AI: ApplicationInfo> ApplicationInfo
@@ -2,17 +2,37 @@ use std::sync::{Arc, RwLock};
 
 use crate::{error::POISONED_LOCK, types::base::min_gas::MinGasPrices};
 
+use super::upgrade::UpgradeInfo;
+
 #[derive(Debug, Clone, Default)]
 pub struct NodeOptions(Arc<RwLock<InnerOptions>>);
 
 #[derive(Debug, Default)]
 struct InnerOptions {
     pub min_gas_prices: MinGasPrices,
+    pub inv_check_period: u32,
+    pub upgrade_info: Option<UpgradeInfo>,
 }
 
 impl NodeOptions {
     pub fn new(min_gas_prices: MinGasPrices) -> Self {
-        Self(Arc::new(RwLock::new(InnerOptions { min_gas_prices })))
+        Self(Arc::new(RwLock::new(InnerOptions {
+            min_gas_prices,
+            inv_check_period: 0,
+            upgrade_info: None,
+        })))
+    }
+
+    /// Assert invariants every `inv_check_period` blocks, `0` disables the checks
+    pub fn with_inv_check_period(self, inv_check_period: u32) -> Self {
+        self.0.write().expect(POISONED_LOCK).inv_check_period = inv_check_period;
+        self
+    }
+
+    /// Upgrade which the node halted for, its store upgrades are applied on load
+    pub fn with_upgrade_info(self, upgrade_info: UpgradeInfo) -> Self {
+        self.0.write().expect(POISONED_LOCK).upgrade_info = Some(upgrade_info);
+        self
     }
 
     pub fn min_gas_prices(&self) -> MinGasPrices {
@@ -22,4 +42,12 @@ impl NodeOptions {
             .min_gas_prices
             .to_owned()
     }
+
+    pub fn inv_check_period(&self) -> u32 {
+        self.0.read().expect(POISONED_LOCK).inv_check_period
+    }
+
+    pub fn upgrade_info(&self) -> Option<UpgradeInfo> {
+        self.0.read().expect(POISONED_LOCK).upgrade_info.clone()
+    }
 }
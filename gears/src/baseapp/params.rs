@@ -15,6 +15,7 @@ use crate::{
     application::keepers::params::ParamsKeeper,
     context::{InfallibleContext, InfallibleContextMut},
     params::{
+        gas::schedule::{GasSchedule, StoreGasCosts, KEY_KV_GAS_COSTS, KEY_TRANSIENT_GAS_COSTS},
         infallible_subspace, infallible_subspace_mut, ParamKind, ParamsDeserialize,
         ParamsSerialize, ParamsSubspaceKey,
     },
@@ -43,7 +44,7 @@ const _SUBSPACE_NAME: &str = "baseapp/";
 //##################################################################################
 
 /// A domain ConsensusParams type that wraps domain consensus params types.
-#[derive(Debug, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize, PartialEq, Eq)]
 pub struct ConsensusParams {
     pub block: BlockParams,
     pub evidence: EvidenceParams,
@@ -159,7 +160,7 @@ impl From<inner::ValidatorParams> for ValidatorParams {
 }
 
 #[serde_as]
-#[derive(Debug, Serialize, Deserialize, PartialEq, Eq)]
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
 pub struct EvidenceParams {
     #[serde_as(as = "serde_with::DisplayFromStr")]
     pub max_age_num_blocks: i64,
@@ -204,6 +205,14 @@ impl<PSK: ParamsSubspaceKey> ParamsKeeper<PSK> for BaseAppParamsKeeper<PSK> {
         &self.params_subspace_key
     }
 
+    /// Gas schedule is stored in the same subspace as consensus params
+    fn check_key<SL: AsRef<[u8]>>(key: SL) -> bool {
+        ConsensusParams::keys()
+            .into_iter()
+            .chain(GasSchedule::keys())
+            .any(|this| this.as_bytes() == key.as_ref())
+    }
+
     #[cfg(feature = "governance")]
     fn validate(key: impl AsRef<[u8]>, value: impl AsRef<[u8]>) -> bool {
         match String::from_utf8_lossy(key.as_ref()).as_ref() {
@@ -212,6 +221,9 @@ impl<PSK: ParamsSubspaceKey> ParamsKeeper<PSK> for BaseAppParamsKeeper<PSK> {
             KEY_VALIDATOR_PARAMS => {
                 serde_json::from_slice::<ValidatorParams>(value.as_ref()).is_ok()
             }
+            KEY_KV_GAS_COSTS | KEY_TRANSIENT_GAS_COSTS => {
+                serde_json::from_slice::<StoreGasCosts>(value.as_ref()).is_ok()
+            }
             _ => false,
         }
     }
@@ -258,6 +270,38 @@ impl<PSK: ParamsSubspaceKey> BaseAppParamsKeeper<PSK> {
         }
     }
 
+    pub fn set_gas_schedule<DB: Database, SK: StoreKey, CTX: InfallibleContextMut<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        schedule: GasSchedule,
+    ) {
+        let mut store = infallible_subspace_mut(ctx, &self.params_subspace_key);
+
+        store.params_set(&schedule);
+    }
+
+    /// Return gas schedule of stores. Costs which aren't set in params store
+    /// fallback to [GasSchedule::default], malformed costs are treated as corruption
+    pub fn gas_schedule<DB: Database, SK: StoreKey, CTX: InfallibleContext<DB, SK>>(
+        &self,
+        store: &CTX,
+    ) -> GasSchedule {
+        let sub_store = infallible_subspace(store, &self.params_subspace_key);
+        let default = GasSchedule::default();
+
+        let store_gas_costs = |key: &str| {
+            sub_store.params_field(key, ParamKind::Bytes).map(|params| {
+                serde_json::from_slice(&params.bytes().expect("We sure that this is bytes"))
+                    .unwrap_or_corrupt()
+            })
+        };
+
+        GasSchedule {
+            kv: store_gas_costs(KEY_KV_GAS_COSTS).unwrap_or(default.kv),
+            transient: store_gas_costs(KEY_TRANSIENT_GAS_COSTS).unwrap_or(default.transient),
+        }
+    }
+
     pub fn block_params<DB: Database, SK: StoreKey, CTX: InfallibleContext<DB, SK>>(
         &self,
         store: &CTX,
@@ -372,4 +416,31 @@ mod tests {
 
         assert_eq!(ConsensusParams::default(), params);
     }
+
+    #[test]
+    fn gas_schedule_set_read_works() {
+        let keeper = BaseAppParamsKeeper {
+            params_subspace_key: SubspaceKey::Params,
+        };
+
+        let mut multi_store =
+            ApplicationMultiBank::<_, SubspaceKey>::new(Arc::new(MemDB::new())).unwrap_test();
+
+        let mut ctx = InitContext::new(
+            &mut multi_store,
+            0,
+            tendermint::types::time::timestamp::Timestamp::UNIX_EPOCH,
+            tendermint::types::chain_id::ChainId::default(),
+            ConsensusParams::default(),
+        );
+
+        assert_eq!(GasSchedule::default(), keeper.gas_schedule(&ctx));
+
+        let mut schedule = GasSchedule::default();
+        schedule.kv.write_cost_per_byte = gas::Gas::from(1_u8);
+
+        keeper.set_gas_schedule(&mut ctx, schedule);
+
+        assert_eq!(schedule, keeper.gas_schedule(&ctx));
+    }
 }
@@ -1,5 +1,6 @@
 use std::num::NonZero;
 
+use bytes::Bytes;
 use database::Database;
 use kv_store::query::QueryMultiStore;
 use serde::Serialize;
@@ -11,7 +12,11 @@ use crate::{
     params::ParamsSubspaceKey,
 };
 
-use super::{errors::QueryError, BaseApp};
+use super::{
+    errors::{QueryError, RunTxError},
+    indexer::TxIndex,
+    BaseApp, RunTxInfo,
+};
 
 /// Trait represents some query which should know how to query itself
 /// and serialize into bytes.
@@ -35,6 +40,15 @@ pub trait QueryResponse: Clone + Send + Sync + 'static + Serialize {
 
 pub trait NodeQueryHandler<QReq, QRes>: Clone + Send + Sync + 'static {
     fn typed_query<Q: Into<QReq>>(&self, request: Q) -> Result<QRes, QueryError>;
+
+    /// Application side index of transactions. `None` if node doesn't index transactions
+    /// and queries should be sent to Tendermint.
+    fn tx_index(&self) -> Option<&dyn TxIndex> {
+        None
+    }
+
+    /// Execute transaction without committing its changes to estimate used gas
+    fn simulate_tx(&self, raw: Bytes) -> Result<RunTxInfo, RunTxError>;
 }
 
 impl<DB: Database, PSK: ParamsSubspaceKey, H: ABCIHandler, AI: ApplicationInfo>
@@ -51,6 +65,16 @@ impl<DB: Database, PSK: ParamsSubspaceKey, H: ABCIHandler, AI: ApplicationInfo>
         )?;
         Ok(self.abci_handler.typed_query(&ctx, request))
     }
+
+    fn simulate_tx(&self, raw: Bytes) -> Result<RunTxInfo, RunTxError> {
+        BaseApp::simulate_tx(self, raw)
+    }
+
+    fn tx_index(&self) -> Option<&dyn TxIndex> {
+        self.tx_indexer
+            .as_ref()
+            .map(|indexer| indexer as &dyn TxIndex)
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -69,6 +69,17 @@ impl<DB: Database, AH: ABCIHandler> ApplicationState<DB, AH> {
         }
     }
 
+    /// Apply changes of the current block to application store without persisting them
+    /// and return application state hash which following [Self::commit] produces.
+    pub fn working_hash(
+        &mut self,
+        multi_store: &mut ApplicationMultiBank<DB, AH::StoreKey>,
+    ) -> [u8; 32] {
+        multi_store.consume_block_cache(&mut self.deliver_mode.multi_store);
+
+        multi_store.working_hash()
+    }
+
     /// Commit changes from state store to application and persist changes to disk.
     /// Returns application state hash.
     ///
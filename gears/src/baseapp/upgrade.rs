@@ -0,0 +1,41 @@
+//! Upgrade plan which the node halted for. The new binary reads it on start
+//! to apply store upgrades of the plan before the upgrade height.
+
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+/// Name of file in the data directory
+pub const UPGRADE_INFO_FILE: &str = "upgrade-info.json";
+
+/// Content of `upgrade-info.json`, compatible with cosmos-sdk and cosmovisor
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct UpgradeInfo {
+    pub name: String,
+    pub height: u32,
+    #[serde(default)]
+    pub info: String,
+}
+
+impl UpgradeInfo {
+    /// Read upgrade info from `data_dir`. Returns `None` if file doesn't exist
+    pub fn read(data_dir: impl AsRef<Path>) -> anyhow::Result<Option<Self>> {
+        let path = data_dir.as_ref().join(UPGRADE_INFO_FILE);
+        if !path.exists() {
+            return Ok(None);
+        }
+
+        let info = serde_json::from_slice(&std::fs::read(&path)?)
+            .map_err(|e| anyhow::anyhow!("invalid {}: {e}", path.display()))?;
+
+        Ok(Some(info))
+    }
+
+    /// Write upgrade info into `data_dir` for the new binary and cosmovisor
+    pub fn write(&self, data_dir: impl AsRef<Path>) -> anyhow::Result<()> {
+        let path = data_dir.as_ref().join(UPGRADE_INFO_FILE);
+        std::fs::create_dir_all(data_dir.as_ref())?;
+        std::fs::write(&path, serde_json::to_vec(self)?)
+            .map_err(|e| anyhow::anyhow!("failed to write {}: {e}", path.display()))
+    }
+}
@@ -1,11 +1,12 @@
 use std::{marker::PhantomData, path::PathBuf};
 
 use clap::{ArgAction, ValueHint};
+use keyring::key::hd_path::HdPath;
 
 use crate::{
     application::ApplicationInfo,
     cli::config::client_config,
-    commands::client::keys::{AddKeyCommand, KeyringBackend},
+    commands::client::keys::{AddKeyCommand, KeyAlgorithm, KeyringBackend},
 };
 
 #[derive(Debug, Clone, ::clap::Args)]
@@ -22,6 +23,21 @@ pub struct CliAddKeyCommand<T: ApplicationInfo> {
     /// select keyring's backend
     #[arg(long = "keyring-backend",  action = ArgAction::Set, default_value_t = client_config(&T::home_dir()).keyring_backend())]
     keyring_backend: KeyringBackend,
+    /// key signing algorithm to generate keys for
+    #[arg(long, default_value_t = KeyAlgorithm::default())]
+    algo: KeyAlgorithm,
+    /// coin type number for HD derivation. Defaults to coin type of selected algorithm
+    #[arg(long = "coin-type", value_parser = clap::value_parser!(u32).range(0..0x8000_0000))]
+    coin_type: Option<u32>,
+    /// account number for HD derivation
+    #[arg(long, default_value_t = 0, value_parser = clap::value_parser!(u32).range(0..0x8000_0000))]
+    account: u32,
+    /// address index number for HD derivation
+    #[arg(long, default_value_t = 0, value_parser = clap::value_parser!(u32).range(0..0x8000_0000))]
+    index: u32,
+    /// manual HD path derivation (overrides BIP44 config)
+    #[arg(long = "hd-path", conflicts_with_all = ["coin_type", "account", "index"])]
+    hd_path: Option<HdPath>,
 
     #[arg(skip)]
     _marker: PhantomData<T>,
@@ -34,6 +50,11 @@ impl<T: ApplicationInfo> From<CliAddKeyCommand<T>> for AddKeyCommand {
             recover,
             home,
             keyring_backend,
+            algo,
+            coin_type,
+            account,
+            index,
+            hd_path,
             _marker,
         } = value;
 
@@ -43,6 +64,13 @@ impl<T: ApplicationInfo> From<CliAddKeyCommand<T>> for AddKeyCommand {
             home,
             keyring_backend,
             bip39_mnemonic: None,
+            algo,
+            hd_path: hd_path.unwrap_or_else(|| {
+                let coin_type =
+                    coin_type.unwrap_or_else(|| keyring::KeyType::from(algo).coin_type());
+                HdPath::bip44(coin_type, account, index)
+                    .expect("clap checks that numbers are less than 2^31")
+            }),
         }
     }
 }
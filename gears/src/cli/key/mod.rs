@@ -1,19 +1,22 @@
 use crate::{application::ApplicationInfo, commands::client::keys::KeyCommand};
 
-use self::add::CliAddKeyCommand;
+use self::{add::CliAddKeyCommand, show::CliShowKeyCommand};
 
 pub mod add;
+pub mod show;
 
 #[derive(Debug, Clone, ::clap::Subcommand)]
 #[command(about = "Manage your application's keys")]
 pub enum CliKeyCommand<T: ApplicationInfo> {
     Add(CliAddKeyCommand<T>),
+    Show(CliShowKeyCommand<T>),
 }
 
 impl<T: ApplicationInfo> From<CliKeyCommand<T>> for KeyCommand {
     fn from(value: CliKeyCommand<T>) -> Self {
         match value {
             CliKeyCommand::Add(cmd) => KeyCommand::Add(cmd.into()),
+            CliKeyCommand::Show(cmd) => KeyCommand::Show(cmd.into()),
         }
     }
 }
@@ -0,0 +1,41 @@
+use std::{marker::PhantomData, path::PathBuf};
+
+use clap::{ArgAction, ValueHint};
+
+use crate::{
+    application::ApplicationInfo,
+    cli::config::client_config,
+    commands::client::keys::{KeyringBackend, ShowKeyCommand},
+};
+
+#[derive(Debug, Clone, ::clap::Args)]
+#[command(about = "Retrieve key information by name")]
+pub struct CliShowKeyCommand<T: ApplicationInfo> {
+    #[arg(required = true)]
+    name: String,
+    #[arg(long, action = ArgAction::Set, value_hint = ValueHint::DirPath, default_value_os_t = T::home_dir(), help = "directory for config and data")]
+    home: PathBuf,
+    /// select keyring's backend
+    #[arg(long = "keyring-backend",  action = ArgAction::Set, default_value_t = client_config(&T::home_dir()).keyring_backend())]
+    keyring_backend: KeyringBackend,
+
+    #[arg(skip)]
+    _marker: PhantomData<T>,
+}
+
+impl<T: ApplicationInfo> From<CliShowKeyCommand<T>> for ShowKeyCommand {
+    fn from(value: CliShowKeyCommand<T>) -> Self {
+        let CliShowKeyCommand {
+            name,
+            home,
+            keyring_backend,
+            _marker,
+        } = value;
+
+        Self {
+            name,
+            home,
+            keyring_backend,
+        }
+    }
+}
@@ -1,6 +1,6 @@
 use std::io::Write;
 
-use clap::{Args, Command, CommandFactory, Subcommand};
+use clap::{Args, Command, CommandFactory, Parser, Subcommand};
 pub use clap_complete::Shell;
 use clap_complete::{generate, Generator};
 
@@ -15,8 +15,9 @@ use self::{
     key::CliKeyCommand,
     query::CliQueryCommand,
     query_txs::{CliQueryTxCommand, CliQueryTxsCommand},
+    rollback::CliRollbackCommand,
     run::CliRunCommand,
-    tx::CliTxCommand,
+    tx::{CliAuxToFeeCommand, CliTxCommand},
 };
 
 pub mod aux;
@@ -27,6 +28,7 @@ pub mod key;
 pub mod pagination;
 pub mod query;
 pub mod query_txs;
+pub mod rollback;
 pub mod run;
 pub mod tx;
 
@@ -64,6 +66,25 @@ where
     CliTX: Args,
     CliQue: Subcommand,
 {
+    /// Set bech32 prefixes of application and parse args from command line.
+    /// Prefixes must be set before clap parses any address argument.
+    pub fn parse_with_prefixes() -> anyhow::Result<Self> {
+        crate::application::init_bech32_prefixes::<T>()?;
+
+        Ok(<Self as Parser>::parse())
+    }
+
+    /// Same as [Self::parse_with_prefixes], but parses args from iterator
+    pub fn try_parse_with_prefixes_from<I, A>(args: I) -> anyhow::Result<Self>
+    where
+        I: IntoIterator<Item = A>,
+        A: Into<std::ffi::OsString> + Clone,
+    {
+        crate::application::init_bech32_prefixes::<T>()?;
+
+        Ok(<Self as Parser>::try_parse_from(args)?)
+    }
+
     pub fn execute_or_help(
         self,
         client_executor: impl FnOnce(
@@ -169,6 +190,8 @@ where
     #[command(flatten)]
     Aux(CliAUX),
     Tx(CliTxCommand<T, CliTX>),
+    /// Build a tx from data signed by an auxiliary signer, sign it as the fee payer and broadcast
+    AuxToFee(CliTxCommand<T, CliAuxToFeeCommand>),
     Query(CliQueryCommand<T, CliQue>),
     QueryTx(CliQueryTxCommand<T>),
     QueryTxs(CliQueryTxsCommand<T>),
@@ -192,6 +215,7 @@ where
         let res = match value {
             CliClientCommands::Aux(cmd) => Self::Aux(cmd.try_into()?),
             CliClientCommands::Tx(cmd) => Self::Tx(cmd.try_into()?),
+            CliClientCommands::AuxToFee(cmd) => Self::AuxToFee(cmd.try_into()?),
             CliClientCommands::Query(cmd) => Self::Query(cmd.try_into()?),
             CliClientCommands::QueryTx(cmd) => Self::QueryTx(cmd.into()),
             CliClientCommands::QueryTxs(cmd) => Self::QueryTxs(cmd.into()),
@@ -206,6 +230,7 @@ where
 pub enum CliAppCommands<T: ApplicationInfo, CliAUX: Subcommand> {
     Init(CliInitCommand<T>),
     Run(CliRunCommand<T>),
+    Rollback(CliRollbackCommand<T>),
     #[command(name = "add-genesis-account")]
     GenesisAdd(CliGenesisCommand<T>),
     #[command(flatten)]
@@ -224,6 +249,7 @@ where
         let res = match value {
             CliAppCommands::Init(cmd) => Self::Init(cmd.into()),
             CliAppCommands::Run(cmd) => Self::Run(cmd.into()),
+            CliAppCommands::Rollback(cmd) => Self::Rollback(cmd.into()),
             CliAppCommands::GenesisAdd(cmd) => Self::GenesisAdd(cmd.into()),
             CliAppCommands::Aux(cmd) => Self::Aux(cmd.try_into()?),
         };
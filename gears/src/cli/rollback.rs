@@ -0,0 +1,24 @@
+use std::{marker::PhantomData, path::PathBuf};
+
+use clap::{ArgAction, ValueHint};
+
+use crate::{application::ApplicationInfo, commands::node::rollback::RollbackCommand};
+
+/// Roll back application state by one height to recover from an app hash mismatch.
+/// Tendermint state should be rolled back too, then the node replays the last block on restart.
+#[derive(Debug, Clone, ::clap::Args)]
+pub struct CliRollbackCommand<T: ApplicationInfo> {
+    #[arg(long, action = ArgAction::Set, value_hint = ValueHint::DirPath, default_value_os_t = T::home_dir(), help = "directory for config and data")]
+    pub home: PathBuf,
+
+    #[arg(skip)]
+    _marker: PhantomData<T>,
+}
+
+impl<T: ApplicationInfo> From<CliRollbackCommand<T>> for RollbackCommand {
+    fn from(value: CliRollbackCommand<T>) -> Self {
+        let CliRollbackCommand { home, _marker } = value;
+
+        Self { home }
+    }
+}
@@ -4,10 +4,10 @@ use clap::{ArgAction, ValueHint};
 
 use crate::{
     application::ApplicationInfo,
-    commands::node::run::{LogLevel, RunCommand},
+    commands::node::run::{AbciVersion, LogLevel, RunCommand},
     config::{
-        DEFAULT_ADDRESS, DEFAULT_GRPC_LISTEN_ADDR, DEFAULT_REST_LISTEN_ADDR,
-        DEFAULT_TENDERMINT_RPC_ADDRESS,
+        DEFAULT_ADDRESS, DEFAULT_GRPC_LISTEN_ADDR, DEFAULT_METRICS_LISTEN_ADDR,
+        DEFAULT_REST_LISTEN_ADDR, DEFAULT_TENDERMINT_RPC_ADDRESS,
     },
     types::base::min_gas::MinGasPrices,
 };
@@ -23,6 +23,8 @@ pub struct CliRunCommand<T: ApplicationInfo> {
     pub rest_listen_addr: Option<SocketAddr>,
     #[arg(long, action = ArgAction::Set, help = format!("Bind the GRPC server to this address. Overrides any listen address in the config. Default value is used if neither this argument nor a config value is provided [default: {}]", DEFAULT_GRPC_LISTEN_ADDR))]
     pub grpc_listen_addr: Option<SocketAddr>,
+    #[arg(long, action = ArgAction::Set, help = format!("Serve prometheus metrics at `/metrics` on this address. Overrides any listen address in the config. Metrics are disabled if neither this argument nor a config value is provided [e.g. {}]", DEFAULT_METRICS_LISTEN_ADDR))]
+    pub metrics_listen_addr: Option<SocketAddr>,
     #[arg(long, help = format!("URL to tendermint instance in format `(http|https)://{{ip}}:{{port}}`. Overrides any address in the config. Default value is used if neither this argument nor a config value is provided [default: {}]", DEFAULT_TENDERMINT_RPC_ADDRESS))]
     pub rpc_addr: Option<tendermint::rpc::url::Url>,
     #[arg(short, long, action = ArgAction::Set, default_value_t = 1048576, help = "The default server read buffer size, in bytes, for each incoming client connection")]
@@ -33,6 +35,19 @@ pub struct CliRunCommand<T: ApplicationInfo> {
     /// Minimum gas prices to accept for transactions; Any fee in a tx must meet this minimum (e.g. 0.01photino,0.0001stake)
     #[arg(long, action = ArgAction::Set)]
     pub min_gas_prices: Option<MinGasPrices>,
+    /// ABCI protocol version of the consensus engine; use v0.38 for CometBFT 0.38
+    #[arg(long, action = ArgAction::Set, default_value_t = AbciVersion::V034)]
+    pub abci_version: AbciVersion,
+    /// Assert registered invariants every N blocks; 0 disables the checks
+    #[arg(long, action = ArgAction::Set, default_value_t = 0)]
+    pub inv_check_period: u32,
+    /// Index delivered transactions in the node's data directory and serve tx queries from
+    /// this index instead of Tendermint `tx_search`
+    #[arg(long, action = ArgAction::SetTrue)]
+    pub tx_index: bool,
+    /// Skip upgrade plans at these heights (comma separated) and keep running the current binary
+    #[arg(long, action = ArgAction::Append, value_delimiter = ',')]
+    pub unsafe_skip_upgrades: Vec<u32>,
 
     #[arg(skip)]
     pub _marker: PhantomData<T>,
@@ -49,7 +64,12 @@ impl<T: ApplicationInfo> From<CliRunCommand<T>> for RunCommand {
             log_level,
             min_gas_prices,
             grpc_listen_addr,
+            metrics_listen_addr,
             rpc_addr,
+            abci_version,
+            inv_check_period,
+            tx_index,
+            unsafe_skip_upgrades,
         }: CliRunCommand<T>,
     ) -> Self {
         Self {
@@ -57,10 +77,15 @@ impl<T: ApplicationInfo> From<CliRunCommand<T>> for RunCommand {
             address,
             rest_listen_addr,
             grpc_listen_addr,
+            metrics_listen_addr,
             read_buf_size,
             log_level,
             min_gas_prices,
             tendermint_rpc_addr: rpc_addr,
+            abci_version,
+            inv_check_period,
+            tx_index,
+            unsafe_skip_upgrades,
         }
     }
 }
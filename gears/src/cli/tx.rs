@@ -12,8 +12,12 @@ use crate::{
     cli::config::client_config,
     commands::client::{
         keys::KeyringBackend,
-        tx::{AccountProvider, ClientTxContext, Keyring as TxKeyring, LocalInfo, TxCommand},
+        tx::{
+            AccountProvider, AuxToFeeCommand, ClientTxContext, Keyring as TxKeyring, LocalInfo,
+            RemoteInfo, TxCommand,
+        },
     },
+    crypto::remote_signer::SharedSecret,
     types::{auth::fee::Fee, base::coins::UnsignedCoins},
 };
 
@@ -42,6 +46,10 @@ pub struct CliTxCommand<T: ApplicationInfo, C: Args> {
     #[cfg_attr(not(feature = "ledger"), group(id = "local", global = true))]
     pub local: Option<Local<T>>,
 
+    #[command(flatten)]
+    #[group(id = "remote", global = true)]
+    pub remote: Option<Remote>,
+
     #[command(flatten)]
     #[group(id = "Broadcast mode", global = true)]
     pub mode: Mode,
@@ -54,6 +62,15 @@ pub struct CliTxCommand<T: ApplicationInfo, C: Args> {
     #[arg(long, global = true, action = ArgAction::Set, required = false )]
     pub timeout_height: Option<u32>,
 
+    /// Sign with SIGN_MODE_DIRECT_AUX and print the signer data instead of broadcasting the tx.
+    /// The output should be passed to a fee payer which submits the tx with `aux-to-fee`
+    #[arg(long, global = true, default_value_t = false)]
+    pub aux: bool,
+
+    /// Tip to pay to the fee payer; eg: 10uatom. Can only be used with `--aux`
+    #[arg(long, global = true, action = ArgAction::Set, requires = "aux")]
+    pub tip: Option<UnsignedCoins>,
+
     #[command(flatten)]
     pub command: C,
 
@@ -61,6 +78,23 @@ pub struct CliTxCommand<T: ApplicationInfo, C: Args> {
     _marker: PhantomData<T>,
 }
 
+#[derive(Debug, Clone, ::clap::Args)]
+pub struct CliAuxToFeeCommand {
+    /// file with the signer data printed by the auxiliary signer with `--aux`
+    #[arg(required = true, value_hint = ValueHint::FilePath)]
+    pub aux_signer_data: PathBuf,
+}
+
+impl TryFrom<CliAuxToFeeCommand> for AuxToFeeCommand {
+    type Error = anyhow::Error;
+
+    fn try_from(
+        CliAuxToFeeCommand { aux_signer_data }: CliAuxToFeeCommand,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self { aux_signer_data })
+    }
+}
+
 #[derive(Debug, Clone, ::clap::Args)]
 pub struct FeeCli {
     // TODO: Cosmos has "auto" feature to calculate gas price if needed
@@ -132,6 +166,9 @@ pub enum Keyring {
     /// Use a local keyring to source the signing key
     #[strum(to_string = "local")]
     Local,
+    /// Delegate signing to a remote signer over HTTP or a Unix socket
+    #[strum(to_string = "remote")]
+    Remote,
 }
 
 #[derive(Debug, Clone, ::clap::Args)]
@@ -150,6 +187,30 @@ pub struct Local<T: ApplicationInfo> {
     _marker: PhantomData<T>,
 }
 
+#[derive(Debug, Clone, ::clap::Args)]
+pub struct Remote {
+    /// url of the remote signer: http://<host>:<port> or unix:///<path to socket>
+    #[arg(long = "signer-url", global = true, required = false, env = "GEARS_REMOTE_SIGNER_URL", value_hint = ValueHint::Url)]
+    #[arg(help_heading = "Remote signing options")]
+    signer_url: url::Url,
+
+    /// name of the key held by the remote signer
+    #[arg(long = "remote-key", global = true, required = false)]
+    #[arg(help_heading = "Remote signing options")]
+    key_name: String,
+
+    /// secret shared with the remote signer, if it requires one
+    #[arg(
+        long = "signer-secret",
+        global = true,
+        required = false,
+        env = "GEARS_REMOTE_SIGNER_SECRET",
+        hide_env_values = true
+    )]
+    #[arg(help_heading = "Remote signing options")]
+    secret: Option<SharedSecret>,
+}
+
 #[cfg(feature = "ledger")]
 #[derive(Debug, Clone, ::clap::Args)]
 pub struct Ledger<C: Subcommand> {
@@ -177,9 +238,12 @@ where
             _marker,
             keyring,
             local,
+            remote,
             mode,
             note,
             timeout_height,
+            aux,
+            tip,
             fee,
             command,
         } = value;
@@ -201,6 +265,21 @@ where
                     from_key,
                 })
             }
+            Keyring::Remote => {
+                let Remote {
+                    signer_url,
+                    key_name,
+                    secret,
+                } = remote.ok_or(MissingCliOptions(
+                    "remote signing options: signer-url, remote-key".to_owned(),
+                ))?;
+
+                TxKeyring::Remote(RemoteInfo {
+                    signer_url,
+                    key_name,
+                    secret,
+                })
+            }
         };
 
         let account = match mode {
@@ -242,6 +321,8 @@ where
                 memo: note,
                 timeout_height,
                 fee: fee.try_into()?,
+                aux,
+                tip,
             },
         })
     }
@@ -1,5 +1,6 @@
 use anyhow::Result;
 use bip32::Mnemonic;
+use keyring::key::{hd_path::HdPath, pair::KeyInfo};
 use serde::{Deserialize, Serialize};
 use std::path::PathBuf;
 use strum::Display;
@@ -36,9 +37,36 @@ impl KeyringBackend {
     }
 }
 
+/// Algorithm of generated key
+#[derive(Clone, Copy, Default, Debug, Display, PartialEq, Eq)]
+#[cfg_attr(feature = "cli", derive(clap::ValueEnum))]
+pub enum KeyAlgorithm {
+    #[default]
+    #[strum(to_string = "secp256k1")]
+    Secp256k1,
+    /// ethermint compatible key with ethereum style address
+    #[strum(to_string = "eth_secp256k1")]
+    #[cfg_attr(feature = "cli", value(name = "eth_secp256k1"))]
+    EthSecp256k1,
+    /// NIST P-256 key
+    #[strum(to_string = "secp256r1")]
+    Secp256r1,
+}
+
+impl From<KeyAlgorithm> for keyring::KeyType {
+    fn from(value: KeyAlgorithm) -> Self {
+        match value {
+            KeyAlgorithm::Secp256k1 => keyring::KeyType::Secp256k1,
+            KeyAlgorithm::EthSecp256k1 => keyring::KeyType::EthSecp256k1,
+            KeyAlgorithm::Secp256r1 => keyring::KeyType::Secp256r1,
+        }
+    }
+}
+
 #[derive(Debug, Clone)]
 pub enum KeyCommand {
     Add(AddKeyCommand),
+    Show(ShowKeyCommand),
 }
 
 #[derive(Debug, Clone, former::Former)]
@@ -48,6 +76,15 @@ pub struct AddKeyCommand {
     pub home: PathBuf,
     pub keyring_backend: KeyringBackend,
     pub bip39_mnemonic: Option<String>,
+    pub algo: KeyAlgorithm,
+    pub hd_path: HdPath,
+}
+
+#[derive(Debug, Clone, former::Former)]
+pub struct ShowKeyCommand {
+    pub name: String,
+    pub home: PathBuf,
+    pub keyring_backend: KeyringBackend,
 }
 
 // TODO: remove this cli code
@@ -60,6 +97,8 @@ pub fn keys(command: KeyCommand) -> Result<()> {
                 home,
                 keyring_backend,
                 bip39_mnemonic,
+                algo,
+                hd_path,
             } = cmd;
 
             let keyring_home = home.join(keyring_backend.get_sub_dir());
@@ -77,10 +116,10 @@ pub fn keys(command: KeyCommand) -> Result<()> {
 
                 let mnemonic = Mnemonic::new(phrase, bip32::Language::English)?;
 
-                keyring::add_key(&name, &mnemonic, keyring::KeyType::Secp256k1, backend)?;
+                keyring::add_key_with_path(&name, &mnemonic, &hd_path, algo.into(), backend)?;
             } else {
                 let (mnemonic, key_pair) =
-                    keyring::create_key(&name, keyring::KeyType::Secp256k1, backend)?;
+                    keyring::create_key_with_path(&name, &hd_path, algo.into(), backend)?;
 
                 println!("Created key {}\nAddress: {}", name, key_pair.get_address());
 
@@ -88,6 +127,24 @@ pub fn keys(command: KeyCommand) -> Result<()> {
                 println!("{}", mnemonic.phrase());
             }
         }
+        KeyCommand::Show(cmd) => {
+            let ShowKeyCommand {
+                name,
+                home,
+                keyring_backend,
+            } = cmd;
+
+            let keyring_home = home.join(keyring_backend.get_sub_dir());
+            let backend = keyring_backend.to_keyring_backend(&keyring_home);
+
+            let KeyInfo { key_pair, hd_path } = keyring::key_info_by_name(&name, backend)?;
+
+            println!("Name: {}\nAddress: {}", name, key_pair.get_address());
+            match hd_path {
+                Some(hd_path) => println!("HD path: {hd_path}"),
+                None => println!("HD path: unknown"),
+            }
+        }
     }
 
     Ok(())
@@ -1,4 +1,8 @@
-use self::{keys::KeyCommand, query::QueryCommand, tx::TxCommand};
+use self::{
+    keys::KeyCommand,
+    query::QueryCommand,
+    tx::{AuxToFeeCommand, TxCommand},
+};
 use crate::cli::query_txs::{TxQueryCli, TxsQueryCli};
 
 pub mod keys;
@@ -9,6 +13,7 @@ pub mod tx;
 pub enum ClientCommands<AUX, TX, QUE> {
     Aux(AUX),
     Tx(TxCommand<TX>),
+    AuxToFee(TxCommand<AuxToFeeCommand>),
     Query(QueryCommand<QUE>),
     QueryTx(QueryCommand<TxQueryCli>),
     QueryTxs(QueryCommand<TxsQueryCli>),
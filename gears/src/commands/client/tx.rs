@@ -12,8 +12,11 @@ use crate::crypto::any_key::AnyKey;
 use crate::crypto::keys::GearsPublicKey;
 #[cfg(feature = "ledger")]
 use crate::crypto::ledger::LedgerProxyKey;
+use crate::crypto::remote_signer::{RemoteSignerKey, SharedSecret};
 use crate::runtime::runtime;
 use crate::types::auth::fee::Fee;
+use crate::types::base::coins::UnsignedCoins;
+use crate::types::tx::aux_signer::AuxSignerData;
 use crate::types::tx::raw::TxRaw;
 use gas::Gas;
 
@@ -46,6 +49,10 @@ pub struct ClientTxContext {
     pub timeout_height: Option<u32>,
 
     pub fee: Fee,
+    /// Sign with `SIGN_MODE_DIRECT_AUX` and print aux signer data instead of broadcasting tx
+    pub aux: bool,
+    /// Tip which auxiliary signer pays to fee payer
+    pub tip: Option<UnsignedCoins>,
 }
 
 impl ClientTxContext {
@@ -85,6 +92,8 @@ impl ClientTxContext {
                 payer: None,
                 granter: "".to_owned(),
             },
+            aux: false,
+            tip: None,
         }
     }
 }
@@ -95,6 +104,7 @@ pub enum Keyring {
     #[cfg(feature = "ledger")]
     Ledger,
     Local(LocalInfo),
+    Remote(RemoteInfo),
 }
 
 /// Additional information for local keyring
@@ -104,6 +114,17 @@ pub struct LocalInfo {
     pub from_key: String,
 }
 
+/// Additional information for remote signer
+#[derive(Debug, Clone)]
+pub struct RemoteInfo {
+    /// `http://` or `unix://` url of the signer
+    pub signer_url: url::Url,
+    /// name of the key held by the signer
+    pub key_name: String,
+    /// secret which the signer requires from its clients
+    pub secret: Option<SharedSecret>,
+}
+
 /// Result of execution of tx
 #[derive(Debug, Clone)]
 pub enum RuntxResult {
@@ -111,6 +132,8 @@ pub enum RuntxResult {
     Broadcast(Vec<Response>),
     /// Path to tx saved to file
     File(PathBuf),
+    /// Data signed by auxiliary signer which should be passed to fee payer
+    Aux(AuxSignerData),
     /// No result of tx. Probably it was printed to `stdout`
     None,
 }
@@ -120,6 +143,7 @@ impl RuntxResult {
         match self {
             Self::Broadcast(var) => Some(var),
             Self::File(_) => None,
+            Self::Aux(_) => None,
             Self::None => None,
         }
     }
@@ -128,6 +152,16 @@ impl RuntxResult {
         match self {
             Self::Broadcast(_) => None,
             Self::File(var) => Some(var),
+            Self::Aux(_) => None,
+            Self::None => None,
+        }
+    }
+
+    pub fn aux(self) -> Option<AuxSignerData> {
+        match self {
+            Self::Broadcast(_) => None,
+            Self::File(_) => None,
+            Self::Aux(var) => Some(var),
             Self::None => None,
         }
     }
@@ -158,6 +192,11 @@ fn handle_key(client_tx_context: &ClientTxContext) -> anyhow::Result<AnyKey> {
 
             Ok(AnyKey::Local(key))
         }
+        Keyring::Remote(ref remote) => Ok(AnyKey::Remote(RemoteSignerKey::new(
+            &remote.signer_url,
+            remote.key_name.clone(),
+            remote.secret.clone(),
+        )?)),
     }
 }
 
@@ -174,6 +213,18 @@ pub fn run_tx<C, H: TxHandler<TxCommands = C>, F: NodeFetcher + Clone>(
 
     let messages = handler.prepare_tx(&mut ctx, inner, key.get_gears_public_key())?;
 
+    if ctx.aux {
+        if messages.chunk_size() > 0 {
+            Err(anyhow::anyhow!(
+                "aux signing isn't supported for txs split into chunks"
+            ))?
+        }
+
+        return Ok(RuntxResult::Aux(
+            handler.sign_msg_aux(messages, &key, &mut ctx, fetcher)?,
+        ));
+    }
+
     if messages.chunk_size() > 0
     // TODO: uncomment and update logic when command will be extended by broadcast_mode
     /* && command.broadcast_mode == BroadcastMode::Block */
@@ -213,6 +264,40 @@ pub fn run_tx<C, H: TxHandler<TxCommands = C>, F: NodeFetcher + Clone>(
     }
 }
 
+/// Command of fee payer to build tx from data of auxiliary signer
+#[derive(Debug, Clone)]
+pub struct AuxToFeeCommand {
+    /// Path to file with [AuxSignerData] in json format
+    pub aux_signer_data: PathBuf,
+}
+
+/// Reads data signed by auxiliary signer, adds fee and signature of key from context
+/// which pays this fee and broadcasts tx
+pub fn run_aux_to_fee<H: TxHandler, F: NodeFetcher + Clone>(
+    TxCommand {
+        mut ctx,
+        inner: AuxToFeeCommand { aux_signer_data },
+    }: TxCommand<AuxToFeeCommand>,
+    handler: &H,
+    fetcher: &F,
+) -> anyhow::Result<RuntxResult> {
+    if ctx.aux {
+        Err(anyhow::anyhow!("fee payer can't sign with `--aux`"))?
+    }
+
+    let key = handle_key(&ctx)?;
+
+    let aux_signer_data: AuxSignerData =
+        serde_json::from_str(&std::fs::read_to_string(aux_signer_data)?)?;
+
+    handler
+        .handle_tx_with_raw(
+            handler.sign_aux_signer_data(aux_signer_data, &key, &mut ctx, fetcher)?,
+            &mut ctx,
+        )
+        .map(Into::into)
+}
+
 /// Helper method to run a tx with blocking.
 ///
 /// **WARNING**: never use this method in async context due internal blocking using tokio runtime
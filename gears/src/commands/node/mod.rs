@@ -1,11 +1,13 @@
 pub mod genesis;
 pub mod init;
+pub mod rollback;
 pub mod run;
 
 #[derive(Debug, Clone)]
 pub enum AppCommands<AUX> {
     Init(init::InitCommand),
     Run(run::RunCommand),
+    Rollback(rollback::RollbackCommand),
     GenesisAdd(genesis::GenesisCommand),
     Aux(AUX),
 }
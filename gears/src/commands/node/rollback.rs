@@ -0,0 +1,41 @@
+use std::{path::PathBuf, sync::Arc};
+
+use database::{Database, DatabaseBuilder};
+use kv_store::{bank::multi::ApplicationMultiBank, StoreKey};
+
+#[derive(Debug, Clone)]
+pub struct RollbackCommand {
+    pub home: PathBuf,
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum RollbackError {
+    #[error("{0}")]
+    Database(String),
+    #[error("failed to roll back state: {0}")]
+    Store(String),
+}
+
+/// Rewind application state by one height. Tendermint state should be rolled back
+/// by its own `rollback` command, so both restart from the same height.
+pub fn rollback<DB: Database, DBO: DatabaseBuilder<DB>, SK: StoreKey>(
+    cmd: RollbackCommand,
+    db_builder: DBO,
+) -> Result<(), RollbackError> {
+    let RollbackCommand { home } = cmd;
+
+    let db = db_builder
+        .build(home.join("data").join("application.db"))
+        .map_err(|e| RollbackError::Database(format!("{e:?}")))?;
+
+    let multi_store = ApplicationMultiBank::<DB, SK>::rollback(Arc::new(db))
+        .map_err(|e| RollbackError::Store(e.to_string()))?;
+
+    println!(
+        "Rolled back state to height {} and hash {}",
+        multi_store.head_version(),
+        data_encoding::HEXUPPER.encode(&multi_store.head_commit_hash())
+    );
+
+    Ok(())
+}
@@ -1,9 +1,12 @@
 use crate::application::handlers::node::ABCIHandler;
 use crate::application::ApplicationInfo;
+use crate::baseapp::indexer::TxIndexer;
 use crate::baseapp::options::NodeOptions;
+use crate::baseapp::upgrade::UpgradeInfo;
 use crate::baseapp::{BaseApp, NodeQueryHandler};
 use crate::config::{ApplicationConfig, Config, ConfigDirectory};
-use crate::grpc::run_grpc_server;
+use crate::grpc::{health::health_server, run_grpc_server, tx::tx_server};
+use crate::metrics::run_metrics_server;
 use crate::params::ParamsSubspaceKey;
 use crate::rest::{run_rest_server, RestState};
 use crate::types::base::min_gas::MinGasPrices;
@@ -13,6 +16,8 @@ use std::net::SocketAddr;
 use std::path::PathBuf;
 use tendermint::abci::ServerBuilder;
 use tendermint::application::ABCI;
+use tendermint::informal::genesis::Genesis;
+use tendermint::rpc::client::HttpClientUrl;
 use tower_layer::Identity;
 use tracing::metadata::LevelFilter;
 use tracing::{error, info};
@@ -23,10 +28,24 @@ pub struct RunCommand {
     pub address: Option<SocketAddr>,
     pub grpc_listen_addr: Option<SocketAddr>,
     pub rest_listen_addr: Option<SocketAddr>,
+    pub metrics_listen_addr: Option<SocketAddr>,
     pub tendermint_rpc_addr: Option<tendermint::rpc::url::Url>,
     pub read_buf_size: usize,
     pub log_level: LogLevel,
     pub min_gas_prices: Option<MinGasPrices>,
+    pub abci_version: AbciVersion,
+    pub inv_check_period: u32,
+    pub tx_index: bool,
+    pub unsafe_skip_upgrades: Vec<u32>,
+}
+
+/// State of node which is passed to application on start, but isn't part of config file
+#[derive(Debug, Clone, Default)]
+pub struct NodeRuntime {
+    /// Home directory of the node
+    pub home: PathBuf,
+    /// Heights of upgrade plans to skip, set from `--unsafe-skip-upgrades`
+    pub unsafe_skip_upgrades: Vec<u32>,
 }
 
 #[derive(Debug, thiserror::Error)]
@@ -38,6 +57,8 @@ pub enum RunError {
     #[error("{0}")]
     TendermintServer(#[from] tendermint::abci::errors::Error),
     #[error("{0}")]
+    CometServer(#[from] tendermint::abci::v038::Error),
+    #[error("{0}")]
     Custom(String),
     #[error("{0}")]
     TendermintRPC(#[from] tendermint::rpc::error::Error),
@@ -59,6 +80,21 @@ pub enum LogLevel {
     Off,
 }
 
+/// Version of the ABCI protocol served to the consensus engine
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, strum::Display)]
+#[cfg_attr(feature = "cli", derive(clap::ValueEnum))]
+pub enum AbciVersion {
+    /// Tendermint 0.34: `BeginBlock`, `DeliverTx` and `EndBlock`
+    #[default]
+    #[strum(to_string = "v0.34")]
+    #[cfg_attr(feature = "cli", value(name = "v0.34"))]
+    V034,
+    /// CometBFT 0.38 (ABCI++): `PrepareProposal`, `ProcessProposal`, `FinalizeBlock` and vote extensions
+    #[strum(to_string = "v0.38")]
+    #[cfg_attr(feature = "cli", value(name = "v0.38"))]
+    V038,
+}
+
 impl From<LogLevel> for LevelFilter {
     fn from(value: LogLevel) -> Self {
         match value {
@@ -75,6 +111,8 @@ pub trait RouterBuilder<QReq, QRes> {
     fn build_router<App: NodeQueryHandler<QReq, QRes>>(&self)
         -> Router<RestState<QReq, QRes, App>>;
 
+    /// Router with gRPC services of application. Tendermint and tx services
+    /// of cosmos sdk are added by node itself.
     fn build_grpc_router<App: NodeQueryHandler<QReq, QRes>>(
         &self,
         app: App,
@@ -87,7 +125,7 @@ pub trait RouterBuilder<QReq, QRes> {
 #[allow(clippy::result_large_err)]
 pub fn run<
     DB: Database,
-    DBO: DatabaseBuilder<DB>,
+    DBO: DatabaseBuilder<DB> + Clone,
     PSK: ParamsSubspaceKey,
     H: ABCIHandler,
     AC: ApplicationConfig,
@@ -97,7 +135,7 @@ pub fn run<
     cmd: RunCommand,
     db_builder: DBO,
     params_subspace_key: PSK,
-    abci_handler_builder: impl FnOnce(Config<AC>) -> H,
+    abci_handler_builder: impl FnOnce(Config<AC>, NodeRuntime) -> H,
     router_builder: RB,
 ) -> Result<(), RunError> {
     let RunCommand {
@@ -105,10 +143,15 @@ pub fn run<
         address,
         rest_listen_addr,
         grpc_listen_addr,
+        metrics_listen_addr,
         read_buf_size,
         log_level,
         min_gas_prices,
         tendermint_rpc_addr: tendermint_addr,
+        abci_version,
+        inv_check_period,
+        tx_index,
+        unsafe_skip_upgrades,
     } = cmd;
 
     tracing_subscriber::fmt()
@@ -120,6 +163,7 @@ pub fn run<
 
     let db_dir = home.join("data");
     let db = db_builder
+        .clone()
         .build(db_dir.join("application.db"))
         .map_err(|e| RunError::Database(format!("{e:?}")))?;
 
@@ -128,35 +172,96 @@ pub fn run<
     let config: Config<AC> = Config::from_file(cfg_file_path)
         .map_err(|e| RunError::Custom(format!("Error reading config file: {:?}", e)))?;
 
-    let abci_handler = abci_handler_builder(config.clone());
+    let abci_handler = abci_handler_builder(
+        config.clone(),
+        NodeRuntime {
+            home: home.clone(),
+            unsafe_skip_upgrades,
+        },
+    );
 
     let options = NodeOptions::new(min_gas_prices.or(config.min_gas_prices).ok_or(
         RunError::HomeDirectory(
             "Failed to get `min_gas_prices` set it via cli or in config file".to_owned(),
         ),
-    )?);
+    )?)
+    .with_inv_check_period(inv_check_period);
 
-    let app: BaseApp<DB, PSK, H, AI> = BaseApp::new(db, params_subspace_key, abci_handler, options);
+    let upgrade_info = UpgradeInfo::read(&db_dir).map_err(|e| RunError::Custom(e.to_string()))?;
+    let options = match upgrade_info {
+        Some(upgrade_info) => options.with_upgrade_info(upgrade_info),
+        None => options,
+    };
+
+    let mut app: BaseApp<DB, PSK, H, AI> =
+        BaseApp::new(db, params_subspace_key, abci_handler, options);
+
+    if tx_index {
+        let index_db = db_builder
+            .build(db_dir.join("tx_index.db"))
+            .map_err(|e| RunError::Database(format!("{e:?}")))?;
+
+        app = app.with_tx_indexer(TxIndexer::new(index_db));
+    }
+
+    let tendermint_rpc_address: HttpClientUrl = tendermint_addr
+        .unwrap_or(config.tendermint_rpc_address)
+        .try_into()?;
 
     run_rest_server::<H::Message, H::QReq, H::QRes, _>(
         app.clone(),
         rest_listen_addr.unwrap_or(config.rest_listen_addr),
         router_builder.build_router::<BaseApp<DB, PSK, H, AI>>(),
-        tendermint_addr
-            .unwrap_or(config.tendermint_rpc_address)
-            .try_into()?,
+        tendermint_rpc_address.clone(),
     );
 
     run_grpc_server(
-        router_builder.build_grpc_router::<BaseApp<DB, PSK, H, AI>>(app.clone()),
+        router_builder
+            .build_grpc_router::<BaseApp<DB, PSK, H, AI>>(app.clone())
+            .add_service(health_server::<AI>(tendermint_rpc_address.clone()))
+            .add_service(tx_server::<_, H::QReq, H::QRes>(
+                app.clone(),
+                tendermint_rpc_address,
+            )),
         grpc_listen_addr.unwrap_or(config.grpc_listen_addr),
     );
 
+    if let Some(metrics_listen_addr) = metrics_listen_addr.or(config.metrics_listen_addr) {
+        run_metrics_server(metrics_listen_addr);
+    }
+
     let addr = address.unwrap_or(config.address);
 
-    let server = ServerBuilder::new(read_buf_size).bind(addr, ABCI::from(app))?;
+    match abci_version {
+        AbciVersion::V034 => {
+            let server = ServerBuilder::new(read_buf_size).bind(addr, ABCI::from(app))?;
+
+            info!("Starting proxy server at: {}", addr.to_string());
 
-    info!("Starting proxy server at: {}", addr.to_string());
+            server.listen().map_err(|e| e.into())
+        }
+        AbciVersion::V038 => {
+            let genesis_file_path = ConfigDirectory::GenesisFile.path_from_home(&home);
+            let raw_genesis = std::fs::read_to_string(&genesis_file_path).map_err(|e| {
+                RunError::Custom(format!(
+                    "Failed to read genesis file {}: {e}",
+                    genesis_file_path.display()
+                ))
+            })?;
+            let genesis: Genesis = serde_json::from_str(&raw_genesis)
+                .map_err(|e| RunError::Custom(format!("Error parsing genesis file: {e}")))?;
 
-    server.listen().map_err(|e| e.into())
+            app.set_chain_id(genesis.chain_id.into());
+
+            let server = tendermint::abci::v038::ServerBuilder::new(read_buf_size)
+                .bind(addr, tendermint::application::v038::ABCI::from(app))?;
+
+            info!(
+                "Starting CometBFT 0.38 proxy server at: {}",
+                addr.to_string()
+            );
+
+            server.listen().map_err(|e| e.into())
+        }
+    }
 }
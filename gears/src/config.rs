@@ -18,6 +18,7 @@ pub const DEFAULT_REST_LISTEN_ADDR: SocketAddr =
 pub const DEFAULT_ADDRESS: SocketAddr =
     SocketAddr::new(std::net::IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1)), 26658);
 pub const DEFAULT_TENDERMINT_RPC_ADDRESS: &str = "http://localhost:26657";
+pub const DEFAULT_METRICS_LISTEN_ADDR: SocketAddr = socket_addr!(127, 0, 0, 1, 26660);
 
 #[derive(Debug, Clone, Eq, PartialEq, PartialOrd, Ord, Hash)]
 pub enum ConfigDirectory {
@@ -50,6 +51,8 @@ pub struct Config<AC: Default + Clone> {
     pub rest_listen_addr: SocketAddr,
     pub grpc_listen_addr: SocketAddr,
     pub address: SocketAddr,
+    /// Address of prometheus `/metrics` endpoint, disabled if not set
+    pub metrics_listen_addr: Option<SocketAddr>,
     pub min_gas_prices: Option<MinGasPrices>,
     pub app_config: AC,
 }
@@ -90,6 +93,7 @@ impl<AC: ApplicationConfig> Config<AC> {
             rest_listen_addr: self.rest_listen_addr.to_owned(),
             grpc_listen_addr: self.grpc_listen_addr.to_owned(),
             address: self.address.to_owned(),
+            metrics_listen_addr: self.metrics_listen_addr.to_owned(),
             min_gas_prices: self.min_gas_prices.to_owned(),
             app_config: AC::default(),
         }
@@ -104,6 +108,7 @@ impl<AC: ApplicationConfig> Default for Config<AC> {
                 .expect("const should be valid"),
             rest_listen_addr: DEFAULT_REST_LISTEN_ADDR,
             address: DEFAULT_ADDRESS,
+            metrics_listen_addr: None,
             app_config: AC::default(),
             min_gas_prices: None,
             grpc_listen_addr: DEFAULT_GRPC_LISTEN_ADDR,
@@ -130,5 +135,8 @@ grpc_listen_addr = "{{grpc_listen_addr}}"
 # Tendermint node RPC proxy address
 tendermint_rpc_address = "{{tendermint_rpc_address}}"
 
+# Prometheus metrics TCP socket address, metrics are served at `/metrics`. Uncomment to enable.
+{{#if metrics_listen_addr}}metrics_listen_addr = "{{metrics_listen_addr}}"{{else}}# metrics_listen_addr = "127.0.0.1:26660"{{/if}}
+
 min_gas_prices = "{{min_gas_prices}}"
 "#;
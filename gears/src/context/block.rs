@@ -1,4 +1,5 @@
 use database::{prefix::PrefixDB, Database};
+use gas::metering::{infinite_meter::InfiniteGasMeter, GasMeter};
 use kv_store::{
     bank::multi::ApplicationMultiBank,
     store::kv::{immutable::KVStore, mutable::KVStoreMut},
@@ -6,7 +7,8 @@ use kv_store::{
 };
 
 use crate::{
-    baseapp::ConsensusParams,
+    baseapp::{options::NodeOptions, ConsensusParams},
+    params::gas::schedule::GasSchedule,
     types::store::kv::{mutable::StoreMut, Store},
 };
 use tendermint::types::{
@@ -15,7 +17,9 @@ use tendermint::types::{
     time::timestamp::Timestamp,
 };
 
-use super::{InfallibleContext, InfallibleContextMut, QueryableContext, TransactionalContext};
+use super::{
+    tx::TxContext, InfallibleContext, InfallibleContextMut, QueryableContext, TransactionalContext,
+};
 
 #[derive(Debug)]
 pub struct BlockContext<'a, DB, SK> {
@@ -59,6 +63,55 @@ impl<DB: Database, SK: StoreKey> BlockContext<'_, DB, SK> {
     pub fn kv_store_mut(&mut self, store_key: &SK) -> KVStoreMut<'_, PrefixDB<DB>> {
         KVStoreMut::from(self.multi_store.kv_store_mut(store_key))
     }
+
+    /// Run `f` over cache of block state. Changes and events of `f` are kept only if it succeeds
+    pub fn cached<T, E>(&mut self, f: impl FnOnce(&mut Self) -> Result<T, E>) -> Result<T, E> {
+        let snapshot = self.multi_store.cache_snapshot();
+        let events_len = self.events.len();
+
+        let result = f(self);
+        if result.is_err() {
+            self.multi_store.cache_restore(snapshot);
+            self.events.truncate(events_len);
+        }
+
+        result
+    }
+
+    /// Run `f` in transaction context on top of block state without gas limit.
+    /// Changes and events of `f` are written back to block state only if it succeeds
+    pub fn with_tx_context<T, E>(
+        &mut self,
+        f: impl FnOnce(&mut TxContext<'_, DB, SK>) -> Result<T, E>,
+    ) -> Result<T, E> {
+        let mut multi_store = self.multi_store.to_tx_kind();
+        let mut block_gas_meter = GasMeter::new(Box::<InfiniteGasMeter>::default());
+
+        let mut ctx = TxContext::new(
+            &mut multi_store,
+            self.height,
+            self.header.clone(),
+            self.consensus_params.clone(),
+            GasSchedule::default(),
+            GasMeter::new(Box::<InfiniteGasMeter>::default()),
+            &mut block_gas_meter,
+            NodeOptions::default(),
+            0,
+            [0; 32],
+        );
+
+        let result = f(&mut ctx);
+        let events = std::mem::take(&mut ctx.events);
+        drop(ctx);
+
+        if result.is_ok() {
+            multi_store.upgrade_cache();
+            self.multi_store.consume_block_cache(&mut multi_store);
+            self.events.extend(events);
+        }
+
+        result
+    }
 }
 
 impl<DB: Database, SK: StoreKey> QueryableContext<DB, SK> for BlockContext<'_, DB, SK> {
@@ -116,3 +169,99 @@ impl<DB: Database, SK: StoreKey> TransactionalContext<DB, SK> for BlockContext<'
         [0u8; 32]
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::{str::FromStr, sync::Arc};
+
+    use database::MemDB;
+    use extensions::testing::UnwrapTesting;
+    use kv_store::StoreKey;
+    use tendermint::types::proto::{block::BlockId, consensus::Consensus};
+
+    use super::*;
+
+    #[derive(strum::EnumIter, Debug, PartialEq, Eq, Hash, Clone)]
+    enum TestStoreKey {
+        Test,
+    }
+
+    impl StoreKey for TestStoreKey {
+        fn name(&self) -> &'static str {
+            "test"
+        }
+
+        fn params() -> &'static Self {
+            &Self::Test
+        }
+    }
+
+    fn header() -> Header {
+        Header {
+            version: Consensus { block: 11, app: 10 },
+            chain_id: ChainId::from_str("test-chain").expect("this is a valid chain id"),
+            height: 1,
+            time: Timestamp::UNIX_EPOCH,
+            last_block_id: BlockId {
+                hash: vec![],
+                part_set_header: None,
+            },
+            last_commit_hash: vec![],
+            data_hash: vec![],
+            validators_hash: vec![],
+            next_validators_hash: vec![],
+            consensus_hash: vec![],
+            app_hash: vec![],
+            last_results_hash: vec![],
+            evidence_hash: vec![],
+            proposer_address: vec![],
+        }
+    }
+
+    #[test]
+    fn cached_keeps_all_tx_contexts_on_success() {
+        let mut multi_store =
+            ApplicationMultiBank::<_, TestStoreKey>::new(Arc::new(MemDB::new())).unwrap_test();
+        let mut ctx = BlockContext::new(&mut multi_store, 1, header(), ConsensusParams::default());
+
+        ctx.cached(|ctx| {
+            ctx.with_tx_context(|ctx| ctx.kv_store_mut(&TestStoreKey::Test).set([1], [1]))?;
+            ctx.with_tx_context(|ctx| ctx.kv_store_mut(&TestStoreKey::Test).set([2], [2]))
+        })
+        .unwrap_test();
+
+        let store = ctx.kv_store(&TestStoreKey::Test);
+        assert_eq!(store.get(&[1u8]), Some(vec![1]));
+        assert_eq!(store.get(&[2u8]), Some(vec![2]));
+    }
+
+    #[test]
+    fn cached_rolls_back_earlier_tx_contexts_on_failure() {
+        let mut multi_store =
+            ApplicationMultiBank::<_, TestStoreKey>::new(Arc::new(MemDB::new())).unwrap_test();
+        let mut ctx = BlockContext::new(&mut multi_store, 1, header(), ConsensusParams::default());
+        ctx.kv_store_mut(&TestStoreKey::Test).set([0], [0]);
+
+        let result = ctx.cached(|ctx| {
+            ctx.with_tx_context(|ctx| {
+                ctx.push_event(Event::new("first", []));
+                ctx.kv_store_mut(&TestStoreKey::Test)
+                    .set([1], [1])
+                    .map_err(|e| e.to_string())
+            })?;
+            ctx.with_tx_context(|ctx| {
+                ctx.kv_store_mut(&TestStoreKey::Test)
+                    .set([2], [2])
+                    .map_err(|e| e.to_string())?;
+                Err::<(), _>("second message failed".to_owned())
+            })
+        });
+
+        assert!(result.is_err());
+        let store = ctx.kv_store(&TestStoreKey::Test);
+        assert_eq!(store.get(&[0u8]), Some(vec![0]));
+        assert_eq!(store.get(&[1u8]), None);
+        assert_eq!(store.get(&[2u8]), None);
+        assert!(ctx.events.is_empty());
+    }
+}
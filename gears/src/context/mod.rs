@@ -48,6 +48,11 @@ pub trait TransactionalContext<DB, SK>: QueryableContext<DB, SK> {
 
     /// Hash of the currently executing transaction.
     fn tx_hash(&self) -> [u8; 32];
+
+    /// Whether transaction is executed only to estimate gas and its result is discarded.
+    fn is_simulate(&self) -> bool {
+        false
+    }
 }
 
 pub trait InfallibleContextMut<DB, SK>:
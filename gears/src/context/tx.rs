@@ -24,6 +24,7 @@ use tendermint::types::{
 
 use crate::{
     baseapp::{options::NodeOptions, ConsensusParams},
+    params::gas::schedule::GasSchedule,
     types::store::kv::{mutable::StoreMut, Store},
 };
 
@@ -38,8 +39,10 @@ pub struct TxContext<'a, DB, SK> {
     pub(crate) header: Header,
     pub(crate) block_gas_meter: &'a mut GasMeter<BlockKind>,
     pub(crate) consensus_params: ConsensusParams,
+    pub(crate) gas_schedule: GasSchedule,
     pub(crate) tx_index: u32,
     pub(crate) tx_hash: [u8; 32],
+    pub(crate) simulate: bool,
     multi_store: &'a mut TransactionMultiBank<DB, SK>,
 }
 
@@ -49,6 +52,7 @@ impl<'a, DB, SK> TxContext<'a, DB, SK> {
         height: u32,
         header: Header,
         consensus_params: ConsensusParams,
+        gas_schedule: GasSchedule,
         gas_meter: GasMeter<TxKind>,
         block_gas_meter: &'a mut GasMeter<BlockKind>,
         node_opt: NodeOptions,
@@ -63,12 +67,20 @@ impl<'a, DB, SK> TxContext<'a, DB, SK> {
             gas_meter: Arc::new(RefCell::new(gas_meter)),
             block_gas_meter,
             consensus_params,
+            gas_schedule,
             node_opt,
             tx_index,
             tx_hash,
+            simulate: false,
         }
     }
 
+    /// Mark context as simulation, see [TransactionalContext::is_simulate]
+    pub fn with_simulate(mut self) -> Self {
+        self.simulate = true;
+        self
+    }
+
     #[allow(dead_code)]
     pub(crate) fn multi_store(&self) -> MultiStore<'_, DB, SK> {
         MultiStore::from(&*self.multi_store)
@@ -100,19 +112,35 @@ impl<DB: Database, SK: StoreKey> TxContext<'_, DB, SK> {
         &self.consensus_params
     }
 
+    pub fn gas_schedule(&self) -> &GasSchedule {
+        &self.gas_schedule
+    }
+
     pub fn kv_store(&self, store_key: &SK) -> GasKVStore<'_, PrefixDB<DB>> {
         GasKVStore::new(
-            GasGuard::new(Arc::clone(&self.gas_meter)),
+            self.gas_guard(store_key),
             self.multi_store.kv_store(store_key).into(),
         )
     }
 
     pub fn kv_store_mut(&mut self, store_key: &SK) -> GasKVStoreMut<'_, PrefixDB<DB>> {
         GasKVStoreMut::new(
-            GasGuard::new(Arc::clone(&self.gas_meter)),
+            self.gas_guard(store_key),
             self.multi_store.kv_store_mut(store_key).into(),
         )
     }
+
+    fn gas_guard(&self, store_key: &SK) -> GasGuard {
+        match store_key.is_transient() {
+            true => GasGuard::with_config(
+                Arc::clone(&self.gas_meter),
+                self.gas_schedule.transient_config(),
+            ),
+            false => {
+                GasGuard::with_config(Arc::clone(&self.gas_meter), self.gas_schedule.kv_config())
+            }
+        }
+    }
 }
 
 impl<DB: Database, SK: StoreKey> QueryableContext<DB, SK> for TxContext<'_, DB, SK> {
@@ -157,4 +185,8 @@ impl<DB: Database, SK: StoreKey> TransactionalContext<DB, SK> for TxContext<'_,
     fn tx_hash(&self) -> [u8; 32] {
         self.tx_hash
     }
+
+    fn is_simulate(&self) -> bool {
+        self.simulate
+    }
 }
@@ -3,14 +3,23 @@ use keyring::key::pair::KeyPair;
 use super::keys::{GearsPublicKey, ReadAccAddress, SigningKey};
 #[cfg(feature = "ledger")]
 use super::ledger::{LedgerError, LedgerProxyKey};
-#[cfg(not(feature = "ledger"))]
-type LedgerError = core::convert::Infallible;
+use super::remote_signer::{RemoteSignerError, RemoteSignerKey};
 
 #[derive(Debug)]
 pub enum AnyKey {
     Local(KeyPair),
     #[cfg(feature = "ledger")]
     Ledger(LedgerProxyKey),
+    Remote(RemoteSignerKey),
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum AnyKeyError {
+    #[cfg(feature = "ledger")]
+    #[error("{0}")]
+    Ledger(#[from] LedgerError),
+    #[error("{0}")]
+    Remote(#[from] RemoteSignerError),
 }
 
 impl ReadAccAddress for AnyKey {
@@ -19,6 +28,7 @@ impl ReadAccAddress for AnyKey {
             AnyKey::Local(k) => k.get_address(),
             #[cfg(feature = "ledger")]
             AnyKey::Ledger(k) => k.get_address(),
+            AnyKey::Remote(k) => k.get_address(),
         }
     }
 }
@@ -29,18 +39,20 @@ impl GearsPublicKey for AnyKey {
             AnyKey::Local(k) => k.get_gears_public_key(),
             #[cfg(feature = "ledger")]
             AnyKey::Ledger(k) => k.get_gears_public_key(),
+            AnyKey::Remote(k) => k.get_gears_public_key(),
         }
     }
 }
 
 impl SigningKey for AnyKey {
-    type Error = LedgerError;
+    type Error = AnyKeyError;
 
     fn sign(&self, message: &[u8]) -> Result<Vec<u8>, Self::Error> {
         match self {
             AnyKey::Local(k) => Ok(k.sign(message)),
             #[cfg(feature = "ledger")]
-            AnyKey::Ledger(k) => k.sign(message),
+            AnyKey::Ledger(k) => Ok(k.sign(message)?),
+            AnyKey::Remote(k) => Ok(k.sign(message)?),
         }
     }
 }
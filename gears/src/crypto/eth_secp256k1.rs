@@ -0,0 +1,154 @@
+use address::AccAddress;
+use core_types::Protobuf;
+use keyring::error::DecodeError;
+use secp256k1::{ecdsa::Signature, Message, Secp256k1};
+use serde::{Deserialize, Serialize};
+use sha3::{Digest, Keccak256};
+
+use super::{public::SigningError, secp256k1::Secp256k1PubKey};
+
+/// Length of ethereum signature with recovery id: `R || S || V`
+const RECOVERABLE_SIGNATURE_LEN: usize = 65;
+
+/// Ethermint compatible secp256k1 key. It differs from [Secp256k1PubKey] in
+/// the way address is derived and messages are hashed before signing.
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+#[serde(transparent)]
+pub struct EthSecp256k1PubKey(Secp256k1PubKey);
+
+impl EthSecp256k1PubKey {
+    /// Verifies signature of `keccak256(message)`. Signature may contain recovery id
+    /// as the last byte which is ignored.
+    pub fn verify_signature(
+        &self,
+        message: impl AsRef<[u8]>,
+        signature: impl AsRef<[u8]>,
+    ) -> Result<(), SigningError> {
+        let signature = signature.as_ref();
+        let signature = match signature.len() {
+            RECOVERABLE_SIGNATURE_LEN => &signature[..RECOVERABLE_SIGNATURE_LEN - 1],
+            _ => signature,
+        };
+
+        let signature = Signature::from_compact(signature)?;
+        let message = Message::from_digest_slice(&Keccak256::digest(message.as_ref()))?;
+        Secp256k1::verification_only().verify_ecdsa(&message, &signature, self.0.inner())
+    }
+
+    /// Returns ethereum style address: last 20 bytes of keccak256 of uncompressed key
+    pub fn get_address(&self) -> AccAddress {
+        let uncompressed = self.0.inner().serialize_uncompressed();
+
+        // first byte is a tag of uncompressed key
+        let hash = Keccak256::digest(&uncompressed[1..]);
+
+        hash[12..]
+            .try_into()
+            .expect("the slice is 20 bytes long which is less than AccAddress::MAX_ADDR_LEN")
+    }
+}
+
+impl TryFrom<Vec<u8>> for EthSecp256k1PubKey {
+    type Error = DecodeError;
+
+    fn try_from(value: Vec<u8>) -> Result<Self, Self::Error> {
+        Ok(Self(value.try_into()?))
+    }
+}
+
+impl From<EthSecp256k1PubKey> for Vec<u8> {
+    fn from(key: EthSecp256k1PubKey) -> Vec<u8> {
+        key.0.into()
+    }
+}
+
+impl From<EthSecp256k1PubKey> for Secp256k1PubKey {
+    fn from(key: EthSecp256k1PubKey) -> Self {
+        key.0
+    }
+}
+
+mod inner {
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct EthSecp256k1PubKey {
+        #[prost(bytes = "vec", tag = "1")]
+        pub key: Vec<u8>,
+    }
+}
+
+impl TryFrom<inner::EthSecp256k1PubKey> for EthSecp256k1PubKey {
+    type Error = DecodeError;
+
+    fn try_from(raw: inner::EthSecp256k1PubKey) -> Result<Self, Self::Error> {
+        raw.key.try_into()
+    }
+}
+
+impl From<EthSecp256k1PubKey> for inner::EthSecp256k1PubKey {
+    fn from(key: EthSecp256k1PubKey) -> inner::EthSecp256k1PubKey {
+        inner::EthSecp256k1PubKey { key: key.into() }
+    }
+}
+
+impl Protobuf<inner::EthSecp256k1PubKey> for EthSecp256k1PubKey {}
+
+#[cfg(test)]
+mod tests {
+    use keyring::key::pair::{eth_secp256k1_key_pair::EthSecp256k1KeyPair, KeyPair};
+
+    use crate::crypto::keys::{GearsPublicKey, ReadAccAddress};
+
+    use super::*;
+
+    const MNEMONIC: &str = "race draft rival universe maid cheese steel logic crowd fork comic easy truth drift tomorrow eye buddy head time cash swing swift midnight borrow";
+
+    fn key_pair() -> KeyPair {
+        let mnemonic =
+            bip32::Mnemonic::new(MNEMONIC, bip32::Language::English).expect("hardcoded is valid");
+        KeyPair::EthSecp256k1(EthSecp256k1KeyPair::from_mnemonic(&mnemonic))
+    }
+
+    #[test]
+    fn get_address_is_keccak_of_uncompressed_key() {
+        // private key `1` gives public key equal to generator point with well known address
+        let key: EthSecp256k1PubKey = data_encoding::HEXLOWER
+            .decode(b"0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798")
+            .expect("hardcoded is valid")
+            .try_into()
+            .expect("hardcoded is valid");
+
+        assert_eq!(
+            Vec::<u8>::from(key.get_address()),
+            data_encoding::HEXLOWER
+                .decode(b"7e5f4552091a69125d5dfcb7b8c2659029395bdf")
+                .expect("hardcoded is valid")
+        );
+    }
+
+    #[test]
+    fn verify_signature_works() {
+        let key_pair = key_pair();
+        let message = b"message to sign";
+
+        let signature = key_pair.sign(message);
+        assert_eq!(signature.len(), RECOVERABLE_SIGNATURE_LEN);
+
+        let crate::crypto::public::PublicKey::EthSecp256k1(public_key) =
+            key_pair.get_gears_public_key()
+        else {
+            panic!("eth key pair should return eth public key")
+        };
+
+        public_key
+            .verify_signature(message, &signature)
+            .expect("signature is valid");
+        public_key
+            .verify_signature(message, &signature[..RECOVERABLE_SIGNATURE_LEN - 1])
+            .expect("signature without recovery id is valid");
+        public_key
+            .verify_signature(b"other message", &signature)
+            .expect_err("signature of other message is invalid");
+
+        assert_eq!(public_key.get_address(), key_pair.get_address());
+    }
+}
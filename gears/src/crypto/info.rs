@@ -19,7 +19,13 @@ use crate::{
     types::{
         auth::{fee::Fee, info::AuthInfo, tip::Tip},
         signing::SignerInfo,
-        tx::{body::TxBody, signer::SignerData, Tx, TxMessage},
+        tx::{
+            aux_signer::{AuxSignerData, SignDocDirectAux},
+            body::TxBody,
+            raw::{TxRaw, TxWithRaw},
+            signer::SignerData,
+            Tx, TxMessage,
+        },
     },
 };
 
@@ -67,6 +73,96 @@ pub fn create_signed_transaction_direct<M: TxMessage, K: SigningKey + GearsPubli
     // Ok()
 }
 
+/// Sign tx body with `SIGN_MODE_DIRECT_AUX`. Result should be passed to fee payer
+/// which adds fee, its signature and broadcasts tx, see [create_signed_transaction_with_aux]
+pub fn create_aux_signer_data<M: TxMessage, K: SigningKey + ReadAccAddress + GearsPublicKey>(
+    signing_info: SigningInfo<'_, K>,
+    chain_id: ChainId,
+    tip: Option<Tip>,
+    body: TxBody<M>,
+) -> Result<AuxSignerData, K::Error> {
+    let sign_doc = SignDocDirectAux {
+        body_bytes: body.encode_vec(),
+        public_key: signing_info.key.get_gears_public_key(),
+        chain_id,
+        account_number: signing_info.account_number,
+        sequence: signing_info.sequence,
+        tip,
+    };
+
+    let sig = signing_info.key.sign(&sign_doc.to_sign_bytes())?;
+
+    Ok(AuxSignerData {
+        address: signing_info.key.get_address(),
+        sign_doc,
+        mode: SignMode::DirectAux,
+        sig,
+    })
+}
+
+/// Build tx from data of auxiliary signer and sign it by fee payer with `SIGN_MODE_DIRECT`.
+/// Fee payer must be the last signer of tx, so `fee.payer` should be set to its address.
+///
+/// `body` should be decoded from `body_bytes` of aux signer. Raw tx keeps those bytes
+/// as is, because re-encoding of `body` may differ from what aux signer signed.
+pub fn create_signed_transaction_with_aux<M: TxMessage, K: SigningKey + GearsPublicKey>(
+    aux_signer_data: AuxSignerData,
+    fee_payer: SigningInfo<'_, K>,
+    fee: Fee,
+    body: TxBody<M>,
+) -> Result<TxWithRaw<M>, K::Error> {
+    let AuxSignerData {
+        address: _,
+        sign_doc,
+        mode,
+        sig,
+    } = aux_signer_data;
+
+    let auth_info = AuthInfo {
+        signer_infos: vec![
+            SignerInfo {
+                public_key: Some(sign_doc.public_key),
+                mode_info: ModeInfo::Single(mode),
+                sequence: sign_doc.sequence,
+            },
+            SignerInfo {
+                public_key: Some(fee_payer.key.get_gears_public_key()),
+                mode_info: ModeInfo::Single(SignMode::Direct),
+                sequence: fee_payer.sequence,
+            },
+        ],
+        fee,
+        tip: sign_doc.tip,
+    };
+
+    let sign_doc = SignDoc {
+        body_bytes: sign_doc.body_bytes,
+        auth_info_bytes: auth_info.encode_vec(),
+        chain_id: sign_doc.chain_id.into(),
+        account_number: fee_payer.account_number,
+    };
+
+    let fee_payer_sig = fee_payer.key.sign(&sign_doc.encode_to_vec())?;
+    let signatures = vec![sig, fee_payer_sig];
+
+    let raw = TxRaw {
+        body_bytes: sign_doc.body_bytes,
+        auth_info_bytes: sign_doc.auth_info_bytes,
+        signatures: signatures.clone(),
+    };
+
+    Ok(TxWithRaw {
+        tx_len: core_types::tx::raw::TxRaw::from(raw.clone()).encoded_len(),
+        tx: Tx {
+            body,
+            auth_info,
+            signatures,
+            signatures_data: Vec::new(),
+        },
+        raw,
+    })
+}
+
 // NOTE: we can't implement From<K::Error> for this type
 #[derive(Debug)]
 pub enum TextualSigningError<K: SigningKey> {
@@ -2,10 +2,14 @@ use std::{convert::Infallible, error::Error};
 
 use bip32::PublicKey as PublicKeyTrait;
 use keyring::key::pair::KeyPair;
+use p256::elliptic_curve::sec1::ToEncodedPoint;
 
 use crate::types::address::AccAddress;
 
-use super::{public::PublicKey, secp256k1::Secp256k1PubKey};
+use super::{
+    eth_secp256k1::EthSecp256k1PubKey, public::PublicKey, secp256k1::Secp256k1PubKey,
+    secp256r1::Secp256r1PubKey,
+};
 
 pub trait GearsPublicKey {
     /// Returns a Gears public key.
@@ -13,7 +17,9 @@ pub trait GearsPublicKey {
 }
 
 pub trait ReadAccAddress {
-    /// Returns a Bitcoin style addresses: RIPEMD160(SHA256(pubkey)).
+    /// Returns an address derived from public key. Bitcoin style RIPEMD160(SHA256(pubkey))
+    /// for secp256k1 keys, ethereum style for eth_secp256k1 keys and
+    /// SHA256(SHA256(proto_name) || pubkey) for secp256r1 keys.
     fn get_address(&self) -> AccAddress;
 }
 
@@ -33,6 +39,25 @@ impl GearsPublicKey for KeyPair {
                 );
                 PublicKey::Secp256k1(public_key)
             }
+            KeyPair::EthSecp256k1(key) => {
+                let raw_public_key = key.inner().public_key().to_bytes().to_vec();
+                let public_key: EthSecp256k1PubKey = raw_public_key.try_into().expect(
+                    "raw public key is a valid secp256k1 public key so this will always succeed",
+                );
+                PublicKey::EthSecp256k1(public_key)
+            }
+            KeyPair::Secp256r1(key) => {
+                let raw_public_key = key
+                    .inner()
+                    .public_key()
+                    .to_encoded_point(true)
+                    .as_bytes()
+                    .to_vec();
+                let public_key: Secp256r1PubKey = raw_public_key.try_into().expect(
+                    "raw public key is a valid secp256r1 public key so this will always succeed",
+                );
+                PublicKey::Secp256r1(public_key)
+            }
         }
     }
 }
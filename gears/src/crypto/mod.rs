@@ -1,9 +1,12 @@
 pub mod any_key;
 pub mod ed25519;
 pub mod errors;
+pub mod eth_secp256k1;
 pub mod info;
 pub mod keys;
 #[cfg(feature = "ledger")]
 pub mod ledger;
 pub mod public;
+pub mod remote_signer;
 pub mod secp256k1;
+pub mod secp256r1;
@@ -4,7 +4,10 @@ use core_types::any::google::Any;
 use core_types::Protobuf;
 use serde::{Deserialize, Serialize};
 
-use super::{ed25519::Ed25519PubKey, secp256k1::Secp256k1PubKey};
+use super::{
+    ed25519::Ed25519PubKey, eth_secp256k1::EthSecp256k1PubKey, secp256k1::Secp256k1PubKey,
+    secp256r1::Secp256r1PubKey,
+};
 
 pub type SigningError = secp256k1::Error;
 
@@ -17,9 +20,12 @@ pub struct DecodeError(pub String);
 pub enum PublicKey {
     #[serde(rename = "/cosmos.crypto.secp256k1.PubKey")]
     Secp256k1(Secp256k1PubKey),
-    //Secp256r1(Vec<u8>),
+    #[serde(rename = "/cosmos.crypto.secp256r1.PubKey")]
+    Secp256r1(Secp256r1PubKey),
     #[serde(rename = "/cosmos.crypto.ed25519.PubKey")]
     Ed25519(Ed25519PubKey),
+    #[serde(rename = "/ethermint.crypto.v1.ethsecp256k1.PubKey")]
+    EthSecp256k1(EthSecp256k1PubKey),
     //Multisig(Vec<u8>),
 }
 
@@ -31,14 +37,18 @@ impl PublicKey {
     ) -> Result<(), SigningError> {
         match self {
             PublicKey::Secp256k1(key) => key.verify_signature(message, signature),
+            PublicKey::Secp256r1(key) => key.verify_signature(message, signature),
             PublicKey::Ed25519(key) => key.verify_signature(message, signature),
+            PublicKey::EthSecp256k1(key) => key.verify_signature(message, signature),
         }
     }
 
     pub fn get_address(&self) -> AccAddress {
         match self {
             PublicKey::Secp256k1(key) => key.get_address(),
+            PublicKey::Secp256r1(key) => key.get_address(),
             PublicKey::Ed25519(key) => key.get_address(),
+            PublicKey::EthSecp256k1(key) => key.get_address(),
         }
     }
 }
@@ -53,11 +63,21 @@ impl TryFrom<Any> for PublicKey {
                     .map_err(|e| DecodeError(e.to_string()))?;
                 Ok(Self::Secp256k1(key))
             }
+            "/cosmos.crypto.secp256r1.PubKey" => {
+                let key = Secp256r1PubKey::decode::<Bytes>(any.value.into())
+                    .map_err(|e| DecodeError(e.to_string()))?;
+                Ok(Self::Secp256r1(key))
+            }
             "/cosmos.crypto.ed25519.PubKey" => {
                 let key = Ed25519PubKey::decode::<Bytes>(any.value.into())
                     .map_err(|e| DecodeError(e.to_string()))?;
                 Ok(Self::Ed25519(key))
             }
+            "/ethermint.crypto.v1.ethsecp256k1.PubKey" => {
+                let key = EthSecp256k1PubKey::decode::<Bytes>(any.value.into())
+                    .map_err(|e| DecodeError(e.to_string()))?;
+                Ok(Self::EthSecp256k1(key))
+            }
 
             _ => Err(DecodeError(format!(
                 "Key type not recognized: {}",
@@ -74,10 +94,18 @@ impl From<PublicKey> for Any {
                 type_url: "/cosmos.crypto.secp256k1.PubKey".to_string(),
                 value: key.encode_vec(),
             },
+            PublicKey::Secp256r1(key) => Any {
+                type_url: "/cosmos.crypto.secp256r1.PubKey".to_string(),
+                value: key.encode_vec(),
+            },
             PublicKey::Ed25519(key) => Any {
                 type_url: "/cosmos.crypto.ed25519.PubKey".to_string(),
                 value: key.encode_vec(),
             },
+            PublicKey::EthSecp256k1(key) => Any {
+                type_url: "/ethermint.crypto.v1.ethsecp256k1.PubKey".to_string(),
+                value: key.encode_vec(),
+            },
         }
     }
 }
@@ -112,11 +140,20 @@ impl From<InformalPublicKey> for PublicKey {
     }
 }
 
-impl From<PublicKey> for TendermintPublicKey {
-    fn from(key: PublicKey) -> Self {
+impl TryFrom<PublicKey> for TendermintPublicKey {
+    type Error = DecodeError;
+
+    fn try_from(key: PublicKey) -> Result<Self, Self::Error> {
         match key {
-            PublicKey::Ed25519(value) => TendermintPublicKey::Ed25519(value.into()),
-            PublicKey::Secp256k1(value) => TendermintPublicKey::Secp256k1(value.into()),
+            PublicKey::Ed25519(value) => Ok(TendermintPublicKey::Ed25519(value.into())),
+            PublicKey::Secp256k1(value) => Ok(TendermintPublicKey::Secp256k1(value.into())),
+            // tendermint has no separate type for ethereum keys, but underlying curve is the same
+            PublicKey::EthSecp256k1(value) => Ok(TendermintPublicKey::Secp256k1(
+                Secp256k1PubKey::from(value).into(),
+            )),
+            PublicKey::Secp256r1(_) => Err(DecodeError(
+                "secp256r1 keys are not supported by tendermint".to_owned(),
+            )),
         }
     }
 }
@@ -0,0 +1,534 @@
+//! Key which delegates signing to a remote signer service.
+//!
+//! The signer is reached over HTTP (`http://host:port/base`) or a Unix domain
+//! socket (`unix:///path/to/signer.sock`) and speaks the following JSON protocol.
+//! Byte strings are base64 encoded.
+//!
+//! `POST /pubkey` returns public key of a key held by the signer:
+//! ```json
+//! request:  { "key_name": "alice" }
+//! response: { "pub_key": { "@type": "/cosmos.crypto.secp256k1.PubKey", "key": "A0z..." } }
+//! ```
+//!
+//! `POST /sign` signs bytes with a key held by the signer. For `SIGN_MODE_DIRECT`
+//! `sign_bytes` is the encoded `SignDoc`, hashing is left to the signer:
+//! ```json
+//! request:  { "key_name": "alice", "sign_bytes": "CpABCo0BChwv..." }
+//! response: { "signature": "3Kv..." }
+//! ```
+//!
+//! Any status other than `200` is a failure with body `{ "error": "<reason>" }`.
+//!
+//! If the signer is started with a shared secret every request must carry it in
+//! `Authorization: Bearer <secret>` header, requests without it are rejected with `401`.
+
+pub mod server;
+
+#[cfg(unix)]
+use std::path::PathBuf;
+use std::{future::Future, io, time::Duration};
+
+use axum::{
+    body::Bytes,
+    http::{header, Request, StatusCode},
+};
+use http_body_util::{BodyExt, Full, Limited};
+use hyper_util::rt::TokioIo;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use tokio::{
+    io::{AsyncRead, AsyncWrite},
+    runtime::{Handle, RuntimeFlavor},
+};
+
+use crate::{runtime::runtime, types::address::AccAddress};
+
+use super::{
+    keys::{GearsPublicKey, ReadAccAddress, SigningKey},
+    public::PublicKey,
+};
+
+pub const PUBKEY_PATH: &str = "/pubkey";
+pub const SIGN_PATH: &str = "/sign";
+
+/// How long to wait for the signer. Custody services may ask an operator for approval
+/// so the timeout is generous.
+const IO_TIMEOUT: Duration = Duration::from_secs(120);
+
+/// Upper limit of body size accepted from the peer
+const MAX_BODY_LEN: usize = 1024 * 1024;
+
+#[derive(Debug, thiserror::Error)]
+pub enum RemoteSignerError {
+    #[error("unsupported remote signer url scheme: {0}")]
+    UnsupportedScheme(String),
+    #[error("invalid remote signer url: {0}")]
+    InvalidUrl(String),
+    #[error("remote signer connection error: {0}")]
+    Io(#[from] io::Error),
+    #[error("remote signer http error: {0}")]
+    Http(String),
+    #[error("remote signer didn't respond in {}s", IO_TIMEOUT.as_secs())]
+    Timeout,
+    #[error("remote signer responded with status {status}: {error}")]
+    Rejected { status: u16, error: String },
+    #[error("invalid remote signer response: {0}")]
+    InvalidResponse(String),
+    #[error("remote signer returned signature which doesn't match public key of `{0}`")]
+    SignatureMismatch(String),
+}
+
+/// Secret shared by the signer and its clients. Never printed by `Debug`.
+#[derive(Clone, PartialEq, Eq)]
+pub struct SharedSecret(String);
+
+impl SharedSecret {
+    pub fn new(secret: impl Into<String>) -> Self {
+        Self(secret.into())
+    }
+
+    /// Value of `Authorization` header which carries the secret
+    fn bearer(&self) -> String {
+        format!("Bearer {}", self.0)
+    }
+
+    /// Checks `Authorization` header in constant time
+    fn authorizes(&self, header: &[u8]) -> bool {
+        let expected = self.bearer();
+        let expected = expected.as_bytes();
+
+        expected.len() == header.len()
+            && expected
+                .iter()
+                .zip(header)
+                .fold(0, |acc, (a, b)| acc | (a ^ b))
+                == 0
+    }
+}
+
+impl std::fmt::Debug for SharedSecret {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("SharedSecret(***)")
+    }
+}
+
+impl std::str::FromStr for SharedSecret {
+    type Err = std::convert::Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self::new(s))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PubKeyRequest {
+    pub key_name: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PubKeyResponse {
+    pub pub_key: PublicKey,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SignRequest {
+    pub key_name: String,
+    #[serde(with = "base64_bytes")]
+    pub sign_bytes: Vec<u8>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SignResponse {
+    #[serde(with = "base64_bytes")]
+    pub signature: Vec<u8>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ErrorResponse {
+    pub error: String,
+}
+
+/// Address of remote signer
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SignerEndpoint {
+    Http {
+        /// `host:port` to connect to
+        address: String,
+        /// path prefix of the protocol endpoints
+        base_path: String,
+    },
+    #[cfg(unix)]
+    Unix(PathBuf),
+}
+
+impl TryFrom<&url::Url> for SignerEndpoint {
+    type Error = RemoteSignerError;
+
+    fn try_from(url: &url::Url) -> Result<Self, Self::Error> {
+        match url.scheme() {
+            "http" => {
+                let host = url
+                    .host_str()
+                    .ok_or_else(|| RemoteSignerError::InvalidUrl(format!("missing host: {url}")))?;
+                let port = url.port_or_known_default().unwrap_or(80);
+
+                Ok(Self::Http {
+                    address: format!("{host}:{port}"),
+                    base_path: url.path().trim_end_matches('/').to_owned(),
+                })
+            }
+            #[cfg(unix)]
+            "unix" => {
+                if url.path().is_empty() {
+                    return Err(RemoteSignerError::InvalidUrl(format!(
+                        "missing socket path: {url}"
+                    )));
+                }
+
+                Ok(Self::Unix(PathBuf::from(url.path())))
+            }
+            scheme => Err(RemoteSignerError::UnsupportedScheme(scheme.to_owned())),
+        }
+    }
+}
+
+impl SignerEndpoint {
+    fn call<Req: Serialize, Res: DeserializeOwned>(
+        &self,
+        path: &str,
+        request: &Req,
+        secret: Option<&SharedSecret>,
+    ) -> Result<Res, RemoteSignerError> {
+        let body = serde_json::to_vec(request).expect("serialization of request won't fail");
+
+        let (status, body) = block_on(async {
+            tokio::time::timeout(IO_TIMEOUT, self.exchange(path, body, secret))
+                .await
+                .map_err(|_| RemoteSignerError::Timeout)
+        })??;
+
+        if status != StatusCode::OK {
+            let error = serde_json::from_slice::<ErrorResponse>(&body)
+                .map(|e| e.error)
+                .unwrap_or_else(|_| String::from_utf8_lossy(&body).into_owned());
+
+            return Err(RemoteSignerError::Rejected {
+                status: status.as_u16(),
+                error,
+            });
+        }
+
+        serde_json::from_slice(&body).map_err(|e| RemoteSignerError::InvalidResponse(e.to_string()))
+    }
+
+    async fn exchange(
+        &self,
+        path: &str,
+        body: Vec<u8>,
+        secret: Option<&SharedSecret>,
+    ) -> Result<(StatusCode, Bytes), RemoteSignerError> {
+        let (host, uri) = match self {
+            SignerEndpoint::Http { address, base_path } => {
+                (address.as_str(), format!("{base_path}{path}"))
+            }
+            #[cfg(unix)]
+            SignerEndpoint::Unix(_) => ("localhost", path.to_owned()),
+        };
+
+        let mut request = Request::post(uri)
+            .header(header::HOST, host)
+            .header(header::CONTENT_TYPE, "application/json")
+            .header(header::ACCEPT, "application/json");
+        if let Some(secret) = secret {
+            request = request.header(header::AUTHORIZATION, secret.bearer());
+        }
+        let request = request
+            .body(Full::new(Bytes::from(body)))
+            .map_err(|e| RemoteSignerError::Http(e.to_string()))?;
+
+        match self {
+            SignerEndpoint::Http { address, .. } => {
+                send(tokio::net::TcpStream::connect(address).await?, request).await
+            }
+            #[cfg(unix)]
+            SignerEndpoint::Unix(socket) => {
+                send(tokio::net::UnixStream::connect(socket).await?, request).await
+            }
+        }
+    }
+}
+
+/// Run `future` on the shared runtime from sync code. [tokio::runtime::Runtime::block_on]
+/// panics within async context, e.g. if signing is called by a client which runs its own
+/// tokio runtime, so the current worker leaves that runtime first.
+fn block_on<F>(future: F) -> F::Output
+where
+    F: Future + Send,
+    F::Output: Send,
+{
+    match Handle::try_current().map(|handle| handle.runtime_flavor()) {
+        Err(_) => runtime().block_on(future),
+        // single threaded runtime can't give up its only worker
+        Ok(RuntimeFlavor::CurrentThread) => std::thread::scope(|scope| {
+            scope
+                .spawn(|| runtime().block_on(future))
+                .join()
+                .unwrap_or_else(|e| std::panic::resume_unwind(e))
+        }),
+        Ok(_) => tokio::task::block_in_place(|| runtime().block_on(future)),
+    }
+}
+
+/// Sends single request over fresh connection and reads the whole response
+async fn send<S: AsyncRead + AsyncWrite + Send + Unpin + 'static>(
+    stream: S,
+    request: Request<Full<Bytes>>,
+) -> Result<(StatusCode, Bytes), RemoteSignerError> {
+    let http_err = |e: hyper::Error| RemoteSignerError::Http(e.to_string());
+
+    let (mut sender, connection) = hyper::client::conn::http1::handshake(TokioIo::new(stream))
+        .await
+        .map_err(http_err)?;
+    tokio::spawn(async move {
+        if let Err(e) = connection.await {
+            tracing::debug!("remote signer connection closed with error: {e}");
+        }
+    });
+
+    let response = sender.send_request(request).await.map_err(http_err)?;
+    let status = response.status();
+    let body = Limited::new(response.into_body(), MAX_BODY_LEN)
+        .collect()
+        .await
+        .map_err(|e| RemoteSignerError::InvalidResponse(e.to_string()))?
+        .to_bytes();
+
+    Ok((status, body))
+}
+
+/// Key stored by remote signer. Public key is fetched once on creation,
+/// each signature is requested from the signer.
+#[derive(Debug, Clone)]
+pub struct RemoteSignerKey {
+    endpoint: SignerEndpoint,
+    key_name: String,
+    secret: Option<SharedSecret>,
+    public_key: PublicKey,
+    address: AccAddress,
+}
+
+impl RemoteSignerKey {
+    pub fn new(
+        url: &url::Url,
+        key_name: impl Into<String>,
+        secret: Option<SharedSecret>,
+    ) -> Result<Self, RemoteSignerError> {
+        let endpoint = SignerEndpoint::try_from(url)?;
+        let key_name = key_name.into();
+
+        let PubKeyResponse { pub_key } = endpoint.call(
+            PUBKEY_PATH,
+            &PubKeyRequest {
+                key_name: key_name.clone(),
+            },
+            secret.as_ref(),
+        )?;
+
+        Ok(Self {
+            endpoint,
+            key_name,
+            secret,
+            address: pub_key.get_address(),
+            public_key: pub_key,
+        })
+    }
+
+    pub fn key_name(&self) -> &str {
+        &self.key_name
+    }
+}
+
+impl ReadAccAddress for RemoteSignerKey {
+    fn get_address(&self) -> AccAddress {
+        self.address.clone()
+    }
+}
+
+impl GearsPublicKey for RemoteSignerKey {
+    fn get_gears_public_key(&self) -> PublicKey {
+        self.public_key.clone()
+    }
+}
+
+impl SigningKey for RemoteSignerKey {
+    type Error = RemoteSignerError;
+
+    fn sign(&self, message: &[u8]) -> Result<Vec<u8>, Self::Error> {
+        let SignResponse { signature } = self.endpoint.call(
+            SIGN_PATH,
+            &SignRequest {
+                key_name: self.key_name.clone(),
+                sign_bytes: message.to_vec(),
+            },
+            self.secret.as_ref(),
+        )?;
+
+        // catch misconfigured signer before broadcasting a tx which will be rejected
+        self.public_key
+            .verify_signature(message, &signature)
+            .map_err(|_| RemoteSignerError::SignatureMismatch(self.key_name.clone()))?;
+
+        Ok(signature)
+    }
+}
+
+mod base64_bytes {
+    use serde::{de, Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&data_encoding::BASE64.encode(bytes))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        data_encoding::BASE64
+            .decode(s.as_bytes())
+            .map_err(de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use keyring::key::pair::{secp256k1_key_pair::Secp256k1KeyPair, KeyPair};
+
+    use super::{server::RemoteSigner, *};
+
+    const MNEMONIC: &str = "race draft rival universe maid cheese steel logic crowd fork comic easy truth drift tomorrow eye buddy head time cash swing swift midnight borrow";
+
+    fn signer() -> (RemoteSigner, KeyPair) {
+        let mnemonic =
+            bip32::Mnemonic::new(MNEMONIC, bip32::Language::English).expect("hardcoded is valid");
+        let key = KeyPair::Secp256k1(Secp256k1KeyPair::from_mnemonic(&mnemonic));
+
+        (
+            RemoteSigner::new(HashMap::from([("alice".to_owned(), key.clone())])),
+            key,
+        )
+    }
+
+    #[test]
+    fn endpoint_from_url() {
+        let url = url::Url::parse("http://127.0.0.1:26660/signer/").expect("hardcoded is valid");
+        assert_eq!(
+            SignerEndpoint::try_from(&url).expect("http is supported"),
+            SignerEndpoint::Http {
+                address: "127.0.0.1:26660".to_owned(),
+                base_path: "/signer".to_owned()
+            }
+        );
+
+        let url = url::Url::parse("https://127.0.0.1").expect("hardcoded is valid");
+        assert!(matches!(
+            SignerEndpoint::try_from(&url),
+            Err(RemoteSignerError::UnsupportedScheme(_))
+        ));
+    }
+
+    fn serve_http(signer: RemoteSigner) -> url::Url {
+        let listener = runtime()
+            .block_on(tokio::net::TcpListener::bind("127.0.0.1:0"))
+            .expect("port is available");
+        let url = url::Url::parse(&format!(
+            "http://{}",
+            listener.local_addr().expect("listener is bound")
+        ))
+        .expect("valid url");
+        runtime().spawn(signer.serve_tcp(listener));
+
+        url
+    }
+
+    #[test]
+    fn sign_over_http() {
+        let (signer, local_key) = signer();
+        let url = serve_http(signer);
+
+        let key = RemoteSignerKey::new(&url, "alice", None).expect("signer is running");
+        assert_eq!(key.get_address(), local_key.get_address());
+        assert_eq!(key.get_gears_public_key(), local_key.get_gears_public_key());
+
+        let message = b"sign doc bytes";
+        let signature = key.sign(message).expect("signer has the key");
+        assert_eq!(signature, local_key.sign(message));
+
+        let err = RemoteSignerKey::new(&url, "bob", None).expect_err("signer has no such key");
+        assert!(matches!(
+            err,
+            RemoteSignerError::Rejected { status: 404, .. }
+        ));
+    }
+
+    #[test]
+    fn sign_within_async_context() {
+        let (signer, local_key) = signer();
+        let url = serve_http(signer);
+
+        let key = RemoteSignerKey::new(&url, "alice", None).expect("signer is running");
+        let message = b"sign doc bytes";
+
+        for mut builder in [
+            tokio::runtime::Builder::new_current_thread(),
+            tokio::runtime::Builder::new_multi_thread(),
+        ] {
+            let client_runtime = builder.build().expect("runtime is created");
+            let signature = client_runtime
+                .block_on(async { key.sign(message) })
+                .expect("signer has the key");
+
+            assert_eq!(signature, local_key.sign(message));
+        }
+    }
+
+    #[test]
+    fn signer_with_secret_rejects_unauthorized() {
+        let (signer, local_key) = signer();
+        let url = serve_http(signer.with_secret(SharedSecret::new("s3cret")));
+
+        for secret in [None, Some(SharedSecret::new("wrong"))] {
+            let err =
+                RemoteSignerKey::new(&url, "alice", secret).expect_err("secret doesn't match");
+            assert!(matches!(
+                err,
+                RemoteSignerError::Rejected { status: 401, .. }
+            ));
+        }
+
+        let key = RemoteSignerKey::new(&url, "alice", Some(SharedSecret::new("s3cret")))
+            .expect("secret matches");
+        assert_eq!(key.get_address(), local_key.get_address());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn sign_over_unix_socket() {
+        let (signer, local_key) = signer();
+        let socket = std::env::temp_dir().join(format!("gears-signer-{}.sock", std::process::id()));
+        let _ = std::fs::remove_file(&socket);
+        let listener = runtime()
+            .block_on(async { tokio::net::UnixListener::bind(&socket) })
+            .expect("socket path is available");
+        runtime().spawn(signer.serve_unix(listener));
+
+        let url = url::Url::parse(&format!("unix://{}", socket.display())).expect("valid url");
+        let key = RemoteSignerKey::new(&url, "alice", None).expect("signer is running");
+
+        let message = b"sign doc bytes";
+        assert_eq!(
+            key.sign(message).expect("signer has the key"),
+            local_key.sign(message)
+        );
+
+        let _ = std::fs::remove_file(&socket);
+    }
+}
@@ -0,0 +1,150 @@
+//! Reference implementation of remote signer which serves keys from memory.
+//! It is meant as a stand-in for real custody services in tests and local setups.
+
+use std::{collections::HashMap, io, sync::Arc, time::Duration};
+
+use axum::{
+    extract::{rejection::JsonRejection, DefaultBodyLimit, Request, State},
+    http::{header, StatusCode},
+    middleware::{self, Next},
+    response::{IntoResponse, Response},
+    routing::post,
+    Json, Router,
+};
+use keyring::key::pair::KeyPair;
+use tower_http::timeout::TimeoutLayer;
+
+use crate::crypto::keys::GearsPublicKey;
+
+use super::{
+    ErrorResponse, PubKeyRequest, PubKeyResponse, SharedSecret, SignRequest, SignResponse,
+    MAX_BODY_LEN, PUBKEY_PATH, SIGN_PATH,
+};
+
+/// Time limit to read a request and respond to it
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+type Keys = Arc<HashMap<String, KeyPair>>;
+
+#[derive(Debug, Clone)]
+pub struct RemoteSigner {
+    keys: Keys,
+    secret: Option<SharedSecret>,
+}
+
+impl RemoteSigner {
+    pub fn new(keys: HashMap<String, KeyPair>) -> Self {
+        Self {
+            keys: Arc::new(keys),
+            secret: None,
+        }
+    }
+
+    /// Require clients to present `secret`
+    pub fn with_secret(mut self, secret: SharedSecret) -> Self {
+        self.secret = Some(secret);
+        self
+    }
+
+    pub fn router(self) -> Router {
+        Router::new()
+            .route(PUBKEY_PATH, post(pubkey))
+            .route(SIGN_PATH, post(sign))
+            .fallback(|| async { error(StatusCode::NOT_FOUND, "unknown path".to_owned()) })
+            .layer(middleware::from_fn_with_state(self.secret, authorize))
+            .layer(DefaultBodyLimit::max(MAX_BODY_LEN))
+            .layer(TimeoutLayer::new(REQUEST_TIMEOUT))
+            .with_state(self.keys)
+    }
+
+    /// Returns only if listener fails.
+    pub async fn serve_tcp(self, listener: tokio::net::TcpListener) -> io::Result<()> {
+        axum::serve(listener, self.router()).await
+    }
+
+    /// Returns only if listener fails.
+    #[cfg(unix)]
+    pub async fn serve_unix(self, listener: tokio::net::UnixListener) -> io::Result<()> {
+        use hyper_util::{
+            rt::{TokioIo, TokioTimer},
+            service::TowerToHyperService,
+        };
+
+        let service = TowerToHyperService::new(self.router());
+        loop {
+            let (stream, _) = listener.accept().await?;
+            let service = service.clone();
+
+            tokio::spawn(async move {
+                let result = hyper::server::conn::http1::Builder::new()
+                    .timer(TokioTimer::new())
+                    .header_read_timeout(REQUEST_TIMEOUT)
+                    .serve_connection(TokioIo::new(stream), service)
+                    .await;
+
+                if let Err(e) = result {
+                    tracing::warn!("failed to serve remote signer connection: {e}");
+                }
+            });
+        }
+    }
+}
+
+async fn authorize(
+    State(secret): State<Option<SharedSecret>>,
+    request: Request,
+    next: Next,
+) -> Response {
+    if let Some(secret) = secret {
+        let authorized = request
+            .headers()
+            .get(header::AUTHORIZATION)
+            .is_some_and(|header| secret.authorizes(header.as_bytes()));
+
+        if !authorized {
+            return error(StatusCode::UNAUTHORIZED, "invalid credentials".to_owned());
+        }
+    }
+
+    next.run(request).await
+}
+
+async fn pubkey(
+    State(keys): State<Keys>,
+    request: Result<Json<PubKeyRequest>, JsonRejection>,
+) -> Result<Json<PubKeyResponse>, Response> {
+    let Json(PubKeyRequest { key_name }) = request.map_err(rejection)?;
+    let key = key(&keys, &key_name)?;
+
+    Ok(Json(PubKeyResponse {
+        pub_key: key.get_gears_public_key(),
+    }))
+}
+
+async fn sign(
+    State(keys): State<Keys>,
+    request: Result<Json<SignRequest>, JsonRejection>,
+) -> Result<Json<SignResponse>, Response> {
+    let Json(SignRequest {
+        key_name,
+        sign_bytes,
+    }) = request.map_err(rejection)?;
+    let key = key(&keys, &key_name)?;
+
+    Ok(Json(SignResponse {
+        signature: key.sign(&sign_bytes),
+    }))
+}
+
+fn key<'a>(keys: &'a Keys, key_name: &str) -> Result<&'a KeyPair, Response> {
+    keys.get(key_name)
+        .ok_or_else(|| error(StatusCode::NOT_FOUND, format!("key `{key_name}` not found")))
+}
+
+fn rejection(rejection: JsonRejection) -> Response {
+    error(rejection.status(), rejection.body_text())
+}
+
+fn error(status: StatusCode, error: String) -> Response {
+    (status, Json(ErrorResponse { error })).into_response()
+}
@@ -18,6 +18,10 @@ pub struct Secp256k1PubKey {
 }
 
 impl Secp256k1PubKey {
+    pub(crate) fn inner(&self) -> &PublicKey {
+        &self.key
+    }
+
     pub fn verify_signature(
         &self,
         message: impl AsRef<[u8]>,
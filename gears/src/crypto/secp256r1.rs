@@ -0,0 +1,192 @@
+use address::AccAddress;
+use core_types::Protobuf;
+use keyring::error::DecodeError;
+use p256::ecdsa::{signature::Verifier, Signature, VerifyingKey};
+use p256::elliptic_curve::sec1::ToEncodedPoint;
+use p256::PublicKey;
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use sha2::{Digest, Sha256};
+use std::fmt;
+
+use super::public::SigningError;
+
+/// Proto name of the key which is used in address derivation
+const PROTO_NAME: &str = "cosmos.crypto.secp256r1.PubKey";
+
+/// NIST P-256 public key. Mainly used by passkeys and HSMs.
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub struct Secp256r1PubKey {
+    #[serde(serialize_with = "serialize_key", deserialize_with = "deserialize_key")]
+    key: PublicKey,
+}
+
+impl Secp256r1PubKey {
+    /// Verifies signature `R || S` of `sha256(message)`. Like in cosmos sdk only
+    /// signatures with low `S` are accepted.
+    pub fn verify_signature(
+        &self,
+        message: impl AsRef<[u8]>,
+        signature: impl AsRef<[u8]>,
+    ) -> Result<(), SigningError> {
+        let signature = Signature::from_slice(signature.as_ref())
+            .map_err(|_| SigningError::InvalidSignature)?;
+
+        if signature.normalize_s().is_some() {
+            return Err(SigningError::InvalidSignature);
+        }
+
+        VerifyingKey::from(&self.key)
+            .verify(message.as_ref(), &signature)
+            .map_err(|_| SigningError::IncorrectSignature)
+    }
+
+    /// Returns address as cosmos sdk does: `sha256(sha256(proto_name) || key)`
+    pub fn get_address(&self) -> AccAddress {
+        let key_bytes = Vec::from(self.to_owned());
+
+        let type_hash = Sha256::digest(PROTO_NAME);
+
+        let mut hasher = Sha256::new();
+        hasher.update(type_hash);
+        hasher.update(key_bytes);
+        let hash = hasher.finalize();
+
+        hash.as_slice()
+            .try_into()
+            .expect("sha256 digest size is 32 bytes which is less than AccAddress::MAX_ADDR_LEN")
+    }
+}
+
+impl TryFrom<Vec<u8>> for Secp256r1PubKey {
+    type Error = DecodeError;
+
+    fn try_from(value: Vec<u8>) -> Result<Self, Self::Error> {
+        let key = PublicKey::from_sec1_bytes(&value)
+            .map_err(|e| DecodeError(format!("invalid key: {e}")))?;
+
+        Ok(Secp256r1PubKey { key })
+    }
+}
+
+impl From<Secp256r1PubKey> for Vec<u8> {
+    /// Compressed SEC1 encoded key
+    fn from(key: Secp256r1PubKey) -> Vec<u8> {
+        key.key.to_encoded_point(true).as_bytes().to_vec()
+    }
+}
+
+mod inner {
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Secp256r1PubKey {
+        #[prost(bytes = "vec", tag = "1")]
+        pub key: Vec<u8>,
+    }
+}
+
+impl TryFrom<inner::Secp256r1PubKey> for Secp256r1PubKey {
+    type Error = DecodeError;
+
+    fn try_from(raw: inner::Secp256r1PubKey) -> Result<Self, Self::Error> {
+        raw.key.try_into()
+    }
+}
+
+impl From<Secp256r1PubKey> for inner::Secp256r1PubKey {
+    fn from(key: Secp256r1PubKey) -> inner::Secp256r1PubKey {
+        inner::Secp256r1PubKey { key: key.into() }
+    }
+}
+
+impl Protobuf<inner::Secp256r1PubKey> for Secp256r1PubKey {}
+
+fn serialize_key<S>(key: &PublicKey, s: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    s.serialize_str(&data_encoding::BASE64.encode(key.to_encoded_point(true).as_bytes()))
+}
+
+fn deserialize_key<'de, D>(deserializer: D) -> Result<PublicKey, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_str(Secp256r1Visitor)
+}
+
+struct Secp256r1Visitor;
+
+impl<'de> de::Visitor<'de> for Secp256r1Visitor {
+    type Value = PublicKey;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("string-encoded secp256r1 public key")
+    }
+
+    fn visit_str<E>(self, v: &str) -> Result<Self::Value, E>
+    where
+        E: de::Error,
+    {
+        let key = data_encoding::BASE64
+            .decode(v.as_bytes())
+            .map_err(|e| E::custom(format!("Error parsing public key '{}': {}", v, e)))?;
+
+        PublicKey::from_sec1_bytes(&key)
+            .map_err(|e| E::custom(format!("Error parsing public key '{}': {}", v, e)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use keyring::key::pair::{secp256r1_key_pair::Secp256r1KeyPair, KeyPair};
+
+    use crate::crypto::{
+        keys::{GearsPublicKey, ReadAccAddress},
+        public::PublicKey,
+    };
+
+    use super::*;
+
+    const MNEMONIC: &str = "race draft rival universe maid cheese steel logic crowd fork comic easy truth drift tomorrow eye buddy head time cash swing swift midnight borrow";
+
+    fn key_pair() -> KeyPair {
+        let mnemonic =
+            bip32::Mnemonic::new(MNEMONIC, bip32::Language::English).expect("hardcoded is valid");
+        KeyPair::Secp256r1(Secp256r1KeyPair::from_mnemonic(&mnemonic))
+    }
+
+    #[test]
+    fn serde_roundtrip() {
+        let PublicKey::Secp256r1(key) = key_pair().get_gears_public_key() else {
+            panic!("secp256r1 key pair should return secp256r1 public key")
+        };
+
+        let json = serde_json::to_string(&key).expect("serialization won't fail");
+        let deserialized: Secp256r1PubKey =
+            serde_json::from_str(&json).expect("serialized key is valid");
+
+        assert_eq!(key, deserialized);
+        assert_eq!(Vec::<u8>::from(key).len(), 33);
+    }
+
+    #[test]
+    fn verify_signature_works() {
+        let key_pair = key_pair();
+        let message = b"message to sign";
+
+        let signature = key_pair.sign(message);
+
+        let PublicKey::Secp256r1(public_key) = key_pair.get_gears_public_key() else {
+            panic!("secp256r1 key pair should return secp256r1 public key")
+        };
+
+        public_key
+            .verify_signature(message, &signature)
+            .expect("signature is valid");
+        public_key
+            .verify_signature(b"other message", &signature)
+            .expect_err("signature of other message is invalid");
+
+        assert_eq!(public_key.get_address(), key_pair.get_address());
+        assert_eq!(Vec::<u8>::from(public_key.get_address()).len(), 32);
+    }
+}
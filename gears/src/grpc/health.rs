@@ -1,77 +1,287 @@
+use std::marker::PhantomData;
+
+use extensions::pagination::IteratorPaginate;
+use ibc_proto::cosmos::base::query::v1beta1::{PageRequest, PageResponse};
 use ibc_proto::cosmos::base::tendermint::v1beta1::service_server::Service as HealthService;
 use ibc_proto::cosmos::base::tendermint::v1beta1::service_server::ServiceServer as HealthServer;
 use ibc_proto::cosmos::base::tendermint::v1beta1::{
     AbciQueryRequest, AbciQueryResponse, GetBlockByHeightRequest, GetBlockByHeightResponse,
     GetLatestBlockRequest, GetLatestBlockResponse, GetLatestValidatorSetRequest,
     GetLatestValidatorSetResponse, GetNodeInfoRequest, GetNodeInfoResponse, GetSyncingRequest,
-    GetSyncingResponse, GetValidatorSetByHeightRequest, GetValidatorSetByHeightResponse,
+    GetSyncingResponse, GetValidatorSetByHeightRequest, GetValidatorSetByHeightResponse, ProofOp,
+    ProofOps, Validator, VersionInfo,
 };
+use prost::Message;
+use tendermint::informal::validator::Info;
+use tendermint::rpc::client::{Client, HttpClient, HttpClientUrl, Paging};
+use tendermint::rpc::response::block::Response as BlockResponse;
+use tendermint::rpc::url::Url;
+use tendermint::types::proto::block::{BlockId, Height};
+use tendermint::types::proto::p2p::DefaultNodeInfo;
 use tonic::{Request, Response, Status};
 use tracing::info;
 
+use crate::application::ApplicationInfo;
+use crate::types::pagination::request::{PaginationRequest, QUERY_DEFAULT_LIMIT};
+use crate::types::pagination::response::PaginationResponse;
+use crate::types::response::validators::Validator as GearsValidator;
+
+/// Implementation of `cosmos.base.tendermint.v1beta1.Service` which proxies requests to Tendermint
 #[derive(Debug)]
-pub struct GearsHealthService;
+pub struct GearsHealthService<AI> {
+    tendermint_rpc_address: HttpClientUrl,
+    _phantom: PhantomData<AI>,
+}
+
+impl<AI> GearsHealthService<AI> {
+    fn client(&self) -> HttpClient {
+        HttpClient::new::<Url>(self.tendermint_rpc_address.clone().into()).expect("the conversion to Url then back to HttClientUrl should not be necessary, it will never fail, the dep needs to be fixed")
+    }
+}
 
 #[tonic::async_trait]
-impl HealthService for GearsHealthService {
+impl<AI: ApplicationInfo> HealthService for GearsHealthService<AI> {
     async fn abci_query(
         &self,
-        _request: Request<AbciQueryRequest>,
+        request: Request<AbciQueryRequest>,
     ) -> Result<Response<AbciQueryResponse>, Status> {
-        //TODO: implement
-        unimplemented!()
+        info!("Received a gRPC request health::abci_query");
+        let AbciQueryRequest {
+            data,
+            path,
+            height,
+            prove,
+        } = request.into_inner();
+
+        let height = match height {
+            0 => None,
+            height => Some(
+                Height::try_from(height)
+                    .map_err(|_| Status::invalid_argument(format!("invalid height: {height}")))?,
+            ),
+        };
+
+        let res = self
+            .client()
+            .abci_query(Some(path), data, height, prove)
+            .await
+            .map_err(tendermint_error)?;
+
+        Ok(Response::new(AbciQueryResponse {
+            code: res.code.value(),
+            log: res.log,
+            info: res.info,
+            index: res.index,
+            key: res.key,
+            value: res.value,
+            proof_ops: res.proof.map(|proof| ProofOps {
+                ops: proof
+                    .ops
+                    .into_iter()
+                    .map(|op| ProofOp {
+                        r#type: op.field_type,
+                        key: op.key,
+                        data: op.data,
+                    })
+                    .collect(),
+            }),
+            height: res.height.into(),
+            codespace: res.codespace,
+        }))
     }
 
     async fn get_node_info(
         &self,
         _request: Request<GetNodeInfoRequest>,
     ) -> Result<Response<GetNodeInfoResponse>, Status> {
-        //TODO: implement
-        unimplemented!()
+        info!("Received a gRPC request health::get_node_info");
+        let res = self.client().status().await.map_err(tendermint_error)?;
+
+        Ok(Response::new(GetNodeInfoResponse {
+            default_node_info: Some(transcode(
+                &DefaultNodeInfo::from(res.node_info).encode_to_vec(),
+            )?),
+            // TODO: extend ApplicationInfo trait and add member to form the version info
+            application_version: Some(VersionInfo {
+                name: AI::APP_NAME.to_string(),
+                app_name: AI::APP_NAME.to_string(),
+                version: AI::APP_VERSION.to_string(),
+                git_commit: "".to_string(),
+                build_tags: "".to_string(),
+                go_version: "".to_string(),
+                build_deps: vec![],
+                cosmos_sdk_version: "".to_string(),
+            }),
+        }))
     }
 
     async fn get_syncing(
         &self,
         _request: Request<GetSyncingRequest>,
     ) -> Result<Response<GetSyncingResponse>, Status> {
-        //TODO: query the node for syncing status and return it
         info!("Received a gRPC request health::get_syncing");
-        Ok(Response::new(GetSyncingResponse { syncing: false }))
+        let res = self.client().status().await.map_err(tendermint_error)?;
+
+        Ok(Response::new(GetSyncingResponse {
+            syncing: res.sync_info.catching_up,
+        }))
     }
 
     async fn get_latest_block(
         &self,
         _request: Request<GetLatestBlockRequest>,
     ) -> Result<Response<GetLatestBlockResponse>, Status> {
-        //TODO: implement
-        unimplemented!()
+        info!("Received a gRPC request health::get_latest_block");
+        let res = self
+            .client()
+            .latest_block()
+            .await
+            .map_err(tendermint_error)?;
+        let (block_id, block) = block_response(res)?;
+
+        Ok(Response::new(GetLatestBlockResponse {
+            block_id,
+            block,
+            sdk_block: None,
+        }))
     }
 
     async fn get_block_by_height(
         &self,
-        _request: Request<GetBlockByHeightRequest>,
+        request: Request<GetBlockByHeightRequest>,
     ) -> Result<Response<GetBlockByHeightResponse>, Status> {
-        //TODO: implement
-        unimplemented!()
+        info!("Received a gRPC request health::get_block_by_height");
+        let height = request_height(request.into_inner().height)?;
+
+        let res = self
+            .client()
+            .block(height)
+            .await
+            .map_err(tendermint_error)?;
+        let (block_id, block) = block_response(res)?;
+
+        Ok(Response::new(GetBlockByHeightResponse {
+            block_id,
+            block,
+            sdk_block: None,
+        }))
     }
 
     async fn get_latest_validator_set(
         &self,
-        _request: Request<GetLatestValidatorSetRequest>,
+        request: Request<GetLatestValidatorSetRequest>,
     ) -> Result<Response<GetLatestValidatorSetResponse>, Status> {
-        //TODO: implement
-        unimplemented!()
+        info!("Received a gRPC request health::get_latest_validator_set");
+        let res = self
+            .client()
+            .validators_latest(Paging::All)
+            .await
+            .map_err(tendermint_error)?;
+        let (validators, pagination) =
+            paginate_validators(res.validators, request.into_inner().pagination);
+
+        Ok(Response::new(GetLatestValidatorSetResponse {
+            block_height: res.block_height.into(),
+            validators,
+            pagination,
+        }))
     }
 
     async fn get_validator_set_by_height(
         &self,
-        _request: Request<GetValidatorSetByHeightRequest>,
+        request: Request<GetValidatorSetByHeightRequest>,
     ) -> Result<Response<GetValidatorSetByHeightResponse>, Status> {
-        //TODO: implement
-        unimplemented!()
+        info!("Received a gRPC request health::get_validator_set_by_height");
+        let GetValidatorSetByHeightRequest { height, pagination } = request.into_inner();
+        let height = request_height(height)?;
+
+        let res = self
+            .client()
+            .validators(height, Paging::All)
+            .await
+            .map_err(tendermint_error)?;
+        let (validators, pagination) = paginate_validators(res.validators, pagination);
+
+        Ok(Response::new(GetValidatorSetByHeightResponse {
+            block_height: res.block_height.into(),
+            validators,
+            pagination,
+        }))
     }
 }
 
-pub fn health_server() -> HealthServer<GearsHealthService> {
-    HealthServer::new(GearsHealthService)
+pub(super) fn tendermint_error(e: tendermint::rpc::error::Error) -> Status {
+    tracing::error!("Error connecting to Tendermint: {e}");
+    Status::unavailable("error connecting to Tendermint")
+}
+
+pub(super) fn request_height(height: i64) -> Result<u32, Status> {
+    u32::try_from(height)
+        .ok()
+        .filter(|height| *height > 0)
+        .ok_or_else(|| Status::invalid_argument(format!("invalid height: {height}")))
+}
+
+/// Tendermint types of `ibc_proto` and of `tendermint` crate are different versions
+/// of the same protobuf messages, so they're converted through their encoding
+pub(super) fn transcode<T: Message + Default>(bytes: &[u8]) -> Result<T, Status> {
+    T::decode(bytes).map_err(|e| {
+        tracing::error!("Error converting Tendermint data: {e}");
+        Status::internal("invalid data received from Tendermint")
+    })
+}
+
+#[allow(clippy::type_complexity)]
+pub(super) fn block_response<B: Message + Default, I: Message + Default>(
+    res: BlockResponse,
+) -> Result<(Option<I>, Option<B>), Status> {
+    Ok((
+        Some(transcode(&BlockId::from(res.block_id).encode_to_vec())?),
+        Some(transcode(&tendermint::informal::encode_block(res.block))?),
+    ))
+}
+
+fn paginate_validators(
+    validators: Vec<Info>,
+    pagination: Option<PageRequest>,
+) -> (Vec<Validator>, Option<PageResponse>) {
+    let mut pagination = PaginationRequest::from(pagination.unwrap_or_default());
+    if pagination.limit == 0 {
+        pagination.limit = QUERY_DEFAULT_LIMIT;
+    }
+
+    let (pagination_result, iter) = validators
+        .into_iter()
+        .map(GearsValidator::from)
+        .paginate(crate::extensions::pagination::Pagination::from(pagination));
+
+    let validators = iter
+        .map(
+            |GearsValidator {
+                 address,
+                 pub_key,
+                 voting_power,
+                 proposer_priority,
+             }| Validator {
+                address,
+                pub_key,
+                voting_power,
+                proposer_priority,
+            },
+        )
+        .collect();
+
+    (
+        validators,
+        Some(PaginationResponse::from(pagination_result).into()),
+    )
+}
+
+pub fn health_server<AI: ApplicationInfo>(
+    tendermint_rpc_address: HttpClientUrl,
+) -> HealthServer<GearsHealthService<AI>> {
+    HealthServer::new(GearsHealthService {
+        tendermint_rpc_address,
+        _phantom: Default::default(),
+    })
 }
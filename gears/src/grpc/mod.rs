@@ -7,6 +7,7 @@ use crate::runtime::runtime;
 
 mod error;
 pub mod health;
+pub mod service;
 pub mod tx;
 
 pub fn run_grpc_server(router: Router<Identity>, listen_addr: SocketAddr) {
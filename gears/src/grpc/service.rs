@@ -0,0 +1,75 @@
+//! Building blocks for gRPC services of modules which protobuf definitions
+//! don't provide generated `tonic` server
+
+use std::convert::Infallible;
+
+use tonic::{
+    body::BoxBody,
+    codec::ProstCodec,
+    codegen::{empty_body, http, Body, BoxFuture, StdError},
+    server::{Grpc, UnaryService},
+    Code, Request, Response, Status,
+};
+
+use crate::core::Protobuf;
+
+#[derive(Debug, Clone)]
+struct UnaryFn<F>(F);
+
+impl<Req, Res, F> UnaryService<Req> for UnaryFn<F>
+where
+    Req: Send + 'static,
+    Res: Send + 'static,
+    F: Fn(Req) -> Result<Res, Status> + Clone + Send + 'static,
+{
+    type Response = Res;
+    type Future = BoxFuture<Response<Res>, Status>;
+
+    fn call(&mut self, request: Request<Req>) -> Self::Future {
+        let handler = self.0.clone();
+        Box::pin(async move { handler(request.into_inner()).map(Response::new) })
+    }
+}
+
+/// Serve unary gRPC method with `handler` which maps raw request to raw response
+pub fn unary<B, Req, Res, F>(
+    request: http::Request<B>,
+    handler: F,
+) -> BoxFuture<http::Response<BoxBody>, Infallible>
+where
+    B: Body + Send + 'static,
+    B::Error: Into<StdError> + Send + 'static,
+    Req: prost::Message + Default + Send + 'static,
+    Res: prost::Message + Send + 'static,
+    F: Fn(Req) -> Result<Res, Status> + Clone + Send + 'static,
+{
+    Box::pin(async move {
+        let mut grpc = Grpc::new(ProstCodec::<Res, Req>::default());
+        Ok(grpc.unary(UnaryFn(handler), request).await)
+    })
+}
+
+/// Response to call of method which isn't served by service
+pub fn unimplemented() -> BoxFuture<http::Response<BoxBody>, Infallible> {
+    Box::pin(async move {
+        Ok(http::Response::builder()
+            .status(200)
+            .header("grpc-status", Code::Unimplemented as i32)
+            .header(
+                http::header::CONTENT_TYPE,
+                tonic::metadata::GRPC_CONTENT_TYPE,
+            )
+            .body(empty_body())
+            .expect("response with valid headers"))
+    })
+}
+
+/// Convert raw request of gRPC method into domain type
+pub fn from_raw<T, R>(raw: R) -> Result<T, Status>
+where
+    T: Protobuf<R>,
+    R: prost::Message + Default,
+    <T as TryFrom<R>>::Error: std::fmt::Display,
+{
+    T::try_from(raw).map_err(|e| Status::invalid_argument(e.to_string()))
+}
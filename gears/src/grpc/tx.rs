@@ -1,63 +1,388 @@
+use std::marker::PhantomData;
+use std::str::FromStr;
+
+use extensions::pagination::PaginationByOffset;
+use gas::metering::Gas;
+use ibc_proto::cosmos::base::abci::v1beta1::{GasInfo, Result as AbciResult, TxResponse};
+use ibc_proto::cosmos::base::query::v1beta1::{PageRequest, PageResponse};
 use ibc_proto::cosmos::tx::v1beta1::service_server::Service;
 use ibc_proto::cosmos::tx::v1beta1::service_server::ServiceServer as TxServer;
 use ibc_proto::cosmos::tx::v1beta1::{
-    BroadcastTxRequest, BroadcastTxResponse, GetBlockWithTxsRequest, GetBlockWithTxsResponse,
-    GetTxRequest, GetTxResponse, GetTxsEventRequest, GetTxsEventResponse, SimulateRequest,
-    SimulateResponse,
+    BroadcastMode, BroadcastTxRequest, BroadcastTxResponse, GetBlockWithTxsRequest,
+    GetBlockWithTxsResponse, GetTxRequest, GetTxResponse, GetTxsEventRequest, GetTxsEventResponse,
+    OrderBy, SimulateRequest, SimulateResponse, Tx,
 };
+use ibc_proto::google::protobuf::Any;
+use prost::Message;
+use tendermint::rpc::client::{Client, HttpClient, HttpClientUrl};
+use tendermint::rpc::url::Url;
 use tonic::{Request, Response, Status};
 use tracing::info;
 
+use crate::baseapp::indexer::{IndexedTx, TxIndex, TxIndexError, TxOrder, TxSearchQuery};
+use crate::baseapp::{NodeQueryHandler, RunTxInfo};
+use crate::types::pagination::request::QUERY_DEFAULT_LIMIT;
+
+use super::health::{block_response, request_height, tendermint_error, transcode};
+
+const TX_TYPE_URL: &str = "/cosmos.tx.v1beta1.Tx";
+
 #[derive(Debug)]
-pub struct TxService;
+pub struct TxService<QH, QReq, QRes> {
+    app: QH,
+    tendermint_rpc_address: HttpClientUrl,
+    _phantom: PhantomData<(QReq, QRes)>,
+}
+
+impl<QReq, QRes, QH: NodeQueryHandler<QReq, QRes>> TxService<QH, QReq, QRes> {
+    fn client(&self) -> HttpClient {
+        HttpClient::new::<Url>(self.tendermint_rpc_address.clone().into()).expect("the conversion to Url then back to HttClientUrl should not be necessary, it will never fail, the dep needs to be fixed")
+    }
+
+    /// Tx queries are served from application side index only
+    fn tx_index(&self) -> Result<&dyn TxIndex, Status> {
+        self.app.tx_index().ok_or_else(|| {
+            Status::unimplemented("tx queries require the node to be run with --tx-index")
+        })
+    }
+}
 
 #[tonic::async_trait]
-impl Service for TxService {
+impl<
+        QReq: Send + Sync + 'static,
+        QRes: Send + Sync + 'static,
+        QH: NodeQueryHandler<QReq, QRes>,
+    > Service for TxService<QH, QReq, QRes>
+{
     async fn simulate(
         &self,
-        _request: Request<SimulateRequest>,
+        request: Request<SimulateRequest>,
     ) -> Result<Response<SimulateResponse>, Status> {
         info!("Received a gRPC request tx::simulate");
-        // TODO: run simulation once implemented
+        let tx_bytes = request.into_inner().tx_bytes;
+        if tx_bytes.is_empty() {
+            return Err(Status::invalid_argument("empty tx_bytes is not allowed"));
+        }
+
+        let RunTxInfo {
+            events,
+            gas_wanted,
+            gas_used,
+        } = {
+            // simulation executes tx, so it's kept off async workers
+            let app = self.app.clone();
+            tokio::task::spawn_blocking(move || app.simulate_tx(tx_bytes.into()))
+                .await
+                .map_err(|e| Status::internal(e.to_string()))?
+                .map_err(|e| Status::invalid_argument(e.to_string()))?
+        };
+
+        let mut events_res = Vec::with_capacity(events.len());
+        for event in events {
+            // abci types of ibc_proto are another version of the same protobuf messages
+            events_res.push(
+                Message::decode(event.encode_to_vec().as_slice())
+                    .map_err(|e| Status::internal(e.to_string()))?,
+            );
+        }
+
         Ok(Response::new(SimulateResponse {
-            gas_info: None,
-            result: None,
+            gas_info: Some(GasInfo {
+                gas_wanted: match gas_wanted {
+                    Gas::Finite(gas) => gas.into(),
+                    Gas::Infinite => u64::MAX,
+                },
+                gas_used: gas_used.into(),
+            }),
+            result: Some(AbciResult {
+                events: events_res,
+                ..Default::default()
+            }),
         }))
     }
 
     async fn get_tx(
         &self,
-        _request: Request<GetTxRequest>,
+        request: Request<GetTxRequest>,
     ) -> Result<Response<GetTxResponse>, Status> {
-        //TODO: implement
-        unimplemented!()
+        info!("Received a gRPC request tx::get_tx");
+        let hash = request.into_inner().hash;
+        let hash: [u8; 32] = hex::decode(&hash)
+            .ok()
+            .and_then(|hash| hash.try_into().ok())
+            .ok_or_else(|| Status::invalid_argument(format!("invalid tx hash: {hash}")))?;
+
+        let indexed = self
+            .tx_index()?
+            .tx(&hash)
+            .map_err(index_error)?
+            .ok_or_else(|| {
+                Status::not_found(format!("tx not found: {}", hex::encode_upper(hash)))
+            })?;
+        let (tx, tx_response) = indexed_tx_response(indexed)?;
+
+        Ok(Response::new(GetTxResponse {
+            tx: Some(tx),
+            tx_response: Some(tx_response),
+        }))
     }
 
     async fn broadcast_tx(
         &self,
-        _request: Request<BroadcastTxRequest>,
+        request: Request<BroadcastTxRequest>,
     ) -> Result<Response<BroadcastTxResponse>, Status> {
-        //TODO: implement
-        unimplemented!()
+        info!("Received a gRPC request tx::broadcast_tx");
+        let BroadcastTxRequest { tx_bytes, mode } = request.into_inner();
+        if tx_bytes.is_empty() {
+            return Err(Status::invalid_argument("empty tx_bytes is not allowed"));
+        }
+
+        let mode = BroadcastMode::try_from(mode)
+            .map_err(|_| Status::invalid_argument(format!("invalid broadcast mode: {mode}")))?;
+
+        let client = self.client();
+        let tx_response = match mode {
+            BroadcastMode::Sync => {
+                let res = client
+                    .broadcast_tx_sync(tx_bytes)
+                    .await
+                    .map_err(tendermint_error)?;
+                TxResponse {
+                    txhash: res.hash.to_string(),
+                    codespace: res.codespace,
+                    code: res.code.into(),
+                    raw_log: res.log,
+                    ..Default::default()
+                }
+            }
+            BroadcastMode::Async => {
+                let res = client
+                    .broadcast_tx_async(tx_bytes)
+                    .await
+                    .map_err(tendermint_error)?;
+                TxResponse {
+                    txhash: res.hash.to_string(),
+                    codespace: res.codespace,
+                    code: res.code.into(),
+                    raw_log: res.log,
+                    ..Default::default()
+                }
+            }
+            // same as REST, unspecified mode waits for the block
+            BroadcastMode::Block | BroadcastMode::Unspecified => {
+                let res = client
+                    .broadcast_tx_commit(tx_bytes)
+                    .await
+                    .map_err(tendermint_error)?;
+                TxResponse {
+                    height: res.height.into(),
+                    txhash: res.hash.to_string(),
+                    codespace: res.deliver_tx.codespace,
+                    code: res.deliver_tx.code.into(),
+                    raw_log: res.deliver_tx.log,
+                    gas_wanted: res.deliver_tx.gas_wanted,
+                    gas_used: res.deliver_tx.gas_used,
+                    ..Default::default()
+                }
+            }
+        };
+
+        Ok(Response::new(BroadcastTxResponse {
+            tx_response: Some(tx_response),
+        }))
     }
 
     async fn get_txs_event(
         &self,
-        _request: Request<GetTxsEventRequest>,
+        request: Request<GetTxsEventRequest>,
     ) -> Result<Response<GetTxsEventResponse>, Status> {
-        //TODO: implement
-        unimplemented!()
+        info!("Received a gRPC request tx::get_txs_event");
+        let GetTxsEventRequest {
+            events,
+            pagination,
+            order_by,
+            page,
+            limit,
+        } = request.into_inner();
+
+        let query = TxSearchQuery::from_str(&events.join(" AND "))
+            .map_err(|e| Status::invalid_argument(e.to_string()))?;
+
+        let order = match OrderBy::try_from(order_by) {
+            Ok(OrderBy::Unspecified | OrderBy::Asc) => TxOrder::Ascending,
+            Ok(OrderBy::Desc) => TxOrder::Descending,
+            Err(_) => {
+                return Err(Status::invalid_argument(format!(
+                    "invalid order: {order_by}"
+                )))
+            }
+        };
+
+        // `page` and `limit` replace deprecated `pagination`, which is used if they aren't set
+        let pagination = if page > 0 || limit > 0 {
+            let limit = default_limit(limit);
+            PaginationByOffset::from((page.saturating_sub(1) as usize * limit, limit))
+        } else {
+            let PageRequest { offset, limit, .. } = pagination.unwrap_or_default();
+            PaginationByOffset::from((offset as usize, default_limit(limit)))
+        };
+
+        let result = self
+            .tx_index()?
+            .search(&query, pagination, order)
+            .map_err(index_error)?;
+
+        let mut txs = Vec::with_capacity(result.txs.len());
+        let mut tx_responses = Vec::with_capacity(result.txs.len());
+        for indexed in result.txs {
+            let (tx, tx_response) = indexed_tx_response(indexed)?;
+            txs.push(tx);
+            tx_responses.push(tx_response);
+        }
+
+        Ok(Response::new(GetTxsEventResponse {
+            txs,
+            tx_responses,
+            pagination: Some(PageResponse {
+                next_key: Vec::new(),
+                total: result.total,
+            }),
+            total: result.total,
+        }))
     }
 
     async fn get_block_with_txs(
         &self,
-        _request: Request<GetBlockWithTxsRequest>,
+        request: Request<GetBlockWithTxsRequest>,
     ) -> Result<Response<GetBlockWithTxsResponse>, Status> {
-        //TODO: implement
-        unimplemented!()
+        info!("Received a gRPC request tx::get_block_with_txs");
+        let GetBlockWithTxsRequest { height, pagination } = request.into_inner();
+        let height = request_height(height)?;
+
+        let res = self
+            .client()
+            .block(height)
+            .await
+            .map_err(tendermint_error)?;
+        let (block_id, block) = block_response(res)?;
+
+        let mut response = GetBlockWithTxsResponse {
+            txs: Vec::new(),
+            block_id,
+            block,
+            pagination: None,
+        };
+
+        let raw_txs = response
+            .block
+            .as_ref()
+            .and_then(|block| block.data.as_ref())
+            .map(|data| data.txs.as_slice())
+            .unwrap_or_default();
+
+        let PageRequest { offset, limit, .. } = pagination.unwrap_or_default();
+        response.txs = raw_txs
+            .iter()
+            .skip(offset as usize)
+            .take(default_limit(limit))
+            .map(|raw| transcode(raw.as_slice()))
+            .collect::<Result<_, _>>()?;
+        response.pagination = Some(PageResponse {
+            next_key: Vec::new(),
+            total: raw_txs.len() as u64,
+        });
+
+        Ok(Response::new(response))
+    }
+}
+
+/// Limit of page, the default one if it isn't set
+fn default_limit(limit: u64) -> usize {
+    match limit {
+        0 => QUERY_DEFAULT_LIMIT.into(),
+        limit => limit as usize,
+    }
+}
+
+fn index_error(e: TxIndexError) -> Status {
+    match e {
+        TxIndexError::Query(_) => Status::invalid_argument(e.to_string()),
+        TxIndexError::Decode(_) => {
+            tracing::error!("Error reading tx index: {e}");
+            Status::internal("invalid data in tx index")
+        }
     }
 }
 
-pub fn tx_server() -> TxServer<TxService> {
-    TxServer::new(TxService)
+/// Transaction found in the index and its result as they're returned by Cosmos SDK
+fn indexed_tx_response(indexed: IndexedTx) -> Result<(Tx, TxResponse), Status> {
+    let timestamp = indexed.timestamp_rfc3339().map_err(|e| {
+        tracing::error!("Error formatting tx timestamp: {e}");
+        Status::internal("invalid data in tx index")
+    })?;
+
+    let IndexedTx {
+        height,
+        index: _,
+        hash,
+        tx: raw_tx,
+        code,
+        codespace,
+        data,
+        log,
+        info,
+        gas_wanted,
+        gas_used,
+        events,
+        timestamp: _,
+    } = indexed;
+
+    let tx = Tx::decode(raw_tx.clone()).map_err(|e| {
+        tracing::error!("Error decoding indexed tx: {e}");
+        Status::internal("invalid data in tx index")
+    })?;
+
+    let mut events_res = Vec::with_capacity(events.len());
+    for event in events {
+        // abci types of ibc_proto are another version of the same protobuf messages
+        events_res.push(transcode(&event.encode_to_vec())?);
+    }
+
+    Ok((
+        tx,
+        TxResponse {
+            height: height.into(),
+            txhash: hex::encode_upper(hash),
+            codespace,
+            code,
+            data: hex::encode(data),
+            raw_log: log,
+            logs: Vec::new(),
+            info,
+            gas_wanted,
+            gas_used,
+            tx: Some(Any {
+                type_url: TX_TYPE_URL.to_owned(),
+                value: raw_tx.to_vec(),
+            }),
+            timestamp,
+            events: events_res,
+        },
+    ))
+}
+
+pub fn tx_server<QH, QReq, QRes>(
+    app: QH,
+    tendermint_rpc_address: HttpClientUrl,
+) -> TxServer<TxService<QH, QReq, QRes>>
+where
+    QReq: Send + Sync + 'static,
+    QRes: Send + Sync + 'static,
+    QH: NodeQueryHandler<QReq, QRes>,
+{
+    TxServer::new(TxService {
+        app,
+        tendermint_rpc_address,
+        _phantom: Default::default(),
+    })
 }
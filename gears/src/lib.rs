@@ -9,6 +9,7 @@ pub mod crypto;
 pub mod defaults;
 pub mod error;
 pub mod grpc;
+pub mod metrics;
 pub mod params;
 pub mod rest;
 pub(crate) mod runtime;
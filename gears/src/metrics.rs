@@ -0,0 +1,214 @@
+//! Prometheus metrics of the node
+
+use std::{
+    net::SocketAddr,
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        OnceLock,
+    },
+};
+
+use axum::{http::StatusCode, routing::get, Router};
+use kv_store::stats::{CacheStats, StoreStats};
+use prometheus::{
+    Encoder, Gauge, HistogramOpts, HistogramTimer, HistogramVec, IntCounter, IntCounterVec,
+    IntGauge, Opts, Registry, TextEncoder,
+};
+
+use crate::runtime::runtime;
+
+const NAMESPACE: &str = "gears";
+
+static METRICS: OnceLock<NodeMetrics> = OnceLock::new();
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+/// Return metrics of the node. Metrics are collected even if they are not exposed.
+pub fn metrics() -> &'static NodeMetrics {
+    METRICS.get_or_init(NodeMetrics::new)
+}
+
+/// Whether `/metrics` endpoint was started. Used to skip collection of labels
+/// which are costly to compute.
+pub fn enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+#[derive(Debug)]
+pub struct NodeMetrics {
+    registry: Registry,
+    block_height: IntGauge,
+    abci_latency: HistogramVec,
+    txs: IntCounterVec,
+    block_gas_used: IntGauge,
+    iavl_cache_hit_ratio: Gauge,
+    store_reads: IntCounter,
+    store_writes: IntCounter,
+}
+
+impl NodeMetrics {
+    fn new() -> Self {
+        let registry = Registry::new_custom(Some(NAMESPACE.to_owned()), None)
+            .expect("hard coded namespace is valid");
+
+        let block_height = IntGauge::new("block_height", "Height of the last committed block")
+            .expect("hard coded metric is valid");
+        let abci_latency = HistogramVec::new(
+            HistogramOpts::new(
+                "abci_method_duration_seconds",
+                "Time spent handling ABCI method",
+            ),
+            &["method"],
+        )
+        .expect("hard coded metric is valid");
+        let txs = IntCounterVec::new(
+            Opts::new(
+                "txs_total",
+                "Delivered transactions by result code and message type",
+            ),
+            &["code", "msg_type"],
+        )
+        .expect("hard coded metric is valid");
+        let block_gas_used = IntGauge::new("block_gas_used", "Gas used by the last block")
+            .expect("hard coded metric is valid");
+        let iavl_cache_hit_ratio = Gauge::new(
+            "iavl_cache_hit_ratio",
+            "Share of IAVL node lookups served from cache",
+        )
+        .expect("hard coded metric is valid");
+        let store_reads = IntCounter::new("store_reads_total", "Reads from kv stores")
+            .expect("hard coded metric is valid");
+        let store_writes = IntCounter::new("store_writes_total", "Writes and deletes to kv stores")
+            .expect("hard coded metric is valid");
+
+        for collector in [
+            Box::new(block_height.clone()) as Box<dyn prometheus::core::Collector>,
+            Box::new(abci_latency.clone()),
+            Box::new(txs.clone()),
+            Box::new(block_gas_used.clone()),
+            Box::new(iavl_cache_hit_ratio.clone()),
+            Box::new(store_reads.clone()),
+            Box::new(store_writes.clone()),
+        ] {
+            registry
+                .register(collector)
+                .expect("metrics are registered once");
+        }
+
+        Self {
+            registry,
+            block_height,
+            abci_latency,
+            txs,
+            block_gas_used,
+            iavl_cache_hit_ratio,
+            store_reads,
+            store_writes,
+        }
+    }
+
+    /// Start timer which records duration of ABCI `method` when dropped
+    pub fn abci_timer(&self, method: &str) -> HistogramTimer {
+        self.abci_latency.with_label_values(&[method]).start_timer()
+    }
+
+    pub fn record_tx<'a>(&self, code: u32, msg_types: impl IntoIterator<Item = &'a str>) {
+        let code = code.to_string();
+        for msg_type in msg_types {
+            self.txs.with_label_values(&[&code, msg_type]).inc();
+        }
+    }
+
+    pub fn record_commit(
+        &self,
+        height: u32,
+        block_gas_used: u64,
+        cache_stats: CacheStats,
+        store_stats: StoreStats,
+    ) {
+        self.block_height.set(height.into());
+        self.block_gas_used
+            .set(i64::try_from(block_gas_used).unwrap_or(i64::MAX));
+        self.iavl_cache_hit_ratio.set(cache_stats.hit_ratio());
+        // store stats are totals of the process so only difference since last commit is added
+        self.store_reads
+            .inc_by(store_stats.reads.saturating_sub(self.store_reads.get()));
+        self.store_writes
+            .inc_by(store_stats.writes.saturating_sub(self.store_writes.get()));
+    }
+
+    fn encode(&self) -> Result<String, prometheus::Error> {
+        let mut buffer = Vec::new();
+        TextEncoder::new().encode(&self.registry.gather(), &mut buffer)?;
+
+        Ok(String::from_utf8(buffer).expect("text encoder produces utf8"))
+    }
+}
+
+pub fn run_metrics_server(listen_addr: SocketAddr) {
+    ENABLED.store(true, Ordering::Relaxed);
+
+    std::thread::spawn(move || {
+        let result = runtime().block_on(launch(listen_addr));
+        if let Err(err) = result {
+            panic!("Failed to run metrics server with err: {}", err)
+        }
+    });
+}
+
+async fn launch(listen_addr: SocketAddr) -> anyhow::Result<()> {
+    let app = Router::new().route("/metrics", get(handler));
+
+    let listener = tokio::net::TcpListener::bind(listen_addr).await?;
+
+    tracing::info!("Metrics server running at {}", listen_addr);
+    axum::serve(listener, app).await?;
+
+    Ok(())
+}
+
+async fn handler() -> Result<String, (StatusCode, String)> {
+    metrics()
+        .encode()
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn commit_metrics_are_exported() {
+        let metrics = NodeMetrics::new();
+
+        metrics.record_commit(
+            5,
+            100,
+            CacheStats { hits: 3, misses: 1 },
+            StoreStats {
+                reads: 10,
+                writes: 4,
+            },
+        );
+        metrics.record_commit(
+            6,
+            50,
+            CacheStats { hits: 3, misses: 1 },
+            StoreStats {
+                reads: 12,
+                writes: 4,
+            },
+        );
+        metrics.record_tx(0, ["/cosmos.bank.v1beta1.MsgSend"]);
+
+        let text = metrics.encode().expect("encoding works");
+
+        assert!(text.contains("gears_block_height 6"));
+        assert!(text.contains("gears_block_gas_used 50"));
+        assert!(text.contains("gears_iavl_cache_hit_ratio 0.75"));
+        assert!(text.contains("gears_store_reads_total 12"));
+        assert!(text.contains("gears_store_writes_total 4"));
+        assert!(
+            text.contains(r#"gears_txs_total{code="0",msg_type="/cosmos.bank.v1beta1.MsgSend"} 1"#)
+        );
+    }
+}
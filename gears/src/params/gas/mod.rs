@@ -7,6 +7,7 @@ use crate::context::{QueryableContext, TransactionalContext};
 
 use super::ParamsSubspaceKey;
 
+pub mod schedule;
 pub mod space;
 pub mod space_mut;
 
@@ -0,0 +1,188 @@
+use std::collections::{HashMap, HashSet};
+
+use extensions::corruption::UnwrapCorrupt;
+use gas::{metering::config::GasConfig, Gas};
+use serde::{Deserialize, Serialize};
+use serde_with::serde_as;
+
+use crate::params::{ParamsDeserialize, ParamsSerialize};
+
+pub(crate) const KEY_KV_GAS_COSTS: &str = "KVStoreGasCosts";
+pub(crate) const KEY_TRANSIENT_GAS_COSTS: &str = "TransientStoreGasCosts";
+
+/// Gas schedule which is charged by stores on every operation of transaction.
+/// Stored in params store so it could be changed by param change proposal.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct GasSchedule {
+    pub kv: StoreGasCosts,
+    pub transient: StoreGasCosts,
+}
+
+impl Default for GasSchedule {
+    /// Costs of cosmos sdk, except for kv write flat cost. Stores charged read flat cost
+    /// on write before gas schedule, so it's kept to not change gas of existing chains.
+    fn default() -> Self {
+        let kv = GasConfig::kv();
+
+        Self {
+            // `GasConfig::kv` charges 2000 for write flat cost while we charge
+            // its read flat cost (1000) unless schedule is changed in params
+            kv: GasConfig {
+                write_cost_flat: kv.read_cost_flat,
+                ..*kv
+            }
+            .into(),
+            transient: (*GasConfig::default_transient()).into(),
+        }
+    }
+}
+
+impl GasSchedule {
+    pub fn kv_config(&self) -> GasConfig {
+        self.kv.into()
+    }
+
+    pub fn transient_config(&self) -> GasConfig {
+        self.transient.into()
+    }
+}
+
+impl ParamsSerialize for GasSchedule {
+    fn keys() -> HashSet<&'static str> {
+        [KEY_KV_GAS_COSTS, KEY_TRANSIENT_GAS_COSTS]
+            .into_iter()
+            .collect()
+    }
+
+    fn to_raw(&self) -> Vec<(&'static str, Vec<u8>)> {
+        let mut hash_map = Vec::with_capacity(2);
+
+        let kv = serde_json::to_string(&self.kv).expect("conversion to json won't fail");
+        hash_map.push((KEY_KV_GAS_COSTS, kv.into_bytes()));
+
+        let transient =
+            serde_json::to_string(&self.transient).expect("conversion to json won't fail");
+        hash_map.push((KEY_TRANSIENT_GAS_COSTS, transient.into_bytes()));
+
+        hash_map
+    }
+}
+
+impl ParamsDeserialize for GasSchedule {
+    fn from_raw(fields: HashMap<&'static str, Vec<u8>>) -> Self {
+        Self {
+            kv: serde_json::from_slice(fields.get(KEY_KV_GAS_COSTS).unwrap_or_corrupt())
+                .unwrap_or_corrupt(),
+            transient: serde_json::from_slice(
+                fields.get(KEY_TRANSIENT_GAS_COSTS).unwrap_or_corrupt(),
+            )
+            .unwrap_or_corrupt(),
+        }
+    }
+}
+
+/// Serializable version of [GasConfig]
+#[serde_as]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StoreGasCosts {
+    #[serde_as(as = "serde_with::DisplayFromStr")]
+    pub has_cost: Gas,
+    #[serde_as(as = "serde_with::DisplayFromStr")]
+    pub delete_cost: Gas,
+    #[serde_as(as = "serde_with::DisplayFromStr")]
+    pub read_cost_flat: Gas,
+    #[serde_as(as = "serde_with::DisplayFromStr")]
+    pub read_cost_per_byte: Gas,
+    #[serde_as(as = "serde_with::DisplayFromStr")]
+    pub write_cost_flat: Gas,
+    #[serde_as(as = "serde_with::DisplayFromStr")]
+    pub write_cost_per_byte: Gas,
+    #[serde_as(as = "serde_with::DisplayFromStr")]
+    pub iter_next_cost_flat: Gas,
+}
+
+impl From<GasConfig> for StoreGasCosts {
+    fn from(
+        GasConfig {
+            has_cost,
+            delete_cost,
+            read_cost_flat,
+            read_cost_per_byte,
+            write_cost_flat,
+            write_cost_per_byte,
+            iter_next_cost_flat,
+        }: GasConfig,
+    ) -> Self {
+        Self {
+            has_cost,
+            delete_cost,
+            read_cost_flat,
+            read_cost_per_byte,
+            write_cost_flat,
+            write_cost_per_byte,
+            iter_next_cost_flat,
+        }
+    }
+}
+
+impl From<StoreGasCosts> for GasConfig {
+    fn from(
+        StoreGasCosts {
+            has_cost,
+            delete_cost,
+            read_cost_flat,
+            read_cost_per_byte,
+            write_cost_flat,
+            write_cost_per_byte,
+            iter_next_cost_flat,
+        }: StoreGasCosts,
+    ) -> Self {
+        Self {
+            has_cost,
+            delete_cost,
+            read_cost_flat,
+            read_cost_per_byte,
+            write_cost_flat,
+            write_cost_per_byte,
+            iter_next_cost_flat,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn store_gas_costs_serialize_works() {
+        let costs: StoreGasCosts = (*GasConfig::kv()).into();
+
+        assert_eq!(
+            serde_json::to_string(&costs).expect("hardcoded is valid"),
+            "{\"has_cost\":\"1000\",\"delete_cost\":\"1000\",\"read_cost_flat\":\"1000\",\"read_cost_per_byte\":\"3\",\"write_cost_flat\":\"2000\",\"write_cost_per_byte\":\"30\",\"iter_next_cost_flat\":\"30\"}"
+        );
+    }
+
+    #[test]
+    fn gas_schedule_raw_roundtrip() {
+        let schedule = GasSchedule {
+            kv: StoreGasCosts {
+                read_cost_flat: Gas::from(1_u8),
+                ..GasSchedule::default().kv
+            },
+            transient: GasSchedule::default().transient,
+        };
+
+        let raw = schedule.to_raw().into_iter().collect();
+
+        assert_eq!(GasSchedule::from_raw(raw), schedule);
+    }
+
+    #[test]
+    fn default_gas_schedule_keeps_legacy_write_cost_flat() {
+        let schedule = GasSchedule::default();
+
+        assert_eq!(schedule.kv.write_cost_flat, GasConfig::kv().read_cost_flat);
+        assert_eq!(schedule.transient_config(), *GasConfig::default_transient());
+    }
+}
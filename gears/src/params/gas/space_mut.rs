@@ -36,10 +36,16 @@ impl<DB: Database> GasParamsSpaceMut<'_, DB> {
     pub fn params_set<T: ParamsSerialize>(&mut self, params: &T) -> Result<(), GasStoreErrors> {
         let params = params.to_raw();
 
+        let mut omitted = T::keys();
         for (key, value) in params {
+            omitted.remove(key);
             self.inner.set(key.as_bytes().iter().cloned(), value)?;
         }
 
+        for key in omitted {
+            self.inner.delete(key.as_bytes())?;
+        }
+
         Ok(())
     }
 
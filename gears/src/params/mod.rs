@@ -62,10 +62,18 @@ pub trait ParamsSubspaceKey: std::fmt::Debug + Hash + Eq + Clone + Send + Sync +
 pub trait ParamsSerialize {
     /// Return all unique keys for this structure
     fn keys() -> HashSet<&'static str>;
+    /// Return serialized fields. Optional keys may be omitted, in which case
+    /// they are removed from the store on set.
     fn to_raw(&self) -> Vec<(&'static str, Vec<u8>)>;
 }
 
 pub trait ParamsDeserialize: ParamsSerialize {
+    /// Return keys which may be absent in store, e.g. added to structure after chain start.
+    /// Such keys aren't passed to [ParamsDeserialize::from_raw] if missing
+    fn optional_keys() -> HashSet<&'static str> {
+        HashSet::new()
+    }
+
     fn from_raw(fields: HashMap<&'static str, Vec<u8>>) -> Self;
 }
 
@@ -122,3 +130,102 @@ impl ParamKind {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use database::MemDB;
+    use extensions::testing::UnwrapTesting;
+    use key_derive::{ParamsKeys, StoreKeys};
+    use kv_store::bank::multi::ApplicationMultiBank;
+    use tendermint::types::{chain_id::ChainId, time::timestamp::Timestamp};
+
+    use crate::{baseapp::ConsensusParams, context::init::InitContext};
+
+    use super::*;
+
+    #[derive(strum::EnumIter, Debug, PartialEq, Eq, Hash, Clone, StoreKeys, ParamsKeys)]
+    #[skey(params = Params, gears)]
+    #[pkey(gears)]
+    enum SubspaceKey {
+        #[skey(to_string = "params")]
+        #[pkey(to_string = "test")]
+        Params,
+    }
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct TestParams {
+        rate: u64,
+        limit: Option<u64>,
+    }
+
+    impl ParamsSerialize for TestParams {
+        fn keys() -> HashSet<&'static str> {
+            HashSet::from(["Rate", "Limit"])
+        }
+
+        fn to_raw(&self) -> Vec<(&'static str, Vec<u8>)> {
+            let mut raw = vec![("Rate", self.rate.to_string().into_bytes())];
+            if let Some(limit) = self.limit {
+                raw.push(("Limit", limit.to_string().into_bytes()));
+            }
+
+            raw
+        }
+    }
+
+    impl ParamsDeserialize for TestParams {
+        fn optional_keys() -> HashSet<&'static str> {
+            HashSet::from(["Limit"])
+        }
+
+        fn from_raw(mut fields: HashMap<&'static str, Vec<u8>>) -> Self {
+            let parse = |value: Vec<u8>| -> u64 {
+                String::from_utf8(value)
+                    .unwrap_test()
+                    .parse::<u64>()
+                    .unwrap_test()
+            };
+
+            Self {
+                rate: parse(fields.remove("Rate").unwrap_test()),
+                limit: fields.remove("Limit").map(parse),
+            }
+        }
+    }
+
+    #[test]
+    fn params_set_removes_omitted_keys() {
+        let mut multi_store =
+            ApplicationMultiBank::<_, SubspaceKey>::new(Arc::new(MemDB::new())).unwrap_test();
+
+        let mut ctx = InitContext::new(
+            &mut multi_store,
+            0,
+            Timestamp::UNIX_EPOCH,
+            ChainId::default(),
+            ConsensusParams::default(),
+        );
+
+        let mut space = infallible_subspace_mut(&mut ctx, &SubspaceKey::Params);
+
+        let with_limit = TestParams {
+            rate: 1,
+            limit: Some(10),
+        };
+        space.params_set(&with_limit);
+
+        assert_eq!(Some(with_limit), space.params::<TestParams>());
+        assert!(space.params_field("Limit", ParamKind::U64).is_some());
+
+        let without_limit = TestParams {
+            rate: 2,
+            limit: None,
+        };
+        space.params_set(&without_limit);
+
+        assert_eq!(Some(without_limit), space.params::<TestParams>());
+        assert!(space.params_field("Limit", ParamKind::U64).is_none());
+    }
+}
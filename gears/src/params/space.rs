@@ -12,10 +12,15 @@ impl<DB: Database> ParamsSpace<'_, DB> {
     /// Return whole serialized structure.
     pub fn params<T: ParamsDeserialize>(&self) -> Option<T> {
         let keys = T::keys();
+        let optional_keys = T::optional_keys();
         let mut params_fields = Vec::with_capacity(keys.len());
 
         for key in keys {
-            params_fields.push((key, self.inner.get(key)?));
+            match self.inner.get(key) {
+                Some(value) => params_fields.push((key, value)),
+                None if optional_keys.contains(key) => continue,
+                None => return None,
+            }
         }
 
         Some(T::from_raw(params_fields.into_iter().collect()))
@@ -30,9 +30,15 @@ impl<DB: Database> ParamsSpaceMut<'_, DB> {
     pub fn params_set<T: ParamsSerialize>(&mut self, params: &T) {
         let params = params.to_raw();
 
+        let mut omitted = T::keys();
         for (key, value) in params {
+            omitted.remove(key);
             self.inner.set(key.as_bytes().iter().cloned(), value)
         }
+
+        for key in omitted {
+            let _ = self.inner.delete(key.as_bytes());
+        }
     }
 
     // TODO: dangerous fn as it may break consistency
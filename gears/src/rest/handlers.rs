@@ -3,16 +3,21 @@ use std::collections::HashMap;
 use std::str::FromStr;
 
 use crate::application::ApplicationInfo;
+use crate::baseapp::indexer::{TxOrder, TxSearchQuery, TxSearchResult};
 use crate::baseapp::NodeQueryHandler;
 use crate::rest::error::HTTPError;
 use crate::types::pagination::request::PaginationRequest;
 use crate::types::pagination::response::PaginationResponse;
-use crate::types::request::tx::BroadcastTxRequest;
+use crate::types::request::tx::{
+    BroadcastTxRequest, SimulateRequest, TxDecodeRequest, TxEncodeRequest,
+};
+use crate::types::response::abci_query::AbciQueryResponse;
 use crate::types::response::any::AnyTx;
 use crate::types::response::block::GetBlockByHeightResponse;
-use crate::types::response::node_info::{GetNodeInfoResponse, VersionInfo};
+use crate::types::response::node_info::{GetNodeInfoResponse, GetSyncingResponse, VersionInfo};
 use crate::types::response::tx::{
-    BroadcastTxResponse, BroadcastTxResponseLight, TxResponse, TxResponseLight,
+    BroadcastTxResponse, BroadcastTxResponseLight, SimulateResponse, TxDecodeResponse,
+    TxEncodeResponse, TxResponse, TxResponseLight,
 };
 use crate::types::response::tx_event::GetTxsEventResponse;
 use crate::types::response::validators::GetLatestValidatorSetResponse;
@@ -156,20 +161,38 @@ pub struct RawEvents {
     events: String,
 }
 
-pub async fn txs<M: TxMessage>(
+pub async fn txs<M: TxMessage, QReq, QRes, App: NodeQueryHandler<QReq, QRes>>(
     events: AxumQuery<RawEvents>,
     pagination: AxumQuery<Pagination>,
-    State(tendermint_rpc_address): State<HttpClientUrl>,
+    State(state): State<RestState<QReq, QRes, App>>,
 ) -> Result<Json<GetTxsEventResponse<M>>, HTTPError> {
-    let client = HttpClient::new::<Url>(tendermint_rpc_address.into()).expect("the conversion to Url then back to HttClientUrl should not be necessary, it will never fail, the dep needs to be fixed");
-
     let queries = StrEventsHandler::new(&events.0.events)
         .try_parse_tendermint_events_vec()
         .map_err(|e| HTTPError::bad_request(e.to_string()))?;
+    let (page, limit) = parse_pagination(&pagination.0);
+
+    if let Some(index) = state.app.tx_index() {
+        let query = TxSearchQuery::from_str(&queries.join(" AND "))
+            .map_err(|e| HTTPError::bad_request(e.to_string()))?;
+
+        let res = index
+            .search(
+                &query,
+                PaginationByOffset::from(((page as usize - 1) * limit as usize, limit as usize)),
+                TxOrder::Descending,
+            )
+            .map_err(|e| {
+                tracing::error!("Error reading tx index: {e}");
+                HTTPError::internal_server_error()
+            })?;
+
+        return Ok(Json(map_indexed_responses(res)?));
+    }
+
+    let client = HttpClient::new::<Url>(state.tendermint_rpc_address.into()).expect("the conversion to Url then back to HttClientUrl should not be necessary, it will never fail, the dep needs to be fixed");
 
     let query = Query::from_str(&queries.join(" AND "))
         .map_err(|e| HTTPError::bad_request(e.to_string()))?;
-    let (page, limit) = parse_pagination(&pagination.0);
 
     let res_tx = client
         .tx_search(query, false, page, limit, Order::Descending)
@@ -196,12 +219,43 @@ pub async fn txs<M: TxMessage>(
     Ok(Json(res))
 }
 
-pub async fn tx<M: TxMessage>(
+pub async fn tx<M: TxMessage, QReq, QRes, App: NodeQueryHandler<QReq, QRes>>(
     Path(hash): Path<Hash>,
-    State(tendermint_rpc_address): State<HttpClientUrl>,
+    State(state): State<RestState<QReq, QRes, App>>,
 ) -> Result<Json<BroadcastTxResponse<M>>, HTTPError> {
-    let client = HttpClient::new::<Url>(tendermint_rpc_address.into()).expect("the conversion to Url then back to HttClientUrl should not be necessary, it will never fail, the dep needs to be fixed");
+    let res = if let Some(index) = state.app.tx_index() {
+        let hash: [u8; 32] = hash
+            .as_bytes()
+            .try_into()
+            .map_err(|_| HTTPError::bad_request("invalid tx hash".to_owned()))?;
+
+        index
+            .tx(&hash)
+            .map_err(|e| {
+                tracing::error!("Error reading tx index: {e}");
+                HTTPError::internal_server_error()
+            })?
+            .map(TxResponse::try_from)
+            .transpose()
+            .map_err(|_| HTTPError::internal_server_error())?
+    } else {
+        let client = HttpClient::new::<Url>(state.tendermint_rpc_address.into()).expect("the conversion to Url then back to HttClientUrl should not be necessary, it will never fail, the dep needs to be fixed");
+
+        tendermint_tx(&client, hash).await?
+    };
+
+    Ok(Json(BroadcastTxResponse {
+        tx: res.as_ref().map(|r| r.tx.clone()).map(|tx| match tx {
+            AnyTx::Tx(tx) => tx,
+        }),
+        tx_response: res,
+    }))
+}
 
+async fn tendermint_tx<M: TxMessage>(
+    client: &HttpClient,
+    hash: Hash,
+) -> Result<Option<TxResponse<M>>, HTTPError> {
     let res = client.tx(hash, true).await.ok();
     let res = if let Some(r) = res {
         let time = client
@@ -217,12 +271,7 @@ pub async fn tx<M: TxMessage>(
         None
     };
 
-    Ok(Json(BroadcastTxResponse {
-        tx: res.as_ref().map(|r| r.tx.clone()).map(|tx| match tx {
-            AnyTx::Tx(tx) => tx,
-        }),
-        tx_response: res,
-    }))
+    Ok(res)
 }
 
 pub async fn send_tx(
@@ -344,6 +393,36 @@ fn map_responses<M: TxMessage>(
     })
 }
 
+// Maps transactions found in the application side index to a Cosmos get txs by event response
+fn map_indexed_responses<M: TxMessage>(
+    TxSearchResult {
+        total,
+        txs: indexed,
+    }: TxSearchResult,
+) -> Result<GetTxsEventResponse<M>, HTTPError> {
+    let mut tx_responses = Vec::with_capacity(indexed.len());
+    let mut txs = Vec::with_capacity(indexed.len());
+
+    for tx in indexed {
+        let tx_response =
+            TxResponse::<M>::try_from(tx).map_err(|_| HTTPError::internal_server_error())?;
+        let AnyTx::Tx(cosmos_tx) = &tx_response.tx;
+
+        txs.push(cosmos_tx.clone());
+        tx_responses.push(tx_response);
+    }
+
+    Ok(GetTxsEventResponse {
+        pagination: Some(PaginationResponse {
+            next_key: Vec::new(),
+            total,
+        }),
+        total,
+        txs,
+        tx_responses,
+    })
+}
+
 pub async fn block(
     Path(height): Path<u32>,
     State(tendermint_rpc_address): State<HttpClientUrl>,
@@ -384,3 +463,107 @@ pub async fn block_latest(
         })?;
     Ok(Json(res))
 }
+
+pub async fn syncing(
+    State(tendermint_rpc_address): State<HttpClientUrl>,
+) -> Result<Json<GetSyncingResponse>, HTTPError> {
+    let client = HttpClient::new::<Url>(tendermint_rpc_address.into()).expect("the conversion to Url then back to HttClientUrl should not be necessary, it will never fail, the dep needs to be fixed");
+
+    let res = client.status().await.map_err(|e| {
+        tracing::error!("Error connecting to Tendermint: {e}");
+        HTTPError::gateway_timeout()
+    })?;
+
+    Ok(Json(GetSyncingResponse {
+        syncing: res.sync_info.catching_up,
+    }))
+}
+
+#[derive(Deserialize)]
+pub struct AbciQueryParams {
+    path: String,
+    /// base64 encoded query data
+    data: Option<String>,
+    height: Option<u32>,
+    prove: Option<bool>,
+}
+
+pub async fn abci_query(
+    AxumQuery(params): AxumQuery<AbciQueryParams>,
+    State(tendermint_rpc_address): State<HttpClientUrl>,
+) -> Result<Json<AbciQueryResponse>, HTTPError> {
+    let client = HttpClient::new::<Url>(tendermint_rpc_address.into()).expect("the conversion to Url then back to HttClientUrl should not be necessary, it will never fail, the dep needs to be fixed");
+
+    let data = params
+        .data
+        .map(|data| data_encoding::BASE64.decode(data.as_bytes()))
+        .transpose()
+        .map_err(|e| HTTPError::bad_request(e.to_string()))?
+        .unwrap_or_default();
+
+    let res = client
+        .abci_query(
+            Some(params.path),
+            data,
+            params.height.map(Height::from),
+            params.prove.unwrap_or_default(),
+        )
+        .await
+        .map_err(|e| {
+            tracing::error!("Error connecting to Tendermint: {e}");
+            HTTPError::gateway_timeout()
+        })?;
+
+    Ok(Json(res.into()))
+}
+
+pub async fn simulate<QReq, QRes, App: NodeQueryHandler<QReq, QRes>>(
+    State(state): State<RestState<QReq, QRes, App>>,
+    request: String,
+) -> Result<Json<SimulateResponse>, HTTPError> {
+    let request: SimulateRequest =
+        serde_json::from_str(&request).map_err(|e| HTTPError::bad_request(e.to_string()))?;
+
+    let bytes = data_encoding::BASE64
+        .decode(request.tx_bytes.as_bytes())
+        .map_err(|e| HTTPError::bad_request(e.to_string()))?;
+
+    // simulation executes tx, so it's kept off async workers
+    let app = state.app;
+    let res = tokio::task::spawn_blocking(move || app.simulate_tx(bytes.into()))
+        .await
+        .map_err(|_| HTTPError::internal_server_error())?
+        .map_err(|e| HTTPError::bad_request(e.to_string()))?;
+
+    Ok(Json(
+        res.try_into()
+            .map_err(|_| HTTPError::internal_server_error())?,
+    ))
+}
+
+pub async fn decode<M: TxMessage>(request: String) -> Result<Json<TxDecodeResponse<M>>, HTTPError> {
+    let request: TxDecodeRequest =
+        serde_json::from_str(&request).map_err(|e| HTTPError::bad_request(e.to_string()))?;
+
+    let bytes = data_encoding::BASE64
+        .decode(request.tx_bytes.as_bytes())
+        .map_err(|e| HTTPError::bad_request(e.to_string()))?;
+
+    let tx =
+        Tx::decode::<Bytes>(bytes.into()).map_err(|e| HTTPError::bad_request(e.to_string()))?;
+
+    Ok(Json(TxDecodeResponse { tx }))
+}
+
+pub async fn encode(request: String) -> Result<Json<TxEncodeResponse>, HTTPError> {
+    let request: TxEncodeRequest =
+        serde_json::from_str(&request).map_err(|e| HTTPError::bad_request(e.to_string()))?;
+
+    let tx_bytes = request
+        .encode()
+        .map_err(|e| HTTPError::bad_request(e.to_string()))?;
+
+    Ok(Json(TxEncodeResponse {
+        tx_bytes: data_encoding::BASE64.encode(&tx_bytes),
+    }))
+}
@@ -2,13 +2,18 @@ use crate::{
     application::ApplicationInfo,
     baseapp::{NodeQueryHandler, QueryRequest, QueryResponse},
     rest::handlers::{
-        block, block_latest, health, node_info, send_tx, tx, txs, validatorsets,
-        validatorsets_latest,
+        abci_query, block, block_latest, decode, encode, health, node_info, send_tx, simulate,
+        syncing, tx, txs, validatorsets, validatorsets_latest,
     },
     runtime::runtime,
     types::tx::TxMessage,
 };
-use axum::{extract::FromRef, http::Method, routing::get, Router};
+use axum::{
+    extract::FromRef,
+    http::Method,
+    routing::{get, post},
+    Router,
+};
 use std::{marker::PhantomData, net::SocketAddr};
 use tendermint::rpc::client::HttpClientUrl;
 use tower_http::{
@@ -86,6 +91,11 @@ async fn launch<
             "/cosmos/base/tendermint/v1beta1/node_info",
             get(node_info::<QReq, QRes, App>),
         )
+        .route("/cosmos/base/tendermint/v1beta1/syncing", get(syncing))
+        .route(
+            "/cosmos/base/tendermint/v1beta1/abci_query",
+            get(abci_query),
+        )
         .route(
             "/cosmos/base/tendermint/v1beta1/validatorsets/latest",
             get(validatorsets_latest),
@@ -94,8 +104,20 @@ async fn launch<
             "/cosmos/base/tendermint/v1beta1/validatorsets/:height",
             get(validatorsets),
         )
-        .route("/cosmos/tx/v1beta1/txs", get(txs::<M>).post(send_tx))
-        .route("/cosmos/tx/v1beta1/txs/:hash", get(tx::<M>))
+        .route(
+            "/cosmos/tx/v1beta1/txs",
+            get(txs::<M, QReq, QRes, App>).post(send_tx),
+        )
+        .route(
+            "/cosmos/tx/v1beta1/txs/:hash",
+            get(tx::<M, QReq, QRes, App>),
+        )
+        .route(
+            "/cosmos/tx/v1beta1/simulate",
+            post(simulate::<QReq, QRes, App>),
+        )
+        .route("/cosmos/tx/v1beta1/decode", post(decode::<M>))
+        .route("/cosmos/tx/v1beta1/encode", post(encode))
         .route(
             "/cosmos/base/tendermint/v1beta1/blocks/latest",
             get(block_latest),
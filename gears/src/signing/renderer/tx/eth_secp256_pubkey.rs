@@ -0,0 +1,75 @@
+use crate::{
+    crypto::eth_secp256k1::EthSecp256k1PubKey,
+    signing::handler::MetadataGetter,
+    types::rendering::screen::{Indent, Screen},
+};
+
+use crate::signing::renderer::value_renderer::{
+    DefaultPrimitiveRenderer, RenderError, TryPrimitiveValueRenderer, ValueRenderer,
+};
+
+const TYPE_URL: &str = "/ethermint.crypto.v1.ethsecp256k1.PubKey";
+
+impl ValueRenderer for EthSecp256k1PubKey {
+    fn format<MG: MetadataGetter>(&self, _get_metadata: &MG) -> Result<Vec<Screen>, RenderError> {
+        Ok(vec![
+            Screen {
+                title: "Public key".to_string(),
+                content: DefaultPrimitiveRenderer::try_format(TYPE_URL)
+                    .expect("hard coded type URL is not empty"),
+                indent: None,
+                expert: true,
+            },
+            Screen {
+                title: "Key".to_string(),
+                content: DefaultPrimitiveRenderer::try_format(
+                    Vec::<u8>::from(self.clone()).as_slice(),
+                )
+                .expect("key is not empty so it will never fail to parse"),
+                indent: Some(Indent::one()),
+                expert: true,
+            },
+        ])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::crypto::public::PublicKey;
+    use crate::signing::renderer::test_functions::TestMetadataGetter;
+    use crate::types::rendering::screen::{Content, Indent, Screen};
+
+    use crate::signing::renderer::value_renderer::ValueRenderer;
+
+    #[test]
+    fn eth_secp256_pubkey_formating() -> anyhow::Result<()> {
+        let key: PublicKey = serde_json::from_str(
+            r#"{
+            "@type": "/ethermint.crypto.v1.ethsecp256k1.PubKey",
+            "key": "Auvdf+T963bciiBe9l15DNMOijdaXCUo6zqSOvH7TXlN"
+        }"#,
+        )?;
+
+        let expected_screens = vec![
+            Screen {
+                title: "Public key".to_string(),
+                content: Content::try_new("/ethermint.crypto.v1.ethsecp256k1.PubKey")?,
+                indent: None,
+                expert: true,
+            },
+            Screen {
+                title: "Key".to_string(),
+                content: Content::try_new("02EB DD7F E4FD EB76 DC8A 205E F65D 790C D30E 8A37 5A5C 2528 EB3A 923A F1FB 4D79 4D")?,
+                indent: Some(Indent::one()),
+                expert: true,
+            },
+        ];
+
+        let actual_screens = ValueRenderer::format(&key, &TestMetadataGetter)
+            .map_err(|e| anyhow::anyhow!(e.to_string()))?;
+
+        assert_eq!(expected_screens, actual_screens);
+
+        Ok(())
+    }
+}
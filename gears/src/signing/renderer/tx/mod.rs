@@ -1,6 +1,8 @@
 mod envelope;
+pub mod eth_secp256_pubkey;
 pub mod public_key_enum;
 pub mod secp256_pubkey;
+pub mod secp256r1_pubkey;
 
 pub use envelope::*;
 // }
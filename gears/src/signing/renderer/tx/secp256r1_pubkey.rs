@@ -0,0 +1,34 @@
+use crate::{
+    crypto::secp256r1::Secp256r1PubKey,
+    signing::handler::MetadataGetter,
+    types::rendering::screen::{Indent, Screen},
+};
+
+use crate::signing::renderer::value_renderer::{
+    DefaultPrimitiveRenderer, RenderError, TryPrimitiveValueRenderer, ValueRenderer,
+};
+
+const TYPE_URL: &str = "/cosmos.crypto.secp256r1.PubKey";
+
+impl ValueRenderer for Secp256r1PubKey {
+    fn format<MG: MetadataGetter>(&self, _get_metadata: &MG) -> Result<Vec<Screen>, RenderError> {
+        Ok(vec![
+            Screen {
+                title: "Public key".to_string(),
+                content: DefaultPrimitiveRenderer::try_format(TYPE_URL)
+                    .expect("hard coded type URL is not empty"),
+                indent: None,
+                expert: true,
+            },
+            Screen {
+                title: "Key".to_string(),
+                content: DefaultPrimitiveRenderer::try_format(
+                    Vec::<u8>::from(self.clone()).as_slice(),
+                )
+                .expect("key is not empty so it will never fail to parse"),
+                indent: Some(Indent::one()),
+                expert: true,
+            },
+        ])
+    }
+}
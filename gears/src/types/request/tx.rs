@@ -1,3 +1,6 @@
+use std::sync::OnceLock;
+
+use prost_reflect::{DescriptorPool, DynamicMessage};
 use serde::{Deserialize, Serialize};
 
 /// BroadcastTxRequest is the request type for the Service.BroadcastTxRequest
@@ -8,3 +11,116 @@ pub struct BroadcastTxRequest {
     pub tx_bytes: String,
     pub mode: String,
 }
+
+/// SimulateRequest is the request type for the Service.Simulate
+/// RPC method.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct SimulateRequest {
+    /// tx_bytes is the raw transaction, base64 encoded
+    pub tx_bytes: String,
+}
+
+/// TxDecodeRequest is the request type for the Service.TxDecode
+/// RPC method.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct TxDecodeRequest {
+    /// tx_bytes is the raw transaction, base64 encoded
+    pub tx_bytes: String,
+}
+
+/// TxEncodeRequest is the request type for the Service.TxEncode
+/// RPC method.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct TxEncodeRequest {
+    /// tx in proto-JSON as produced by cosmos sdk, messages are `Any` with `@type` field
+    pub tx: serde_json::Value,
+}
+
+impl TxEncodeRequest {
+    /// Encode tx into protobuf bytes. Messages are encoded with descriptors of cosmos sdk
+    /// and ibc protobuf definitions, so they aren't checked by application.
+    pub fn encode(self) -> Result<Vec<u8>, serde_json::Error> {
+        let descriptor = descriptor_pool()
+            .get_message_by_name("cosmos.tx.v1beta1.Tx")
+            .expect("ibc_proto::FILE_DESCRIPTOR_SET contains cosmos.tx.v1beta1.Tx");
+
+        let tx = DynamicMessage::deserialize(descriptor, self.tx)?;
+
+        Ok(prost::Message::encode_to_vec(&tx))
+    }
+}
+
+fn descriptor_pool() -> &'static DescriptorPool {
+    static POOL: OnceLock<DescriptorPool> = OnceLock::new();
+    POOL.get_or_init(|| {
+        DescriptorPool::decode(ibc_proto::FILE_DESCRIPTOR_SET)
+            .expect("ibc_proto::FILE_DESCRIPTOR_SET is a valid proto file descriptor set")
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use core_types::tx::inner::Tx;
+    use extensions::testing::UnwrapTesting;
+    use prost::Message;
+
+    use super::*;
+
+    #[test]
+    fn encode_sdk_proto_json() {
+        let request: TxEncodeRequest = serde_json::from_str(
+            r#"{
+                "tx": {
+                    "body": {
+                        "messages": [
+                            {
+                                "@type": "/cosmos.bank.v1beta1.MsgSend",
+                                "from_address": "cosmos1syavy2npfyt9tcncdtsdzf7kny9lh777pahuux",
+                                "to_address": "cosmos15qzm75pjh0jqsv3u40hzp2vzs2hdp47fkz7j5q",
+                                "amount": [{ "denom": "uatom", "amount": "10" }]
+                            }
+                        ],
+                        "memo": "test",
+                        "timeout_height": "0",
+                        "extension_options": [],
+                        "non_critical_extension_options": []
+                    },
+                    "auth_info": {
+                        "signer_infos": [
+                            {
+                                "public_key": {
+                                    "@type": "/cosmos.crypto.secp256k1.PubKey",
+                                    "key": "AtzjwaiSxwUdNDkA2bpqz/GTXr/Wbr3bUN8kD+dYUzNP"
+                                },
+                                "mode_info": { "single": { "mode": "SIGN_MODE_DIRECT" } },
+                                "sequence": "3"
+                            }
+                        ],
+                        "fee": {
+                            "amount": [{ "denom": "uatom", "amount": "2000" }],
+                            "gas_limit": "200000",
+                            "payer": "",
+                            "granter": ""
+                        },
+                        "tip": null
+                    },
+                    "signatures": ["AQID"]
+                }
+            }"#,
+        )
+        .unwrap_test();
+
+        let tx = Tx::decode(request.encode().unwrap_test().as_slice()).unwrap_test();
+
+        let body = tx.body.unwrap_test();
+        assert_eq!(body.memo, "test");
+        assert_eq!(body.messages.len(), 1);
+        assert_eq!(body.messages[0].type_url, "/cosmos.bank.v1beta1.MsgSend");
+
+        let auth_info = tx.auth_info.unwrap_test();
+        assert_eq!(auth_info.signer_infos[0].sequence, 3);
+        assert_eq!(auth_info.fee.unwrap_test().gas_limit, 200_000);
+
+        assert_eq!(tx.signatures, vec![vec![1, 2, 3]]);
+    }
+}
@@ -0,0 +1,74 @@
+use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, DisplayFromStr};
+use tendermint::rpc::response::abci_query::AbciQuery;
+
+/// ABCIQueryResponse defines the response structure for the ABCIQuery gRPC query.
+/// Byte fields are base64 encoded.
+#[serde_as]
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct AbciQueryResponse {
+    pub code: u32,
+    pub log: String,
+    pub info: String,
+    #[serde_as(as = "DisplayFromStr")]
+    pub index: i64,
+    pub key: String,
+    pub value: String,
+    pub proof_ops: Option<ProofOps>,
+    #[serde_as(as = "DisplayFromStr")]
+    pub height: i64,
+    pub codespace: String,
+}
+
+/// ProofOps is Merkle proof defined by the list of ProofOps.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct ProofOps {
+    pub ops: Vec<ProofOp>,
+}
+
+/// ProofOp defines an operation used for calculating Merkle root.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct ProofOp {
+    #[serde(rename = "type")]
+    pub kind: String,
+    pub key: String,
+    pub data: String,
+}
+
+impl From<AbciQuery> for AbciQueryResponse {
+    fn from(
+        AbciQuery {
+            code,
+            log,
+            info,
+            index,
+            key,
+            value,
+            proof,
+            height,
+            codespace,
+        }: AbciQuery,
+    ) -> Self {
+        Self {
+            code: code.value(),
+            log,
+            info,
+            index,
+            key: data_encoding::BASE64.encode(&key),
+            value: data_encoding::BASE64.encode(&value),
+            proof_ops: proof.map(|proof| ProofOps {
+                ops: proof
+                    .ops
+                    .into_iter()
+                    .map(|op| ProofOp {
+                        kind: op.field_type,
+                        key: data_encoding::BASE64.encode(&op.key),
+                        data: data_encoding::BASE64.encode(&op.data),
+                    })
+                    .collect(),
+            }),
+            height: height.into(),
+            codespace,
+        }
+    }
+}
@@ -1,3 +1,4 @@
+pub mod abci_query;
 pub mod any;
 pub mod block;
 pub mod node_info;
@@ -33,3 +33,9 @@ pub struct Module {
     /// checksum
     pub sum: String,
 }
+
+/// GetSyncingResponse is the response type for the Query/GetSyncing RPC method.
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct GetSyncingResponse {
+    pub syncing: bool,
+}
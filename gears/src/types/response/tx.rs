@@ -1,11 +1,14 @@
 use super::any::AnyTx;
 use crate::{
+    baseapp::{indexer::IndexedTx, RunTxInfo},
     core::{errors::CoreError, Protobuf},
     types::tx::{Tx, TxMessage},
 };
 use core_types::any::google::Any;
+use gas::metering::Gas;
 use prost::Message;
 use serde::{Deserialize, Serialize};
+use serde_with::{serde_as, DisplayFromStr};
 use tendermint::{
     abci::Event as InformalEvent, rpc::response::tx::Response, types::proto::event::Event,
 };
@@ -77,6 +80,60 @@ impl<M: TxMessage> TxResponse<M> {
     }
 }
 
+impl<M: TxMessage> TryFrom<IndexedTx> for TxResponse<M> {
+    type Error = CoreError;
+
+    fn try_from(indexed: IndexedTx) -> Result<Self, Self::Error> {
+        let timestamp = indexed
+            .timestamp_rfc3339()
+            .map_err(|e| CoreError::SerdeSerialize(e.to_string()))?;
+
+        let IndexedTx {
+            height,
+            index: _,
+            hash,
+            tx,
+            code,
+            codespace,
+            data,
+            log,
+            info,
+            gas_wanted,
+            gas_used,
+            events,
+            timestamp: _,
+        } = indexed;
+
+        let cosmos_tx: Tx<M> =
+            Tx::decode(tx).map_err(|e| CoreError::DecodeProtobuf(e.to_string()))?;
+
+        let mut events_res = Vec::with_capacity(events.len());
+        for event in events {
+            events_res.push(
+                event
+                    .try_into()
+                    .map_err(|e| CoreError::DecodeProtobuf(format!("{e}")))?,
+            );
+        }
+
+        Ok(Self {
+            height: height.into(),
+            txhash: hex::encode_upper(hash),
+            codespace,
+            code,
+            data: hex::encode(data),
+            raw_log: log.clone(),
+            logs: log,
+            info,
+            gas_wanted,
+            gas_used,
+            tx: AnyTx::Tx(cosmos_tx),
+            timestamp,
+            events: events_res,
+        })
+    }
+}
+
 impl<M: TxMessage> TryFrom<TxResponseRaw> for TxResponse<M> {
     type Error = CoreError;
 
@@ -214,3 +271,92 @@ pub struct TxResponseLight {
 pub struct BroadcastTxResponseLight {
     pub tx_response: Option<TxResponseLight>,
 }
+
+/// GasInfo defines tx execution gas context.
+#[serde_as]
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct GasInfo {
+    /// GasWanted is the maximum units of work we allow this tx to perform.
+    #[serde_as(as = "DisplayFromStr")]
+    pub gas_wanted: u64,
+    /// GasUsed is the amount of gas actually consumed.
+    #[serde_as(as = "DisplayFromStr")]
+    pub gas_used: u64,
+}
+
+/// SimulateResult is the result of simulated transaction execution.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct SimulateResult {
+    /// Data is any data returned from message or handler execution, base64 encoded.
+    pub data: String,
+    /// Log contains the log information from message or handler execution.
+    pub log: String,
+    /// Events contains a slice of Event objects that were emitted during message
+    /// or handler execution.
+    pub events: Vec<InformalEvent>,
+    /// msg_responses contains the Msg handler responses type packed in Anys.
+    pub msg_responses: Vec<Any>,
+}
+
+/// SimulateResponse is the response type for the
+/// Service.SimulateRPC method.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize)]
+pub struct SimulateResponse {
+    /// gas_info is the information about gas used in the simulation.
+    pub gas_info: Option<GasInfo>,
+    /// result is the result of the simulation.
+    pub result: Option<SimulateResult>,
+}
+
+impl TryFrom<RunTxInfo> for SimulateResponse {
+    type Error = CoreError;
+
+    fn try_from(
+        RunTxInfo {
+            events,
+            gas_wanted,
+            gas_used,
+        }: RunTxInfo,
+    ) -> Result<Self, Self::Error> {
+        let mut events_res = Vec::with_capacity(events.len());
+        for event in events {
+            events_res.push(
+                event
+                    .try_into()
+                    .map_err(|e| CoreError::DecodeProtobuf(format!("{e}")))?,
+            );
+        }
+
+        Ok(Self {
+            gas_info: Some(GasInfo {
+                gas_wanted: match gas_wanted {
+                    Gas::Finite(gas) => gas.into(),
+                    Gas::Infinite => u64::MAX,
+                },
+                gas_used: gas_used.into(),
+            }),
+            result: Some(SimulateResult {
+                data: String::new(),
+                log: String::new(),
+                events: events_res,
+                msg_responses: Vec::new(),
+            }),
+        })
+    }
+}
+
+/// TxDecodeResponse is the response type for the
+/// Service.TxDecode method.
+#[derive(Debug, Clone, Serialize)]
+pub struct TxDecodeResponse<M: TxMessage> {
+    /// tx is the decoded transaction.
+    pub tx: Tx<M>,
+}
+
+/// TxEncodeResponse is the response type for the
+/// Service.TxEncode method.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct TxEncodeResponse {
+    /// tx_bytes is the encoded transaction bytes, base64 encoded
+    pub tx_bytes: String,
+}
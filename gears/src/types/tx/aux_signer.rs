@@ -0,0 +1,239 @@
+use core_types::{serializers::Base64Standard, tx::mode_info::SignMode};
+use prost::Message;
+use serde::{Deserialize, Serialize};
+use tendermint::types::chain_id::ChainId;
+
+use crate::{
+    crypto::public::PublicKey,
+    types::{address::AccAddress, auth::tip::Tip},
+};
+
+mod inner {
+    pub use core_types::signing::SignDocDirectAux;
+}
+
+/// SignDocDirectAux is the type used for generating sign bytes for
+/// `SIGN_MODE_DIRECT_AUX`. Unlike `SignDoc` it doesn't contain `auth_info_bytes`,
+/// so the auxiliary signer doesn't sign over fee and signer infos of other signers.
+///
+/// Since: cosmos-sdk 0.46
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SignDocDirectAux {
+    /// body_bytes is protobuf serialization of a TxBody that matches the
+    /// representation in TxRaw
+    #[serde(with = "Base64Standard")]
+    pub body_bytes: Vec<u8>,
+    /// public_key is the public key of the signing account
+    pub public_key: PublicKey,
+    /// chain_id is the identifier of the chain this transaction targets
+    pub chain_id: ChainId,
+    /// account_number is the account number of the account in state
+    pub account_number: u64,
+    /// sequence is the sequence number of the signing account
+    pub sequence: u64,
+    /// tip is the optional tip used for transactions fees paid in another denom
+    pub tip: Option<Tip>,
+}
+
+impl SignDocDirectAux {
+    /// Bytes which are signed by auxiliary signer
+    pub fn to_sign_bytes(&self) -> Vec<u8> {
+        inner::SignDocDirectAux::from(self.clone()).encode_to_vec()
+    }
+}
+
+impl From<SignDocDirectAux> for inner::SignDocDirectAux {
+    fn from(
+        SignDocDirectAux {
+            body_bytes,
+            public_key,
+            chain_id,
+            account_number,
+            sequence,
+            tip,
+        }: SignDocDirectAux,
+    ) -> Self {
+        Self {
+            body_bytes,
+            public_key: Some(public_key.into()),
+            chain_id: chain_id.into(),
+            account_number,
+            sequence,
+            tip: tip.map(Into::into),
+        }
+    }
+}
+
+/// AuxSignerData is the intermediary format that an auxiliary signer (e.g. a
+/// tipper) passes to the fee payer, who then builds and broadcasts the final tx
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct AuxSignerData {
+    /// address is the bech32-encoded address of the auxiliary signer
+    pub address: AccAddress,
+    /// sign_doc is the sign doc that the auxiliary signer signed
+    pub sign_doc: SignDocDirectAux,
+    /// mode is the signing mode of the auxiliary signer
+    pub mode: SignMode,
+    /// sig is the signature of the sign doc
+    #[serde(with = "Base64Standard")]
+    pub sig: Vec<u8>,
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use core_types::{signing::SignDoc, Protobuf};
+    use extensions::{infallible::UnwrapInfallible, testing::UnwrapTesting};
+    use keyring::key::pair::{secp256k1_key_pair::Secp256k1KeyPair, KeyPair};
+
+    use crate::{
+        crypto::{
+            info::{create_aux_signer_data, create_signed_transaction_with_aux, SigningInfo},
+            keys::{GearsPublicKey, ReadAccAddress, SigningKey},
+        },
+        types::{auth::fee::Fee, base::coins::UnsignedCoins, msg::send::MsgSend, tx::body::TxBody},
+    };
+
+    use super::*;
+
+    const AUX_MNEMONIC: &str = "race draft rival universe maid cheese steel logic crowd fork comic easy truth drift tomorrow eye buddy head time cash swing swift midnight borrow";
+    const FEE_PAYER_MNEMONIC: &str = "abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon abandon art";
+
+    fn key(mnemonic: &str) -> KeyPair {
+        let mnemonic =
+            bip32::Mnemonic::new(mnemonic, bip32::Language::English).expect("hardcoded is valid");
+        KeyPair::Secp256k1(Secp256k1KeyPair::from_mnemonic(&mnemonic))
+    }
+
+    fn body(aux: &KeyPair, fee_payer: &KeyPair) -> TxBody<MsgSend> {
+        TxBody::new_with_defaults(vec1::vec1![MsgSend {
+            from_address: aux.get_address(),
+            to_address: fee_payer.get_address(),
+            amount: UnsignedCoins::from_str("10uatom").unwrap_test(),
+        }])
+    }
+
+    fn aux_signer_data(aux: &KeyPair, body: TxBody<MsgSend>) -> AuxSignerData {
+        create_aux_signer_data(
+            SigningInfo {
+                key: aux,
+                sequence: 2,
+                account_number: 7,
+            },
+            ChainId::from_str("test-chain").unwrap_test(),
+            Some(Tip {
+                amount: Some(UnsignedCoins::from_str("5uatom").unwrap_test()),
+                tipper: aux.get_address(),
+            }),
+            body,
+        )
+        .unwrap_infallible()
+    }
+
+    fn fee() -> Fee {
+        Fee {
+            amount: Some(UnsignedCoins::from_str("100uatom").unwrap_test()),
+            gas_limit: 200_000_u32.into(),
+            payer: None,
+            granter: "".into(),
+        }
+    }
+
+    #[test]
+    fn aux_signer_data_json_roundtrip() {
+        let aux = key(AUX_MNEMONIC);
+        let body = body(&aux, &key(FEE_PAYER_MNEMONIC));
+        let data = aux_signer_data(&aux, body.clone());
+
+        let json = serde_json::to_string(&data).unwrap_test();
+        let decoded: AuxSignerData = serde_json::from_str(&json).unwrap_test();
+
+        assert_eq!(decoded, data);
+        assert_eq!(
+            TxBody::<MsgSend>::decode_vec(&decoded.sign_doc.body_bytes).unwrap_test(),
+            body
+        );
+    }
+
+    #[test]
+    fn aux_signature_verifies() {
+        let aux = key(AUX_MNEMONIC);
+        let data = aux_signer_data(&aux, body(&aux, &key(FEE_PAYER_MNEMONIC)));
+
+        assert_eq!(data.address, aux.get_address());
+        assert_eq!(data.sign_doc.public_key, aux.get_gears_public_key());
+        assert!(data
+            .sign_doc
+            .public_key
+            .verify_signature(data.sign_doc.to_sign_bytes(), &data.sig)
+            .is_ok());
+
+        // signature covers tip
+        let mut sign_doc = data.sign_doc.clone();
+        sign_doc.tip = None;
+        assert!(data
+            .sign_doc
+            .public_key
+            .verify_signature(sign_doc.to_sign_bytes(), &data.sig)
+            .is_err());
+    }
+
+    #[test]
+    fn tx_with_aux_keeps_signed_body_bytes() {
+        let aux = key(AUX_MNEMONIC);
+        let fee_payer = key(FEE_PAYER_MNEMONIC);
+        let body = body(&aux, &fee_payer);
+
+        // unknown field is dropped by decoding, so encoding of body differs from signed bytes
+        let mut body_bytes = body.encode_vec();
+        body_bytes.extend([0xa0, 0x1f, 0x01]);
+        let mut sign_doc = aux_signer_data(&aux, body.clone()).sign_doc;
+        sign_doc.body_bytes = body_bytes.clone();
+        let data = AuxSignerData {
+            address: aux.get_address(),
+            sig: aux.sign(&sign_doc.to_sign_bytes()).unwrap_infallible(),
+            sign_doc,
+            mode: SignMode::DirectAux,
+        };
+
+        let decoded_body = TxBody::<MsgSend>::decode_vec(&body_bytes).unwrap_test();
+        assert_eq!(decoded_body, body);
+        assert_ne!(decoded_body.encode_vec(), body_bytes);
+
+        let tx = create_signed_transaction_with_aux(
+            data.clone(),
+            SigningInfo {
+                key: &fee_payer,
+                sequence: 0,
+                account_number: 9,
+            },
+            fee(),
+            decoded_body,
+        )
+        .unwrap_infallible();
+
+        assert_eq!(tx.raw.body_bytes, body_bytes);
+        assert_eq!(
+            tx.raw.signatures,
+            vec![data.sig.clone(), tx.tx.signatures[1].clone()]
+        );
+
+        // aux signer signed exactly the bytes which are broadcast
+        assert!(aux
+            .get_gears_public_key()
+            .verify_signature(data.sign_doc.to_sign_bytes(), &tx.raw.signatures[0])
+            .is_ok());
+
+        let fee_payer_sign_doc = SignDoc {
+            body_bytes: tx.raw.body_bytes.clone(),
+            auth_info_bytes: tx.raw.auth_info_bytes.clone(),
+            chain_id: "test-chain".to_owned(),
+            account_number: 9,
+        };
+        assert!(fee_payer
+            .get_gears_public_key()
+            .verify_signature(fee_payer_sign_doc.encode_to_vec(), &tx.raw.signatures[1])
+            .is_ok());
+    }
+}
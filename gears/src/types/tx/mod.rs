@@ -1,3 +1,4 @@
+pub mod aux_signer;
 pub mod data;
 pub mod errors;
 pub mod metadata;
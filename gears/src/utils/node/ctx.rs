@@ -14,6 +14,7 @@ use tendermint::types::proto::header::Header;
 use crate::{
     baseapp::{options::NodeOptions, ConsensusParams},
     context::{init::InitContext, tx::TxContext},
+    params::gas::schedule::GasSchedule,
 };
 
 pub fn build_store<SK: StoreKey>() -> ApplicationMultiBank<MemDB, SK> {
@@ -25,6 +26,7 @@ pub struct ContextOptions {
     height: u32,
     header: Header,
     consensus_params: ConsensusParams,
+    gas_schedule: GasSchedule,
     gas_meter: GasMeter<TxKind>,
     options: NodeOptions,
 }
@@ -40,6 +42,7 @@ pub fn build_tx_ctx<'a, DB, SK>(
         height,
         header,
         consensus_params,
+        gas_schedule,
         gas_meter,
         options,
     } = opt.into();
@@ -48,6 +51,7 @@ pub fn build_tx_ctx<'a, DB, SK>(
         height,
         header,
         consensus_params,
+        gas_schedule,
         gas_meter,
         block_gas_meter,
         options,
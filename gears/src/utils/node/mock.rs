@@ -7,13 +7,15 @@ use tendermint::{
         proto::{
             block::BlockId,
             consensus::{Consensus, ConsensusParams},
+            event::Event,
             header::{Header, PartSetHeader},
             info::LastCommitInfo,
             validator::ValidatorUpdate,
         },
         request::{
             begin_block::RequestBeginBlock, deliver_tx::RequestDeliverTx,
-            end_block::RequestEndBlock, init_chain::RequestInitChain, query::RequestQuery,
+            end_block::RequestEndBlock, finalize_block::RequestFinalizeBlock,
+            init_chain::RequestInitChain, query::RequestQuery,
         },
         response::{deliver_tx::ResponseDeliverTx, query::ResponseQuery},
         time::timestamp::Timestamp,
@@ -59,6 +61,8 @@ pub struct StepResponse {
     pub app_hash: Bytes,
     pub tx_responses: Vec<ResponseDeliverTx>,
     pub height: u32,
+    /// Events emitted outside of transactions, e.g. by begin and end block
+    pub events: Vec<Event>,
 }
 
 impl<G: Clone, App: ABCIApplication<G>> MockNode<App, G> {
@@ -111,16 +115,20 @@ impl<G: Clone, App: ABCIApplication<G>> MockNode<App, G> {
             byzantine_validators: vec![],
             hash:  b"\xaaw\xbd^\x9d\x041\xfdc\x17\x11\x82\xb9iU\xde2\xd0\x19\xca\xdeV\x0e\x7fK\x1c\x88\xb6\xa3\xe3\x8b\x89".as_slice().into(),
         };
-        self.app.begin_block(request_begin_block);
+        let mut events = self.app.begin_block(request_begin_block).events;
 
         let mut tx_responses = Vec::with_capacity(txs.len());
         for tx in txs {
             tx_responses.push(self.app.deliver_tx(RequestDeliverTx { tx }));
         }
 
-        self.app.end_block(RequestEndBlock {
-            height: self.height as i64,
-        });
+        events.extend(
+            self.app
+                .end_block(RequestEndBlock {
+                    height: self.height as i64,
+                })
+                .events,
+        );
 
         let res_commit = self.app.commit();
 
@@ -130,6 +138,51 @@ impl<G: Clone, App: ABCIApplication<G>> MockNode<App, G> {
             app_hash: self.app_hash.clone(),
             tx_responses,
             height: self.height,
+            events,
+        }
+    }
+
+    /// Execute block with `FinalizeBlock` of CometBFT 0.38 instead of
+    /// `BeginBlock`, `DeliverTx` and `EndBlock`
+    pub fn finalize_step(
+        &mut self,
+        txs: Vec<Bytes>,
+        block_time: Timestamp,
+        hash: Bytes,
+        next_validators_hash: Bytes,
+    ) -> StepResponse {
+        self.height += 1;
+        self.time = block_time;
+        let header = self.calculate_header();
+
+        let res = self.app.finalize_block(RequestFinalizeBlock {
+            txs,
+            decided_last_commit: LastCommitInfo {
+                round: 0,
+                votes: vec![],
+            },
+            misbehavior: vec![],
+            hash: hash.clone(),
+            height: self.height,
+            time: block_time,
+            next_validators_hash,
+            proposer_address: header.proposer_address.into(),
+        });
+
+        self.last_block_id = BlockId {
+            hash: hash.to_vec(),
+            part_set_header: None,
+        };
+
+        let res_commit = self.app.commit();
+
+        self.app_hash = res_commit.data;
+
+        StepResponse {
+            app_hash: self.app_hash.clone(),
+            tx_responses: res.tx_results,
+            height: self.height,
+            events: res.events,
         }
     }
 
@@ -0,0 +1,827 @@
+//! Decorators which are used by [super::BaseAnteHandler]. Their order follows ante handler of cosmos sdk:
+//! https://github.com/cosmos/cosmos-sdk/blob/2582f0aab7b2cbf66ade066fe570a4622cf0b098/x/auth/ante/ante.go#L45-L60
+
+use std::marker::PhantomData;
+
+use core_types::{
+    signing::SignDoc,
+    tx::mode_info::{ModeInfo, SignMode},
+};
+use cosmwasm_std::Decimal256;
+use database::Database;
+use gas::{metering::descriptor::TX_SIZE_DESCRIPTOR, Gas};
+use kv_store::StoreKey;
+use prost::Message as ProstMessage;
+
+use crate::{
+    application::handlers::node::TxError,
+    context::{QueryableContext, TransactionalContext},
+    crypto::{public::PublicKey, secp256k1::Secp256k1PubKey},
+    signing::{
+        handler::SignModeHandler,
+        renderer::{
+            amino_renderer::{AminoRenderer, RenderError as AminoRendererError},
+            value_renderer::ValueRenderer,
+        },
+        std_sign_doc,
+    },
+    types::{
+        base::{coin::UnsignedCoin, coins::UnsignedCoins},
+        msg::send::MsgSend,
+        tx::{aux_signer::SignDocDirectAux, raw::TxWithRaw, signer::SignerData, TxMessage},
+    },
+    x::{
+        errors::{AccountNotFound, AnteError, AnteGasError},
+        keepers::{
+            auth::{AuthKeeper, AuthParams},
+            bank::BankKeeper,
+        },
+        module::Module,
+    },
+};
+
+use super::{AnteDecorator, AnteEnv, MetadataFromState, SignGasConsumer};
+
+/// Check that fee of tx is enough to satisfy `min_gas_prices` of node. Runs only in `CheckTx`
+/// and is skipped for simulation
+#[derive(Debug, Clone, Default)]
+pub struct MempoolFeeDecorator;
+
+impl<SK: StoreKey, M: TxMessage> AnteDecorator<SK, M> for MempoolFeeDecorator {
+    fn ante_handle<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        _ctx: &mut CTX,
+        TxWithRaw {
+            tx,
+            raw: _,
+            tx_len: _,
+        }: &TxWithRaw<M>,
+        env: &AnteEnv,
+    ) -> Result<(), TxError> {
+        if !env.is_check || env.simulate {
+            return Ok(());
+        }
+
+        let fee = tx.auth_info.fee.amount.as_ref();
+        let gas = tx.auth_info.fee.gas_limit;
+
+        let min_gas_prices = env.node_opt.min_gas_prices();
+
+        if min_gas_prices.is_empty() || min_gas_prices.is_zero() {
+            return Ok(());
+        }
+
+        if let Some(fee_coins) = fee {
+            let mut required_fees = Vec::with_capacity(min_gas_prices.len());
+
+            for gp in min_gas_prices {
+                required_fees.push(UnsignedCoin {
+                    denom: gp.denom,
+                    amount: gp
+                        .amount
+                        .checked_mul(Into::<Decimal256>::into(gas))
+                        .map_err(|_| {
+                            AnteGasError::Overflow("overflow calculating required fees".into())
+                        })
+                        .map_err(AnteError::from)?
+                        .to_uint_ceil(),
+                });
+            }
+
+            let required_fees = UnsignedCoins::new(required_fees)
+                .expect("we know by now that required_fees: contains at least one coin, all amounts are positive, no duplicate denominations and sorted lexicographically");
+
+            if !is_any_gte(fee_coins.inner(), &required_fees) {
+                Err(AnteError::InsufficientFees {
+                    got: format!("{fee_coins:?}"),
+                    required: format!("{required_fees:?}"),
+                })?
+            }
+        } else {
+            Err(AnteError::MissingFee)?
+        }
+
+        fn is_any_gte(coins_a: &Vec<UnsignedCoin>, coins_b: &UnsignedCoins) -> bool {
+            if coins_b.is_empty() {
+                return false;
+            }
+
+            for coin in coins_a {
+                let amount = coins_b.amount_of(&coin.denom);
+                if coin.amount >= amount && !amount.is_zero() {
+                    return true;
+                }
+            }
+
+            false
+        }
+
+        Ok(())
+    }
+}
+
+/// Check that tx has signature for every signer
+#[derive(Debug, Clone, Default)]
+pub struct ValidateBasicDecorator;
+
+impl<SK: StoreKey, M: TxMessage> AnteDecorator<SK, M> for ValidateBasicDecorator {
+    fn ante_handle<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        _ctx: &mut CTX,
+        tx: &TxWithRaw<M>,
+        _env: &AnteEnv,
+    ) -> Result<(), TxError> {
+        // Not sure if we need to explicitly check this given the check which follows.
+        // We'll leave it in for now since it's in the SDK.
+        let sigs = tx.tx.get_signatures();
+        if sigs.is_empty() {
+            Err(AnteError::Validation("signature list is empty".into()))?
+        }
+
+        if sigs.len() != tx.tx.get_signers().len() {
+            Err(AnteError::Validation(format!(
+                "wrong number of signatures; expected {}, got {}",
+                tx.tx.get_signers().len(),
+                sigs.len()
+            )))?
+        }
+
+        Ok(())
+    }
+}
+
+/// Reject tx which timeout height is lower than current height
+#[derive(Debug, Clone, Default)]
+pub struct TxTimeoutHeightDecorator;
+
+impl<SK: StoreKey, M: TxMessage> AnteDecorator<SK, M> for TxTimeoutHeightDecorator {
+    fn ante_handle<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        tx: &TxWithRaw<M>,
+        _env: &AnteEnv,
+    ) -> Result<(), TxError> {
+        let timeout_height = tx.tx.get_timeout_height();
+
+        // timeout_height of zero means no timeout height
+        if timeout_height == 0 {
+            return Ok(());
+        }
+
+        let block_height = ctx.height();
+
+        if block_height > timeout_height {
+            Err(AnteError::Timeout {
+                timeout: timeout_height,
+                current: block_height,
+            })?
+        }
+
+        Ok(())
+    }
+}
+
+/// Check that memo isn't longer than `max_memo_characters` auth param
+#[derive(Debug, Clone)]
+pub struct ValidateMemoDecorator<AK, SK, MOD> {
+    auth_keeper: AK,
+    _marker: PhantomData<(SK, MOD)>,
+}
+
+impl<AK, SK, MOD> ValidateMemoDecorator<AK, SK, MOD> {
+    pub fn new(auth_keeper: AK) -> Self {
+        Self {
+            auth_keeper,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<AK: AuthKeeper<SK, MOD>, SK: StoreKey, MOD: Module, M: TxMessage> AnteDecorator<SK, M>
+    for ValidateMemoDecorator<AK, SK, MOD>
+{
+    fn ante_handle<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        tx: &TxWithRaw<M>,
+        _env: &AnteEnv,
+    ) -> Result<(), TxError> {
+        let max_memo_chars = self
+            .auth_keeper
+            .get_auth_params(ctx)
+            .map_err(AnteError::from)?
+            .max_memo_characters();
+        let memo_length: u64 = tx
+            .tx
+            .get_memo()
+            .len()
+            .try_into()
+            .map_err(|_| AnteError::Memo(max_memo_chars))?;
+
+        if memo_length > max_memo_chars {
+            Err(AnteError::Memo(max_memo_chars))?
+        };
+
+        Ok(())
+    }
+}
+
+/// Length of secp256k1 signature which is expected in place of empty one in simulated tx
+const SIMULATION_SIGNATURE_LEN: u64 = 64;
+
+/// Charge gas for every byte of tx
+#[derive(Debug, Clone)]
+pub struct ConsumeGasForTxSizeDecorator<AK, SK, MOD> {
+    auth_keeper: AK,
+    _marker: PhantomData<(SK, MOD)>,
+}
+
+impl<AK, SK, MOD> ConsumeGasForTxSizeDecorator<AK, SK, MOD> {
+    pub fn new(auth_keeper: AK) -> Self {
+        Self {
+            auth_keeper,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<AK: AuthKeeper<SK, MOD>, SK: StoreKey, MOD: Module, M: TxMessage> AnteDecorator<SK, M>
+    for ConsumeGasForTxSizeDecorator<AK, SK, MOD>
+{
+    fn ante_handle<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        tx: &TxWithRaw<M>,
+        env: &AnteEnv,
+    ) -> Result<(), TxError> {
+        let params = self
+            .auth_keeper
+            .get_auth_params(ctx)
+            .map_err(AnteError::from)?;
+        let mut tx_len = tx.tx_len as u64;
+        if env.simulate {
+            // simulated tx may have empty signatures, account for the ones it'll have
+            tx_len += tx
+                .tx
+                .get_signatures()
+                .iter()
+                .filter(|sig| sig.is_empty())
+                .count() as u64
+                * SIMULATION_SIGNATURE_LEN;
+        }
+        let tx_len: Gas = tx_len.try_into().map_err(|_| AnteError::TxLen)?;
+        let cost_per_byte: Gas = params
+            .tx_cost_per_byte()
+            .try_into()
+            .map_err(|_| {
+                AnteGasError::Overflow("overflow converting tx cost per byte to gas".to_string())
+            })
+            .map_err(AnteError::from)?;
+        let gas_required = tx_len
+            .checked_mul(cost_per_byte)
+            .ok_or(AnteGasError::Overflow(
+                "overflow calculating gas required for tx size".to_string(),
+            ))
+            .map_err(AnteError::from)?;
+
+        env.gas_meter
+            .borrow_mut()
+            .consume_gas(gas_required, TX_SIZE_DESCRIPTOR)
+            .map_err(|e| AnteError::from(AnteGasError::from(e)))?;
+
+        Ok(())
+    }
+}
+
+/// Deduct fee of tx from fee payer and send it to fee collector
+#[derive(Debug, Clone)]
+pub struct DeductFeeDecorator<AK, BK, SK, MOD> {
+    auth_keeper: AK,
+    bank_keeper: BK,
+    fee_collector_module: MOD,
+    _marker: PhantomData<SK>,
+}
+
+impl<AK, BK, SK, MOD> DeductFeeDecorator<AK, BK, SK, MOD> {
+    pub fn new(auth_keeper: AK, bank_keeper: BK, fee_collector_module: MOD) -> Self {
+        Self {
+            auth_keeper,
+            bank_keeper,
+            fee_collector_module,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<AK: AuthKeeper<SK, MOD>, BK: BankKeeper<SK, MOD>, SK: StoreKey, MOD: Module, M: TxMessage>
+    AnteDecorator<SK, M> for DeductFeeDecorator<AK, BK, SK, MOD>
+{
+    fn ante_handle<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        tx: &TxWithRaw<M>,
+        _env: &AnteEnv,
+    ) -> Result<(), TxError> {
+        let fee = tx.tx.get_fee();
+        let fee_payer = tx.tx.get_fee_payer();
+
+        if !self
+            .auth_keeper
+            .has_account(ctx, fee_payer)
+            .map_err(AnteError::from)?
+        {
+            Err(AnteError::from(AccountNotFound::from(fee_payer.clone())))?
+        }
+
+        if let Some(fee) = fee {
+            self.bank_keeper
+                .send_coins_from_account_to_module(
+                    ctx,
+                    fee_payer.to_owned(),
+                    &self.fee_collector_module,
+                    fee.to_owned(),
+                )
+                .map_err(AnteError::from)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Set public keys of signers to their accounts if they aren't set yet.
+/// Must be called before all signature verification decorators
+#[derive(Debug, Clone)]
+pub struct SetPubKeyDecorator<AK, SK, MOD> {
+    auth_keeper: AK,
+    _marker: PhantomData<(SK, MOD)>,
+}
+
+impl<AK, SK, MOD> SetPubKeyDecorator<AK, SK, MOD> {
+    pub fn new(auth_keeper: AK) -> Self {
+        Self {
+            auth_keeper,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<AK: AuthKeeper<SK, MOD>, SK: StoreKey, MOD: Module, M: TxMessage> AnteDecorator<SK, M>
+    for SetPubKeyDecorator<AK, SK, MOD>
+{
+    fn ante_handle<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        tx: &TxWithRaw<M>,
+        _env: &AnteEnv,
+    ) -> Result<(), TxError> {
+        let public_keys = tx.tx.get_public_keys();
+        let signers = tx.tx.get_signers();
+
+        // additional check not found in the sdk - this prevents a panic
+        if signers.len() != public_keys.len() {
+            Err(AnteError::Validation(format!(
+                "wrong number of signer info; expected {}, got {}",
+                signers.len(),
+                public_keys.len()
+            )))?
+        }
+
+        for (i, key) in public_keys.into_iter().enumerate() {
+            if let Some(key) = key {
+                let addr = key.get_address();
+
+                if &addr != signers[i] {
+                    Err(AnteError::Validation(format!(
+                        "public key address number {i} does not match signer {i}; expected {}, got {addr}",
+                        signers[i]
+                    )))?
+                }
+
+                let mut acct = self
+                    .auth_keeper
+                    .get_account(ctx, &addr)
+                    .map_err(AnteError::from)?
+                    .ok_or(AnteError::from(AccountNotFound::from(addr.to_owned())))?;
+
+                if acct.get_public_key().is_some() {
+                    continue;
+                }
+
+                acct.set_public_key(key.clone());
+                self.auth_keeper
+                    .set_account(ctx, acct)
+                    .map_err(AnteError::from)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Check that number of signer keys doesn't exceed `tx_sig_limit` auth param
+#[derive(Debug, Clone)]
+pub struct ValidateSigCountDecorator<AK, SK, MOD> {
+    auth_keeper: AK,
+    _marker: PhantomData<(SK, MOD)>,
+}
+
+impl<AK, SK, MOD> ValidateSigCountDecorator<AK, SK, MOD> {
+    pub fn new(auth_keeper: AK) -> Self {
+        Self {
+            auth_keeper,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<AK: AuthKeeper<SK, MOD>, SK: StoreKey, MOD: Module, M: TxMessage> AnteDecorator<SK, M>
+    for ValidateSigCountDecorator<AK, SK, MOD>
+{
+    fn ante_handle<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        tx: &TxWithRaw<M>,
+        _env: &AnteEnv,
+    ) -> Result<(), TxError> {
+        let limit = self
+            .auth_keeper
+            .get_auth_params(ctx)
+            .map_err(AnteError::from)?
+            .tx_sig_limit();
+
+        // Multisig keys aren't supported so every signer has exactly one key
+        let count = tx.tx.get_public_keys().len() as u64;
+
+        if count > limit {
+            Err(AnteError::TooManySignatures { count, limit })?
+        }
+
+        Ok(())
+    }
+}
+
+/// Charge gas for verification of every signature with [SignGasConsumer]
+#[derive(Debug, Clone)]
+pub struct SigGasConsumeDecorator<AK, GC, SK, MOD> {
+    auth_keeper: AK,
+    sign_gas_consumer: GC,
+    _marker: PhantomData<(SK, MOD)>,
+}
+
+impl<AK, GC, SK, MOD> SigGasConsumeDecorator<AK, GC, SK, MOD> {
+    pub fn new(auth_keeper: AK, sign_gas_consumer: GC) -> Self {
+        Self {
+            auth_keeper,
+            sign_gas_consumer,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<AK: AuthKeeper<SK, MOD>, GC: SignGasConsumer, SK: StoreKey, MOD: Module, M: TxMessage>
+    AnteDecorator<SK, M> for SigGasConsumeDecorator<AK, GC, SK, MOD>
+{
+    fn ante_handle<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        tx: &TxWithRaw<M>,
+        env: &AnteEnv,
+    ) -> Result<(), TxError> {
+        let auth_params = self
+            .auth_keeper
+            .get_auth_params(ctx)
+            .map_err(AnteError::from)?;
+
+        let signatures = tx.tx.get_signatures_data();
+        let signers_addr = tx.tx.get_signers();
+
+        // NOTE: this is also checked in validate_basic_ante_handler
+        if signatures.len() != signers_addr.len() {
+            Err(AnteError::Validation(format!(
+                "wrong number of signatures; expected {}, got {}",
+                signers_addr.len(),
+                signatures.len()
+            )))?
+        }
+
+        for (signer_addr, sig) in signers_addr.into_iter().zip(signatures) {
+            let acct = self
+                .auth_keeper
+                .get_account(ctx, signer_addr)
+                .map_err(AnteError::from)?
+                .ok_or(AnteError::from(AccountNotFound::from(
+                    signer_addr.to_owned(),
+                )))?;
+
+            let pub_key = match acct.get_public_key() {
+                Some(pub_key) => pub_key.to_owned(),
+                // account without public key may only be simulated, charge as for secp256k1
+                None if env.simulate => simulation_public_key(),
+                // set by SetPubKeyDecorator unless it was removed from chain
+                None => Err(AnteError::Validation(
+                    "pubkey on account is not set".to_owned(),
+                ))?,
+            };
+
+            self.sign_gas_consumer
+                .consume(&mut env.gas_meter.borrow_mut(), pub_key, sig, &auth_params)
+                .map_err(|e| AnteError::from(AnteGasError::from(e)))?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Public key used to estimate gas of signature verification for accounts without one,
+/// matches `simSecp256k1Pubkey` of cosmos sdk
+fn simulation_public_key() -> PublicKey {
+    const SIMULATION_PUBLIC_KEY: [u8; 33] = [
+        0x03, 0x5a, 0xd6, 0x81, 0x0a, 0x47, 0xf0, 0x73, 0x55, 0x3f, 0xf3, 0x0d, 0x2f, 0xcc, 0x7e,
+        0x0d, 0x3b, 0x1c, 0x0b, 0x74, 0xb6, 0x1a, 0x1a, 0xaa, 0x25, 0x82, 0x34, 0x40, 0x37, 0x15,
+        0x1e, 0x14, 0x3a,
+    ];
+
+    PublicKey::Secp256k1(
+        Secp256k1PubKey::try_from(SIMULATION_PUBLIC_KEY.to_vec()).expect("hardcoded key is valid"),
+    )
+}
+
+/// Verify signatures of tx. Skipped for simulation
+#[derive(Debug, Clone)]
+pub struct SigVerificationDecorator<AK, BK, SK, MOD> {
+    auth_keeper: AK,
+    bank_keeper: BK,
+    _marker: PhantomData<(SK, MOD)>,
+}
+
+impl<AK, BK, SK, MOD> SigVerificationDecorator<AK, BK, SK, MOD> {
+    pub fn new(auth_keeper: AK, bank_keeper: BK) -> Self {
+        Self {
+            auth_keeper,
+            bank_keeper,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<
+        AK: AuthKeeper<SK, MOD>,
+        BK: BankKeeper<SK, MOD>,
+        SK: StoreKey,
+        MOD: Module,
+        M: TxMessage + ValueRenderer + AminoRenderer,
+    > AnteDecorator<SK, M> for SigVerificationDecorator<AK, BK, SK, MOD>
+{
+    fn ante_handle<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        tx: &TxWithRaw<M>,
+        env: &AnteEnv,
+    ) -> Result<(), TxError> {
+        // simulated tx has no valid signatures yet
+        if env.simulate {
+            return Ok(());
+        }
+
+        Ok(self.verify(ctx, tx)?)
+    }
+}
+
+impl<AK: AuthKeeper<SK, MOD>, BK: BankKeeper<SK, MOD>, SK: StoreKey, MOD: Module>
+    SigVerificationDecorator<AK, BK, SK, MOD>
+{
+    fn verify<
+        DB: Database,
+        M: TxMessage + ValueRenderer + AminoRenderer,
+        CTX: TransactionalContext<DB, SK>,
+    >(
+        &self,
+        ctx: &mut CTX,
+        tx: &TxWithRaw<M>,
+    ) -> Result<(), AnteError> {
+        let signers = tx.tx.get_signers();
+        let signature_data = tx.tx.get_signatures_data();
+
+        // NOTE: this is also checked in validate_basic_ante_handler
+        if signature_data.len() != signers.len() {
+            return Err(AnteError::Validation(format!(
+                "wrong number of signatures; expected {}, got {}",
+                signers.len(),
+                signature_data.len()
+            )));
+        }
+
+        for (i, signature_data) in signature_data.iter().enumerate() {
+            let signer = signers[i];
+
+            let acct = self
+                .auth_keeper
+                .get_account(ctx, signer)?
+                .ok_or(AccountNotFound::from(signer.to_owned()))?;
+
+            let account_seq = acct.get_sequence();
+            if account_seq != signature_data.sequence {
+                return Err(AnteError::Validation(format!(
+                    "incorrect tx sequence; expected {}, got {}",
+                    account_seq, signature_data.sequence
+                )));
+            }
+
+            // set by SetPubKeyDecorator unless it was removed from chain
+            let public_key = acct.get_public_key().ok_or(AnteError::Validation(
+                "pubkey on account is not set".to_owned(),
+            ))?;
+
+            let genesis = ctx.height() == 0;
+            let account_number = if genesis {
+                0
+            } else {
+                acct.get_account_number()
+            };
+
+            let sign_bytes = match &signature_data.mode_info {
+                ModeInfo::Single(mode) => match mode {
+                    SignMode::Direct => SignDoc {
+                        body_bytes: tx.raw.body_bytes.clone(),
+                        auth_info_bytes: tx.raw.auth_info_bytes.clone(),
+                        chain_id: ctx.chain_id().to_string(),
+                        account_number,
+                    }
+                    .encode_to_vec(),
+                    SignMode::LegacyAminoJson => {
+                        let mut msgs = vec![];
+                        for msg in tx.tx.get_msgs() {
+                            msgs.push(std_sign_doc::Msg {
+                                kind: msg.amino_url().to_string(),
+                                value: msg.render()?,
+                            })
+                        }
+                        let doc = std_sign_doc::StdSignDoc {
+                            account_number: account_number.to_string(),
+                            chain_id: ctx.chain_id().to_string(),
+                            fee: tx.tx.auth_info.fee.clone().into(),
+                            memo: tx.tx.get_memo().to_string(),
+                            msgs,
+                            sequence: account_seq.to_string(),
+                            // TODO: check impl
+                            // timeout_height: Some(u64::from(tx.tx.get_timeout_height()).to_string()),
+                            timeout_height: None,
+                        };
+
+                        doc.to_sign_bytes().map_err(|e| {
+                            AnteError::LegacyAminoJson(AminoRendererError::Rendering(e.to_string()))
+                        })?
+                    }
+                    SignMode::DirectAux => {
+                        // fee payer signs over fee, so it has to use a sign mode
+                        // which covers `auth_info_bytes`
+                        if signer == tx.tx.get_fee_payer() {
+                            return Err(AnteError::Validation(format!(
+                                "fee payer {} cannot sign with {:?}",
+                                signer,
+                                SignMode::DirectAux
+                            )));
+                        }
+
+                        SignDocDirectAux {
+                            body_bytes: tx.raw.body_bytes.clone(),
+                            public_key: public_key.to_owned(),
+                            chain_id: ctx.chain_id().to_owned(),
+                            account_number,
+                            sequence: account_seq,
+                            tip: tx.tx.auth_info.tip.clone(),
+                        }
+                        .to_sign_bytes()
+                    }
+                    SignMode::Textual => {
+                        let handler = SignModeHandler;
+
+                        let signer_data = SignerData {
+                            address: signer.to_owned(),
+                            chain_id: ctx.chain_id().to_owned(),
+                            account_number,
+                            sequence: account_seq,
+                            pub_key: public_key.to_owned(),
+                        };
+
+                        let f = MetadataFromState {
+                            bank_keeper: &self.bank_keeper,
+                            ctx,
+                            _phantom: PhantomData,
+                        };
+
+                        handler.sign_bytes_get(&f, signer_data, &tx.tx.body, &tx.tx.auth_info)?
+                    }
+                    mode => {
+                        return Err(AnteError::Validation(format!(
+                            "sign mode not supported: {:?}",
+                            mode
+                        )))
+                    }
+                },
+                ModeInfo::Multi(_) => {
+                    return Err(AnteError::Validation("multi sig not supported".to_string()));
+                }
+            };
+
+            public_key
+                .verify_signature(&sign_bytes, &signature_data.signature)
+                .map_err(|e| AnteError::Validation(format!("invalid signature: {}", e)))?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Increment sequence of every signer
+#[derive(Debug, Clone)]
+pub struct IncrementSequenceDecorator<AK, SK, MOD> {
+    auth_keeper: AK,
+    _marker: PhantomData<(SK, MOD)>,
+}
+
+impl<AK, SK, MOD> IncrementSequenceDecorator<AK, SK, MOD> {
+    pub fn new(auth_keeper: AK) -> Self {
+        Self {
+            auth_keeper,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<AK: AuthKeeper<SK, MOD>, SK: StoreKey, MOD: Module, M: TxMessage> AnteDecorator<SK, M>
+    for IncrementSequenceDecorator<AK, SK, MOD>
+{
+    fn ante_handle<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        tx: &TxWithRaw<M>,
+        _env: &AnteEnv,
+    ) -> Result<(), TxError> {
+        for signer in tx.tx.get_signers() {
+            let mut acct = self
+                .auth_keeper
+                .get_account(ctx, signer)
+                .map_err(AnteError::from)?
+                .ok_or(AnteError::from(AccountNotFound::from(signer.to_owned())))?;
+            acct.increment_sequence();
+            self.auth_keeper
+                .set_account(ctx, acct)
+                .map_err(AnteError::from)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Transfer tip of tx from tipper to fee payer. Tipper must be one of the signers
+/// of tx and usually signs it with `SIGN_MODE_DIRECT_AUX`
+#[derive(Debug, Clone)]
+pub struct TipDecorator<BK, SK, MOD> {
+    bank_keeper: BK,
+    _marker: PhantomData<(SK, MOD)>,
+}
+
+impl<BK, SK, MOD> TipDecorator<BK, SK, MOD> {
+    pub fn new(bank_keeper: BK) -> Self {
+        Self {
+            bank_keeper,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<BK: BankKeeper<SK, MOD>, SK: StoreKey, MOD: Module, M: TxMessage> AnteDecorator<SK, M>
+    for TipDecorator<BK, SK, MOD>
+{
+    fn ante_handle<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        tx: &TxWithRaw<M>,
+        _env: &AnteEnv,
+    ) -> Result<(), TxError> {
+        let tip = match &tx.tx.auth_info.tip {
+            Some(tip) => tip,
+            None => return Ok(()),
+        };
+
+        if !tx.tx.get_signers().contains(&&tip.tipper) {
+            Err(AnteError::TipperNotSigner(tip.tipper.clone()))?
+        }
+
+        if let Some(amount) = &tip.amount {
+            self.bank_keeper
+                .send_coins_from_account_to_account(
+                    ctx,
+                    &MsgSend {
+                        from_address: tip.tipper.clone(),
+                        to_address: tx.tx.get_fee_payer().to_owned(),
+                        amount: amount.clone(),
+                    },
+                )
+                .map_err(AnteError::from)?;
+        }
+
+        Ok(())
+    }
+}
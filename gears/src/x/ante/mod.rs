@@ -0,0 +1,489 @@
+use crate::application::handlers::node::TxError;
+use crate::baseapp::options::NodeOptions;
+use crate::context::QueryableContext;
+use crate::context::TransactionalContext;
+use crate::crypto::public::PublicKey;
+use crate::signing::handler::MetadataGetter;
+use crate::signing::renderer::amino_renderer::AminoRenderer;
+use crate::signing::renderer::value_renderer::ValueRenderer;
+use crate::types::denom::Denom;
+use crate::types::tx::{raw::TxWithRaw, TxMessage};
+use crate::x::keepers::auth::AuthKeeper;
+use crate::x::keepers::auth::AuthParams;
+use crate::x::keepers::bank::BankKeeper;
+use core_types::tx::signature::SignatureData;
+use database::Database;
+use gas::metering::descriptor::{
+    ANTE_ED25519_DESCRIPTOR, ANTE_SECKP251K1_DESCRIPTOR, ANTE_SECP256R1_DESCRIPTOR,
+};
+use gas::metering::kind::TxKind;
+use gas::metering::GasMeter;
+use gas::store::errors::GasStoreErrors;
+use kv_store::StoreKey;
+use std::cell::RefCell;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use super::module::Module;
+
+pub use decorators::*;
+
+mod decorators;
+
+pub trait SignGasConsumer: Clone + Sync + Send + 'static {
+    fn consume<AP: AuthParams>(
+        &self,
+        gas_meter: &mut GasMeter<TxKind>,
+        pub_key: PublicKey,
+        data: &SignatureData,
+        params: &AP,
+    ) -> Result<(), GasStoreErrors>;
+}
+
+#[derive(Debug, Clone)]
+pub struct DefaultSignGasConsumer;
+
+impl SignGasConsumer for DefaultSignGasConsumer {
+    fn consume<AP: AuthParams>(
+        &self,
+        gas_meter: &mut GasMeter<TxKind>,
+        pub_key: PublicKey,
+        _data: &SignatureData,
+        params: &AP,
+    ) -> Result<(), GasStoreErrors> {
+        // TODO I'm unsure that this is 100% correct due multisig mode see: https://github.com/cosmos/cosmos-sdk/blob/d3f09c222243bb3da3464969f0366330dcb977a8/x/auth/ante/sigverify.go#L401
+        match pub_key {
+            PublicKey::Secp256k1(_) | PublicKey::EthSecp256k1(_) => {
+                let amount = params
+                    .sig_verify_cost_secp256k1()
+                    .try_into()
+                    .map_err(|e| GasStoreErrors::new(&[], e))?; // TODO: Should be okay for now, but needs to be changed
+                gas_meter
+                    .consume_gas(amount, ANTE_SECKP251K1_DESCRIPTOR)
+                    .map_err(|e| GasStoreErrors::new(&[], e))?; // TODO: Should be okay for now, but needs to be changed
+            }
+            PublicKey::Ed25519(_key) => {
+                let amount = params
+                    .sig_verify_cost_ed25519()
+                    .try_into()
+                    .map_err(|e| GasStoreErrors::new(&[], e))?;
+                gas_meter
+                    .consume_gas(amount, ANTE_ED25519_DESCRIPTOR)
+                    .map_err(|e| GasStoreErrors::new(&[], e))?;
+            }
+            PublicKey::Secp256r1(_key) => {
+                let amount = params
+                    .sig_verify_cost_secp256r1()
+                    .try_into()
+                    .map_err(|e| GasStoreErrors::new(&[], e))?;
+                gas_meter
+                    .consume_gas(amount, ANTE_SECP256R1_DESCRIPTOR)
+                    .map_err(|e| GasStoreErrors::new(&[], e))?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Data of current run of ante handler which isn't stored in context
+#[derive(Debug, Clone)]
+pub struct AnteEnv {
+    pub is_check: bool,
+    /// Tx is simulated to estimate gas, so signatures may be empty
+    pub simulate: bool,
+    pub node_opt: NodeOptions,
+    pub gas_meter: Arc<RefCell<GasMeter<TxKind>>>,
+}
+
+/// Single step of ante handler. Decorators are composed into chain with [AnteHandlerBuilder]
+/// and run in order they were added, chain stops on first error.
+pub trait AnteDecorator<SK: StoreKey, M: TxMessage>: Clone + Send + Sync + 'static {
+    fn ante_handle<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        tx: &TxWithRaw<M>,
+        env: &AnteEnv,
+    ) -> Result<(), TxError>;
+}
+
+/// Empty chain of decorators
+#[derive(Debug, Clone, Default)]
+pub struct AnteChainEnd;
+
+impl<SK: StoreKey, M: TxMessage> AnteDecorator<SK, M> for AnteChainEnd {
+    fn ante_handle<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        _ctx: &mut CTX,
+        _tx: &TxWithRaw<M>,
+        _env: &AnteEnv,
+    ) -> Result<(), TxError> {
+        Ok(())
+    }
+}
+
+/// Runs `head` decorators and then `next` one
+#[derive(Debug, Clone)]
+pub struct AnteChain<H, N> {
+    head: H,
+    next: N,
+}
+
+impl<SK: StoreKey, M: TxMessage, H: AnteDecorator<SK, M>, N: AnteDecorator<SK, M>>
+    AnteDecorator<SK, M> for AnteChain<H, N>
+{
+    fn ante_handle<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        tx: &TxWithRaw<M>,
+        env: &AnteEnv,
+    ) -> Result<(), TxError> {
+        self.head.ante_handle(ctx, tx, env)?;
+        self.next.ante_handle(ctx, tx, env)
+    }
+}
+
+/// Builder of [AnteHandler]. Decorators run in order they were added to builder.
+/// Default decorators could be added in order with [DefaultAnteDecorators].
+///
+/// ```ignore
+/// let ante_handler = AnteHandlerBuilder::new()
+///     .decorator(ValidateBasicDecorator)
+///     .decorator(MyMsgFilterDecorator::new(allowed))
+///     .decorator(IncrementSequenceDecorator::new(auth_keeper))
+///     .build();
+/// ```
+#[derive(Debug, Clone)]
+pub struct AnteHandlerBuilder<D> {
+    decorators: D,
+}
+
+impl AnteHandlerBuilder<AnteChainEnd> {
+    pub fn new() -> Self {
+        Self {
+            decorators: AnteChainEnd,
+        }
+    }
+}
+
+impl Default for AnteHandlerBuilder<AnteChainEnd> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<D> AnteHandlerBuilder<D> {
+    /// Append `decorator` to the end of chain
+    pub fn decorator<T>(self, decorator: T) -> AnteHandlerBuilder<AnteChain<D, T>> {
+        AnteHandlerBuilder {
+            decorators: AnteChain {
+                head: self.decorators,
+                next: decorator,
+            },
+        }
+    }
+
+    pub fn build(self) -> AnteHandler<D> {
+        AnteHandler {
+            decorators: self.decorators,
+        }
+    }
+}
+
+/// Ante handler which runs chain of decorators built with [AnteHandlerBuilder]
+#[derive(Debug, Clone)]
+pub struct AnteHandler<D> {
+    decorators: D,
+}
+
+impl<D> AnteHandler<D> {
+    pub fn run<SK: StoreKey, DB: Database, M: TxMessage, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        tx: &TxWithRaw<M>,
+        is_check: bool,
+        node_opt: NodeOptions,
+        gas_meter: Arc<RefCell<GasMeter<TxKind>>>,
+    ) -> Result<(), TxError>
+    where
+        D: AnteDecorator<SK, M>,
+    {
+        self.decorators.ante_handle(
+            ctx,
+            tx,
+            &AnteEnv {
+                is_check,
+                simulate: ctx.is_simulate(),
+                node_opt,
+                gas_meter,
+            },
+        )
+    }
+}
+
+/// Ante handler which runs checks of tx before execution of its messages.
+/// Implemented by [AnteHandler] and [BaseAnteHandler], so application could use either of them
+pub trait TxAnteHandler<SK: StoreKey, M: TxMessage>: Clone + Send + Sync + 'static {
+    fn run<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        tx: &TxWithRaw<M>,
+        is_check: bool,
+        node_opt: NodeOptions,
+        gas_meter: Arc<RefCell<GasMeter<TxKind>>>,
+    ) -> Result<(), TxError>;
+}
+
+impl<SK: StoreKey, M: TxMessage, D: AnteDecorator<SK, M>> TxAnteHandler<SK, M> for AnteHandler<D> {
+    fn run<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        tx: &TxWithRaw<M>,
+        is_check: bool,
+        node_opt: NodeOptions,
+        gas_meter: Arc<RefCell<GasMeter<TxKind>>>,
+    ) -> Result<(), TxError> {
+        AnteHandler::run(self, ctx, tx, is_check, node_opt, gas_meter)
+    }
+}
+
+/// Decorators of [BaseAnteHandler] in the order they run.
+///
+/// Decorators are generic over context, so they can't be kept as trait objects in a list which
+/// could be changed by position. Instead [AnteHandlerBuilder] builds type of the chain at compile
+/// time. To insert, replace or remove decorators take fields of default ones and add them to
+/// builder in required order:
+///
+/// ```ignore
+/// let DefaultAnteDecorators {
+///     mempool_fee,
+///     validate_basic,
+///     tx_timeout_height,
+///     ..
+/// } = DefaultAnteDecorators::new(auth_keeper, bank_keeper, sign_gas_consumer, fee_collector);
+///
+/// let ante_handler = AnteHandlerBuilder::new()
+///     .decorator(mempool_fee)
+///     .decorator(validate_basic)
+///     .decorator(MyMsgFilterDecorator::new(allowed))
+///     .decorator(tx_timeout_height)
+///     // the rest of default decorators
+///     .build();
+/// ```
+#[derive(Debug, Clone)]
+pub struct DefaultAnteDecorators<BK, AK, SK, GC, MOD> {
+    pub mempool_fee: MempoolFeeDecorator,
+    pub validate_basic: ValidateBasicDecorator,
+    pub tx_timeout_height: TxTimeoutHeightDecorator,
+    pub validate_memo: ValidateMemoDecorator<AK, SK, MOD>,
+    pub consume_gas_for_tx_size: ConsumeGasForTxSizeDecorator<AK, SK, MOD>,
+    pub deduct_fee: DeductFeeDecorator<AK, BK, SK, MOD>,
+    pub set_pub_key: SetPubKeyDecorator<AK, SK, MOD>,
+    pub validate_sig_count: ValidateSigCountDecorator<AK, SK, MOD>,
+    pub sig_gas_consume: SigGasConsumeDecorator<AK, GC, SK, MOD>,
+    pub sig_verification: SigVerificationDecorator<AK, BK, SK, MOD>,
+    pub increment_sequence: IncrementSequenceDecorator<AK, SK, MOD>,
+    pub tip: TipDecorator<BK, SK, MOD>,
+}
+
+impl<BK: Clone, AK: Clone, SK, GC, MOD> DefaultAnteDecorators<BK, AK, SK, GC, MOD> {
+    pub fn new(
+        auth_keeper: AK,
+        bank_keeper: BK,
+        sign_gas_consumer: GC,
+        fee_collector_module: MOD,
+    ) -> Self {
+        Self {
+            mempool_fee: MempoolFeeDecorator,
+            validate_basic: ValidateBasicDecorator,
+            tx_timeout_height: TxTimeoutHeightDecorator,
+            validate_memo: ValidateMemoDecorator::new(auth_keeper.clone()),
+            consume_gas_for_tx_size: ConsumeGasForTxSizeDecorator::new(auth_keeper.clone()),
+            deduct_fee: DeductFeeDecorator::new(
+                auth_keeper.clone(),
+                bank_keeper.clone(),
+                fee_collector_module,
+            ),
+            set_pub_key: SetPubKeyDecorator::new(auth_keeper.clone()),
+            validate_sig_count: ValidateSigCountDecorator::new(auth_keeper.clone()),
+            sig_gas_consume: SigGasConsumeDecorator::new(auth_keeper.clone(), sign_gas_consumer),
+            sig_verification: SigVerificationDecorator::new(
+                auth_keeper.clone(),
+                bank_keeper.clone(),
+            ),
+            increment_sequence: IncrementSequenceDecorator::new(auth_keeper),
+            tip: TipDecorator::new(bank_keeper),
+        }
+    }
+}
+
+impl<
+        AK: AuthKeeper<SK, MOD>,
+        BK: BankKeeper<SK, MOD>,
+        SK: StoreKey,
+        GC: SignGasConsumer,
+        MOD: Module,
+        M: TxMessage + ValueRenderer + AminoRenderer,
+    > AnteDecorator<SK, M> for DefaultAnteDecorators<BK, AK, SK, GC, MOD>
+{
+    fn ante_handle<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        tx: &TxWithRaw<M>,
+        env: &AnteEnv,
+    ) -> Result<(), TxError> {
+        // ante.NewSetUpContextDecorator(), // WE not going to implement this in ante. Some logic should be in application
+        //  - ante.NewRejectExtensionOptionsDecorator(), // Covered in tx parsing code
+        //  ** ibcante.NewAnteDecorator(opts.IBCkeeper),
+        self.mempool_fee.ante_handle(ctx, tx, env)?;
+        self.validate_basic.ante_handle(ctx, tx, env)?;
+        self.tx_timeout_height.ante_handle(ctx, tx, env)?;
+        self.validate_memo.ante_handle(ctx, tx, env)?;
+        self.consume_gas_for_tx_size.ante_handle(ctx, tx, env)?;
+        self.deduct_fee.ante_handle(ctx, tx, env)?;
+        // SetPubKeyDecorator must be called before all signature verification decorators
+        self.set_pub_key.ante_handle(ctx, tx, env)?;
+        self.validate_sig_count.ante_handle(ctx, tx, env)?;
+        self.sig_gas_consume.ante_handle(ctx, tx, env)?;
+        self.sig_verification.ante_handle(ctx, tx, env)?;
+        self.increment_sequence.ante_handle(ctx, tx, env)?;
+        self.tip.ante_handle(ctx, tx, env)
+    }
+}
+
+/// Ante handler with default chain of decorators, see [DefaultAnteDecorators]
+#[derive(Debug, Clone)]
+pub struct BaseAnteHandler<
+    BK: BankKeeper<SK, M>,
+    AK: AuthKeeper<SK, M>,
+    SK: StoreKey,
+    GC,
+    M: Module,
+> {
+    inner: AnteHandler<DefaultAnteDecorators<BK, AK, SK, GC, M>>,
+}
+
+impl<
+        AK: AuthKeeper<SK, MOD>,
+        BK: BankKeeper<SK, MOD>,
+        SK: StoreKey,
+        GC: SignGasConsumer,
+        MOD: Module,
+    > BaseAnteHandler<BK, AK, SK, GC, MOD>
+{
+    pub fn new(
+        auth_keeper: AK,
+        bank_keeper: BK,
+        sign_gas_consumer: GC,
+        fee_collector_module: MOD,
+    ) -> BaseAnteHandler<BK, AK, SK, GC, MOD> {
+        BaseAnteHandler {
+            inner: AnteHandler {
+                decorators: DefaultAnteDecorators::new(
+                    auth_keeper,
+                    bank_keeper,
+                    sign_gas_consumer,
+                    fee_collector_module,
+                ),
+            },
+        }
+    }
+
+    pub fn run<
+        DB: Database,
+        M: TxMessage + ValueRenderer + AminoRenderer,
+        CTX: TransactionalContext<DB, SK>,
+    >(
+        &self,
+        ctx: &mut CTX,
+        tx: &TxWithRaw<M>,
+        is_check: bool,
+        node_opt: NodeOptions,
+        gas_meter: Arc<RefCell<GasMeter<TxKind>>>,
+    ) -> Result<(), TxError> {
+        self.inner.run(ctx, tx, is_check, node_opt, gas_meter)
+    }
+}
+
+impl<
+        AK: AuthKeeper<SK, MOD>,
+        BK: BankKeeper<SK, MOD>,
+        SK: StoreKey,
+        GC: SignGasConsumer,
+        MOD: Module,
+        M: TxMessage + ValueRenderer + AminoRenderer,
+    > TxAnteHandler<SK, M> for BaseAnteHandler<BK, AK, SK, GC, MOD>
+{
+    fn run<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        tx: &TxWithRaw<M>,
+        is_check: bool,
+        node_opt: NodeOptions,
+        gas_meter: Arc<RefCell<GasMeter<TxKind>>>,
+    ) -> Result<(), TxError> {
+        BaseAnteHandler::run(self, ctx, tx, is_check, node_opt, gas_meter)
+    }
+}
+
+#[derive(Debug)]
+pub struct MetadataFromState<'a, DB, SK, BK, CTX, MK> {
+    pub bank_keeper: &'a BK,
+    pub ctx: &'a CTX,
+    pub _phantom: PhantomData<(DB, SK, MK)>,
+}
+
+impl<
+        'a,
+        DB: Database,
+        SK: StoreKey,
+        BK: BankKeeper<SK, M>,
+        CTX: QueryableContext<DB, SK>,
+        M: Module,
+    > MetadataGetter for MetadataFromState<'a, DB, SK, BK, CTX, M>
+{
+    type Error = GasStoreErrors; // this is not used here
+
+    fn metadata(
+        &self,
+        denom: &Denom,
+    ) -> Result<Option<crate::types::tx::metadata::Metadata>, Self::Error> {
+        self.bank_keeper.denom_metadata(self.ctx, denom)
+    }
+}
+
+// TODO: uncomment tests
+// #[cfg(test)]
+// mod tests {
+//     use database::MemDB;
+//     use proto_messages::cosmos::auth::v1beta1::{Account, BaseAccount};
+//     use proto_types::AccAddress;
+//     use extensions::testing::UnwrapCorrupt;
+
+//     use crate::store::MultiStore;
+//     use crate::types::tests::get_signed_tx;
+//     use crate::types::InitContext;
+//     use crate::x::auth::Auth;
+
+//     use super::*;
+
+//     #[test]
+//     fn sig_verification_handler_works() {
+//         // TODO: add tests for transactions that are expected to fail
+//         let tx = get_signed_tx();
+
+//         let db = MemDB::new();
+//         let mut store = MultiStore::new(db);
+//         let mut ctx = InitContext::new(&mut store, 0, "unit-testing".into());
+//         let account = BaseAccount {
+//             address: AccAddress::from_bech32("cosmos1syavy2npfyt9tcncdtsdzf7kny9lh777pahuux")
+//                 .unwrap_test(),
+//             pub_key: None,
+//             account_number: 1,
+//             sequence: 1,
+//         };
+//         Auth::set_account(&mut ctx.as_any(), Account::Base(account));
+//         set_pub_key_ante_handler(&mut ctx.as_any(), &tx).unwrap_test();
+//         sig_verification_handler(&mut ctx.as_any(), &tx).unwrap_test();
+//     }
+// }
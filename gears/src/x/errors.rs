@@ -105,6 +105,10 @@ pub(crate) enum AnteError {
     LegacyAminoJson(#[from] RenderError),
     #[error("failed get sign bytes from tx: {0}")]
     Signing(#[from] SigningErrors),
+    #[error("signatures: {count}, limit: {limit}")]
+    TooManySignatures { count: u64, limit: u64 },
+    #[error("tipper {0} must be a signer of the tx")]
+    TipperNotSigner(AccAddress),
 }
 
 impl From<AnteError> for TxError {
@@ -129,6 +133,8 @@ impl From<AnteError> for TxError {
             AnteError::Gas(_) => 10,
             AnteError::LegacyAminoJson(_) => 11,
             AnteError::Signing(_) => 12,
+            AnteError::TooManySignatures { count: _, limit: _ } => 13,
+            AnteError::TipperNotSigner(_) => 14,
         };
 
         TxError {
@@ -192,3 +198,17 @@ impl From<CoinsError> for BankKeeperError {
         Self::Coins(BankCoinsError::Parse(value))
     }
 }
+
+#[derive(Debug, Clone, thiserror::Error)]
+pub enum CommunityPoolError {
+    #[error("community pool does not have sufficient coins to distribute")]
+    InsufficientFunds,
+    #[error("{0} is not allowed to receive external funds")]
+    Blocked(AccAddress),
+    #[error("{0}")]
+    Bank(#[from] BankKeeperError),
+    #[error("{0}")]
+    GasError(#[from] GasStoreErrors),
+    #[error("{0}")]
+    Other(String),
+}
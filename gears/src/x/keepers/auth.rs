@@ -10,7 +10,10 @@ use crate::{
 
 pub trait AuthParams {
     fn max_memo_characters(&self) -> u64;
+    fn tx_sig_limit(&self) -> u64;
+    fn sig_verify_cost_ed25519(&self) -> u64;
     fn sig_verify_cost_secp256k1(&self) -> u64;
+    fn sig_verify_cost_secp256r1(&self) -> u64;
     fn tx_cost_per_byte(&self) -> u64;
 }
 
@@ -0,0 +1,20 @@
+use database::Database;
+use kv_store::StoreKey;
+
+use crate::{
+    context::TransactionalContext,
+    types::{address::AccAddress, base::coins::UnsignedCoins},
+    x::errors::CommunityPoolError,
+};
+
+/// Access to community pool of distribution module which is required by governance
+/// to spend funds of the pool
+pub trait CommunityPoolKeeper<SK: StoreKey>: Clone + Send + Sync + 'static {
+    /// Send `amount` from community pool to `recipient`
+    fn distribute_from_fee_pool<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        amount: UnsignedCoins,
+        recipient: &AccAddress,
+    ) -> Result<(), CommunityPoolError>;
+}
@@ -15,7 +15,10 @@ use crate::{
 #[derive(Debug, Clone)]
 pub struct MockAuthParams {
     pub max_memo_characters: u64,
+    pub tx_sig_limit: u64,
+    pub sig_verify_cost_ed25519: u64,
     pub sig_verify_cost_secp256k1: u64,
+    pub sig_verify_cost_secp256r1: u64,
     pub tx_cost_per_byte: u64,
 }
 
@@ -23,8 +26,11 @@ impl Default for MockAuthParams {
     fn default() -> Self {
         Self {
             max_memo_characters: 256,
+            tx_sig_limit: 7,
             tx_cost_per_byte: 10,
+            sig_verify_cost_ed25519: 590,
             sig_verify_cost_secp256k1: 1000,
+            sig_verify_cost_secp256r1: 500,
         }
     }
 }
@@ -34,10 +40,22 @@ impl AuthParams for MockAuthParams {
         self.max_memo_characters
     }
 
+    fn tx_sig_limit(&self) -> u64 {
+        self.tx_sig_limit
+    }
+
+    fn sig_verify_cost_ed25519(&self) -> u64 {
+        self.sig_verify_cost_ed25519
+    }
+
     fn sig_verify_cost_secp256k1(&self) -> u64 {
         self.sig_verify_cost_secp256k1
     }
 
+    fn sig_verify_cost_secp256r1(&self) -> u64 {
+        self.sig_verify_cost_secp256r1
+    }
+
     fn tx_cost_per_byte(&self) -> u64 {
         self.tx_cost_per_byte
     }
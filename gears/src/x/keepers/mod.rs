@@ -1,5 +1,6 @@
 pub mod auth;
 pub mod bank;
+pub mod distribution;
 pub mod gov;
 pub mod mint;
 #[cfg(feature = "mocks")]
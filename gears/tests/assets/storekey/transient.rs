@@ -0,0 +1,17 @@
+#[derive(strum::EnumIter, Debug, PartialEq, Eq, Hash, Clone, gears::derive::StoreKeys)]
+#[skey(params = Params)]
+pub enum GaiaStoreKey {
+    #[skey(to_string = "bank")]
+    Bank,
+    #[skey(to_string = "params")]
+    Params,
+    #[skey(to_string = "transient_fees", transient)]
+    TransientFees,
+}
+
+fn main() {
+    use gears::store::StoreKey;
+
+    assert!(GaiaStoreKey::TransientFees.is_transient());
+    assert!(!GaiaStoreKey::Bank.is_transient());
+}
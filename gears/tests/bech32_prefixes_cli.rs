@@ -0,0 +1,70 @@
+#![cfg(feature = "cli")]
+
+use gears::{
+    application::ApplicationInfo,
+    cli::{
+        aux::CliNilAuxCommand, CliApplicationArgs, CliApplicationCommands, CliClientCommands,
+        CliCommands,
+    },
+    types::address::{AccAddress, Bech32Prefixes},
+};
+
+#[derive(Debug, Clone)]
+struct OsmoApplication;
+
+impl ApplicationInfo for OsmoApplication {
+    fn bech32_prefixes() -> Bech32Prefixes {
+        Bech32Prefixes::from_main_prefix("osmo")
+    }
+}
+
+#[derive(Debug, Clone, ::clap::Args)]
+struct CliNilTxArgs {}
+
+#[derive(Debug, Clone, ::clap::Subcommand)]
+enum CliBalanceQuery {
+    Balance { address: AccAddress },
+}
+
+type Args = CliApplicationArgs<
+    OsmoApplication,
+    CliNilAuxCommand,
+    CliNilAuxCommand,
+    CliNilTxArgs,
+    CliBalanceQuery,
+>;
+
+#[test]
+fn address_args_are_parsed_with_application_prefixes() {
+    let args = Args::try_parse_with_prefixes_from([
+        "osmod",
+        "query",
+        "balance",
+        "osmo1qyqszqgpqyqszqgpqyqszqgpqyqszqgp6gjwmw",
+    ])
+    .expect("address with application prefix is parsed");
+
+    let CliCommands::Cli(CliApplicationCommands::Client(CliClientCommands::Query(query))) =
+        args.command
+    else {
+        panic!("expected query command");
+    };
+    let CliBalanceQuery::Balance { address } = query.command;
+    assert_eq!(
+        address,
+        AccAddress::try_from(vec![1; 20]).expect("valid address")
+    );
+    assert_eq!(
+        address.to_string(),
+        "osmo1qyqszqgpqyqszqgpqyqszqgpqyqszqgp6gjwmw"
+    );
+
+    // prefixes are already set, so an address with another prefix is rejected
+    assert!(Args::try_parse_with_prefixes_from([
+        "osmod",
+        "query",
+        "balance",
+        "cosmos1qyqszqgpqyqszqgpqyqszqgpqyqszqgpjnp7du",
+    ])
+    .is_err());
+}
@@ -6,6 +6,12 @@ fn simple() {
     t.pass("tests/assets/storekey/simple.rs");
 }
 
+#[test]
+fn transient() {
+    let t = trybuild::TestCases::new();
+    t.pass("tests/assets/storekey/transient.rs");
+}
+
 #[test]
 #[should_panic]
 fn empty_key() {
@@ -0,0 +1,109 @@
+use std::{fmt::Display, str::FromStr};
+
+use bip32::DerivationPath;
+use serde::{Deserialize, Serialize};
+
+use crate::error::DecodeError;
+
+/// Coin type of cosmos hub registered in [SLIP-0044](https://github.com/satoshilabs/slips/blob/master/slip-0044.md)
+pub const DEFAULT_COIN_TYPE: u32 = 118;
+/// Coin type of ethereum registered in [SLIP-0044](https://github.com/satoshilabs/slips/blob/master/slip-0044.md)
+pub const ETH_COIN_TYPE: u32 = 60;
+
+/// Path used to derive key from mnemonic.
+/// Defaults to `m/44'/118'/0'/0/0`
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct HdPath(DerivationPath);
+
+impl HdPath {
+    /// Returns [BIP44](https://github.com/bitcoin/bips/blob/master/bip-0044.mediawiki) path
+    /// `m/44'/{coin_type}'/{account}'/0/{index}`. Fails if any of numbers is greater than
+    /// or equal to `2^31`
+    pub fn bip44(coin_type: u32, account: u32, index: u32) -> Result<Self, DecodeError> {
+        format!("m/44'/{coin_type}'/{account}'/0/{index}").parse()
+    }
+
+    /// Returns default path of ethereum wallets `m/44'/60'/0'/0/0`
+    pub fn default_eth() -> Self {
+        Self::bip44(ETH_COIN_TYPE, 0, 0).expect("default eth path is valid")
+    }
+
+    pub fn inner(&self) -> &DerivationPath {
+        &self.0
+    }
+}
+
+impl Default for HdPath {
+    fn default() -> Self {
+        Self::bip44(DEFAULT_COIN_TYPE, 0, 0).expect("default path is valid")
+    }
+}
+
+impl FromStr for HdPath {
+    type Err = DecodeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self(s.parse().map_err(|e: bip32::Error| {
+            DecodeError(format!("invalid hd path {s}: {e}"))
+        })?))
+    }
+}
+
+impl TryFrom<String> for HdPath {
+    type Error = DecodeError;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        value.parse()
+    }
+}
+
+impl From<HdPath> for String {
+    fn from(value: HdPath) -> Self {
+        value.to_string()
+    }
+}
+
+impl Display for HdPath {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use extensions::testing::UnwrapTesting;
+
+    use super::*;
+
+    #[test]
+    fn default_path_is_cosmos_path() {
+        assert_eq!(HdPath::default().to_string(), "m/44'/118'/0'/0/0");
+    }
+
+    #[test]
+    fn bip44_path_from_parts() {
+        assert_eq!(
+            HdPath::bip44(60, 1, 5).unwrap_test().to_string(),
+            "m/44'/60'/1'/0/5"
+        );
+        assert_eq!(
+            "m/44'/60'/1'/0/5".parse::<HdPath>().unwrap_test(),
+            HdPath::bip44(60, 1, 5).unwrap_test()
+        );
+    }
+
+    #[test]
+    fn bip44_path_out_of_range_fails() {
+        assert!(HdPath::bip44(0x8000_0000, 0, 0).is_err());
+        assert!(HdPath::bip44(118, 0x8000_0000, 0).is_err());
+        assert!(HdPath::bip44(118, 0, 0x8000_0000).is_err());
+        assert!(HdPath::bip44(118, 0x7fff_ffff, 0x7fff_ffff).is_ok());
+    }
+
+    #[test]
+    fn invalid_path_fails() {
+        assert!("44'/118'/0'/0/0".parse::<HdPath>().is_err());
+        assert!("m/44'/foo".parse::<HdPath>().is_err());
+    }
+}
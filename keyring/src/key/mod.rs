@@ -1 +1,2 @@
+pub mod hd_path;
 pub mod pair;
@@ -0,0 +1,115 @@
+use bip32::Mnemonic;
+use hex::{FromHex, ToHex};
+use k256::ecdsa::SigningKey;
+use k256::SecretKey;
+use sha3::{Digest, Keccak256};
+use std::error::Error;
+
+use crate::key::hd_path::HdPath;
+
+use super::secp256k1_key_pair::Secp256k1KeyPair;
+
+/// Signature with recovery id: `R || S || V`
+type RecoverableSignatureBytes = [u8; 65];
+
+/// An ethermint compatible secp256k1 key pair. Messages are hashed with keccak256 before signing.
+#[derive(Clone, Debug, PartialEq)]
+pub struct EthSecp256k1KeyPair(Secp256k1KeyPair);
+
+impl From<SecretKey> for EthSecp256k1KeyPair {
+    fn from(value: SecretKey) -> Self {
+        Self(value.into())
+    }
+}
+
+impl From<EthSecp256k1KeyPair> for SecretKey {
+    fn from(value: EthSecp256k1KeyPair) -> Self {
+        value.0.into()
+    }
+}
+
+impl EthSecp256k1KeyPair {
+    pub fn inner(&self) -> &SecretKey {
+        self.0.inner()
+    }
+
+    /// Returns PKCS8 PEM encoded private key.
+    pub fn to_pkcs8_pem(&self) -> k256::elliptic_curve::zeroize::Zeroizing<String> {
+        self.0.to_pkcs8_pem()
+    }
+
+    /// Returns PKCS8 PEM encoded private key encrypted with password.
+    pub fn to_pkcs8_encrypted_pem(
+        &self,
+        password: impl AsRef<[u8]>,
+    ) -> k256::elliptic_curve::zeroize::Zeroizing<String> {
+        self.0.to_pkcs8_encrypted_pem(password)
+    }
+
+    /// Returns a key pair from a mnemonic using default ethereum [HdPath].
+    pub fn from_mnemonic(mnemonic: &Mnemonic) -> Self {
+        Self::from_mnemonic_with_path(mnemonic, &HdPath::default_eth())
+    }
+
+    /// Returns a key pair from a mnemonic derived with `hd_path`.
+    pub fn from_mnemonic_with_path(mnemonic: &Mnemonic, hd_path: &HdPath) -> Self {
+        Self(Secp256k1KeyPair::from_mnemonic_with_path(mnemonic, hd_path))
+    }
+
+    /// Signs keccak256 hash of a message.
+    pub fn sign(&self, message: &[u8]) -> RecoverableSignatureBytes {
+        let signing_key: SigningKey = SigningKey::from(self.inner());
+        let (signature, recovery_id) = signing_key
+            .sign_prehash_recoverable(&Keccak256::digest(message))
+            .expect("hash has valid length so signing never fails");
+
+        let mut bytes = [0; 65];
+        bytes[..64].copy_from_slice(&signature.to_bytes());
+        bytes[64] = recovery_id.to_byte();
+
+        bytes
+    }
+}
+
+impl ToHex for &EthSecp256k1KeyPair {
+    fn encode_hex<T: std::iter::FromIterator<char>>(&self) -> T {
+        (&self.0).encode_hex()
+    }
+
+    fn encode_hex_upper<T: std::iter::FromIterator<char>>(&self) -> T {
+        (&self.0).encode_hex_upper()
+    }
+}
+
+impl FromHex for EthSecp256k1KeyPair {
+    type Error = Box<dyn Error>;
+
+    fn from_hex<T: AsRef<[u8]>>(hex: T) -> Result<Self, Self::Error> {
+        Ok(Self(Secp256k1KeyPair::from_hex(hex)?))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use extensions::testing::UnwrapTesting;
+
+    use super::*;
+
+    #[test]
+    fn default_path_differs_from_cosmos() {
+        let mnemonic = "race draft rival universe maid cheese steel logic crowd fork comic easy truth drift tomorrow eye buddy head time cash swing swift midnight borrow";
+        let mnemonic = Mnemonic::new(mnemonic, bip32::Language::English).unwrap_test();
+
+        assert_eq!(
+            EthSecp256k1KeyPair::from_mnemonic(&mnemonic),
+            EthSecp256k1KeyPair::from_mnemonic_with_path(
+                &mnemonic,
+                &HdPath::bip44(60, 0, 0).unwrap_test()
+            )
+        );
+        assert_ne!(
+            EthSecp256k1KeyPair::from_mnemonic(&mnemonic).inner(),
+            Secp256k1KeyPair::from_mnemonic(&mnemonic).inner()
+        );
+    }
+}
@@ -1,8 +1,15 @@
+pub mod eth_secp256k1_key_pair;
 pub mod secp256k1_key_pair;
+pub mod secp256r1_key_pair;
 
 use serde::{Deserialize, Serialize};
 
-use self::secp256k1_key_pair::Secp256k1KeyPair;
+use self::{
+    eth_secp256k1_key_pair::EthSecp256k1KeyPair, secp256k1_key_pair::Secp256k1KeyPair,
+    secp256r1_key_pair::Secp256r1KeyPair,
+};
+
+use super::hd_path::HdPath;
 
 /// A key pair.
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -11,6 +18,12 @@ pub enum KeyPair {
     #[serde(rename = "secp256k1")]
     #[serde(with = "hex::serde")]
     Secp256k1(Secp256k1KeyPair),
+    #[serde(rename = "eth_secp256k1")]
+    #[serde(with = "hex::serde")]
+    EthSecp256k1(EthSecp256k1KeyPair),
+    #[serde(rename = "secp256r1")]
+    #[serde(with = "hex::serde")]
+    Secp256r1(Secp256r1KeyPair),
 }
 
 impl KeyPair {
@@ -18,6 +31,8 @@ impl KeyPair {
     pub fn to_pkcs8_pem(&self) -> k256::elliptic_curve::zeroize::Zeroizing<String> {
         match self {
             KeyPair::Secp256k1(key) => key.to_pkcs8_pem(),
+            KeyPair::EthSecp256k1(key) => key.to_pkcs8_pem(),
+            KeyPair::Secp256r1(key) => key.to_pkcs8_pem(),
         }
     }
 
@@ -28,6 +43,8 @@ impl KeyPair {
     ) -> k256::elliptic_curve::zeroize::Zeroizing<String> {
         match self {
             KeyPair::Secp256k1(key) => key.to_pkcs8_encrypted_pem(password),
+            KeyPair::EthSecp256k1(key) => key.to_pkcs8_encrypted_pem(password),
+            KeyPair::Secp256r1(key) => key.to_pkcs8_encrypted_pem(password),
         }
     }
 
@@ -50,12 +67,29 @@ impl KeyPair {
     pub fn sign(&self, message: &[u8]) -> Vec<u8> {
         match self {
             KeyPair::Secp256k1(key) => key.sign(message).into(),
+            KeyPair::EthSecp256k1(key) => key.sign(message).into(),
+            KeyPair::Secp256r1(key) => key.sign(message).into(),
         }
     }
 
     pub fn from_mnemonic(mnemonic: &bip32::Mnemonic) -> Self {
         Self::Secp256k1(Secp256k1KeyPair::from_mnemonic(mnemonic))
     }
+
+    pub fn from_mnemonic_with_path(mnemonic: &bip32::Mnemonic, hd_path: &HdPath) -> Self {
+        Self::Secp256k1(Secp256k1KeyPair::from_mnemonic_with_path(mnemonic, hd_path))
+    }
+}
+
+/// Key pair stored in keyring with its metadata
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct KeyInfo {
+    #[serde(flatten)]
+    pub key_pair: KeyPair,
+    /// path used to derive key from mnemonic. Missing for keys which were
+    /// stored before path was recorded
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub hd_path: Option<HdPath>,
 }
 
 #[cfg(test)]
@@ -77,4 +111,28 @@ mod tests {
             r#"{"type":"secp256k1","secret_key":"f6fdd0e88e3988cc108690e28184508471f48eba283eeb61fce858f7b7a9642f"}"#
         );
     }
+
+    #[test]
+    fn test_key_info_serialization() {
+        let mnemonic = "race draft rival universe maid cheese steel logic crowd fork comic easy truth drift tomorrow eye buddy head time cash swing swift midnight borrow";
+        let mnemonic = Mnemonic::new(mnemonic, bip32::Language::English).unwrap_test();
+        let key_info = KeyInfo {
+            key_pair: KeyPair::from_mnemonic(&mnemonic),
+            hd_path: Some(HdPath::default()),
+        };
+
+        let serialized = serde_json::to_string(&key_info).unwrap_test();
+
+        assert_eq!(
+            serialized,
+            r#"{"type":"secp256k1","secret_key":"f6fdd0e88e3988cc108690e28184508471f48eba283eeb61fce858f7b7a9642f","hd_path":"m/44'/118'/0'/0/0"}"#
+        );
+
+        // keys stored without path are still readable
+        let legacy: KeyInfo = serde_json::from_str(
+            r#"{"type":"secp256k1","secret_key":"f6fdd0e88e3988cc108690e28184508471f48eba283eeb61fce858f7b7a9642f"}"#,
+        )
+        .unwrap_test();
+        assert_eq!(legacy.hd_path, None);
+    }
 }
@@ -1,4 +1,4 @@
-use bip32::{DerivationPath, Mnemonic, XPrv};
+use bip32::{Mnemonic, XPrv};
 use hex::{FromHex, ToHex};
 use k256::ecdsa::signature::Signer;
 use k256::ecdsa::SigningKey;
@@ -10,7 +10,8 @@ use pkcs8::{
     DecodePrivateKey, EncodePrivateKey, EncryptedPrivateKeyInfo, LineEnding, PrivateKeyInfo,
 };
 use std::error::Error;
-const HDPATH: &str = "m/44'/118'/0'/0/0";
+
+use crate::key::hd_path::HdPath;
 
 type SignatureBytes = [u8; 64];
 
@@ -55,31 +56,9 @@ impl Secp256k1KeyPair {
         //     .to_pkcs8_encrypted_pem(&mut OsRng, password, LineEnding::default())
         //     .expect("this can't fail")
 
-        let mut rng = OsRng;
-
-        let mut salt = [0u8; 16];
-        rng.fill_bytes(&mut salt);
-
-        let mut iv = [0u8; 16];
-        rng.fill_bytes(&mut iv);
-
-        // TODO: remove unwraps
-
-        // 14 = log_2(16384), 32 bytes = 256 bits
-        let scrypt_params = scrypt::Params::new(14, 8, 1, 32).expect("Default params is valid");
-        let pbes2_params = pbes2::Parameters::scrypt_aes256cbc(scrypt_params, &salt, &iv)
-            .expect("Default params is valid: r & p < u16::MAX");
-
         let plain_text_der = self.0.to_pkcs8_der().unwrap();
-        let private_key_info = PrivateKeyInfo::try_from(plain_text_der.as_bytes()).unwrap();
 
-        let secret_doc = private_key_info
-            .encrypt_with_params(pbes2_params, password.as_ref())
-            .unwrap();
-
-        secret_doc
-            .to_pem(EncryptedPrivateKeyInfo::PEM_LABEL, LineEnding::LF)
-            .unwrap()
+        encrypt_pkcs8_der(plain_text_der.as_bytes(), password.as_ref())
     }
 
     /// Returns a key pair from a PKCS8 PEM encoded private key.
@@ -95,11 +74,15 @@ impl Secp256k1KeyPair {
         Ok(Self(SecretKey::from_pkcs8_encrypted_pem(s, password)?))
     }
 
-    /// Returns a key pair from a mnemonic.
+    /// Returns a key pair from a mnemonic using default [HdPath].
     pub fn from_mnemonic(mnemonic: &Mnemonic) -> Self {
+        Self::from_mnemonic_with_path(mnemonic, &HdPath::default())
+    }
+
+    /// Returns a key pair from a mnemonic derived with `hd_path`.
+    pub fn from_mnemonic_with_path(mnemonic: &Mnemonic, hd_path: &HdPath) -> Self {
         let seed = mnemonic.to_seed("");
-        let child_path: DerivationPath = HDPATH.parse().expect("hard coded path will never fail");
-        let child_xprv = XPrv::derive_from_path(&seed, &child_path)
+        let child_xprv = XPrv::derive_from_path(&seed, hd_path.inner())
             .expect("seed has length 64 so this will never return an error");
         let signing_key = child_xprv.private_key();
 
@@ -115,6 +98,37 @@ impl Secp256k1KeyPair {
     }
 }
 
+/// Encrypts PKCS8 DER encoded private key with password using the same scrypt params as openssl.
+pub(super) fn encrypt_pkcs8_der(
+    plain_text_der: &[u8],
+    password: &[u8],
+) -> k256::elliptic_curve::zeroize::Zeroizing<String> {
+    let mut rng = OsRng;
+
+    let mut salt = [0u8; 16];
+    rng.fill_bytes(&mut salt);
+
+    let mut iv = [0u8; 16];
+    rng.fill_bytes(&mut iv);
+
+    // TODO: remove unwraps
+
+    // 14 = log_2(16384), 32 bytes = 256 bits
+    let scrypt_params = scrypt::Params::new(14, 8, 1, 32).expect("Default params is valid");
+    let pbes2_params = pbes2::Parameters::scrypt_aes256cbc(scrypt_params, &salt, &iv)
+        .expect("Default params is valid: r & p < u16::MAX");
+
+    let private_key_info = PrivateKeyInfo::try_from(plain_text_der).unwrap();
+
+    let secret_doc = private_key_info
+        .encrypt_with_params(pbes2_params, password)
+        .unwrap();
+
+    secret_doc
+        .to_pem(EncryptedPrivateKeyInfo::PEM_LABEL, LineEnding::LF)
+        .unwrap()
+}
+
 impl ToHex for &Secp256k1KeyPair {
     fn encode_hex<T: std::iter::FromIterator<char>>(&self) -> T {
         self.0.to_bytes().encode_hex()
@@ -181,6 +195,24 @@ mod tests {
         assert_eq!(key_pair, key_pair_from_pem);
     }
 
+    #[test]
+    fn from_mnemonic_with_path_works() {
+        let mnemonic = "race draft rival universe maid cheese steel logic crowd fork comic easy truth drift tomorrow eye buddy head time cash swing swift midnight borrow";
+        let mnemonic = Mnemonic::new(mnemonic, bip32::Language::English).unwrap_test();
+
+        assert_eq!(
+            Secp256k1KeyPair::from_mnemonic_with_path(&mnemonic, &HdPath::default()),
+            Secp256k1KeyPair::from_mnemonic(&mnemonic)
+        );
+        assert_ne!(
+            Secp256k1KeyPair::from_mnemonic_with_path(
+                &mnemonic,
+                &HdPath::bip44(118, 0, 1).unwrap_test()
+            ),
+            Secp256k1KeyPair::from_mnemonic(&mnemonic)
+        );
+    }
+
     #[test]
     fn sandpit() {
         let mnemonic = "race draft rival universe maid cheese steel logic crowd fork comic easy truth drift tomorrow eye buddy head time cash swing swift midnight borrow";
@@ -0,0 +1,192 @@
+use bip32::Mnemonic;
+use hex::{FromHex, ToHex};
+use hmac::{Hmac, Mac};
+use p256::ecdsa::{signature::Signer, SigningKey};
+use p256::elliptic_curve::sec1::ToEncodedPoint;
+use p256::{NonZeroScalar, SecretKey};
+use pkcs8::{EncodePrivateKey, LineEnding};
+use sha2::Sha512;
+use std::error::Error;
+
+use crate::key::hd_path::HdPath;
+
+use super::secp256k1_key_pair::encrypt_pkcs8_der;
+
+type SignatureBytes = [u8; 64];
+
+type HmacSha512 = Hmac<Sha512>;
+
+/// Key of master HMAC in [SLIP-0010](https://github.com/satoshilabs/slips/blob/master/slip-0010.md)
+const SLIP10_SEED_KEY: &[u8] = b"Nist256p1 seed";
+
+/// A secp256r1 (NIST P-256) key pair.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Secp256r1KeyPair(SecretKey);
+
+impl From<SecretKey> for Secp256r1KeyPair {
+    fn from(value: SecretKey) -> Self {
+        Self(value)
+    }
+}
+
+impl From<Secp256r1KeyPair> for SecretKey {
+    fn from(value: Secp256r1KeyPair) -> Self {
+        value.0
+    }
+}
+
+impl Secp256r1KeyPair {
+    pub fn inner(&self) -> &SecretKey {
+        &self.0
+    }
+
+    /// Returns PKCS8 PEM encoded private key.
+    pub fn to_pkcs8_pem(&self) -> k256::elliptic_curve::zeroize::Zeroizing<String> {
+        self.0
+            .to_pkcs8_pem(LineEnding::default())
+            .expect("this can't fail")
+    }
+
+    /// Returns PKCS8 PEM encoded private key encrypted with password.
+    pub fn to_pkcs8_encrypted_pem(
+        &self,
+        password: impl AsRef<[u8]>,
+    ) -> k256::elliptic_curve::zeroize::Zeroizing<String> {
+        let plain_text_der = self.0.to_pkcs8_der().expect("this can't fail");
+
+        encrypt_pkcs8_der(plain_text_der.as_bytes(), password.as_ref())
+    }
+
+    /// Returns a key pair from a mnemonic using default [HdPath].
+    pub fn from_mnemonic(mnemonic: &Mnemonic) -> Self {
+        Self::from_mnemonic_with_path(mnemonic, &HdPath::default())
+    }
+
+    /// Returns a key pair from a mnemonic derived with `hd_path` as described
+    /// in [SLIP-0010](https://github.com/satoshilabs/slips/blob/master/slip-0010.md).
+    pub fn from_mnemonic_with_path(mnemonic: &Mnemonic, hd_path: &HdPath) -> Self {
+        Self::from_seed_with_path(mnemonic.to_seed("").as_bytes(), hd_path)
+    }
+
+    fn from_seed_with_path(seed: &[u8], hd_path: &HdPath) -> Self {
+        let mut i = hmac_sha512(SLIP10_SEED_KEY, &[seed]);
+        let (mut key, mut chain_code) = loop {
+            match SecretKey::from_slice(&i[..32]) {
+                Ok(key) => break (key, i[32..].to_vec()),
+                Err(_) => i = hmac_sha512(SLIP10_SEED_KEY, &[&i]),
+            }
+        };
+
+        for child in hd_path.inner().iter() {
+            let index = u32::from(child).to_be_bytes();
+            let mut data = if child.is_hardened() {
+                [&[0][..], key.to_bytes().as_slice()].concat()
+            } else {
+                key.public_key().to_encoded_point(true).as_bytes().to_vec()
+            };
+
+            loop {
+                let i = hmac_sha512(&chain_code, &[&data, &index]);
+
+                let child_key = SecretKey::from_slice(&i[..32]).ok().and_then(|il| {
+                    Option::from(NonZeroScalar::new(
+                        *il.to_nonzero_scalar() + *key.to_nonzero_scalar(),
+                    ))
+                });
+
+                match child_key {
+                    Some(child_key) => {
+                        key = SecretKey::from(child_key);
+                        chain_code = i[32..].to_vec();
+                        break;
+                    }
+                    // invalid key, next iteration uses `0x01 || IR` as data
+                    None => data = [&[1][..], &i[32..]].concat(),
+                }
+            }
+        }
+
+        Self(key)
+    }
+
+    /// Signs sha256 hash of a message. Signature is normalized to low `S` form.
+    pub fn sign(&self, message: &[u8]) -> SignatureBytes {
+        let signing_key: SigningKey = SigningKey::from(&self.0);
+        let signature: p256::ecdsa::Signature = signing_key.sign(message);
+        let signature = signature.normalize_s().unwrap_or(signature);
+
+        signature.to_bytes().into()
+    }
+}
+
+fn hmac_sha512(key: &[u8], data: &[&[u8]]) -> [u8; 64] {
+    let mut mac = HmacSha512::new_from_slice(key).expect("hmac accepts key of any size");
+    for data in data {
+        mac.update(data);
+    }
+
+    mac.finalize().into_bytes().into()
+}
+
+impl ToHex for &Secp256r1KeyPair {
+    fn encode_hex<T: std::iter::FromIterator<char>>(&self) -> T {
+        self.0.to_bytes().encode_hex()
+    }
+
+    fn encode_hex_upper<T: std::iter::FromIterator<char>>(&self) -> T {
+        self.0.to_bytes().encode_hex_upper()
+    }
+}
+
+impl FromHex for Secp256r1KeyPair {
+    type Error = Box<dyn Error>;
+
+    fn from_hex<T: AsRef<[u8]>>(hex: T) -> Result<Self, Self::Error> {
+        let secret_key = SecretKey::from_slice(&hex::decode(hex.as_ref())?)?;
+        Ok(Secp256r1KeyPair(secret_key))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use extensions::testing::UnwrapTesting;
+
+    use super::*;
+
+    #[test]
+    fn slip10_test_vector() {
+        // test vector 1 for nist256p1 from SLIP-0010
+        let seed = hex::decode("000102030405060708090a0b0c0d0e0f").unwrap_test();
+
+        let master = Secp256r1KeyPair::from_seed_with_path(&seed, &"m".parse().unwrap_test());
+        assert_eq!(
+            (&master).encode_hex::<String>(),
+            "612091aaa12e22dd2abef664f8a01a82cae99ad7441b7ef8110424915c268bc2"
+        );
+
+        let hardened = Secp256r1KeyPair::from_seed_with_path(&seed, &"m/0'".parse().unwrap_test());
+        assert_eq!(
+            (&hardened).encode_hex::<String>(),
+            "6939694369114c67917a182c59ddb8cafc3004e63ca5d3b84403ba8613debc0c"
+        );
+
+        let normal = Secp256r1KeyPair::from_seed_with_path(&seed, &"m/0'/1".parse().unwrap_test());
+        assert_eq!(
+            (&normal).encode_hex::<String>(),
+            "284e9d38d07d21e4e281b645089a94f4cf5a5a81369acf151a1c3a57f18b2129"
+        );
+    }
+
+    #[test]
+    fn signature_is_low_s() {
+        let mnemonic = "race draft rival universe maid cheese steel logic crowd fork comic easy truth drift tomorrow eye buddy head time cash swing swift midnight borrow";
+        let mnemonic = Mnemonic::new(mnemonic, bip32::Language::English).unwrap_test();
+        let key_pair = Secp256r1KeyPair::from_mnemonic(&mnemonic);
+
+        for message in [&b"first"[..], b"second", b"third", b"fourth"] {
+            let signature =
+                p256::ecdsa::Signature::from_slice(&key_pair.sign(message)).unwrap_test();
+            assert!(signature.normalize_s().is_none());
+        }
+    }
+}
@@ -6,7 +6,7 @@ use std::{
 
 use std::fs::File;
 
-use crate::{error::Error, key::pair::KeyPair};
+use crate::{error::Error, key::pair::KeyInfo};
 
 use argon2::{
     password_hash::{rand_core::OsRng, PasswordHash, PasswordHasher, PasswordVerifier, SaltString},
@@ -193,7 +193,7 @@ pub fn get_key_by_name<S>(
     name: &S,
     path: impl AsRef<Path>,
     backend: Backend,
-) -> Result<KeyPair, Error>
+) -> Result<KeyInfo, Error>
 where
     S: AsRef<str> + ?Sized,
 {
@@ -249,9 +249,9 @@ where
 
 /// Returns an [`Error`] if an entry with the same name already exists. If an entry already exists for
 /// the given key but with a different name then a new separate entry will be created.
-pub fn set_key_pair<S: AsRef<str>>(
+pub fn set_key_info<S: AsRef<str>>(
     key_name: S,
-    key_pair: &KeyPair,
+    key_info: &KeyInfo,
     path: impl AsRef<Path>,
     backend: Backend,
 ) -> Result<(), Error> {
@@ -279,10 +279,10 @@ pub fn set_key_pair<S: AsRef<str>>(
             }
         })?;
 
-    let serialized_key_pair = serde_json::to_string(&key_pair).expect("serialization won't fail");
+    let serialized_key_info = serde_json::to_string(&key_info).expect("serialization won't fail");
     let key = match password {
-        Some(password) => encrypt_key_string(&mut OsRng, serialized_key_pair, password).0,
-        None => serde_json::to_string_pretty(&key_pair).expect("key pair will always serialize"),
+        Some(password) => encrypt_key_string(&mut OsRng, serialized_key_info, password).0,
+        None => serde_json::to_string_pretty(&key_info).expect("key info will always serialize"),
     };
 
     file.write_all(key.as_bytes()).map_err(|e| Error::FileIO {
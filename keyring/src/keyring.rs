@@ -2,7 +2,13 @@ use std::path::Path;
 
 use crate::{
     error::Error,
-    key::pair::{secp256k1_key_pair::Secp256k1KeyPair, KeyPair},
+    key::{
+        hd_path::{HdPath, DEFAULT_COIN_TYPE, ETH_COIN_TYPE},
+        pair::{
+            eth_secp256k1_key_pair::EthSecp256k1KeyPair, secp256k1_key_pair::Secp256k1KeyPair,
+            secp256r1_key_pair::Secp256r1KeyPair, KeyInfo, KeyPair,
+        },
+    },
     key_store::file_store,
 };
 use bip32::Mnemonic;
@@ -11,9 +17,28 @@ use k256::elliptic_curve::rand_core;
 use rand_core::OsRng;
 
 /// Used to specify the type of key to generate.
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum KeyType {
     Secp256k1,
+    /// ethermint compatible secp256k1 key
+    EthSecp256k1,
+    /// NIST P-256 key derived as described in SLIP-0010
+    Secp256r1,
+}
+
+impl KeyType {
+    /// Coin type used in default derivation path of the key type
+    pub fn coin_type(&self) -> u32 {
+        match self {
+            KeyType::Secp256k1 | KeyType::Secp256r1 => DEFAULT_COIN_TYPE,
+            KeyType::EthSecp256k1 => ETH_COIN_TYPE,
+        }
+    }
+
+    /// Default derivation path of the key type
+    pub fn default_hd_path(&self) -> HdPath {
+        HdPath::bip44(self.coin_type(), 0, 0).expect("coin types of key types are valid")
+    }
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -29,23 +54,57 @@ pub fn add_key<S>(
     key_type: KeyType,
     backend: Backend<'_>,
 ) -> Result<KeyPair, Error>
+where
+    S: AsRef<str>,
+{
+    add_key_with_path(
+        name,
+        mnemonic,
+        &key_type.default_hd_path(),
+        key_type,
+        backend,
+    )
+}
+
+/// Generates a key pair from the mnemonic provided using `hd_path` and stores the keypair
+/// along with the path.
+pub fn add_key_with_path<S>(
+    name: S,
+    mnemonic: &Mnemonic,
+    hd_path: &HdPath,
+    key_type: KeyType,
+    backend: Backend<'_>,
+) -> Result<KeyPair, Error>
 where
     S: AsRef<str>,
 {
     let key_pair = match key_type {
-        KeyType::Secp256k1 => KeyPair::Secp256k1(Secp256k1KeyPair::from_mnemonic(mnemonic)),
+        KeyType::Secp256k1 => {
+            KeyPair::Secp256k1(Secp256k1KeyPair::from_mnemonic_with_path(mnemonic, hd_path))
+        }
+        KeyType::EthSecp256k1 => KeyPair::EthSecp256k1(
+            EthSecp256k1KeyPair::from_mnemonic_with_path(mnemonic, hd_path),
+        ),
+        KeyType::Secp256r1 => {
+            KeyPair::Secp256r1(Secp256r1KeyPair::from_mnemonic_with_path(mnemonic, hd_path))
+        }
+    };
+
+    let key_info = KeyInfo {
+        key_pair,
+        hd_path: Some(hd_path.clone()),
     };
 
     match backend {
         Backend::File(path) => {
-            file_store::set_key_pair(name, &key_pair, path, file_store::Backend::Encrypted)?;
+            file_store::set_key_info(name, &key_info, path, file_store::Backend::Encrypted)?;
         }
         Backend::Test(path) => {
-            file_store::set_key_pair(name, &key_pair, path, file_store::Backend::Test)?;
+            file_store::set_key_info(name, &key_info, path, file_store::Backend::Test)?;
         }
     };
 
-    Ok(key_pair)
+    Ok(key_info.key_pair)
 }
 
 /// Generates a new random mnemonic and key pair, stores the new key pair and
@@ -55,16 +114,39 @@ pub fn create_key<S>(
     key_type: KeyType,
     backend: Backend<'_>,
 ) -> Result<(Mnemonic, KeyPair), Error>
+where
+    S: AsRef<str>,
+{
+    create_key_with_path(name, &key_type.default_hd_path(), key_type, backend)
+}
+
+/// Generates a new random mnemonic and key pair derived with `hd_path`, stores the new
+/// key pair along with the path and returns the generated mnemonic.
+pub fn create_key_with_path<S>(
+    name: S,
+    hd_path: &HdPath,
+    key_type: KeyType,
+    backend: Backend<'_>,
+) -> Result<(Mnemonic, KeyPair), Error>
 where
     S: AsRef<str>,
 {
     let mnemonic = Mnemonic::random(OsRng, bip32::Language::English);
-    let key_pair = add_key(name, &mnemonic, key_type, backend)?;
+    let key_pair = add_key_with_path(name, &mnemonic, hd_path, key_type, backend)?;
     Ok((mnemonic, key_pair))
 }
 
 /// Get a key by name.
 pub fn key_by_name<S>(name: &S, backend: Backend<'_>) -> Result<KeyPair, Error>
+where
+    S: AsRef<str> + ?Sized,
+{
+    Ok(key_info_by_name(name, backend)?.key_pair)
+    //TODO: return key wrapped in Secret
+}
+
+/// Get a key with its metadata by name.
+pub fn key_info_by_name<S>(name: &S, backend: Backend<'_>) -> Result<KeyInfo, Error>
 where
     S: AsRef<str> + ?Sized,
 {
@@ -74,7 +156,6 @@ where
         }
         Backend::Test(path) => file_store::get_key_by_name(name, path, file_store::Backend::Test),
     }
-    //TODO: return key wrapped in Secret
 }
 
 /// Delete a key by name.
@@ -139,6 +220,21 @@ mod tests {
         // get key should succeed
         key_by_name("bob", Backend::Test(&path)).expect("key should be retrieved");
 
+        // add key with custom path should store the path
+        let hd_path = HdPath::bip44(60, 1, 2).unwrap_test();
+        let key_pair = add_key_with_path(
+            "alice",
+            &mnemonic,
+            &hd_path,
+            KeyType::Secp256k1,
+            Backend::Test(&path),
+        )
+        .expect("key should be added");
+        let key_info =
+            key_info_by_name("alice", Backend::Test(&path)).expect("key should be retrieved");
+        assert_eq!(key_info.hd_path, Some(hd_path));
+        assert_eq!(key_info.key_pair.to_pkcs8_pem(), key_pair.to_pkcs8_pem());
+
         std::fs::remove_dir_all(path.clone()).expect("tmp directory should be deleted");
 
         // get should fail
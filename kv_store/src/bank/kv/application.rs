@@ -10,7 +10,7 @@ use std::{
 
 use database::Database;
 use extensions::corruption::UnwrapCorrupt;
-use trees::iavl::Tree;
+use trees::iavl::{CacheStats, Tree};
 
 use crate::{
     cache::KVCache,
@@ -32,9 +32,11 @@ use super::transaction::TransactionKVBank;
 ///
 /// *Note*: ordering of insertion doesn't impact state hash as it does with plain tree.
 /// This is due all insertion and deletion is sorted in lexicographical order and executed during [Self::commit]
+///
+/// Transient store has no tree: its state lives only in cache and is dropped on [Self::commit].
 #[derive(Debug)]
 pub struct ApplicationKVBank<DB> {
-    pub(crate) persistent: Arc<RwLock<Tree<DB>>>,
+    pub(crate) persistent: Option<Arc<RwLock<Tree<DB>>>>,
     pub(crate) cache: KVCache,
 }
 
@@ -46,22 +48,32 @@ impl<DB: Database> ApplicationKVBank<DB> {
         name: Option<String>,
     ) -> Result<Self, KVStoreError> {
         Ok(Self {
-            persistent: Arc::new(RwLock::new(Tree::new(
+            persistent: Some(Arc::new(RwLock::new(Tree::new(
                 db,
                 target_version,
                 TREE_CACHE_SIZE
                     .try_into()
                     .expect("Unreachable. Tree cache size is > 0"),
                 name,
-            )?)),
+            )?))),
             cache: Default::default(),
         })
     }
 
-    /// Read persistent database
+    /// Create new transient `self` which keeps state in memory until [Self::commit]
+    pub fn transient() -> Self {
+        Self {
+            persistent: None,
+            cache: Default::default(),
+        }
+    }
+
+    /// Read persistent database. Transient store has none
     #[inline]
-    pub fn persistent(&self) -> std::sync::RwLockReadGuard<'_, Tree<DB>> {
-        self.persistent.read().expect(POISONED_LOCK)
+    pub fn persistent(&self) -> Option<std::sync::RwLockReadGuard<'_, Tree<DB>>> {
+        self.persistent
+            .as_ref()
+            .map(|tree| tree.read().expect(POISONED_LOCK))
     }
 
     /// Clear uncommitted cache
@@ -75,7 +87,7 @@ impl<DB: Database> ApplicationKVBank<DB> {
     #[inline]
     pub fn to_tx_kind(&self) -> TransactionKVBank<DB> {
         TransactionKVBank {
-            persistent: Arc::clone(&self.persistent),
+            persistent: self.persistent.clone(),
             tx: Default::default(),
             block: self.cache.clone(),
         }
@@ -84,7 +96,7 @@ impl<DB: Database> ApplicationKVBank<DB> {
     /// Delete key from storage
     #[inline]
     pub fn delete(&mut self, k: &[u8]) -> Option<Vec<u8>> {
-        self.cache.delete(k).or(self.persistent().get(k))
+        self.cache.delete(k).or_else(|| self.persistent()?.get(k))
     }
 
     /// Set or append new key to storage
@@ -105,7 +117,7 @@ impl<DB: Database> ApplicationKVBank<DB> {
             .get(k.as_ref())
             .ok()?
             .cloned()
-            .or(self.persistent().get(k.as_ref()))
+            .or_else(|| self.persistent()?.get(k.as_ref()))
     }
 
     /// Return store which uses prefix for all store methods
@@ -138,9 +150,10 @@ impl<DB: Database> ApplicationKVBank<DB> {
             .range(range.clone())
             .map(|(first, second)| (Cow::Borrowed(first), Cow::Borrowed(second)));
 
-        let tree = self.persistent.read().expect(POISONED_LOCK);
+        let tree = self.persistent();
         let persisted_values = tree
-            .range(range)
+            .iter()
+            .flat_map(|tree| tree.range(range.clone()))
             // NOTE: Keys filtered only for persisted 'cause cache structure should remove inserted values on delete, but if this change then it's a place for a bug
             .filter(|(key, _)| !self.cache.delete.contains(&**key))
             .map(|(first, second)| (Cow::Owned(first), Cow::Owned(second)));
@@ -162,15 +175,47 @@ impl<DB: Database> ApplicationKVBank<DB> {
         }
     }
 
-    /// Commit changes from cache to tree and return state hash
+    /// Commit changes from cache to tree and return state hash.
+    /// Transient store drops its changes and returns `None`.
     ///
     /// # Panics
     /// Currently this method could panic if fails to persist changes to disk.
     /// This is matter of changes and should be discussed.
-    pub fn commit(&mut self) -> [u8; 32] {
+    pub fn commit(&mut self) -> Option<[u8; 32]> {
+        let Some(persistent) = self.flush_cache() else {
+            self.cache_clear();
+            return None;
+        };
+
+        let mut persistent = persistent.write().expect(POISONED_LOCK);
+
+        //TODO: is it safe to assume this won't ever error?
+        Some(persistent.save_version().unwrap_or_corrupt().0)
+    }
+
+    /// Write changes from cache to tree without saving new version and return hash of
+    /// the working tree. Following [Self::commit] persists the same state under this hash.
+    /// Transient store has no hash.
+    pub fn working_hash(&mut self) -> Option<[u8; 32]> {
+        let persistent = self.flush_cache()?;
+
+        let hash = persistent.read().expect(POISONED_LOCK).root_hash();
+        Some(hash)
+    }
+
+    /// Return node cache statistics of underlying tree
+    pub fn cache_stats(&self) -> CacheStats {
+        self.persistent()
+            .map(|tree| tree.cache_stats())
+            .unwrap_or_default()
+    }
+
+    /// Move cache to tree and return the tree. Transient store keeps its cache.
+    fn flush_cache(&mut self) -> Option<&Arc<RwLock<Tree<DB>>>> {
+        let tree = self.persistent.as_ref()?;
         let (insert, delete) = self.cache.take();
 
-        let mut persistent = self.persistent.write().expect(POISONED_LOCK);
+        let mut persistent = tree.write().expect(POISONED_LOCK);
 
         let cache = insert
             .into_iter()
@@ -187,8 +232,7 @@ impl<DB: Database> ApplicationKVBank<DB> {
             }
         }
 
-        //TODO: is it safe to assume this won't ever error?
-        persistent.save_version().unwrap_or_corrupt().0
+        Some(tree)
     }
 }
 
@@ -223,7 +267,33 @@ mod tests {
             175, 221, 165, 53, 187, 80, 14, 185, 198, 52, 197, 207, 47,
         ];
 
-        assert_eq!(resulted_cache, expected_hash)
+        assert_eq!(resulted_cache, Some(expected_hash))
+    }
+
+    #[test]
+    fn working_hash_equals_commit_hash() {
+        let mut store = app_store_build([(1, 11)], [(2, 22), (3, 33)], [4, 5]);
+
+        store.set([20], [10]);
+        let _ = store.delete(&[2]);
+
+        let working_hash = store.working_hash();
+        let commit_hash = store.commit();
+
+        assert_eq!(working_hash, commit_hash)
+    }
+
+    #[test]
+    fn transient_keeps_state_until_commit() {
+        let mut store = ApplicationKVBank::<MemDB>::transient();
+
+        store.set([1], [11]);
+
+        assert_eq!(None, store.working_hash());
+        assert_eq!(Some(vec![11]), store.get(&[1]));
+        assert_eq!(None, store.commit());
+        assert_eq!(None, store.get(&[1]));
+        assert_eq!(0, store.range(..).count());
     }
 
     #[test]
@@ -539,7 +609,7 @@ mod tests {
 
     fn build_store(tree: Tree<MemDB>, cache: Option<KVCache>) -> ApplicationKVBank<MemDB> {
         ApplicationKVBank {
-            persistent: Arc::new(RwLock::new(tree)),
+            persistent: Some(Arc::new(RwLock::new(tree))),
             cache: cache.unwrap_or_default(),
         }
     }
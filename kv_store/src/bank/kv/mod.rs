@@ -34,7 +34,7 @@ mod test_utils {
         }
 
         ApplicationKVBank {
-            persistent: Arc::new(RwLock::new(tree)),
+            persistent: Some(Arc::new(RwLock::new(tree))),
             cache: {
                 let mut cache = KVCache::default();
                 for (key, value) in cache_set {
@@ -72,7 +72,7 @@ mod test_utils {
         }
 
         TransactionKVBank {
-            persistent: Arc::new(RwLock::new(tree)),
+            persistent: Some(Arc::new(RwLock::new(tree))),
             tx: {
                 let mut cache = KVCache::default();
                 for (key, value) in tx_set {
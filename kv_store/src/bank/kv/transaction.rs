@@ -30,7 +30,8 @@ use super::application::ApplicationKVBank;
 /// successful transactions.
 #[derive(Debug)]
 pub struct TransactionKVBank<DB> {
-    pub(crate) persistent: Arc<RwLock<Tree<DB>>>,
+    /// Tree of application store, transient store has none
+    pub(crate) persistent: Option<Arc<RwLock<Tree<DB>>>>,
     pub(crate) tx: KVCache,
     pub(crate) block: KVCache,
 }
@@ -38,8 +39,10 @@ pub struct TransactionKVBank<DB> {
 impl<DB: Database> TransactionKVBank<DB> {
     /// Read persistent database
     #[inline]
-    fn persistent(&self) -> std::sync::RwLockReadGuard<'_, Tree<DB>> {
-        self.persistent.read().expect(POISONED_LOCK)
+    fn persistent(&self) -> Option<std::sync::RwLockReadGuard<'_, Tree<DB>>> {
+        self.persistent
+            .as_ref()
+            .map(|tree| tree.read().expect(POISONED_LOCK))
     }
 
     /// Clear uncommitted cache for tx
@@ -73,13 +76,23 @@ impl<DB: Database> TransactionKVBank<DB> {
         self.block.take()
     }
 
+    /// Return store with same tree and copied cache of both layers.
+    /// Changes of the copy don't affect `self`
+    pub fn snapshot(&self) -> Self {
+        Self {
+            persistent: self.persistent.clone(),
+            tx: self.tx.clone(),
+            block: self.block.clone(),
+        }
+    }
+
     /// Delete value from storage
     #[inline]
     pub fn delete(&mut self, k: &[u8]) -> Option<Vec<u8>> {
         self.tx
             .delete(k)
             .or_else(|| self.block.storage.get(k).cloned())
-            .or_else(|| self.persistent().get(k))
+            .or_else(|| self.persistent()?.get(k))
     }
 
     /// Set or append value
@@ -119,7 +132,7 @@ impl<DB: Database> TransactionKVBank<DB> {
                 .get(k.as_ref())
                 .ok()?
                 .cloned()
-                .or_else(|| self.persistent().get(k.as_ref())),
+                .or_else(|| self.persistent()?.get(k.as_ref())),
         }
     }
 
@@ -157,7 +170,8 @@ impl<DB: Database> TransactionKVBank<DB> {
 
         let tree = self.persistent();
         let persisted_values = tree
-            .range(range)
+            .iter()
+            .flat_map(|tree| tree.range(range.clone()))
             .filter(|(key, _)| {
                 !(self.tx.delete.contains(&**key)
                     || (self.block.delete.contains(&**key)
@@ -872,6 +886,25 @@ mod tests {
         assert_eq!(expected_range, result_range);
     }
 
+    /// # What
+    /// Test checks that snapshot sees cache of both layers and its changes don't leak back
+    #[test]
+    fn snapshot_is_independent() {
+        let store = tx_store_build([(0, 0)], [(1, 11)], [(2, 22)], [], []);
+
+        let mut snapshot = store.snapshot();
+        assert_eq!(snapshot.get(&[0]), Some(vec![0]));
+        assert_eq!(snapshot.get(&[1]), Some(vec![11]));
+        assert_eq!(snapshot.get(&[2]), Some(vec![22]));
+
+        snapshot.set([3], [33]);
+        snapshot.delete(&[1]);
+        snapshot.upgrade_cache();
+
+        assert_eq!(store.get(&[1]), Some(vec![11]));
+        assert_eq!(store.get(&[3]), None);
+    }
+
     fn build_tree(values: impl IntoIterator<Item = (u8, u8)>) -> Tree<MemDB> {
         let mut tree = Tree::new(
             MemDB::new(),
@@ -892,7 +925,7 @@ mod tests {
 
     fn build_store(tree: Tree<MemDB>, cache: Option<KVCache>) -> TransactionKVBank<MemDB> {
         TransactionKVBank {
-            persistent: Arc::new(RwLock::new(tree)),
+            persistent: Some(Arc::new(RwLock::new(tree))),
             tx: cache.unwrap_or_default(),
             block: Default::default(),
         }
@@ -1,19 +1,32 @@
 //! Application kind of multi store
 
-use std::{collections::HashMap, sync::Arc};
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+};
 
-use database::{prefix::PrefixDB, Database};
+use database::{prefix::PrefixDB, BatchOp, Database};
+use strum::IntoEnumIterator;
+use trees::iavl::CacheStats;
 
 use crate::{
-    bank::kv::application::ApplicationKVBank, build_prefixed_stores, error::MultiStoreError,
-    hash::StoreInfo, StoreKey,
+    bank::kv::application::ApplicationKVBank,
+    build_prefixed_stores,
+    cache::KVCache,
+    commit::{self, CommitInfo, StoreInfo},
+    error::{KVStoreError, MultiStoreError, RollbackError, KEY_EXISTS_MSG, POISONED_LOCK},
+    upgrade::{StoreRename, StoreUpgradeError, StoreUpgrades},
+    StoreKey,
 };
 
 use super::*;
 
-/// Backend for application multi store
+/// Backend for application multi store. Keeps database of stores to persist commit info
 #[derive(Debug)]
-pub struct ApplicationStore<DB, SK>(pub(crate) HashMap<SK, ApplicationKVBank<PrefixDB<DB>>>);
+pub struct ApplicationStore<DB, SK>(
+    pub(crate) HashMap<SK, ApplicationKVBank<PrefixDB<DB>>>,
+    pub(crate) Arc<DB>,
+);
 
 impl<SK, DB> MultiBankBackend<DB, SK> for ApplicationStore<DB, SK> {
     type Bank = ApplicationKVBank<PrefixDB<DB>>;
@@ -32,35 +45,159 @@ impl<DB: Database, SK: StoreKey> MultiBank<DB, SK, ApplicationStore<DB, SK>> {
     /// Method create a prefixed db for each store
     /// and makes sure that no overlap exists
     pub fn new(db: Arc<DB>) -> Result<Self, MultiStoreError<SK>> {
-        let mut store_infos = Vec::new();
-        let mut head_version = 0;
+        let (stores, version) = load_stores::<_, SK>(Arc::clone(&db))?;
 
-        let map = build_prefixed_stores::<_, SK>(db);
-        let mut stores = HashMap::with_capacity(map.len());
-        for (store_key, store) in map {
-            let kv_store = ApplicationKVBank::new(store, None, Some(store_key.name().to_owned()))
-                .map_err(|err| MultiStoreError {
-                sk: store_key.clone(),
-                err,
-            })?;
+        Ok(Self::from_stores(db, stores, version))
+    }
 
-            let store_info = StoreInfo {
-                name: store_key.name().into(),
-                hash: kv_store.persistent().root_hash(),
-            };
+    /// Rewind state by one version and return `self` loaded at previous version.
+    /// Used to recover from app hash mismatch of the last committed block.
+    pub fn rollback(db: Arc<DB>) -> Result<Self, RollbackError<SK>> {
+        let (_, version) = load_stores::<_, SK>(Arc::clone(&db))?;
+        let target = version.checked_sub(1).ok_or(RollbackError::NoVersion)?;
+
+        // Stores drop tree versions after the latest committed version on load
+        commit::set_latest_version(&*db, target);
+        commit::delete_commit_info(&*db, version);
+
+        Ok(Self::new(db)?)
+    }
+
+    /// Return new `self` and apply `upgrades` if the next block is `upgrade_height`.
+    /// At any other height upgrades are ignored, so they are applied only once.
+    pub fn new_with_upgrades(
+        db: Arc<DB>,
+        upgrade_height: u32,
+        upgrades: &StoreUpgrades,
+    ) -> Result<Self, StoreUpgradeError<SK>> {
+        let (mut stores, head_version) = load_stores::<_, SK>(Arc::clone(&db))?;
+
+        if upgrades.is_empty() || head_version.checked_add(1) != Some(upgrade_height) {
+            return Ok(Self::from_stores(db, stores, head_version));
+        }
+
+        let store_key = |name: &str| {
+            SK::iter()
+                .find(|sk| sk.name() == name)
+                .ok_or_else(|| StoreUpgradeError::UnknownStore(name.to_owned()))
+        };
 
-            store_infos.push(store_info);
-            head_version = kv_store.persistent().loaded_version();
+        check_upgrade_prefixes::<SK>(upgrades)?;
 
-            stores.insert(store_key, kv_store);
+        for name in &upgrades.deleted {
+            if let Ok(sk) = store_key(name) {
+                return Err(StoreUpgradeError::Deleted(sk.name().to_owned()));
+            }
         }
 
-        Ok(MultiBank {
+        for StoreRename { old_name, new_name } in &upgrades.renamed {
+            store_key(new_name)?;
+            if store_key(old_name).is_ok() {
+                return Err(StoreUpgradeError::Deleted(old_name.to_owned()));
+            }
+
+            let new_store = PrefixDB::new(Arc::clone(&db), new_name.as_bytes().to_vec());
+            let old_store = PrefixDB::new(Arc::clone(&db), old_name.as_bytes().to_vec());
+            // Data could be already moved if node stopped before upgrade block was committed.
+            // Old store is empty then and nothing is moved again.
+            if new_store.iterator().next().is_some() && old_store.iterator().next().is_some() {
+                return Err(StoreUpgradeError::NotEmpty(new_name.to_owned()));
+            }
+        }
+
+        for name in upgrades.added.iter().chain(
+            upgrades
+                .renamed
+                .iter()
+                .map(|StoreRename { new_name, .. }| new_name),
+        ) {
+            if store_key(name)?.is_transient() {
+                return Err(StoreUpgradeError::Transient(name.to_owned()));
+            }
+        }
+
+        // Upgrade is validated, data of stores is moved and deleted at once,
+        // so an interrupted upgrade doesn't leave stores half moved
+        let mut batch = Vec::new();
+        for name in &upgrades.deleted {
+            let old_store = PrefixDB::new(Arc::clone(&db), name.as_bytes().to_vec());
+            batch.extend(
+                old_store
+                    .iterator()
+                    .map(|(key, _)| BatchOp::Delete([name.as_bytes(), &key[..]].concat())),
+            );
+        }
+
+        for StoreRename { old_name, new_name } in &upgrades.renamed {
+            // Tree nodes are addressed by hash, so raw records could be moved as is
+            let old_store = PrefixDB::new(Arc::clone(&db), old_name.as_bytes().to_vec());
+            for (key, value) in old_store.iterator() {
+                batch.push(BatchOp::Put(
+                    [new_name.as_bytes(), &key[..]].concat(),
+                    value.into_vec(),
+                ));
+                batch.push(BatchOp::Delete([old_name.as_bytes(), &key[..]].concat()));
+            }
+        }
+
+        db.write_batch(batch);
+
+        for StoreRename { new_name, .. } in &upgrades.renamed {
+            let sk = store_key(new_name)?;
+            let new_store = PrefixDB::new(Arc::clone(&db), new_name.as_bytes().to_vec());
+
+            let kv_store = ApplicationKVBank::new(new_store, None, Some(new_name.to_owned()))
+                .and_then(|kv_store| {
+                    drop_versions_after(&kv_store, head_version)?;
+                    Ok(kv_store)
+                })
+                .map_err(|err| MultiStoreError {
+                    sk: sk.clone(),
+                    err,
+                })?;
+            stores.insert(sk, kv_store);
+        }
+
+        for name in &upgrades.added {
+            let sk = store_key(name)?;
+
+            stores
+                .get(&sk)
+                .and_then(|store| store.persistent.as_ref())
+                .expect(KEY_EXISTS_MSG)
+                .write()
+                .expect(POISONED_LOCK)
+                .set_initial_version(head_version)
+                .map_err(|err| MultiStoreError {
+                    sk: sk.clone(),
+                    err: err.into(),
+                })?;
+        }
+
+        Ok(Self::from_stores(db, stores, head_version))
+    }
+
+    fn from_stores(
+        db: Arc<DB>,
+        stores: HashMap<SK, ApplicationKVBank<PrefixDB<DB>>>,
+        head_version: u32,
+    ) -> Self {
+        let store_infos = stores
+            .iter()
+            .filter_map(|(store_key, kv_store)| {
+                Some(StoreInfo {
+                    name: store_key.name().into(),
+                    hash: kv_store.persistent()?.root_hash(),
+                })
+            })
+            .collect();
+
+        MultiBank {
             head_version,
             head_commit_hash: crate::hash::hash_store_infos(store_infos),
-            backend: ApplicationStore(stores),
+            backend: ApplicationStore(stores, db),
             _marker: PhantomData,
-        })
+        }
     }
 
     /// Return tx kind of store. You need to create application kind before creation of transaction
@@ -86,32 +223,557 @@ impl<DB: Database, SK: StoreKey> MultiBank<DB, SK, ApplicationStore<DB, SK>> {
         }
     }
 
-    /// Commit changes for all kv stores and get application hash
+    /// Commit changes for all kv stores and get application hash.
+    /// Commit info is saved after all trees, so an interrupted commit is dropped on load.
+    /// Transient stores are cleared instead and don't take part in application hash.
     pub fn commit(&mut self) -> [u8; 32] {
+        let version = match self.head_version.checked_add(1) {
+            Some(version) => version,
+            None => panic!("version overflow"),
+        };
+
         let mut store_infos = vec![];
         for (store, kv_store) in &mut self.backend.0 {
-            let store_info = StoreInfo {
-                name: store.name().into(),
-                hash: kv_store.commit(),
-            };
-
-            store_infos.push(store_info)
+            if let Some(hash) = kv_store.commit() {
+                store_infos.push(StoreInfo {
+                    name: store.name().into(),
+                    hash,
+                })
+            }
         }
 
-        let hash = crate::hash::hash_store_infos(store_infos);
+        let commit_info = CommitInfo {
+            version,
+            store_infos,
+        };
+        commit::save_commit_info(&*self.backend.1, &commit_info);
+
+        let hash = commit_info.hash();
 
         self.head_commit_hash = hash;
-        self.head_version = match self.head_version.checked_add(1) {
-            Some(head_version) => head_version,
-            None => panic!("version overflow"),
-        };
+        self.head_version = version;
         hash
     }
 
+    /// Return commit info of committed `version`
+    pub fn commit_info(&self, version: u32) -> Option<CommitInfo> {
+        commit::commit_info(&*self.backend.1, version)
+    }
+
+    /// Apply changes of all kv stores to their trees without saving new versions and
+    /// get application hash which the next [Self::commit] will return
+    pub fn working_hash(&mut self) -> [u8; 32] {
+        let mut store_infos = vec![];
+        for (store, kv_store) in &mut self.backend.0 {
+            if let Some(hash) = kv_store.working_hash() {
+                store_infos.push(StoreInfo {
+                    name: store.name().into(),
+                    hash,
+                })
+            }
+        }
+
+        crate::hash::hash_store_infos(store_infos)
+    }
+
+    /// Node cache statistics summed over trees of all kv stores
+    pub fn cache_stats(&self) -> CacheStats {
+        self.backend
+            .0
+            .values()
+            .map(|store| store.cache_stats())
+            .fold(CacheStats::default(), |acc, stats| acc + stats)
+    }
+
     /// Clear cache of all stores
     pub fn clear_cache(&mut self) {
         for store in self.backend.0.values_mut() {
             store.cache_clear();
         }
     }
+
+    /// Copy uncommitted changes of all stores to restore them later with [Self::cache_restore]
+    pub fn cache_snapshot(&self) -> HashMap<SK, KVCache> {
+        self.backend
+            .0
+            .iter()
+            .map(|(sk, store)| (sk.to_owned(), store.cache.clone()))
+            .collect()
+    }
+
+    /// Replace uncommitted changes of all stores with ones from snapshot
+    pub fn cache_restore(&mut self, mut snapshot: HashMap<SK, KVCache>) {
+        for (sk, store) in &mut self.backend.0 {
+            store.cache = snapshot.remove(sk).unwrap_or_default();
+        }
+    }
+}
+
+/// Load stores at the latest version committed by all of them. Databases without
+/// commit info use the lowest latest version of non-empty stores.
+fn load_stores<DB: Database, SK: StoreKey>(
+    db: Arc<DB>,
+) -> Result<(HashMap<SK, ApplicationKVBank<PrefixDB<DB>>>, u32), MultiStoreError<SK>> {
+    let map = build_prefixed_stores::<_, SK>(Arc::clone(&db));
+    let mut stores = HashMap::with_capacity(map.len());
+    for (store_key, store) in map {
+        // Transient stores keep nothing in database
+        let kv_store = match store_key.is_transient() {
+            true => ApplicationKVBank::transient(),
+            false => ApplicationKVBank::new(store, None, Some(store_key.name().to_owned()))
+                .map_err(|err| MultiStoreError {
+                    sk: store_key.clone(),
+                    err,
+                })?,
+        };
+
+        stores.insert(store_key, kv_store);
+    }
+
+    let version = commit::latest_version(&*db).unwrap_or_else(|| {
+        stores
+            .values()
+            .filter_map(|store| Some(store.persistent()?.loaded_version()))
+            .filter(|version| *version > 0)
+            .min()
+            .unwrap_or_default()
+    });
+
+    for (store_key, store) in &stores {
+        drop_versions_after(store, version).map_err(|err| MultiStoreError {
+            sk: store_key.clone(),
+            err,
+        })?;
+    }
+
+    Ok((stores, version))
+}
+
+/// Names of stores are prefixes of their records in database, so a store whose name is
+/// a prefix of another name or of commit info shares records with it. Moving or deleting
+/// such store would corrupt the other one.
+fn check_upgrade_prefixes<SK: StoreKey>(
+    upgrades: &StoreUpgrades,
+) -> Result<(), StoreUpgradeError<SK>> {
+    let upgraded = upgrades
+        .added
+        .iter()
+        .chain(&upgrades.deleted)
+        .chain(
+            upgrades
+                .renamed
+                .iter()
+                .flat_map(|rename| [&rename.old_name, &rename.new_name]),
+        )
+        .map(|name| name.as_bytes())
+        .collect::<HashSet<_>>();
+
+    let all = upgraded
+        .iter()
+        .copied()
+        .chain(SK::iter().map(|sk| sk.name().as_bytes()))
+        .chain([commit::COMMIT_INFO_PREFIX])
+        .collect::<HashSet<_>>();
+
+    for &name in &upgraded {
+        let overlapped = all
+            .iter()
+            .find(|&&other| other != name && (other.starts_with(name) || name.starts_with(other)));
+
+        if let Some(other) = overlapped {
+            return Err(StoreUpgradeError::PrefixOverlap(
+                String::from_utf8_lossy(name).into_owned(),
+                String::from_utf8_lossy(other).into_owned(),
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+/// Drop versions of store tree which weren't committed by multi store
+fn drop_versions_after<DB: Database>(
+    kv_store: &ApplicationKVBank<PrefixDB<DB>>,
+    version: u32,
+) -> Result<(), KVStoreError> {
+    let Some(tree) = &kv_store.persistent else {
+        return Ok(());
+    };
+
+    let mut tree = tree.write().expect(POISONED_LOCK);
+    if tree.loaded_version() != version {
+        tree.delete_versions_after(version)?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use database::MemDB;
+
+    use super::*;
+
+    #[derive(Debug, Clone, Hash, PartialEq, Eq, strum::EnumIter)]
+    enum OldStoreKey {
+        Bank,
+        Gov,
+        Mint,
+    }
+
+    impl StoreKey for OldStoreKey {
+        fn name(&self) -> &'static str {
+            match self {
+                Self::Bank => "bank",
+                Self::Gov => "gov",
+                Self::Mint => "mint",
+            }
+        }
+
+        fn params() -> &'static Self {
+            &Self::Bank
+        }
+    }
+
+    #[derive(Debug, Clone, Hash, PartialEq, Eq, strum::EnumIter)]
+    enum NewStoreKey {
+        Bank,
+        Council,
+        Authz,
+    }
+
+    impl StoreKey for NewStoreKey {
+        fn name(&self) -> &'static str {
+            match self {
+                Self::Bank => "bank",
+                Self::Council => "council",
+                Self::Authz => "authz",
+            }
+        }
+
+        fn params() -> &'static Self {
+            &Self::Bank
+        }
+    }
+
+    #[derive(Debug, Clone, Hash, PartialEq, Eq, strum::EnumIter)]
+    enum TransientStoreKey {
+        Bank,
+        Fees,
+    }
+
+    impl StoreKey for TransientStoreKey {
+        fn name(&self) -> &'static str {
+            match self {
+                Self::Bank => "bank",
+                Self::Fees => "fees",
+            }
+        }
+
+        fn params() -> &'static Self {
+            &Self::Bank
+        }
+
+        fn is_transient(&self) -> bool {
+            matches!(self, Self::Fees)
+        }
+    }
+
+    fn upgrades() -> StoreUpgrades {
+        StoreUpgrades {
+            added: vec!["authz".to_owned()],
+            renamed: vec![StoreRename {
+                old_name: "gov".to_owned(),
+                new_name: "council".to_owned(),
+            }],
+            deleted: vec!["mint".to_owned()],
+        }
+    }
+
+    /// Commit two versions of stores with old keys
+    fn old_db() -> Arc<MemDB> {
+        let db = Arc::new(MemDB::new());
+        let mut multi_store =
+            ApplicationMultiBank::<_, OldStoreKey>::new(Arc::clone(&db)).expect("stores are new");
+
+        for sk in OldStoreKey::iter() {
+            multi_store.kv_store_mut(&sk).set(sk.name().bytes(), [1]);
+        }
+        multi_store.commit();
+        multi_store.commit();
+
+        db
+    }
+
+    #[test]
+    fn store_upgrades_are_applied_at_upgrade_height() {
+        let db = old_db();
+
+        let mut multi_store = ApplicationMultiBank::<_, NewStoreKey>::new_with_upgrades(
+            Arc::clone(&db),
+            3,
+            &upgrades(),
+        )
+        .expect("upgrades are valid");
+
+        assert_eq!(multi_store.head_version(), 2);
+        assert_eq!(
+            multi_store
+                .kv_store(&NewStoreKey::Council)
+                .get("gov".as_bytes()),
+            Some(vec![1])
+        );
+        assert_eq!(
+            multi_store
+                .kv_store(&NewStoreKey::Authz)
+                .get("authz".as_bytes()),
+            None
+        );
+        assert!(PrefixDB::new(Arc::clone(&db), b"mint".to_vec())
+            .iterator()
+            .next()
+            .is_none());
+        assert!(PrefixDB::new(Arc::clone(&db), b"gov".to_vec())
+            .iterator()
+            .next()
+            .is_none());
+
+        multi_store
+            .kv_store_mut(&NewStoreKey::Authz)
+            .set(*b"grant", [1]);
+        multi_store.commit();
+
+        for sk in NewStoreKey::iter() {
+            assert_eq!(
+                multi_store
+                    .kv_store(&sk)
+                    .persistent()
+                    .map(|tree| tree.loaded_version()),
+                Some(3),
+                "{sk:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn store_upgrades_could_be_applied_again() {
+        let expected_hash = {
+            let mut multi_store =
+                ApplicationMultiBank::<_, NewStoreKey>::new_with_upgrades(old_db(), 3, &upgrades())
+                    .expect("upgrades are valid");
+            multi_store.commit();
+            multi_store.head_commit_hash()
+        };
+
+        let db = old_db();
+        // Node stops before upgrade block is committed and applies upgrades again on restart
+        let _ = ApplicationMultiBank::<_, NewStoreKey>::new_with_upgrades(
+            Arc::clone(&db),
+            3,
+            &upgrades(),
+        )
+        .expect("upgrades are valid");
+        let mut multi_store = ApplicationMultiBank::<_, NewStoreKey>::new_with_upgrades(
+            Arc::clone(&db),
+            3,
+            &upgrades(),
+        )
+        .expect("upgrades are applied again");
+
+        assert_eq!(multi_store.head_version(), 2);
+        assert_eq!(
+            multi_store
+                .kv_store(&NewStoreKey::Council)
+                .get("gov".as_bytes()),
+            Some(vec![1])
+        );
+
+        multi_store.commit();
+        assert_eq!(multi_store.head_commit_hash(), expected_hash);
+    }
+
+    #[test]
+    fn store_upgrades_are_ignored_at_other_height() {
+        let db = old_db();
+
+        let multi_store = ApplicationMultiBank::<_, NewStoreKey>::new_with_upgrades(
+            Arc::clone(&db),
+            5,
+            &upgrades(),
+        )
+        .expect("upgrades are valid");
+
+        assert_eq!(
+            multi_store
+                .kv_store(&NewStoreKey::Council)
+                .get("gov".as_bytes()),
+            None
+        );
+        assert!(PrefixDB::new(db, b"mint".to_vec())
+            .iterator()
+            .next()
+            .is_some());
+    }
+
+    #[test]
+    fn deleted_store_in_use_is_rejected() {
+        let db = old_db();
+
+        let result = ApplicationMultiBank::<_, NewStoreKey>::new_with_upgrades(
+            db,
+            3,
+            &StoreUpgrades {
+                deleted: vec!["bank".to_owned()],
+                ..Default::default()
+            },
+        );
+
+        assert!(matches!(result, Err(StoreUpgradeError::Deleted(name)) if name == "bank"));
+    }
+
+    #[test]
+    fn overlapping_store_names_are_rejected() {
+        for name in ["ban", "bank2", "s"] {
+            let db = old_db();
+
+            let result = ApplicationMultiBank::<_, NewStoreKey>::new_with_upgrades(
+                Arc::clone(&db),
+                3,
+                &StoreUpgrades {
+                    deleted: vec![name.to_owned()],
+                    ..Default::default()
+                },
+            );
+
+            assert!(
+                matches!(result, Err(StoreUpgradeError::PrefixOverlap(upgraded, _)) if upgraded == name),
+                "{name}"
+            );
+            assert!(commit::latest_version(&*db).is_some());
+            assert!(PrefixDB::new(db, b"bank".to_vec())
+                .iterator()
+                .next()
+                .is_some());
+        }
+    }
+
+    #[test]
+    fn interrupted_commit_is_dropped_on_load() {
+        let db = old_db();
+        let multi_store =
+            ApplicationMultiBank::<_, OldStoreKey>::new(Arc::clone(&db)).expect("stores are valid");
+        let first = multi_store.commit_info(1).expect("version is committed");
+        assert_eq!(
+            multi_store.head_commit_hash(),
+            multi_store
+                .commit_info(2)
+                .expect("version is committed")
+                .hash()
+        );
+
+        // Trees saved version 2, but commit info of it wasn't written
+        commit::set_latest_version(&*db, 1);
+        commit::delete_commit_info(&*db, 2);
+
+        let mut multi_store =
+            ApplicationMultiBank::<_, OldStoreKey>::new(Arc::clone(&db)).expect("stores are valid");
+        assert_eq!(multi_store.head_version(), 1);
+        assert_eq!(multi_store.head_commit_hash(), first.hash());
+
+        multi_store
+            .kv_store_mut(&OldStoreKey::Bank)
+            .set(*b"key", [2]);
+        let hash = multi_store.commit();
+
+        assert_eq!(multi_store.head_version(), 2);
+        assert_eq!(
+            multi_store.commit_info(2).map(|info| info.hash()),
+            Some(hash)
+        );
+    }
+
+    #[test]
+    fn rollback_rewinds_one_version() {
+        let db = old_db();
+        let first = commit::commit_info(&*db, 1).expect("version is committed");
+
+        let multi_store = ApplicationMultiBank::<_, OldStoreKey>::rollback(Arc::clone(&db))
+            .expect("rollback succeeds");
+
+        assert_eq!(multi_store.head_version(), 1);
+        assert_eq!(multi_store.head_commit_hash(), first.hash());
+        assert_eq!(multi_store.commit_info(2), None);
+
+        ApplicationMultiBank::<_, OldStoreKey>::rollback(Arc::clone(&db))
+            .expect("rollback succeeds");
+        assert!(matches!(
+            ApplicationMultiBank::<_, OldStoreKey>::rollback(db),
+            Err(RollbackError::NoVersion)
+        ));
+    }
+
+    #[test]
+    fn transient_store_is_cleared_on_commit() {
+        let db = Arc::new(MemDB::new());
+        let mut multi_store = ApplicationMultiBank::<_, TransientStoreKey>::new(Arc::clone(&db))
+            .expect("stores are new");
+        assert!(multi_store
+            .kv_store(&TransientStoreKey::Fees)
+            .persistent()
+            .is_none());
+
+        multi_store
+            .kv_store_mut(&TransientStoreKey::Bank)
+            .set(*b"key", [1]);
+        let hash = multi_store.working_hash();
+
+        multi_store
+            .kv_store_mut(&TransientStoreKey::Fees)
+            .set(*b"fee", [2]);
+        assert_eq!(
+            multi_store.kv_store(&TransientStoreKey::Fees).get(b"fee"),
+            Some(vec![2])
+        );
+        assert_eq!(multi_store.working_hash(), hash);
+        assert_eq!(multi_store.commit(), hash);
+
+        assert_eq!(
+            multi_store.kv_store(&TransientStoreKey::Fees).get(b"fee"),
+            None
+        );
+        assert_eq!(
+            multi_store
+                .commit_info(1)
+                .map(|info| info.store_infos.len()),
+            Some(1)
+        );
+        assert!(PrefixDB::new(db, b"fees".to_vec())
+            .iterator()
+            .next()
+            .is_none());
+    }
+
+    #[test]
+    fn cache_restore_drops_changes_after_snapshot() {
+        let mut multi_store = ApplicationMultiBank::<_, OldStoreKey>::new(Arc::new(MemDB::new()))
+            .expect("stores are new");
+
+        multi_store.kv_store_mut(&OldStoreKey::Bank).set([1], [1]);
+        multi_store.commit();
+        multi_store.kv_store_mut(&OldStoreKey::Bank).set([2], [2]);
+
+        let snapshot = multi_store.cache_snapshot();
+
+        multi_store.kv_store_mut(&OldStoreKey::Bank).set([3], [3]);
+        multi_store.kv_store_mut(&OldStoreKey::Bank).delete(&[1]);
+        multi_store.kv_store_mut(&OldStoreKey::Gov).set([4], [4]);
+
+        multi_store.cache_restore(snapshot);
+
+        let bank = multi_store.kv_store(&OldStoreKey::Bank);
+        assert_eq!(bank.get(&[1u8]), Some(vec![1]));
+        assert_eq!(bank.get(&[2u8]), Some(vec![2]));
+        assert_eq!(bank.get(&[3u8]), None);
+        assert_eq!(multi_store.kv_store(&OldStoreKey::Gov).get(&[4u8]), None);
+    }
 }
@@ -37,6 +37,23 @@ impl<DB: Database, SK: StoreKey> MultiBank<DB, SK, TransactionStore<DB, SK>> {
         }
     }
 
+    /// Return store with same trees and copied cache of all stores.
+    /// Changes of the copy don't affect `self`
+    pub fn snapshot(&self) -> Self {
+        Self {
+            head_version: self.head_version,
+            head_commit_hash: self.head_commit_hash,
+            backend: TransactionStore(
+                self.backend
+                    .0
+                    .iter()
+                    .map(|(sk, store)| (sk.to_owned(), store.snapshot()))
+                    .collect(),
+            ),
+            _marker: PhantomData,
+        }
+    }
+
     /// Move values from tx layer to block
     pub fn upgrade_cache(&mut self) {
         for store in self.backend.0.values_mut() {
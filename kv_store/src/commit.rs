@@ -0,0 +1,138 @@
+//! Commit info of multi store which is persisted for every version.
+//!
+//! Roots of all trees are saved before commit info of their version. Commit info and
+//! the latest version are written in one batch after them, so the latest version marks
+//! state which was committed by every store. Tree versions after it are leftovers of an
+//! interrupted commit and are dropped on load, which is why tree roots don't need to be
+//! part of the same batch.
+
+use database::{BatchOp, Database};
+use integer_encoding::VarInt;
+
+pub use crate::hash::StoreInfo;
+
+/// Prefix of commit info keys. Store names must not overlap with it
+pub const COMMIT_INFO_PREFIX: &[u8] = b"s/";
+const LATEST_VERSION_KEY: &[u8] = b"s/latest";
+
+/// Root hashes of all stores at some version
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommitInfo {
+    pub version: u32,
+    pub store_infos: Vec<StoreInfo>,
+}
+
+impl CommitInfo {
+    /// Application hash of this version
+    pub fn hash(&self) -> [u8; 32] {
+        crate::hash::hash_store_infos(self.store_infos.clone())
+    }
+
+    /// Root hash of store with `name` at this version
+    pub fn store_hash(&self, name: &str) -> Option<[u8; 32]> {
+        self.store_infos
+            .iter()
+            .find(|info| info.name == name)
+            .map(|info| info.hash)
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        let mut bytes = self.version.to_be_bytes().to_vec();
+        for StoreInfo { name, hash } in &self.store_infos {
+            bytes.extend(name.len().encode_var_vec());
+            bytes.extend(name.as_bytes());
+            bytes.extend(hash);
+        }
+
+        bytes
+    }
+
+    fn decode(bytes: &[u8]) -> Option<Self> {
+        let version = u32::from_be_bytes(bytes.get(..4)?.try_into().ok()?);
+
+        let mut store_infos = Vec::new();
+        let mut rest = &bytes[4..];
+        while !rest.is_empty() {
+            let (len, n) = usize::decode_var(rest)?;
+            let name = String::from_utf8(rest.get(n..n + len)?.to_vec()).ok()?;
+            let hash = rest.get(n + len..n + len + 32)?.try_into().ok()?;
+
+            store_infos.push(StoreInfo { name, hash });
+            rest = &rest[n + len + 32..];
+        }
+
+        Some(Self {
+            version,
+            store_infos,
+        })
+    }
+}
+
+fn commit_info_key(version: u32) -> Vec<u8> {
+    [COMMIT_INFO_PREFIX, version.to_be_bytes().as_slice()].concat()
+}
+
+/// Latest version which was committed by all stores
+pub(crate) fn latest_version<DB: Database>(db: &DB) -> Option<u32> {
+    let bytes = db.get(LATEST_VERSION_KEY)?;
+
+    Some(u32::from_be_bytes(bytes.try_into().ok()?))
+}
+
+pub(crate) fn set_latest_version<DB: Database>(db: &DB, version: u32) {
+    db.put(LATEST_VERSION_KEY.to_vec(), version.to_be_bytes().to_vec());
+}
+
+pub(crate) fn commit_info<DB: Database>(db: &DB, version: u32) -> Option<CommitInfo> {
+    CommitInfo::decode(&db.get(&commit_info_key(version))?)
+}
+
+/// Save commit info and mark its version as the latest one atomically
+pub(crate) fn save_commit_info<DB: Database>(db: &DB, info: &CommitInfo) {
+    db.write_batch(vec![
+        BatchOp::Put(commit_info_key(info.version), info.encode()),
+        BatchOp::Put(
+            LATEST_VERSION_KEY.to_vec(),
+            info.version.to_be_bytes().to_vec(),
+        ),
+    ]);
+}
+
+pub(crate) fn delete_commit_info<DB: Database>(db: &DB, version: u32) {
+    db.delete(&commit_info_key(version));
+}
+
+#[cfg(test)]
+mod tests {
+    use database::MemDB;
+
+    use super::*;
+
+    #[test]
+    fn commit_info_roundtrip() {
+        let db = MemDB::new();
+        let info = CommitInfo {
+            version: 7,
+            store_infos: vec![
+                StoreInfo {
+                    name: "bank".to_owned(),
+                    hash: [1; 32],
+                },
+                StoreInfo {
+                    name: "staking".to_owned(),
+                    hash: [2; 32],
+                },
+            ],
+        };
+
+        save_commit_info(&db, &info);
+
+        assert_eq!(latest_version(&db), Some(7));
+        assert_eq!(commit_info(&db, 7), Some(info.clone()));
+        assert_eq!(commit_info(&db, 6), None);
+        assert_eq!(info.store_hash("staking"), Some([2; 32]));
+
+        delete_commit_info(&db, 7);
+        assert_eq!(commit_info(&db, 7), None);
+    }
+}
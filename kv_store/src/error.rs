@@ -26,5 +26,19 @@ impl<SK: StoreKey> std::fmt::Display for MultiStoreError<SK> {
     }
 }
 
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum RollbackError<SK: StoreKey> {
+    #[error("{0}")]
+    MultiStore(MultiStoreError<SK>),
+    #[error("there is no committed version to roll back")]
+    NoVersion,
+}
+
+impl<SK: StoreKey> From<MultiStoreError<SK>> for RollbackError<SK> {
+    fn from(value: MultiStoreError<SK>) -> Self {
+        Self::MultiStore(value)
+    }
+}
+
 pub const KEY_EXISTS_MSG: &str = "a store for every key is guaranteed to exist";
 pub const POISONED_LOCK: &str = "poisoned lock";
@@ -4,10 +4,13 @@ use strum::IntoEnumIterator;
 
 pub mod bank;
 pub mod cache;
+pub mod commit;
 pub mod error;
 pub mod query;
 pub mod range;
+pub mod stats;
 pub mod store;
+pub mod upgrade;
 
 mod hash;
 mod utils;
@@ -26,6 +29,12 @@ pub trait StoreKey:
 
     /// Return key for parameters
     fn params() -> &'static Self;
+
+    /// Transient store lives in memory only. It's excluded from application hash
+    /// and its state is dropped on every commit, so it could hold data of a single block.
+    fn is_transient(&self) -> bool {
+        false
+    }
 }
 
 fn build_prefixed_stores<DB: Database, SK: StoreKey>(db: Arc<DB>) -> HashMap<SK, PrefixDB<DB>> {
@@ -3,14 +3,14 @@ use std::ops::RangeBounds;
 use database::Database;
 use trees::iavl::QueryTree;
 
-use crate::store::prefix::immutable::ImmutablePrefixStore;
+use crate::{store::prefix::immutable::ImmutablePrefixStore, utils::MergedRange};
 
-/// Key value store for query
+/// Key value store for query. Transient store has no tree and is always empty
 #[derive(Debug)]
-pub struct QueryKVStore<DB>(QueryTree<DB>);
+pub struct QueryKVStore<DB>(Option<QueryTree<DB>>);
 
 impl<DB: Database> QueryKVStore<DB> {
-    pub fn new(tree: QueryTree<DB>) -> Self {
+    pub fn new(tree: Option<QueryTree<DB>>) -> Self {
         Self(tree)
     }
 }
@@ -20,11 +20,14 @@ impl<DB: Database> QueryKVStore<DB> {
         &self,
         range: R,
     ) -> crate::range::Range<'_, DB, Vec<u8>, R> {
-        self.0.range(range).into()
+        match &self.0 {
+            Some(tree) => tree.range(range).into(),
+            None => MergedRange::merge(std::iter::empty(), std::iter::empty()).into(),
+        }
     }
 
     pub fn get<R: AsRef<[u8]> + ?Sized>(&self, k: &R) -> Option<Vec<u8>> {
-        self.0.get(k.as_ref())
+        self.0.as_ref()?.get(k.as_ref())
     }
 
     pub fn prefix_store<I: IntoIterator<Item = u8>>(
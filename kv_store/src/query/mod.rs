@@ -56,9 +56,12 @@ impl<DB: Database, SK: StoreKey> QueryMultiStore<DB, SK> {
         let mut stores = HashMap::with_capacity(inner.len());
 
         for (key, bank) in inner {
-            let tree = bank.persistent.read().expect(POISONED_LOCK);
-
-            let query_kv_store = QueryKVStore::new(QueryTree::new(&tree, version)?);
+            // Transient stores don't commit their state, so there is nothing to query
+            let query_tree = match &bank.persistent {
+                Some(tree) => Some(QueryTree::new(&tree.read().expect(POISONED_LOCK), version)?),
+                None => None,
+            };
+            let query_kv_store = QueryKVStore::new(query_tree);
 
             stores.insert(key.to_owned(), query_kv_store);
         }
@@ -0,0 +1,32 @@
+//! Process wide counters of kv store accesses
+
+use std::sync::atomic::{AtomicU64, Ordering};
+
+pub use trees::iavl::CacheStats;
+
+static READS: AtomicU64 = AtomicU64::new(0);
+static WRITES: AtomicU64 = AtomicU64::new(0);
+
+/// Number of reads and writes made through [KVStore](crate::store::kv::immutable::KVStore)
+/// and [KVStoreMut](crate::store::kv::mutable::KVStoreMut) since start of the process
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct StoreStats {
+    pub reads: u64,
+    pub writes: u64,
+}
+
+pub fn store_stats() -> StoreStats {
+    StoreStats {
+        reads: READS.load(Ordering::Relaxed),
+        writes: WRITES.load(Ordering::Relaxed),
+    }
+}
+
+pub(crate) fn record_read() {
+    READS.fetch_add(1, Ordering::Relaxed);
+}
+
+/// Deletes are counted as writes
+pub(crate) fn record_write() {
+    WRITES.fetch_add(1, Ordering::Relaxed);
+}
@@ -47,6 +47,8 @@ impl<'a, DB: Database> KVStore<'a, DB> {
 
 impl<DB: Database> KVStore<'_, DB> {
     pub fn get<R: AsRef<[u8]> + ?Sized>(&self, k: &R) -> Option<Vec<u8>> {
+        crate::stats::record_read();
+
         match self.0 {
             KVStoreBackend::App(var) => var.get(k),
             KVStoreBackend::Tx(var) => var.get(k),
@@ -23,6 +23,8 @@ pub struct KVStoreMut<'a, DB>(pub(crate) KVStoreBackendMut<'a, DB>);
 
 impl<'a, DB: Database> KVStoreMut<'a, DB> {
     pub fn delete(&mut self, k: &[u8]) -> Option<Vec<u8>> {
+        crate::stats::record_write();
+
         match &mut self.0 {
             KVStoreBackendMut::App(var) => var.delete(k),
             KVStoreBackendMut::Tx(var) => var.delete(k),
@@ -71,6 +73,8 @@ impl<'a, DB> KVStoreMut<'a, DB> {
 
 impl<DB: Database> KVStoreMut<'_, DB> {
     pub fn get<R: AsRef<[u8]> + ?Sized>(&self, k: &R) -> Option<Vec<u8>> {
+        crate::stats::record_read();
+
         match &self.0 {
             KVStoreBackendMut::App(var) => var.get(k),
             KVStoreBackendMut::Tx(var) => var.get(k),
@@ -82,6 +86,8 @@ impl<DB: Database> KVStoreMut<'_, DB> {
         key: KI,
         value: VI,
     ) {
+        crate::stats::record_write();
+
         match &mut self.0 {
             KVStoreBackendMut::App(var) => var.set(key, value),
             KVStoreBackendMut::Tx(var) => var.set(key, value),
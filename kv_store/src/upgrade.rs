@@ -0,0 +1,54 @@
+//! Changes of the set of stores which are applied at upgrade height
+
+use std::fmt::Debug;
+
+use thiserror::Error;
+
+use crate::{error::MultiStoreError, StoreKey};
+
+/// Store which data moves to the prefix of other store
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StoreRename {
+    pub old_name: String,
+    pub new_name: String,
+}
+
+/// Stores added, renamed or deleted by an upgrade. Stores are referenced by their names,
+/// as renamed or deleted stores don't exist in the new [StoreKey].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StoreUpgrades {
+    /// Stores which start with empty tree at upgrade height
+    pub added: Vec<String>,
+    /// Stores which data moves to new prefix
+    pub renamed: Vec<StoreRename>,
+    /// Stores which data is dropped
+    pub deleted: Vec<String>,
+}
+
+impl StoreUpgrades {
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.renamed.is_empty() && self.deleted.is_empty()
+    }
+}
+
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum StoreUpgradeError<SK: StoreKey> {
+    #[error("{0}")]
+    MultiStore(MultiStoreError<SK>),
+    #[error("store `{0}` doesn't exist")]
+    UnknownStore(String),
+    #[error("store `{0}` already contains data")]
+    NotEmpty(String),
+    #[error("deleted store `{0}` is still in use")]
+    Deleted(String),
+    #[error("name of store `{0}` overlaps with `{1}`, one is a prefix of the other")]
+    PrefixOverlap(String, String),
+    #[error("store `{0}` is transient and has no state to upgrade")]
+    Transient(String),
+}
+
+impl<SK: StoreKey> From<MultiStoreError<SK>> for StoreUpgradeError<SK> {
+    fn from(value: MultiStoreError<SK>) -> Self {
+        Self::MultiStore(value)
+    }
+}
@@ -18,6 +18,7 @@ struct KeysArg {
 #[darling(and_then = Self::not_empty)]
 struct KeysAttr {
     pub to_string: String,
+    pub transient: Flag,
 }
 
 impl KeysAttr {
@@ -42,10 +43,25 @@ pub fn expand_store(input: DeriveInput) -> syn::Result<TokenStream> {
             };
 
             let mut enum_variants = Vec::<TokenStream>::new();
+            let mut transient_variants = Vec::<TokenStream>::new();
             let mut set = HashSet::<String>::with_capacity(enum_variants.len());
 
             for Variant { attrs, ident, .. } in variants {
-                let KeysAttr { to_string } = KeysAttr::from_attributes(&attrs)?;
+                let KeysAttr {
+                    to_string,
+                    transient,
+                } = KeysAttr::from_attributes(&attrs)?;
+
+                if transient.is_present() {
+                    if ident == params {
+                        Err(syn::Error::new(
+                            ident.span(),
+                            "Params store can't be transient",
+                        ))?
+                    }
+
+                    transient_variants.push(quote! { Self::#ident });
+                }
 
                 if let Some(prefix) =
                     set.iter()
@@ -66,6 +82,11 @@ pub fn expand_store(input: DeriveInput) -> syn::Result<TokenStream> {
                 enum_variants.push(quote! { Self::#ident => #to_string });
             }
 
+            let is_transient = match transient_variants.is_empty() {
+                true => quote! { false },
+                false => quote! { matches!(self, #(#transient_variants)|*) },
+            };
+
             let result = quote! {
                 impl #crate_prefix ::store::StoreKey for #ident
                 {
@@ -81,6 +102,10 @@ pub fn expand_store(input: DeriveInput) -> syn::Result<TokenStream> {
 
                         &PARAM_KEY
                     }
+
+                    fn is_transient(&self) -> bool {
+                        #is_transient
+                    }
                 }
             };
 
@@ -1,6 +1,12 @@
 pub mod errors;
 pub use tendermint_abci::ServerBuilder;
 
+/// ABCI++ server for CometBFT 0.38
+pub mod v038 {
+    pub use tendermint_abci_v038::Error;
+    pub use tendermint_abci_v038::ServerBuilder;
+}
+
 pub use tendermint_informal::abci::Event;
 pub use tendermint_informal::abci::EventAttribute;
 
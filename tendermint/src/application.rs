@@ -11,10 +11,13 @@ use crate::types::{
         deliver_tx::RequestDeliverTx,
         echo::RequestEcho,
         end_block::RequestEndBlock,
+        finalize_block::RequestFinalizeBlock,
         info::RequestInfo,
         init_chain::RequestInitChain,
+        proposal::{RequestPrepareProposal, RequestProcessProposal},
         query::RequestQuery,
         snapshot::{RequestApplySnapshotChunk, RequestLoadSnapshotChunk, RequestOfferSnapshot},
+        vote_extension::{RequestExtendVote, RequestVerifyVoteExtension},
     },
     response::{
         begin_block::ResponseBeginBlock,
@@ -22,16 +25,22 @@ use crate::types::{
         deliver_tx::ResponseDeliverTx,
         echo::ResponseEcho,
         end_block::ResponseEndBlock,
+        finalize_block::ResponseFinalizeBlock,
         info::ResponseInfo,
         init_chain::ResponseInitChain,
+        proposal::{ResponsePrepareProposal, ResponseProcessProposal},
         query::ResponseQuery,
         snapshot::{
             ResponseApplySnapshotChunk, ResponseListSnapshots, ResponseLoadSnapshotChunk,
             ResponseOfferSnapshot,
         },
+        vote_extension::{ResponseExtendVote, ResponseVerifyVoteExtension},
         ResponseCommit, ResponseFlush,
     },
 };
+
+pub mod v038;
+
 /// An ABCI application.
 ///
 /// Applications are `Send` + `Clone` + `'static` because they are cloned for
@@ -109,6 +118,38 @@ pub trait ABCIApplication<G>: Send + Clone + 'static {
     ) -> ResponseApplySnapshotChunk {
         Default::default()
     }
+
+    /// Build the transactions of a block proposed by this node. CometBFT 0.38 only.
+    ///
+    /// Default implementation keeps mempool order and drops the transactions
+    /// which don't fit into `max_tx_bytes`.
+    fn prepare_proposal(&self, request: RequestPrepareProposal) -> ResponsePrepareProposal {
+        ResponsePrepareProposal {
+            txs: request.txs_within_limit(),
+        }
+    }
+
+    /// Accept or reject a block proposed by another validator. CometBFT 0.38 only.
+    fn process_proposal(&self, _request: RequestProcessProposal) -> ResponseProcessProposal {
+        ResponseProcessProposal::accept()
+    }
+
+    /// Execute a decided block. CometBFT 0.38 only, replaces `BeginBlock`,
+    /// `DeliverTx` and `EndBlock`.
+    fn finalize_block(&self, request: RequestFinalizeBlock) -> ResponseFinalizeBlock;
+
+    /// Attach application data to the precommit of this node. CometBFT 0.38 only.
+    fn extend_vote(&self, _request: RequestExtendVote) -> ResponseExtendVote {
+        Default::default()
+    }
+
+    /// Validate a vote extension of another validator. CometBFT 0.38 only.
+    fn verify_vote_extension(
+        &self,
+        _request: RequestVerifyVoteExtension,
+    ) -> ResponseVerifyVoteExtension {
+        ResponseVerifyVoteExtension::accept()
+    }
 }
 
 #[derive(Debug, Clone)]
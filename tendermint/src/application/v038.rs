@@ -0,0 +1,196 @@
+//! CometBFT 0.38 (ABCI++) server adapter for [`ABCIApplication`].
+//!
+//! Methods shared with Tendermint 0.34 kept their wire format, so their requests are
+//! re-encoded to the 0.34 messages and handled by the same conversions as [`super::ABCI`].
+
+use prost::Message;
+use serde::de::DeserializeOwned;
+use tendermint_abci_v038::Application;
+use tendermint_proto::abci as legacy;
+use tendermint_proto_v038::v0_38::abci as proto;
+use tracing::info;
+
+use crate::ext::{transcode, UnwrapInvalid};
+
+use super::ABCIApplication;
+
+#[derive(Debug, Clone)]
+pub struct ABCI<T: ABCIApplication<G>, G> {
+    handler: T,
+    _phantom: std::marker::PhantomData<G>,
+}
+
+impl<G, T: ABCIApplication<G>> From<T> for ABCI<T, G> {
+    fn from(handler: T) -> Self {
+        Self {
+            handler,
+            _phantom: Default::default(),
+        }
+    }
+}
+
+fn to_legacy<F: Message, L: Message + Default>(request: F) -> L {
+    transcode(&request).unwrap_or_invalid()
+}
+
+fn from_legacy<L: Message, F: Message + Default>(response: L) -> F {
+    transcode(&response).expect("response layout is shared by Tendermint 0.34 and CometBFT 0.38")
+}
+
+impl<G: DeserializeOwned + Send + Clone + 'static, T: ABCIApplication<G>> Application
+    for ABCI<T, G>
+{
+    fn echo(&self, request: proto::RequestEcho) -> proto::ResponseEcho {
+        info!("Got echo request");
+
+        let request: legacy::RequestEcho = to_legacy(request);
+        let result = T::echo(&self.handler, request.into());
+
+        from_legacy(legacy::ResponseEcho::from(result))
+    }
+
+    fn info(&self, request: proto::RequestInfo) -> proto::ResponseInfo {
+        info!(
+            "Got info request. CometBFT version: {}; Block version: {}; P2P version: {}; ABCI version: {}",
+            request.version, request.block_version, request.p2p_version, request.abci_version
+        );
+
+        let request: legacy::RequestInfo = to_legacy(request);
+        let result = T::info(&self.handler, request.into());
+
+        from_legacy(legacy::ResponseInfo::from(result))
+    }
+
+    fn init_chain(&self, request: proto::RequestInitChain) -> proto::ResponseInitChain {
+        info!("Got init chain request");
+
+        let request: legacy::RequestInitChain = to_legacy(request);
+        let result = T::init_chain(&self.handler, request.try_into().unwrap_or_invalid());
+
+        from_legacy(legacy::ResponseInitChain::from(result))
+    }
+
+    fn query(&self, request: proto::RequestQuery) -> proto::ResponseQuery {
+        info!("Got query request to: {}", request.path);
+
+        let request: legacy::RequestQuery = to_legacy(request);
+        let result = T::query(&self.handler, request.into());
+
+        from_legacy(legacy::ResponseQuery::from(result))
+    }
+
+    fn check_tx(&self, request: proto::RequestCheckTx) -> proto::ResponseCheckTx {
+        info!("Got check tx request");
+
+        let request: legacy::RequestCheckTx = to_legacy(request);
+        let result = T::check_tx(&self.handler, request.into());
+
+        from_legacy(legacy::ResponseCheckTx::from(result))
+    }
+
+    fn flush(&self) -> proto::ResponseFlush {
+        info!("Got flush request");
+
+        let result = T::flush(&self.handler);
+
+        from_legacy(legacy::ResponseFlush::from(result))
+    }
+
+    fn commit(&self) -> proto::ResponseCommit {
+        info!("Got commit request");
+
+        let result = T::commit(&self.handler);
+
+        result.into()
+    }
+
+    fn list_snapshots(&self) -> proto::ResponseListSnapshots {
+        info!("Got list snapshots request");
+
+        let result = T::list_snapshots(&self.handler);
+
+        from_legacy(legacy::ResponseListSnapshots::from(result))
+    }
+
+    fn offer_snapshot(&self, request: proto::RequestOfferSnapshot) -> proto::ResponseOfferSnapshot {
+        info!("Got offer snapshot request");
+
+        let request: legacy::RequestOfferSnapshot = to_legacy(request);
+        let result = T::offer_snapshot(&self.handler, request.into());
+
+        from_legacy(legacy::ResponseOfferSnapshot::from(result))
+    }
+
+    fn load_snapshot_chunk(
+        &self,
+        request: proto::RequestLoadSnapshotChunk,
+    ) -> proto::ResponseLoadSnapshotChunk {
+        info!("Got load snapshot chunk request");
+
+        let request: legacy::RequestLoadSnapshotChunk = to_legacy(request);
+        let result = T::load_snapshot_chunk(&self.handler, request.into());
+
+        from_legacy(legacy::ResponseLoadSnapshotChunk::from(result))
+    }
+
+    fn apply_snapshot_chunk(
+        &self,
+        request: proto::RequestApplySnapshotChunk,
+    ) -> proto::ResponseApplySnapshotChunk {
+        info!("Got apply snapshot chunk request");
+
+        let request: legacy::RequestApplySnapshotChunk = to_legacy(request);
+        let result = T::apply_snapshot_chunk(&self.handler, request.into());
+
+        from_legacy(legacy::ResponseApplySnapshotChunk::from(result))
+    }
+
+    fn prepare_proposal(
+        &self,
+        request: proto::RequestPrepareProposal,
+    ) -> proto::ResponsePrepareProposal {
+        info!("Got prepare proposal request");
+
+        let result = T::prepare_proposal(&self.handler, request.try_into().unwrap_or_invalid());
+
+        result.into()
+    }
+
+    fn process_proposal(
+        &self,
+        request: proto::RequestProcessProposal,
+    ) -> proto::ResponseProcessProposal {
+        info!("Got process proposal request");
+
+        let result = T::process_proposal(&self.handler, request.try_into().unwrap_or_invalid());
+
+        result.into()
+    }
+
+    fn finalize_block(&self, request: proto::RequestFinalizeBlock) -> proto::ResponseFinalizeBlock {
+        info!("Got finalize block request");
+
+        let result = T::finalize_block(&self.handler, request.try_into().unwrap_or_invalid());
+
+        result.into()
+    }
+
+    fn extend_vote(&self, request: proto::RequestExtendVote) -> proto::ResponseExtendVote {
+        info!("Got extend vote request");
+
+        let result = T::extend_vote(&self.handler, request.try_into().unwrap_or_invalid());
+
+        result.into()
+    }
+
+    fn verify_vote_extension(
+        &self,
+        request: proto::RequestVerifyVoteExtension,
+    ) -> proto::ResponseVerifyVoteExtension {
+        info!("Got verify vote extension request");
+
+        let result = T::verify_vote_extension(&self.handler, request.into());
+
+        result.into()
+    }
+}
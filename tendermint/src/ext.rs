@@ -18,3 +18,13 @@ impl<T, E: Error> UnwrapInvalid for Result<T, E> {
         }
     }
 }
+
+/// Re-encodes a protobuf message as another message type which shares its wire format.
+///
+/// CometBFT 0.38 kept the encoding of most ABCI messages from Tendermint 0.34, so this lets
+/// the 0.38 server reuse the conversions written for the 0.34 types.
+pub fn transcode<F: prost::Message, T: prost::Message + Default>(
+    msg: &F,
+) -> Result<T, prost::DecodeError> {
+    T::decode(msg.encode_to_vec().as_slice())
+}
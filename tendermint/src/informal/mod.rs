@@ -8,3 +8,8 @@ pub mod hash {
 pub use tendermint_informal::Block;
 pub use tendermint_informal::Hash;
 pub use tendermint_informal::PublicKey;
+
+/// Protobuf encoding of [Block], for services which have own copy of Tendermint types
+pub fn encode_block(block: Block) -> Vec<u8> {
+    prost::Message::encode_to_vec(&tendermint_proto::types::Block::from(block))
+}
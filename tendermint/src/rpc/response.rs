@@ -1,3 +1,7 @@
+pub mod abci_query {
+    pub use tendermint_rpc::endpoint::abci_query::AbciQuery;
+}
+
 pub mod block {
     pub use tendermint_rpc::endpoint::block::Response;
 }
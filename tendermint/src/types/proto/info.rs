@@ -242,6 +242,118 @@ impl From<inner::Snapshot> for Snapshot {
     }
 }
 
+impl TryFrom<inner_v038::CommitInfo> for LastCommitInfo {
+    type Error = crate::error::Error;
+
+    fn try_from(
+        inner_v038::CommitInfo { round, votes }: inner_v038::CommitInfo,
+    ) -> Result<Self, Self::Error> {
+        let mut votes_res = vec![];
+        for inner_v038::VoteInfo {
+            validator,
+            block_id_flag,
+        } in votes
+        {
+            votes_res.push(VoteInfo {
+                validator: validator_from_v038(validator)?,
+                signed_last_block: block_id_flag == inner_v038::BlockIdFlag::Commit as i32,
+            });
+        }
+        Ok(Self {
+            round,
+            votes: votes_res,
+        })
+    }
+}
+
+/// Commit info of the last block extended with the vote extensions of the precommits.
+/// Only passed to the application by CometBFT 0.38 in `PrepareProposal`.
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ExtendedCommitInfo {
+    pub round: i32,
+    pub votes: Vec<ExtendedVoteInfo>,
+}
+
+impl TryFrom<inner_v038::ExtendedCommitInfo> for ExtendedCommitInfo {
+    type Error = crate::error::Error;
+
+    fn try_from(
+        inner_v038::ExtendedCommitInfo { round, votes }: inner_v038::ExtendedCommitInfo,
+    ) -> Result<Self, Self::Error> {
+        let mut votes_res = vec![];
+        for v in votes {
+            votes_res.push(v.try_into()?);
+        }
+        Ok(Self {
+            round,
+            votes: votes_res,
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ExtendedVoteInfo {
+    pub validator: Validator,
+    pub signed_last_block: bool,
+    /// Non-deterministic extension provided by the sending validator's application
+    pub vote_extension: ::prost::bytes::Bytes,
+    /// Vote extension signature created by CometBFT
+    pub extension_signature: ::prost::bytes::Bytes,
+}
+
+impl TryFrom<inner_v038::ExtendedVoteInfo> for ExtendedVoteInfo {
+    type Error = crate::error::Error;
+
+    fn try_from(
+        inner_v038::ExtendedVoteInfo {
+            validator,
+            vote_extension,
+            extension_signature,
+            block_id_flag,
+        }: inner_v038::ExtendedVoteInfo,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            validator: validator_from_v038(validator)?,
+            signed_last_block: block_id_flag == inner_v038::BlockIdFlag::Commit as i32,
+            vote_extension,
+            extension_signature,
+        })
+    }
+}
+
+impl TryFrom<inner_v038::Misbehavior> for Evidence {
+    type Error = crate::error::Error;
+
+    fn try_from(value: inner_v038::Misbehavior) -> Result<Self, Self::Error> {
+        // `Misbehavior` is the renamed `Evidence` message and shares its encoding
+        crate::ext::transcode::<_, inner::Evidence>(&value)
+            .map_err(|e| Self::Error::InvalidData(e.to_string()))?
+            .try_into()
+    }
+}
+
+fn validator_from_v038(
+    validator: Option<inner_v038::Validator>,
+) -> Result<Validator, crate::error::Error> {
+    let validator = validator.ok_or(crate::error::Error::InvalidData(
+        "validator is missing".into(),
+    ))?;
+
+    crate::ext::transcode::<_, super::validator::inner::Validator>(&validator)
+        .map_err(|e| crate::error::Error::InvalidData(e.to_string()))?
+        .try_into()
+}
+
+pub(crate) mod inner_v038 {
+    pub use tendermint_proto_v038::v0_38::abci::CommitInfo;
+    pub use tendermint_proto_v038::v0_38::abci::ExtendedCommitInfo;
+    pub use tendermint_proto_v038::v0_38::abci::ExtendedVoteInfo;
+    pub use tendermint_proto_v038::v0_38::abci::Misbehavior;
+    pub use tendermint_proto_v038::v0_38::abci::Validator;
+    pub use tendermint_proto_v038::v0_38::abci::VoteInfo;
+    pub use tendermint_proto_v038::v0_38::types::BlockIdFlag;
+}
+
 pub(crate) mod inner {
     pub use tendermint_proto::abci::Evidence;
     pub use tendermint_proto::abci::EvidenceType;
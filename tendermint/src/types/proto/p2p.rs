@@ -1,13 +1,13 @@
-// the alternatives are place below, for now we don't need conversions
-// mod inner {
-//     pub use tendermint_proto::p2p::DefaultNodeInfo;
-//     pub use tendermint_proto::p2p::DefaultNodeInfoOther;
-//     pub use tendermint_proto::p2p::ProtocolVersion;
-// }
-
 use crate::informal::node::{Info, OtherInfo, ProtocolVersionInfo};
+use prost::Message;
 use serde::{Deserialize, Serialize};
 
+mod inner {
+    pub use tendermint_proto::p2p::DefaultNodeInfo;
+    pub use tendermint_proto::p2p::DefaultNodeInfoOther;
+    pub use tendermint_proto::p2p::ProtocolVersion;
+}
+
 #[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
 pub struct DefaultNodeInfo {
     pub protocol_version: Option<ProtocolVersion>,
@@ -20,6 +20,39 @@ pub struct DefaultNodeInfo {
     pub other: Option<DefaultNodeInfoOther>,
 }
 
+impl DefaultNodeInfo {
+    /// Protobuf encoding of node info, for services which have own copy of Tendermint types
+    pub fn encode_to_vec(self) -> Vec<u8> {
+        inner::DefaultNodeInfo::from(self).encode_to_vec()
+    }
+}
+
+impl From<DefaultNodeInfo> for inner::DefaultNodeInfo {
+    fn from(
+        DefaultNodeInfo {
+            protocol_version,
+            default_node_id,
+            listen_addr,
+            network,
+            version,
+            channels,
+            moniker,
+            other,
+        }: DefaultNodeInfo,
+    ) -> Self {
+        Self {
+            protocol_version: protocol_version.map(Into::into),
+            default_node_id,
+            listen_addr,
+            network,
+            version,
+            channels,
+            moniker,
+            other: other.map(Into::into),
+        }
+    }
+}
+
 impl From<Info> for DefaultNodeInfo {
     fn from(
         Info {
@@ -53,6 +86,12 @@ pub struct ProtocolVersion {
     pub app: u64,
 }
 
+impl From<ProtocolVersion> for inner::ProtocolVersion {
+    fn from(ProtocolVersion { p2p, block, app }: ProtocolVersion) -> Self {
+        Self { p2p, block, app }
+    }
+}
+
 impl From<ProtocolVersionInfo> for ProtocolVersion {
     fn from(ProtocolVersionInfo { p2p, block, app }: ProtocolVersionInfo) -> Self {
         Self { p2p, block, app }
@@ -80,3 +119,17 @@ impl From<OtherInfo> for DefaultNodeInfoOther {
         }
     }
 }
+
+impl From<DefaultNodeInfoOther> for inner::DefaultNodeInfoOther {
+    fn from(
+        DefaultNodeInfoOther {
+            tx_index,
+            rpc_address,
+        }: DefaultNodeInfoOther,
+    ) -> Self {
+        Self {
+            tx_index: if tx_index { "on" } else { "off" }.to_owned(),
+            rpc_address,
+        }
+    }
+}
@@ -0,0 +1,55 @@
+use bytes::Bytes;
+
+use crate::{
+    error::Error,
+    types::{
+        proto::info::{Evidence, LastCommitInfo},
+        time::timestamp::Timestamp,
+    },
+};
+
+/// Delivers a decided block to the application. Replaces the `BeginBlock`,
+/// `DeliverTx` and `EndBlock` sequence in CometBFT 0.38.
+#[derive(Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize, Debug)]
+pub struct RequestFinalizeBlock {
+    pub txs: Vec<Bytes>,
+    pub decided_last_commit: LastCommitInfo,
+    pub misbehavior: Vec<Evidence>,
+    /// Hash of the decided block
+    pub hash: Bytes,
+    pub height: u32,
+    pub time: Timestamp,
+    pub next_validators_hash: Bytes,
+    /// Address of the validator that proposed the block
+    pub proposer_address: Bytes,
+}
+
+impl TryFrom<super::inner_v038::RequestFinalizeBlock> for RequestFinalizeBlock {
+    type Error = Error;
+
+    fn try_from(
+        super::inner_v038::RequestFinalizeBlock {
+            txs,
+            decided_last_commit,
+            misbehavior,
+            hash,
+            height,
+            time,
+            next_validators_hash,
+            proposer_address,
+        }: super::inner_v038::RequestFinalizeBlock,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            txs,
+            decided_last_commit: decided_last_commit
+                .ok_or_else(|| Error::InvalidData("decided_last_commit is missing".into()))?
+                .try_into()?,
+            misbehavior: super::misbehavior_from_v038(misbehavior)?,
+            hash,
+            height: super::height_from_v038(height)?,
+            time: super::time_from_v038(time)?,
+            next_validators_hash,
+            proposer_address,
+        })
+    }
+}
@@ -3,10 +3,13 @@ pub mod check_tx;
 pub mod deliver_tx;
 pub mod echo;
 pub mod end_block;
+pub mod finalize_block;
 pub mod info;
 pub mod init_chain;
+pub mod proposal;
 pub mod query;
 pub mod snapshot;
+pub mod vote_extension;
 
 pub(crate) mod inner {
     pub use tendermint_proto::abci::RequestApplySnapshotChunk;
@@ -21,3 +24,35 @@ pub(crate) mod inner {
     pub use tendermint_proto::abci::RequestOfferSnapshot;
     pub use tendermint_proto::abci::RequestQuery;
 }
+
+pub(crate) mod inner_v038 {
+    pub use tendermint_proto_v038::v0_38::abci::RequestExtendVote;
+    pub use tendermint_proto_v038::v0_38::abci::RequestFinalizeBlock;
+    pub use tendermint_proto_v038::v0_38::abci::RequestPrepareProposal;
+    pub use tendermint_proto_v038::v0_38::abci::RequestProcessProposal;
+    pub use tendermint_proto_v038::v0_38::abci::RequestVerifyVoteExtension;
+}
+
+fn height_from_v038(height: i64) -> Result<u32, crate::error::Error> {
+    height.try_into().map_err(|e| {
+        crate::error::Error::InvalidData(format!(
+            "failed to read `height`. Expected value of type u32, got value {height} with error {e}"
+        ))
+    })
+}
+
+fn time_from_v038(
+    time: Option<crate::types::time::timestamp::inner::TimestampV038>,
+) -> Result<crate::types::time::timestamp::Timestamp, crate::error::Error> {
+    time.ok_or(crate::error::Error::InvalidData(
+        "time is empty".to_string(),
+    ))?
+    .try_into()
+    .map_err(|e| crate::error::Error::InvalidData(format!("{e}")))
+}
+
+fn misbehavior_from_v038(
+    misbehavior: Vec<tendermint_proto_v038::v0_38::abci::Misbehavior>,
+) -> Result<Vec<crate::types::proto::info::Evidence>, crate::error::Error> {
+    misbehavior.into_iter().map(TryInto::try_into).collect()
+}
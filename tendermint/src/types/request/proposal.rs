@@ -0,0 +1,117 @@
+use bytes::Bytes;
+
+use crate::{
+    error::Error,
+    types::{
+        proto::info::{Evidence, ExtendedCommitInfo, LastCommitInfo},
+        time::timestamp::Timestamp,
+    },
+};
+
+/// Sent to the proposer of a block so the application can reorder, add or remove
+/// transactions before the block is proposed. CometBFT 0.38 only.
+#[derive(Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize, Debug)]
+pub struct RequestPrepareProposal {
+    /// The maximum size in bytes of the transactions the application may return
+    pub max_tx_bytes: i64,
+    /// Transactions from the mempool, possibly exceeding `max_tx_bytes` in total
+    pub txs: Vec<Bytes>,
+    pub local_last_commit: ExtendedCommitInfo,
+    pub misbehavior: Vec<Evidence>,
+    pub height: u32,
+    pub time: Timestamp,
+    pub next_validators_hash: Bytes,
+    /// Address of the validator that is creating the proposal
+    pub proposer_address: Bytes,
+}
+
+impl RequestPrepareProposal {
+    /// Mempool transactions in their original order, up to the first one which
+    /// doesn't fit into `max_tx_bytes`
+    pub fn txs_within_limit(self) -> Vec<Bytes> {
+        let max_tx_bytes = self.max_tx_bytes;
+        let mut total_bytes: i64 = 0;
+        self.txs
+            .into_iter()
+            .take_while(|tx| {
+                total_bytes = total_bytes.saturating_add(tx.len() as i64);
+                total_bytes <= max_tx_bytes
+            })
+            .collect()
+    }
+}
+
+impl TryFrom<super::inner_v038::RequestPrepareProposal> for RequestPrepareProposal {
+    type Error = Error;
+
+    fn try_from(
+        super::inner_v038::RequestPrepareProposal {
+            max_tx_bytes,
+            txs,
+            local_last_commit,
+            misbehavior,
+            height,
+            time,
+            next_validators_hash,
+            proposer_address,
+        }: super::inner_v038::RequestPrepareProposal,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            max_tx_bytes,
+            txs,
+            local_last_commit: local_last_commit
+                .ok_or_else(|| Error::InvalidData("local_last_commit is missing".into()))?
+                .try_into()?,
+            misbehavior: super::misbehavior_from_v038(misbehavior)?,
+            height: super::height_from_v038(height)?,
+            time: super::time_from_v038(time)?,
+            next_validators_hash,
+            proposer_address,
+        })
+    }
+}
+
+/// Sent to every validator to accept or reject a proposed block. CometBFT 0.38 only.
+#[derive(Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize, Debug)]
+pub struct RequestProcessProposal {
+    pub txs: Vec<Bytes>,
+    pub proposed_last_commit: LastCommitInfo,
+    pub misbehavior: Vec<Evidence>,
+    /// Hash of the proposed block
+    pub hash: Bytes,
+    pub height: u32,
+    pub time: Timestamp,
+    pub next_validators_hash: Bytes,
+    /// Address of the validator that created the proposal
+    pub proposer_address: Bytes,
+}
+
+impl TryFrom<super::inner_v038::RequestProcessProposal> for RequestProcessProposal {
+    type Error = Error;
+
+    fn try_from(
+        super::inner_v038::RequestProcessProposal {
+            txs,
+            proposed_last_commit,
+            misbehavior,
+            hash,
+            height,
+            time,
+            next_validators_hash,
+            proposer_address,
+        }: super::inner_v038::RequestProcessProposal,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            txs,
+            proposed_last_commit: proposed_last_commit
+                .ok_or_else(|| Error::InvalidData("proposed_last_commit is missing".into()))?
+                .try_into()?,
+            misbehavior: super::misbehavior_from_v038(misbehavior)?,
+            hash,
+            height: super::height_from_v038(height)?,
+            time: super::time_from_v038(time)?,
+            next_validators_hash,
+            proposer_address,
+        })
+    }
+}
@@ -0,0 +1,88 @@
+use bytes::Bytes;
+
+use crate::{
+    error::Error,
+    types::{
+        proto::info::{Evidence, LastCommitInfo},
+        time::timestamp::Timestamp,
+    },
+};
+
+/// Asks the application for an extension to attach to the validator's precommit.
+/// CometBFT 0.38 only.
+#[derive(Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize, Debug)]
+pub struct RequestExtendVote {
+    /// Hash of the block being voted on
+    pub hash: Bytes,
+    pub height: u32,
+    pub time: Timestamp,
+    pub txs: Vec<Bytes>,
+    pub proposed_last_commit: LastCommitInfo,
+    pub misbehavior: Vec<Evidence>,
+    pub next_validators_hash: Bytes,
+    /// Address of the validator that proposed the block
+    pub proposer_address: Bytes,
+}
+
+impl TryFrom<super::inner_v038::RequestExtendVote> for RequestExtendVote {
+    type Error = Error;
+
+    fn try_from(
+        super::inner_v038::RequestExtendVote {
+            hash,
+            height,
+            time,
+            txs,
+            proposed_last_commit,
+            misbehavior,
+            next_validators_hash,
+            proposer_address,
+        }: super::inner_v038::RequestExtendVote,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            hash,
+            height: super::height_from_v038(height)?,
+            time: super::time_from_v038(time)?,
+            txs,
+            proposed_last_commit: proposed_last_commit
+                .ok_or_else(|| Error::InvalidData("proposed_last_commit is missing".into()))?
+                .try_into()?,
+            misbehavior: super::misbehavior_from_v038(misbehavior)?,
+            next_validators_hash,
+            proposer_address,
+        })
+    }
+}
+
+/// Asks the application to validate a vote extension received from another validator.
+/// CometBFT 0.38 only.
+#[derive(Clone, PartialEq, Eq, ::prost::Message, serde::Serialize, serde::Deserialize)]
+pub struct RequestVerifyVoteExtension {
+    /// Hash of the block the extension refers to
+    #[prost(bytes = "bytes", tag = "1")]
+    pub hash: Bytes,
+    #[prost(bytes = "bytes", tag = "2")]
+    pub validator_address: Bytes,
+    #[prost(int64, tag = "3")]
+    pub height: i64,
+    #[prost(bytes = "bytes", tag = "4")]
+    pub vote_extension: Bytes,
+}
+
+impl From<super::inner_v038::RequestVerifyVoteExtension> for RequestVerifyVoteExtension {
+    fn from(
+        super::inner_v038::RequestVerifyVoteExtension {
+            hash,
+            validator_address,
+            height,
+            vote_extension,
+        }: super::inner_v038::RequestVerifyVoteExtension,
+    ) -> Self {
+        Self {
+            hash,
+            validator_address,
+            height,
+            vote_extension,
+        }
+    }
+}
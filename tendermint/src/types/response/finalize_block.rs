@@ -0,0 +1,58 @@
+use crate::{
+    ext::transcode,
+    types::proto::{consensus::ConsensusParams, event::Event, validator::ValidatorUpdate},
+};
+
+use super::deliver_tx::ResponseDeliverTx;
+
+#[derive(Clone, Debug, PartialEq, Eq, Default, serde::Serialize, serde::Deserialize)]
+pub struct ResponseFinalizeBlock {
+    /// Events emitted outside of transaction execution, e.g. by begin and end block
+    pub events: Vec<Event>,
+    /// Result of each transaction of the block, in order. Encoded as `ExecTxResult`
+    /// which shares its layout with `ResponseDeliverTx`.
+    pub tx_results: Vec<ResponseDeliverTx>,
+    pub validator_updates: Vec<ValidatorUpdate>,
+    pub consensus_param_updates: Option<ConsensusParams>,
+    /// Application state hash after the block was executed
+    pub app_hash: ::prost::bytes::Bytes,
+}
+
+impl From<ResponseFinalizeBlock> for super::inner_v038::ResponseFinalizeBlock {
+    fn from(
+        ResponseFinalizeBlock {
+            events,
+            tx_results,
+            validator_updates,
+            consensus_param_updates,
+            app_hash,
+        }: ResponseFinalizeBlock,
+    ) -> Self {
+        // All nested messages kept their wire format in CometBFT 0.38
+        Self {
+            events: events
+                .iter()
+                .map(|this| transcode(this).expect("`Event` layout is unchanged"))
+                .collect(),
+            tx_results: tx_results
+                .into_iter()
+                .map(|this| {
+                    transcode(&super::inner::ResponseDeliverTx::from(this))
+                        .expect("`ExecTxResult` shares layout with `ResponseDeliverTx`")
+                })
+                .collect(),
+            validator_updates: validator_updates
+                .into_iter()
+                .map(|this| {
+                    transcode(&crate::types::proto::validator::inner::ValidatorUpdate::from(this))
+                        .expect("`ValidatorUpdate` layout is unchanged")
+                })
+                .collect(),
+            consensus_param_updates: consensus_param_updates.map(|this| {
+                transcode(&crate::types::proto::consensus::inner::ConsensusParams::from(this))
+                    .expect("`ConsensusParams` layout is a subset of the 0.38 one")
+            }),
+            app_hash,
+        }
+    }
+}
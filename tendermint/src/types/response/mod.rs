@@ -3,10 +3,13 @@ pub mod check_tx;
 pub mod deliver_tx;
 pub mod echo;
 pub mod end_block;
+pub mod finalize_block;
 pub mod info;
 pub mod init_chain;
+pub mod proposal;
 pub mod query;
 pub mod snapshot;
+pub mod vote_extension;
 
 pub(crate) mod inner {
     pub use tendermint_informal::abci::response::DeliverTx;
@@ -26,6 +29,15 @@ pub(crate) mod inner {
     pub use tendermint_proto::abci::ResponseQuery;
 }
 
+pub(crate) mod inner_v038 {
+    pub use tendermint_proto_v038::v0_38::abci::ResponseCommit;
+    pub use tendermint_proto_v038::v0_38::abci::ResponseExtendVote;
+    pub use tendermint_proto_v038::v0_38::abci::ResponseFinalizeBlock;
+    pub use tendermint_proto_v038::v0_38::abci::ResponsePrepareProposal;
+    pub use tendermint_proto_v038::v0_38::abci::ResponseProcessProposal;
+    pub use tendermint_proto_v038::v0_38::abci::ResponseVerifyVoteExtension;
+}
+
 #[derive(Clone, PartialEq, Eq, ::prost::Message, serde::Serialize, serde::Deserialize)]
 pub struct ResponseFlush {}
 
@@ -60,3 +72,12 @@ impl From<ResponseCommit> for inner::ResponseCommit {
         }
     }
 }
+
+impl From<ResponseCommit> for inner_v038::ResponseCommit {
+    fn from(ResponseCommit { retain_height, .. }: ResponseCommit) -> Self {
+        // CometBFT 0.38 takes the app hash from `FinalizeBlock`
+        Self {
+            retain_height: retain_height.into(),
+        }
+    }
+}
@@ -0,0 +1,48 @@
+use bytes::Bytes;
+
+#[derive(Clone, PartialEq, Eq, ::prost::Message, serde::Serialize, serde::Deserialize)]
+pub struct ResponsePrepareProposal {
+    /// Transactions of the proposed block, in order
+    #[prost(bytes = "bytes", repeated, tag = "1")]
+    pub txs: Vec<Bytes>,
+}
+
+impl From<ResponsePrepareProposal> for super::inner_v038::ResponsePrepareProposal {
+    fn from(ResponsePrepareProposal { txs }: ResponsePrepareProposal) -> Self {
+        Self { txs }
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, ::prost::Message, serde::Serialize, serde::Deserialize)]
+pub struct ResponseProcessProposal {
+    #[prost(enumeration = "ProposalStatus", tag = "1")]
+    pub status: i32,
+}
+
+impl ResponseProcessProposal {
+    pub fn accept() -> Self {
+        Self {
+            status: ProposalStatus::Accept as i32,
+        }
+    }
+
+    pub fn reject() -> Self {
+        Self {
+            status: ProposalStatus::Reject as i32,
+        }
+    }
+}
+
+impl From<ResponseProcessProposal> for super::inner_v038::ResponseProcessProposal {
+    fn from(ResponseProcessProposal { status }: ResponseProcessProposal) -> Self {
+        Self { status }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum ProposalStatus {
+    Unknown = 0,
+    Accept = 1,
+    Reject = 2,
+}
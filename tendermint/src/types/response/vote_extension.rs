@@ -0,0 +1,48 @@
+use bytes::Bytes;
+
+#[derive(Clone, PartialEq, Eq, ::prost::Message, serde::Serialize, serde::Deserialize)]
+pub struct ResponseExtendVote {
+    #[prost(bytes = "bytes", tag = "1")]
+    pub vote_extension: Bytes,
+}
+
+impl From<ResponseExtendVote> for super::inner_v038::ResponseExtendVote {
+    fn from(ResponseExtendVote { vote_extension }: ResponseExtendVote) -> Self {
+        Self { vote_extension }
+    }
+}
+
+#[derive(Clone, PartialEq, Eq, ::prost::Message, serde::Serialize, serde::Deserialize)]
+pub struct ResponseVerifyVoteExtension {
+    #[prost(enumeration = "VerifyStatus", tag = "1")]
+    pub status: i32,
+}
+
+impl ResponseVerifyVoteExtension {
+    pub fn accept() -> Self {
+        Self {
+            status: VerifyStatus::Accept as i32,
+        }
+    }
+
+    pub fn reject() -> Self {
+        Self {
+            status: VerifyStatus::Reject as i32,
+        }
+    }
+}
+
+impl From<ResponseVerifyVoteExtension> for super::inner_v038::ResponseVerifyVoteExtension {
+    fn from(ResponseVerifyVoteExtension { status }: ResponseVerifyVoteExtension) -> Self {
+        Self { status }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum VerifyStatus {
+    Unknown = 0,
+    Accept = 1,
+    /// Rejecting an extension makes the precommit carrying it invalid, use with care
+    Reject = 2,
+}
@@ -352,12 +352,21 @@ impl TryFrom<inner::IBCProtoTimestamp> for Timestamp {
     }
 }
 
+impl TryFrom<inner::TimestampV038> for Timestamp {
+    type Error = NewTimestampError;
+
+    fn try_from(ts: inner::TimestampV038) -> Result<Self, Self::Error> {
+        Self::try_new(ts.seconds, ts.nanos)
+    }
+}
+
 impl tendermint_proto::Protobuf<Timestamp> for Timestamp {}
 impl ibc_proto::protobuf::Protobuf<Timestamp> for Timestamp {}
 
 pub mod inner {
     pub use ibc_proto::google::protobuf::Timestamp as IBCProtoTimestamp;
     pub use tendermint_proto::google::protobuf::Timestamp;
+    pub use tendermint_proto_v038::google::protobuf::Timestamp as TimestampV038;
 }
 
 #[cfg(test)]
@@ -9,6 +9,8 @@ pub enum Error {
     VersionNotFound(u32),
     #[error("cannot overwrite existing version")]
     Overwrite,
+    #[error("cannot set initial version of tree with saved versions")]
+    InitialVersion,
 }
 
 #[derive(Error, Debug, PartialEq, Eq)]
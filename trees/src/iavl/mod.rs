@@ -8,5 +8,6 @@ mod tree;
 #[allow(missing_docs)]
 mod tree_v3;
 
+pub use node_db::CacheStats;
 pub use query_tree::*;
 pub use tree::*;
@@ -1,10 +1,14 @@
 use std::{
-    collections::BTreeSet,
-    sync::{Arc, Mutex},
+    collections::{BTreeMap, BTreeSet},
+    ops::Bound,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex,
+    },
 };
 
 use caches::{Cache, DefaultHashBuilder, LRUCache};
-use database::Database;
+use database::{prefix_end_bound, BatchOp, Database};
 use extensions::corruption::UnwrapCorrupt;
 use integer_encoding::VarInt;
 
@@ -16,13 +20,48 @@ use super::{CacheSize, Node};
 pub struct NodeDB<T> {
     db: T,
     cache: Arc<Mutex<LRUCache<[u8; 32], Node, DefaultHashBuilder>>>,
+    cache_hits: Arc<AtomicU64>,
+    cache_misses: Arc<AtomicU64>,
+}
+
+/// Node cache lookups since the tree was loaded
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CacheStats {
+    pub hits: u64,
+    pub misses: u64,
+}
+
+impl CacheStats {
+    /// Share of lookups served from the cache, `0.0` if there were none
+    pub fn hit_ratio(&self) -> f64 {
+        let total = self.hits + self.misses;
+        if total == 0 {
+            return 0.0;
+        }
+
+        self.hits as f64 / total as f64
+    }
+}
+
+impl std::ops::Add for CacheStats {
+    type Output = CacheStats;
+
+    fn add(self, rhs: Self) -> Self::Output {
+        CacheStats {
+            hits: self.hits + rhs.hits,
+            misses: self.misses + rhs.misses,
+        }
+    }
 }
 
 const ROOTS_PREFIX: [u8; 1] = [1];
 const NODES_PREFIX: [u8; 1] = [2];
+/// Prefix of fast nodes: values of the latest version addressed by key
+const FAST_NODES_PREFIX: [u8; 1] = [3];
+/// Version which fast nodes reflect. Written after nodes, so interrupted update is detected
+const FAST_INDEX_VERSION_KEY: [u8; 1] = [4];
 
 // TODO: batch writes
-// TODO: fast nodes
 impl<T> NodeDB<T>
 where
     T: Database,
@@ -33,13 +72,26 @@ where
             cache: Arc::new(Mutex::new(
                 LRUCache::new(cache_size.into()).expect("won't panic since cache_size > zero"),
             )),
+            cache_hits: Default::default(),
+            cache_misses: Default::default(),
+        }
+    }
+
+    pub fn cache_stats(&self) -> CacheStats {
+        CacheStats {
+            hits: self.cache_hits.load(Ordering::Relaxed),
+            misses: self.cache_misses.load(Ordering::Relaxed),
         }
     }
 
     pub fn get_versions(&self) -> BTreeSet<u32> {
         self.db
             .prefix_iterator(ROOTS_PREFIX.into())
-            .map(|(k, _)| u32::decode_var(&k).unwrap_or_corrupt().0)
+            .map(|(k, _)| {
+                u32::decode_var(&k[ROOTS_PREFIX.len()..])
+                    .unwrap_or_corrupt()
+                    .0
+            })
             .collect()
     }
 
@@ -75,9 +127,12 @@ where
         let cache_node = cache.get(hash);
 
         if cache_node.is_some() {
+            self.cache_hits.fetch_add(1, Ordering::Relaxed);
             return cache_node.map(|v| Box::new(v.to_owned()));
         };
 
+        self.cache_misses.fetch_add(1, Ordering::Relaxed);
+
         let node_bytes = self.db.get(&Self::get_node_key(hash))?;
         let node = Node::deserialize(node_bytes).ok().unwrap_or_corrupt();
 
@@ -124,6 +179,132 @@ where
         let key = Self::get_root_key(version);
         self.db.put(key, hash.to_vec());
     }
+
+    /// Version of tree which fast nodes reflect, `None` if index doesn't exist
+    pub(crate) fn fast_index_version(&self) -> Option<u32> {
+        let bytes = self.db.get(&FAST_INDEX_VERSION_KEY)?;
+
+        Some(u32::from_be_bytes(
+            bytes.try_into().ok().unwrap_or_corrupt(),
+        ))
+    }
+
+    fn get_fast_node_key(key: &[u8]) -> Vec<u8> {
+        [FAST_NODES_PREFIX.as_slice(), key].concat()
+    }
+
+    pub(crate) fn get_fast_node(&self, key: &[u8]) -> Option<Vec<u8>> {
+        self.db.get(&Self::get_fast_node_key(key))
+    }
+
+    /// Bounds of fast nodes with keys between `start` and `end` in database
+    fn fast_node_bounds(
+        start: Bound<Vec<u8>>,
+        end: Bound<Vec<u8>>,
+    ) -> (Bound<Vec<u8>>, Bound<Vec<u8>>) {
+        let start = match start {
+            Bound::Included(start) => Bound::Included(Self::get_fast_node_key(&start)),
+            Bound::Excluded(start) => Bound::Excluded(Self::get_fast_node_key(&start)),
+            Bound::Unbounded => Bound::Included(FAST_NODES_PREFIX.to_vec()),
+        };
+        let end = match end {
+            Bound::Included(end) => Bound::Included(Self::get_fast_node_key(&end)),
+            Bound::Excluded(end) => Bound::Excluded(Self::get_fast_node_key(&end)),
+            Bound::Unbounded => prefix_end_bound(FAST_NODES_PREFIX.to_vec()),
+        };
+
+        (start, end)
+    }
+
+    /// Iterate over fast nodes with keys between `start` and `end` in ascending order.
+    /// Nodes are read lazily, iteration starts at `start` and stops at `end`
+    pub(crate) fn get_fast_nodes(
+        &self,
+        start: Bound<Vec<u8>>,
+        end: Bound<Vec<u8>>,
+    ) -> impl Iterator<Item = (Vec<u8>, Vec<u8>)> + '_ {
+        let (start, end) = Self::fast_node_bounds(start, end);
+
+        self.db
+            .range_iterator(start, end)
+            .map(|(key, value)| (key[FAST_NODES_PREFIX.len()..].to_vec(), value.into_vec()))
+    }
+
+    /// Same as [`NodeDB::get_fast_nodes`], but in descending order
+    pub(crate) fn get_fast_nodes_rev(
+        &self,
+        start: Bound<Vec<u8>>,
+        end: Bound<Vec<u8>>,
+    ) -> impl Iterator<Item = (Vec<u8>, Vec<u8>)> + '_ {
+        let (start, end) = Self::fast_node_bounds(start, end);
+
+        self.db
+            .rev_range_iterator(start, end)
+            .map(|(key, value)| (key[FAST_NODES_PREFIX.len()..].to_vec(), value.into_vec()))
+    }
+
+    /// Apply changes of saved `version` to fast nodes. Nodes and version are written at once,
+    /// so readers never see nodes of a version other than the index version
+    pub(crate) fn save_fast_nodes(
+        &self,
+        version: u32,
+        additions: &BTreeMap<Vec<u8>, Vec<u8>>,
+        removals: &BTreeSet<Vec<u8>>,
+    ) {
+        let mut batch = Vec::with_capacity(removals.len() + additions.len() + 1);
+        for key in removals {
+            batch.push(BatchOp::Delete(Self::get_fast_node_key(key)));
+        }
+
+        for (key, value) in additions {
+            batch.push(BatchOp::Put(Self::get_fast_node_key(key), value.clone()));
+        }
+
+        batch.push(BatchOp::Put(
+            FAST_INDEX_VERSION_KEY.to_vec(),
+            version.to_be_bytes().to_vec(),
+        ));
+
+        self.db.write_batch(batch);
+    }
+
+    /// Replace fast nodes with `leaves` of `version`
+    pub(crate) fn rebuild_fast_index(
+        &self,
+        version: u32,
+        leaves: impl IntoIterator<Item = (Vec<u8>, Vec<u8>)>,
+    ) {
+        self.clear_fast_index();
+
+        for (key, value) in leaves {
+            self.db.put(Self::get_fast_node_key(&key), value);
+        }
+
+        self.db.put(
+            FAST_INDEX_VERSION_KEY.to_vec(),
+            version.to_be_bytes().to_vec(),
+        );
+    }
+
+    /// Remove all fast nodes. Version is removed last, so interrupted clear is repeated on load
+    pub(crate) fn clear_fast_index(&self) {
+        let keys = self
+            .db
+            .prefix_iterator(FAST_NODES_PREFIX.to_vec())
+            .map(|(key, _)| key)
+            .collect::<Vec<_>>();
+        for key in keys {
+            self.db.delete(&key);
+        }
+
+        self.db.delete(&FAST_INDEX_VERSION_KEY);
+    }
+
+    /// Remove root of `version`. Nodes are addressed by hash and could be shared
+    /// with other versions, so they are kept.
+    pub(crate) fn delete_version(&mut self, version: u32) {
+        self.db.delete(&Self::get_root_key(version));
+    }
 }
 
 #[cfg(test)]
@@ -160,6 +341,8 @@ mod tests {
         let node_db = NodeDB {
             db,
             cache: Arc::new(Mutex::new(LRUCache::new(2).unwrap_test())),
+            cache_hits: Default::default(),
+            cache_misses: Default::default(),
         };
 
         let mut expected_versions = BTreeSet::new();
@@ -180,10 +363,25 @@ mod tests {
         let node_db = NodeDB {
             db,
             cache: Arc::new(Mutex::new(LRUCache::new(2).unwrap_test())),
+            cache_hits: Default::default(),
+            cache_misses: Default::default(),
         };
 
         let got_root_hash = node_db.get_root_hash(1).unwrap_test();
 
         assert_eq!(root_hash, got_root_hash);
     }
+
+    #[test]
+    fn cache_stats_count_hits_and_misses() {
+        let mut node_db = NodeDB::new(MemDB::new(), CacheSize::try_from(1).unwrap_test());
+        let mut root = Node::new_leaf(vec![1], vec![2], 1);
+        let hash = node_db.save_tree(&mut root);
+
+        node_db.get_node(&hash).unwrap_test();
+        node_db.get_node(&[0; 32]);
+
+        assert_eq!(CacheStats { hits: 1, misses: 1 }, node_db.cache_stats());
+        assert_eq!(0.5, node_db.cache_stats().hit_ratio());
+    }
 }
@@ -12,6 +12,8 @@ use super::{node_db::NodeDB, Node, Range, Tree};
 pub struct QueryTree<DB> {
     pub(crate) root: Option<Box<Node>>,
     pub(crate) node_db: NodeDB<DB>,
+    /// Version of the tree if fast index reflects it, so values could be read from the index
+    pub(crate) fast_version: Option<u32>,
 }
 
 impl<DB: Database> QueryTree<DB> {
@@ -26,9 +28,13 @@ impl<DB: Database> QueryTree<DB> {
                 "the requested version is in the list of versions so the node should be in the db",
             );
 
+            let fast_version =
+                (tree.node_db.fast_index_version() == Some(version)).then_some(version);
+
             Ok(QueryTree {
                 root,
                 node_db: tree.node_db.clone(),
+                fast_version,
             })
         } else {
             Err(Error::VersionNotFound(version))
@@ -37,8 +43,24 @@ impl<DB: Database> QueryTree<DB> {
 }
 
 impl<DB: Database> QueryTree<DB> {
+    /// Whether fast index still reflects queried version. Tree could save
+    /// a newer version while query tree exists, then nodes are read instead
+    fn is_fast(&self) -> bool {
+        self.fast_version.is_some() && self.node_db.fast_index_version() == self.fast_version
+    }
+
     /// Return value of key
     pub fn get(&self, key: &[u8]) -> Option<Vec<u8>> {
+        if self.fast_version.is_some() {
+            let value = self.node_db.get_fast_node(key);
+
+            // index nodes and version are written in one batch, so value read
+            // before the check is of queried version if index still reflects it
+            if self.is_fast() {
+                return value;
+            }
+        }
+
         match &self.root {
             Some(root) => self.get_(key, root),
             None => None,
@@ -94,6 +116,10 @@ impl<DB: Database> QueryTree<DB> {
 
     /// Return range iterator
     pub fn range<R: RangeBounds<RB>, RB: AsRef<[u8]>>(&self, range: R) -> Range<'_, DB, RB, R> {
+        if self.is_fast() {
+            return Range::new_fast(range, &self.node_db, self.fast_version);
+        }
+
         match &self.root {
             Some(root) => Range::new(
                 range,
@@ -142,4 +168,66 @@ mod tests {
         let expected = None;
         assert_eq!(expected, result);
     }
+
+    #[test]
+    fn query_tree_reads_fast_index_of_latest_version() {
+        let db = MemDB::new();
+        let mut tree = Tree::new(db, None, 100.try_into().unwrap_test(), None).unwrap_test();
+        tree.set(b"alice".to_vec(), b"abc".to_vec());
+        tree.set(b"bob".to_vec(), b"123".to_vec());
+        tree.save_version().unwrap_test();
+
+        let query_tree = QueryTree::new(&tree, None).unwrap_test();
+        assert_eq!(query_tree.fast_version, Some(1));
+        assert_eq!(query_tree.get(b"alice"), Some(b"abc".to_vec()));
+        assert_eq!(
+            query_tree.range(b"b".to_vec()..).collect::<Vec<_>>(),
+            vec![(b"bob".to_vec(), b"123".to_vec())]
+        );
+
+        // Index moves to the next version, so query tree reads nodes of its version
+        tree.set(b"alice".to_vec(), b"456".to_vec());
+        tree.save_version().unwrap_test();
+        assert_eq!(query_tree.get(b"alice"), Some(b"abc".to_vec()));
+        assert_eq!(
+            query_tree.range::<_, Vec<u8>>(..).next(),
+            Some((b"alice".to_vec(), b"abc".to_vec()))
+        );
+    }
+
+    #[test]
+    fn query_tree_range_falls_back_to_nodes_when_index_is_updated() {
+        let db = MemDB::new();
+        let mut tree = Tree::new(db, None, 100.try_into().unwrap_test(), None).unwrap_test();
+        for i in 0..200_u8 {
+            tree.set(vec![i], vec![i]);
+        }
+        tree.save_version().unwrap_test();
+
+        let query_tree = QueryTree::new(&tree, None).unwrap_test();
+        let mut range = query_tree.range::<_, Vec<u8>>(..);
+        let mut front = vec![range.next().unwrap_test()];
+        let mut back = vec![range.next_back().unwrap_test()];
+
+        // Index moves to the next version between batches of fast index
+        for i in 0..200_u8 {
+            tree.set(vec![i], vec![i.wrapping_add(1)]);
+        }
+        tree.set(vec![250], vec![250]);
+        tree.save_version().unwrap_test();
+
+        loop {
+            let (next, next_back) = (range.next(), range.next_back());
+            if next.is_none() && next_back.is_none() {
+                break;
+            }
+
+            front.extend(next);
+            back.extend(next_back);
+        }
+        front.extend(back.into_iter().rev());
+
+        let expected = (0..200_u8).map(|i| (vec![i], vec![i])).collect::<Vec<_>>();
+        assert_eq!(expected, front);
+    }
 }
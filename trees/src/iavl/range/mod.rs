@@ -1,6 +1,7 @@
 mod rev;
 
 use std::{
+    collections::VecDeque,
     marker::PhantomData,
     ops::{Bound, RangeBounds},
 };
@@ -16,9 +17,123 @@ pub struct Range<'a, DB, RB, R> {
     delayed_nodes: Vec<Node>,
     delayed_nodes_rev: Vec<Node>,
     node_db: &'a NodeDB<DB>,
+    fast: bool,
+    /// Version which fast index must reflect. It's checked after every batch read from index,
+    /// `None` if index can't be updated during range, e.g. range of mutable tree
+    fast_version: Option<u32>,
+    /// Cursor over fast index of the latest version, created on first access
+    fast_cursor: Option<FastCursor>,
     _marker: PhantomData<RB>,
 }
 
+/// Amount of fast nodes read from database at once
+const FAST_NODES_BATCH: usize = 64;
+
+/// Fast index was updated to another version while range was read
+#[derive(Debug)]
+struct StaleFastIndex;
+
+/// Position of range in fast index. Nodes are read by batches from both ends of range,
+/// keys between `start` and `end` aren't read yet.
+#[derive(Debug, Clone)]
+struct FastCursor {
+    start: Bound<Vec<u8>>,
+    end: Bound<Vec<u8>>,
+    /// Nodes read from the start of range in ascending order
+    front: VecDeque<(Vec<u8>, Vec<u8>)>,
+    /// Nodes read from the end of range in descending order
+    back: VecDeque<(Vec<u8>, Vec<u8>)>,
+}
+
+impl FastCursor {
+    fn new<R: RangeBounds<RB>, RB: AsRef<[u8]>>(range: &R) -> Self {
+        let owned = |bound: Bound<&RB>| match bound {
+            Bound::Included(key) => Bound::Included(key.as_ref().to_vec()),
+            Bound::Excluded(key) => Bound::Excluded(key.as_ref().to_vec()),
+            Bound::Unbounded => Bound::Unbounded,
+        };
+
+        Self {
+            start: owned(range.start_bound()),
+            end: owned(range.end_bound()),
+            front: VecDeque::new(),
+            back: VecDeque::new(),
+        }
+    }
+
+    fn after_start(&self, key: &[u8]) -> bool {
+        match &self.start {
+            Bound::Included(start) => key >= start.as_slice(),
+            Bound::Excluded(start) => key > start.as_slice(),
+            Bound::Unbounded => true,
+        }
+    }
+
+    fn before_end(&self, key: &[u8]) -> bool {
+        match &self.end {
+            Bound::Included(end) => key <= end.as_slice(),
+            Bound::Excluded(end) => key < end.as_slice(),
+            Bound::Unbounded => true,
+        }
+    }
+
+    /// Check that batch read from index belongs to `version`. Nodes and version of index
+    /// are written in one batch, so nodes read before the check are of `version`
+    /// if the index still reflects it.
+    fn check_version<DB: Database>(
+        node_db: &NodeDB<DB>,
+        version: Option<u32>,
+    ) -> Result<(), StaleFastIndex> {
+        match version {
+            Some(version) if node_db.fast_index_version() != Some(version) => Err(StaleFastIndex),
+            _ => Ok(()),
+        }
+    }
+
+    fn next<DB: Database>(
+        &mut self,
+        node_db: &NodeDB<DB>,
+        version: Option<u32>,
+    ) -> Result<Option<(Vec<u8>, Vec<u8>)>, StaleFastIndex> {
+        if self.front.is_empty() {
+            let front: VecDeque<_> = node_db
+                .get_fast_nodes(self.start.clone(), self.end.clone())
+                .take(FAST_NODES_BATCH)
+                .collect();
+            Self::check_version(node_db, version)?;
+
+            if let Some((key, _)) = front.back() {
+                self.start = Bound::Excluded(key.clone());
+            }
+            self.front = front;
+        }
+
+        // nodes at the end of range could be already read by `next_back`
+        Ok(self.front.pop_front().or_else(|| self.back.pop_back()))
+    }
+
+    fn next_back<DB: Database>(
+        &mut self,
+        node_db: &NodeDB<DB>,
+        version: Option<u32>,
+    ) -> Result<Option<(Vec<u8>, Vec<u8>)>, StaleFastIndex> {
+        if self.back.is_empty() {
+            let back: VecDeque<_> = node_db
+                .get_fast_nodes_rev(self.start.clone(), self.end.clone())
+                .take(FAST_NODES_BATCH)
+                .collect();
+            Self::check_version(node_db, version)?;
+
+            if let Some((key, _)) = back.back() {
+                self.end = Bound::Excluded(key.clone());
+            }
+            self.back = back;
+        }
+
+        Ok(self.back.pop_front().or_else(|| self.front.pop_back()))
+    }
+}
+
 impl<'a, DB, RB, R> Range<'a, DB, RB, R> {
     pub(crate) fn new(range: R, root: Option<Box<Node>>, node_db: &'a NodeDB<DB>) -> Self {
         Self {
@@ -26,12 +141,75 @@ impl<'a, DB, RB, R> Range<'a, DB, RB, R> {
             delayed_nodes: root.clone().map(|this| vec![*this]).unwrap_or_default(),
             delayed_nodes_rev: root.map(|this| vec![*this]).unwrap_or_default(),
             node_db,
+            fast: false,
+            fast_version: None,
+            fast_cursor: None,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Range over fast index instead of tree nodes. If index stops reflecting `version`
+    /// during range, the rest of range is read from nodes of `version`
+    pub(crate) fn new_fast(range: R, node_db: &'a NodeDB<DB>, version: Option<u32>) -> Self {
+        Self {
+            range,
+            delayed_nodes: Vec::new(),
+            delayed_nodes_rev: Vec::new(),
+            node_db,
+            fast: true,
+            fast_version: version,
+            fast_cursor: None,
             _marker: PhantomData,
         }
     }
 }
 
 impl<'a, DB: Database, R: RangeBounds<RB>, RB: AsRef<[u8]>> Range<'a, DB, RB, R> {
+    fn fast_cursor(&mut self) -> &mut FastCursor {
+        self.fast_cursor
+            .get_or_insert_with(|| FastCursor::new(&self.range))
+    }
+
+    /// Switch range to nodes of version which fast index doesn't reflect anymore.
+    /// Nodes already read from index are kept in cursor and returned first.
+    fn fall_back_to_nodes(&mut self) {
+        self.fast = false;
+
+        let root = self
+            .fast_version
+            .and_then(|version| {
+                self.node_db
+                    .get_root_node(version)
+                    .expect("version of fast index should be saved in node db")
+            })
+            .map(|root| *root);
+        self.delayed_nodes = root.clone().into_iter().collect();
+        self.delayed_nodes_rev = root.into_iter().collect();
+    }
+
+    /// Next node after fast index fall back. Nodes between bounds of cursor are read from tree,
+    /// nodes out of them are already returned or kept in cursor.
+    fn traverse_after_fast(&mut self) -> Option<(Vec<u8>, Vec<u8>)> {
+        let cursor = self.fast_cursor.as_mut()?;
+        if let Some(next) = cursor.front.pop_front() {
+            return Some(next);
+        }
+
+        while let Some((key, value)) = self.traverse() {
+            let cursor = self.fast_cursor.as_mut()?;
+            if !cursor.before_end(&key) {
+                break;
+            }
+
+            if cursor.after_start(&key) {
+                cursor.start = Bound::Excluded(key.clone());
+                return Some((key, value));
+            }
+        }
+
+        self.fast_cursor.as_mut()?.back.pop_back()
+    }
+
     fn traverse(&mut self) -> Option<(Vec<u8>, Vec<u8>)> {
         let node = self.delayed_nodes.pop()?;
 
@@ -97,7 +275,18 @@ impl<'a, DB: Database, R: RangeBounds<RB>, RB: AsRef<[u8]>> Iterator for Range<'
     type Item = (Vec<u8>, Vec<u8>);
 
     fn next(&mut self) -> Option<Self::Item> {
-        self.traverse()
+        if self.fast {
+            let (node_db, version) = (self.node_db, self.fast_version);
+            match self.fast_cursor().next(node_db, version) {
+                Ok(next) => return next,
+                Err(StaleFastIndex) => self.fall_back_to_nodes(),
+            }
+        }
+
+        match self.fast_cursor {
+            Some(_) => self.traverse_after_fast(),
+            None => self.traverse(),
+        }
     }
 }
 
@@ -111,6 +300,34 @@ mod tests {
 
     use super::*;
 
+    #[test]
+    fn fast_range_reads_both_ends_by_batches() {
+        let db = MemDB::new();
+        let mut tree = Tree::new(db, None, 100.try_into().unwrap_test(), None).unwrap_test();
+
+        let expected_array = (0..200_u8).map(|i| (vec![i], vec![i])).collect::<Vec<_>>();
+        for (key, value) in &expected_array {
+            tree.set(key.clone(), value.clone());
+        }
+        tree.save_version().unwrap_test();
+
+        let mut range = tree.range(vec![10_u8]..vec![190]);
+        let mut front = Vec::new();
+        let mut back = Vec::new();
+        loop {
+            let (next, next_back) = (range.next(), range.next_back());
+            if next.is_none() && next_back.is_none() {
+                break;
+            }
+
+            front.extend(next);
+            back.extend(next_back);
+        }
+        front.extend(back.into_iter().rev());
+
+        assert_eq!(expected_array[10..190].to_vec(), front)
+    }
+
     #[test]
     fn empty_range() {
         let db = MemDB::new();
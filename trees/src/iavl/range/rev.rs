@@ -57,13 +57,46 @@ impl<DB: Database, R: RangeBounds<RB>, RB: AsRef<[u8]>> Range<'_, DB, RB, R> {
 
         self.traverse_rev()
     }
+
+    /// Same as [`Range::traverse_after_fast`], but in descending order
+    fn traverse_rev_after_fast(&mut self) -> Option<(Vec<u8>, Vec<u8>)> {
+        let cursor = self.fast_cursor.as_mut()?;
+        if let Some(next) = cursor.back.pop_front() {
+            return Some(next);
+        }
+
+        while let Some((key, value)) = self.traverse_rev() {
+            let cursor = self.fast_cursor.as_mut()?;
+            if !cursor.after_start(&key) {
+                break;
+            }
+
+            if cursor.before_end(&key) {
+                cursor.end = Bound::Excluded(key.clone());
+                return Some((key, value));
+            }
+        }
+
+        self.fast_cursor.as_mut()?.front.pop_back()
+    }
 }
 
 impl<DB: Database, R: RangeBounds<RB>, RB: AsRef<[u8]>> DoubleEndedIterator
     for Range<'_, DB, RB, R>
 {
     fn next_back(&mut self) -> Option<Self::Item> {
-        self.traverse_rev()
+        if self.fast {
+            let (node_db, version) = (self.node_db, self.fast_version);
+            match self.fast_cursor().next_back(node_db, version) {
+                Ok(next) => return next,
+                Err(StaleFastIndex) => self.fall_back_to_nodes(),
+            }
+        }
+
+        match self.fast_cursor {
+            Some(_) => self.traverse_rev_after_fast(),
+            None => self.traverse_rev(),
+        }
     }
 }
 
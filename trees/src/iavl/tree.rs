@@ -1,8 +1,9 @@
 use std::{
     cmp::{self, Ordering},
-    collections::BTreeSet,
+    collections::{BTreeMap, BTreeSet},
     mem,
     num::NonZero,
+    ops::Bound,
 };
 
 use database::Database;
@@ -17,7 +18,7 @@ use crate::{
     Error,
 };
 
-use super::node_db::NodeDB;
+use super::node_db::{CacheStats, NodeDB};
 
 pub use crate::iavl::range::*;
 
@@ -518,6 +519,11 @@ pub struct Tree<T> {
     pub(crate) node_db: NodeDB<T>,
     pub(crate) loaded_version: u32,
     pub(crate) versions: BTreeSet<u32>,
+    /// Fast index reflects loaded version, so reads of it don't traverse nodes
+    fast_index: bool,
+    /// Changes since loaded version which are applied to fast index on save
+    unsaved_fast_additions: BTreeMap<Vec<u8>, Vec<u8>>,
+    unsaved_fast_removals: BTreeSet<Vec<u8>>,
     _name: Option<String>,
 }
 
@@ -538,39 +544,60 @@ where
         let node_db = NodeDB::new(db, cache_size);
         let versions = node_db.get_versions();
 
-        if let Some(target_version) = target_version {
-            let root = node_db.get_root_node(target_version.get())?;
-
-            Ok(Tree {
-                root,
-                loaded_version: target_version.get(),
-                node_db,
-                versions,
-                _name: name,
-            })
-        } else {
+        let (root, loaded_version) = match target_version {
+            Some(target_version) => (
+                node_db.get_root_node(target_version.get())?,
+                target_version.get(),
+            ),
             // use the latest version available
-            if let Some(latest_version) = versions.last() {
-                Ok(Tree {
-                    root: node_db
+            None => match versions.last() {
+                Some(latest_version) => (
+                    node_db
                         .get_root_node(*latest_version)
                         .ok()
                         .unwrap_or_corrupt(),
-                    loaded_version: *latest_version,
-                    node_db,
-                    versions,
-                    _name: name,
-                })
-            } else {
-                Ok(Tree {
-                    root: None,
-                    loaded_version: 0,
-                    node_db,
-                    versions,
-                    _name: name,
-                })
+                    *latest_version,
+                ),
+                None => (None, 0),
+            },
+        };
+
+        let mut tree = Tree {
+            root,
+            loaded_version,
+            node_db,
+            versions,
+            fast_index: false,
+            unsaved_fast_additions: BTreeMap::new(),
+            unsaved_fast_removals: BTreeSet::new(),
+            _name: name,
+        };
+        tree.load_fast_index();
+
+        Ok(tree)
+    }
+
+    /// Enable fast index if it reflects loaded version. Index of the latest version is
+    /// rebuilt if it's missing, e.g. in database created before the index, or outdated.
+    /// Trees loaded at older version read nodes.
+    fn load_fast_index(&mut self) {
+        let latest_version = self.versions.last().copied().unwrap_or_default();
+        let index_version = self.node_db.fast_index_version();
+
+        self.fast_index = match index_version {
+            Some(version) if version == self.loaded_version => true,
+            _ if self.loaded_version != latest_version => false,
+            None if latest_version == 0 => true,
+            _ if latest_version == 0 => {
+                self.node_db.clear_fast_index();
+                true
             }
-        }
+            _ => {
+                let leaves = Range::<_, Vec<u8>, _>::new(.., self.root.clone(), &self.node_db);
+                self.node_db.rebuild_fast_index(latest_version, leaves);
+                true
+            }
+        };
     }
 
     /// Save the current tree to disk.
@@ -610,12 +637,62 @@ where
             EMPTY_HASH
         };
 
+        if self.fast_index {
+            self.node_db.save_fast_nodes(
+                version,
+                &self.unsaved_fast_additions,
+                &self.unsaved_fast_removals,
+            );
+        }
+        self.unsaved_fast_additions.clear();
+        self.unsaved_fast_removals.clear();
+
         self.versions.insert(version);
 
         self.loaded_version = version;
         Ok((root_hash, self.loaded_version))
     }
 
+    /// Start versions of empty tree from `version`, so the next saved version is `version + 1`.
+    /// Used for trees which are added to existing multi store at upgrade height.
+    pub fn set_initial_version(&mut self, version: u32) -> Result<(), Error> {
+        if !self.versions.is_empty() {
+            return Err(Error::InitialVersion);
+        }
+
+        self.loaded_version = version;
+        Ok(())
+    }
+
+    /// Delete all versions after `version` and load the tree at `version`. Used to drop
+    /// versions which weren't committed by multi store and to roll back state.
+    /// If no version up to `version` exists, the tree becomes empty.
+    pub fn delete_versions_after(&mut self, version: u32) -> Result<(), Error> {
+        let (root, loaded_version) = match self.versions.range(..=version).next_back() {
+            Some(last) if *last == version => (self.node_db.get_root_node(version)?, version),
+            Some(_) => return Err(Error::VersionNotFound(version)),
+            None => (None, 0),
+        };
+
+        let stale = self
+            .versions
+            .range((Bound::Excluded(version), Bound::Unbounded))
+            .copied()
+            .collect::<Vec<_>>();
+        for stale_version in stale {
+            self.node_db.delete_version(stale_version);
+            self.versions.remove(&stale_version);
+        }
+
+        self.root = root;
+        self.loaded_version = loaded_version;
+        self.unsaved_fast_additions.clear();
+        self.unsaved_fast_removals.clear();
+        self.load_fast_index();
+
+        Ok(())
+    }
+
     /// Return hash of root
     pub fn root_hash(&self) -> [u8; 32] {
         match &self.root {
@@ -624,6 +701,11 @@ where
         }
     }
 
+    /// Return node cache statistics
+    pub fn cache_stats(&self) -> CacheStats {
+        self.node_db.cache_stats()
+    }
+
     /// Return root version
     pub fn loaded_version(&self) -> u32 {
         self.loaded_version
@@ -631,6 +713,18 @@ where
 
     /// Return value of key
     pub fn get(&self, key: &[u8]) -> Option<Vec<u8>> {
+        if self.fast_index {
+            if let Some(value) = self.unsaved_fast_additions.get(key) {
+                return Some(value.clone());
+            }
+
+            if self.unsaved_fast_removals.contains(key) {
+                return None;
+            }
+
+            return self.node_db.get_fast_node(key);
+        }
+
         match &self.root {
             Some(root) => self.get_(key, root),
             None => None,
@@ -685,6 +779,17 @@ where
 
     /// Remove key from tree
     pub fn remove(&mut self, key: &(impl AsRef<[u8]> + ?Sized)) -> Option<Vec<u8>> {
+        let value = self.remove_(key);
+
+        if self.fast_index && value.is_some() {
+            self.unsaved_fast_additions.remove(key.as_ref());
+            self.unsaved_fast_removals.insert(key.as_ref().to_vec());
+        }
+
+        value
+    }
+
+    fn remove_(&mut self, key: &(impl AsRef<[u8]> + ?Sized)) -> Option<Vec<u8>> {
         // We use this struct to be 100% sure in output of `recursive_remove`
         struct NodeKey(pub Vec<u8>);
         struct NodeValue(pub Vec<u8>);
@@ -836,6 +941,12 @@ where
 
     /// Set(with overwrite) key with value
     pub fn set(&mut self, key: Vec<u8>, value: Vec<u8>) {
+        if self.fast_index {
+            self.unsaved_fast_removals.remove(&key);
+            self.unsaved_fast_additions
+                .insert(key.clone(), value.clone());
+        }
+
         match &mut self.root {
             Some(root) => {
                 Self::recursive_set(root, key, value, self.loaded_version + 1, &mut self.node_db)
@@ -960,8 +1071,15 @@ where
         }
     }
 
-    /// Return range iterator over tree
+    /// Return range iterator over tree. Reads fast index if there are no unsaved changes
     pub fn range<R, RB>(&self, range: R) -> Range<'_, T, RB, R> {
+        if self.fast_index
+            && self.unsaved_fast_additions.is_empty()
+            && self.unsaved_fast_removals.is_empty()
+        {
+            return Range::new_fast(range, &self.node_db, None);
+        }
+
         match &self.root {
             Some(root) => Range::new(
                 range,
@@ -1481,6 +1599,103 @@ mod tests {
         assert_eq!(expected, tree.root_hash());
     }
 
+    #[test]
+    fn set_initial_version_works() {
+        let db = MemDB::new();
+        let mut tree = Tree::new(db, None, 100.try_into().unwrap_test(), None).unwrap_test();
+
+        tree.set_initial_version(10).unwrap_test();
+        tree.set(b"alice".to_vec(), b"abc".to_vec());
+        let (_, version) = tree.save_version().unwrap_test();
+
+        assert_eq!(version, 11);
+        assert_eq!(tree.set_initial_version(20), Err(Error::InitialVersion));
+    }
+
+    #[test]
+    fn delete_versions_after_works() {
+        let db = MemDB::new();
+        let mut tree =
+            Tree::new(db.clone(), None, 100.try_into().unwrap_test(), None).unwrap_test();
+        tree.set(b"alice".to_vec(), b"abc".to_vec());
+        let (hash, _) = tree.save_version().unwrap_test();
+        tree.set(b"bob".to_vec(), b"123".to_vec());
+        tree.save_version().unwrap_test();
+
+        tree.delete_versions_after(1).unwrap_test();
+
+        assert_eq!(tree.loaded_version(), 1);
+        assert_eq!(tree.root_hash(), hash);
+        assert_eq!(tree.get(b"bob"), None);
+
+        let tree = Tree::new(db, None, 100.try_into().unwrap_test(), None).unwrap_test();
+        assert_eq!(tree.loaded_version(), 1);
+        assert_eq!(tree.root_hash(), hash);
+    }
+
+    #[test]
+    fn fast_index_serves_latest_version() {
+        let db = MemDB::new();
+        let mut tree =
+            Tree::new(db.clone(), None, 100.try_into().unwrap_test(), None).unwrap_test();
+        tree.set(b"alice".to_vec(), b"abc".to_vec());
+        tree.set(b"bob".to_vec(), b"123".to_vec());
+        tree.set(b"carol".to_vec(), b"1".to_vec());
+        tree.save_version().unwrap_test();
+
+        assert_eq!(tree.node_db.fast_index_version(), Some(1));
+        assert_eq!(
+            tree.range(b"b".to_vec()..).collect::<Vec<_>>(),
+            vec![
+                (b"bob".to_vec(), b"123".to_vec()),
+                (b"carol".to_vec(), b"1".to_vec())
+            ]
+        );
+        assert_eq!(
+            tree.range::<_, Vec<u8>>(..).rev().next(),
+            Some((b"carol".to_vec(), b"1".to_vec()))
+        );
+
+        // Unsaved changes are visible before they reach the index
+        tree.remove(b"alice");
+        tree.set(b"bob".to_vec(), b"456".to_vec());
+        assert_eq!(tree.get(b"alice"), None);
+        assert_eq!(tree.get(b"bob"), Some(b"456".to_vec()));
+        assert_eq!(tree.node_db.get_fast_node(b"alice"), Some(b"abc".to_vec()));
+
+        tree.save_version().unwrap_test();
+        assert_eq!(tree.node_db.get_fast_node(b"alice"), None);
+        assert_eq!(tree.node_db.get_fast_node(b"bob"), Some(b"456".to_vec()));
+        assert_eq!(
+            tree.range::<_, Vec<u8>>(..).collect::<Vec<_>>(),
+            vec![
+                (b"bob".to_vec(), b"456".to_vec()),
+                (b"carol".to_vec(), b"1".to_vec())
+            ]
+        );
+    }
+
+    #[test]
+    fn fast_index_is_rebuilt_if_missing() {
+        let db = MemDB::new();
+        let mut tree =
+            Tree::new(db.clone(), None, 100.try_into().unwrap_test(), None).unwrap_test();
+        tree.set(b"alice".to_vec(), b"abc".to_vec());
+        tree.save_version().unwrap_test();
+        tree.set(b"bob".to_vec(), b"123".to_vec());
+        tree.save_version().unwrap_test();
+        tree.node_db.clear_fast_index();
+
+        let tree = Tree::new(db.clone(), None, 100.try_into().unwrap_test(), None).unwrap_test();
+        assert_eq!(tree.node_db.fast_index_version(), Some(2));
+        assert_eq!(tree.get(b"bob"), Some(b"123".to_vec()));
+
+        // Older version reads nodes and keeps index of the latest one
+        let tree = Tree::new(db, NonZero::new(1), 100.try_into().unwrap_test(), None).unwrap_test();
+        assert_eq!(tree.get(b"bob"), None);
+        assert_eq!(tree.node_db.fast_index_version(), Some(2));
+    }
+
     #[test]
     fn get_works() {
         let db = MemDB::new();
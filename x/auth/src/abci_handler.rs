@@ -12,6 +12,7 @@ use gears::params::ParamsSubspaceKey;
 use gears::store::database::Database;
 use gears::store::StoreKey;
 use gears::tendermint::types::request::query::RequestQuery;
+use gears::types::account::BaseAccount;
 use gears::types::pagination::response::PaginationResponse;
 use gears::types::tx::raw::TxWithRaw;
 use gears::types::tx::NullTxMsg;
@@ -20,7 +21,11 @@ use gears::x::module::Module;
 use serde::Serialize;
 
 use crate::types::query::{
-    QueryAccountRequest, QueryAccountResponse, QueryAccountsRequest, QueryAccountsResponse,
+    AddressBytesToStringRequest, AddressBytesToStringResponse, AddressStringToBytesRequest,
+    AddressStringToBytesResponse, Bech32PrefixRequest, Bech32PrefixResponse,
+    QueryAccountInfoRequest, QueryAccountInfoResponse, QueryAccountRequest, QueryAccountResponse,
+    QueryAccountsRequest, QueryAccountsResponse, QueryModuleAccountByNameRequest,
+    QueryModuleAccountByNameResponse, QueryModuleAccountsRequest, QueryModuleAccountsResponse,
     QueryParamsRequest, QueryParamsResponse,
 };
 use crate::{GenesisState, Keeper};
@@ -31,6 +36,12 @@ pub enum AuthNodeQueryRequest {
     Account(QueryAccountRequest),
     Accounts(QueryAccountsRequest),
     Params(QueryParamsRequest),
+    ModuleAccounts(QueryModuleAccountsRequest),
+    ModuleAccountByName(QueryModuleAccountByNameRequest),
+    Bech32Prefix(Bech32PrefixRequest),
+    AddressBytesToString(AddressBytesToStringRequest),
+    AddressStringToBytes(AddressStringToBytesRequest),
+    AccountInfo(QueryAccountInfoRequest),
 }
 
 impl QueryRequest for AuthNodeQueryRequest {
@@ -46,6 +57,12 @@ pub enum AuthNodeQueryResponse {
     Account(QueryAccountResponse),
     Accounts(QueryAccountsResponse),
     Params(QueryParamsResponse),
+    ModuleAccounts(QueryModuleAccountsResponse),
+    ModuleAccountByName(QueryModuleAccountByNameResponse),
+    Bech32Prefix(Bech32PrefixResponse),
+    AddressBytesToString(AddressBytesToStringResponse),
+    AddressStringToBytes(AddressStringToBytesResponse),
+    AccountInfo(QueryAccountInfoResponse),
 }
 
 #[derive(Debug, Clone)]
@@ -82,6 +99,26 @@ impl<SK: StoreKey, PSK: ParamsSubspaceKey, M: Module> ABCIHandler for AuthABCIHa
                 let res = self.query_params(ctx, req);
                 AuthNodeQueryResponse::Params(res)
             }
+            AuthNodeQueryRequest::ModuleAccounts(req) => {
+                AuthNodeQueryResponse::ModuleAccounts(self.query_module_accounts(ctx, req))
+            }
+            AuthNodeQueryRequest::ModuleAccountByName(req) => {
+                AuthNodeQueryResponse::ModuleAccountByName(
+                    self.query_module_account_by_name(ctx, req),
+                )
+            }
+            AuthNodeQueryRequest::Bech32Prefix(req) => {
+                AuthNodeQueryResponse::Bech32Prefix(self.query_bech32_prefix(req))
+            }
+            AuthNodeQueryRequest::AddressBytesToString(req) => {
+                AuthNodeQueryResponse::AddressBytesToString(self.query_address_bytes_to_string(req))
+            }
+            AuthNodeQueryRequest::AddressStringToBytes(req) => {
+                AuthNodeQueryResponse::AddressStringToBytes(self.query_address_string_to_bytes(req))
+            }
+            AuthNodeQueryRequest::AccountInfo(req) => {
+                AuthNodeQueryResponse::AccountInfo(self.query_account_info(ctx, req))
+            }
         }
     }
 
@@ -133,6 +170,36 @@ impl<SK: StoreKey, PSK: ParamsSubspaceKey, M: Module> ABCIHandler for AuthABCIHa
 
                 Ok(self.query_params(ctx, req).encode_vec())
             }
+            QueryModuleAccountsRequest::QUERY_URL => {
+                let req = QueryModuleAccountsRequest::decode(query.data)?;
+
+                Ok(self.query_module_accounts(ctx, req).encode_vec())
+            }
+            QueryModuleAccountByNameRequest::QUERY_URL => {
+                let req = QueryModuleAccountByNameRequest::decode(query.data)?;
+
+                Ok(self.query_module_account_by_name(ctx, req).encode_vec())
+            }
+            Bech32PrefixRequest::QUERY_URL => {
+                let req = Bech32PrefixRequest::decode(query.data)?;
+
+                Ok(self.query_bech32_prefix(req).encode_vec())
+            }
+            AddressBytesToStringRequest::QUERY_URL => {
+                let req = AddressBytesToStringRequest::decode(query.data)?;
+
+                Ok(self.query_address_bytes_to_string(req).encode_vec())
+            }
+            AddressStringToBytesRequest::QUERY_URL => {
+                let req = AddressStringToBytesRequest::decode(query.data)?;
+
+                Ok(self.query_address_string_to_bytes(req).encode_vec())
+            }
+            QueryAccountInfoRequest::QUERY_URL => {
+                let req = QueryAccountInfoRequest::decode(query.data)?;
+
+                Ok(self.query_account_info(ctx, req).encode_vec())
+            }
             _ => Err(QueryError::PathNotFound),
         }
     }
@@ -175,4 +242,67 @@ impl<SK: StoreKey, PSK: ParamsSubspaceKey, M: Module> AuthABCIHandler<SK, PSK, M
             params: self.keeper.get_auth_params(ctx).unwrap_gas(),
         }
     }
+
+    pub fn query_module_accounts<DB: Database>(
+        &self,
+        ctx: &QueryContext<DB, SK>,
+        _req: QueryModuleAccountsRequest,
+    ) -> QueryModuleAccountsResponse {
+        QueryModuleAccountsResponse {
+            accounts: self.keeper.module_accounts(ctx),
+        }
+    }
+
+    pub fn query_module_account_by_name<DB: Database>(
+        &self,
+        ctx: &QueryContext<DB, SK>,
+        QueryModuleAccountByNameRequest { name }: QueryModuleAccountByNameRequest,
+    ) -> QueryModuleAccountByNameResponse {
+        QueryModuleAccountByNameResponse {
+            account: self.keeper.module_account_by_name(ctx, &name),
+        }
+    }
+
+    pub fn query_bech32_prefix(&self, _req: Bech32PrefixRequest) -> Bech32PrefixResponse {
+        Bech32PrefixResponse {
+            bech32_prefix: gears::types::address::bech32_prefixes().account.clone(),
+        }
+    }
+
+    pub fn query_address_bytes_to_string(
+        &self,
+        AddressBytesToStringRequest { address_bytes }: AddressBytesToStringRequest,
+    ) -> AddressBytesToStringResponse {
+        AddressBytesToStringResponse {
+            address_string: address_bytes,
+        }
+    }
+
+    pub fn query_address_string_to_bytes(
+        &self,
+        AddressStringToBytesRequest { address_string }: AddressStringToBytesRequest,
+    ) -> AddressStringToBytesResponse {
+        AddressStringToBytesResponse {
+            address_bytes: address_string.into(),
+        }
+    }
+
+    pub fn query_account_info<DB: Database>(
+        &self,
+        ctx: &QueryContext<DB, SK>,
+        QueryAccountInfoRequest { address }: QueryAccountInfoRequest,
+    ) -> QueryAccountInfoResponse {
+        let info = self
+            .keeper
+            .get_account(ctx, &address)
+            .unwrap_gas()
+            .map(|account| BaseAccount {
+                address: account.get_address().clone(),
+                pub_key: account.get_public_key().cloned(),
+                account_number: account.get_account_number(),
+                sequence: account.get_sequence(),
+            });
+
+        QueryAccountInfoResponse { info }
+    }
 }
@@ -1,7 +1,4 @@
-use gears::{
-    baseapp::{NodeQueryHandler, QueryRequest, QueryResponse},
-    types::address::AccAddress,
-};
+use gears::baseapp::{NodeQueryHandler, QueryRequest, QueryResponse};
 use ibc_proto::cosmos::auth::v1beta1::{
     query_server::{Query, QueryServer},
     AddressBytesToStringRequest, AddressBytesToStringResponse, AddressStringToBytesRequest,
@@ -89,59 +86,77 @@ where
 
     async fn module_accounts(
         &self,
-        _request: Request<QueryModuleAccountsRequest>,
+        request: Request<QueryModuleAccountsRequest>,
     ) -> Result<Response<QueryModuleAccountsResponse>, Status> {
-        // TODO: Discuss implementation of this. Easiest way is to implement them with strum enum iterator
-        Err(Status::internal(UNIMPLEMENTED_MSG))
+        info!("Received a gRPC request auth::module_accounts");
+        let req = AuthNodeQueryRequest::ModuleAccounts(request.into_inner().try_into()?);
+        let response: AuthNodeQueryResponse = self.app.typed_query(req)?.try_into()?;
+        let AuthNodeQueryResponse::ModuleAccounts(response) = response else {
+            return Err(Status::internal(ERROR_STATE_MSG));
+        };
+        Ok(Response::new(response.into()))
     }
 
     async fn module_account_by_name(
         &self,
-        _request: Request<QueryModuleAccountByNameRequest>,
+        request: Request<QueryModuleAccountByNameRequest>,
     ) -> Result<Response<QueryModuleAccountByNameResponse>, Status> {
-        // TODO: Discuss implementation of this. Easiest way is to implement them with strum enum iterator
-        Err(Status::internal(UNIMPLEMENTED_MSG))
+        info!("Received a gRPC request auth::module_account_by_name");
+        let req = AuthNodeQueryRequest::ModuleAccountByName(request.into_inner().try_into()?);
+        let response: AuthNodeQueryResponse = self.app.typed_query(req)?.try_into()?;
+        let AuthNodeQueryResponse::ModuleAccountByName(response) = response else {
+            return Err(Status::internal(ERROR_STATE_MSG));
+        };
+        Ok(Response::new(response.into()))
     }
 
     async fn bech32_prefix(
         &self,
-        _request: Request<Bech32PrefixRequest>,
+        request: Request<Bech32PrefixRequest>,
     ) -> Result<Response<Bech32PrefixResponse>, Status> {
-        Ok(Response::new(Bech32PrefixResponse {
-            bech32_prefix: std::env!("BECH_32_MAIN_PREFIX").to_owned(),
-        }))
+        info!("Received a gRPC request auth::bech32_prefix");
+        let req = AuthNodeQueryRequest::Bech32Prefix(request.into_inner().try_into()?);
+        let response: AuthNodeQueryResponse = self.app.typed_query(req)?.try_into()?;
+        let AuthNodeQueryResponse::Bech32Prefix(response) = response else {
+            return Err(Status::internal(ERROR_STATE_MSG));
+        };
+        Ok(Response::new(response.into()))
     }
 
     async fn address_bytes_to_string(
         &self,
         request: Request<AddressBytesToStringRequest>,
     ) -> Result<Response<AddressBytesToStringResponse>, Status> {
-        let AddressBytesToStringRequest { address_bytes } = request.into_inner();
-
-        let address_string = match AccAddress::try_from(address_bytes) {
-            Ok(addr) => addr.to_string(),
-            Err(e) => Err(Status::invalid_argument(format!("Invalid address: {e}")))?,
+        info!("Received a gRPC request auth::address_bytes_to_string");
+        let req = AuthNodeQueryRequest::AddressBytesToString(
+            request
+                .into_inner()
+                .try_into()
+                .map_err(|e| Status::invalid_argument(format!("Invalid address: {e}")))?,
+        );
+        let response: AuthNodeQueryResponse = self.app.typed_query(req)?.try_into()?;
+        let AuthNodeQueryResponse::AddressBytesToString(response) = response else {
+            return Err(Status::internal(ERROR_STATE_MSG));
         };
-
-        Ok(Response::new(AddressBytesToStringResponse {
-            address_string,
-        }))
+        Ok(Response::new(response.into()))
     }
 
     async fn address_string_to_bytes(
         &self,
         request: Request<AddressStringToBytesRequest>,
     ) -> Result<Response<AddressStringToBytesResponse>, Status> {
-        let AddressStringToBytesRequest { address_string } = request.into_inner();
-
-        let address_bytes: Vec<u8> = match AccAddress::from_bech32(&address_string) {
-            Ok(addr) => addr.into(),
-            Err(e) => Err(Status::invalid_argument(format!("Invalid address: {e}")))?,
+        info!("Received a gRPC request auth::address_string_to_bytes");
+        let req = AuthNodeQueryRequest::AddressStringToBytes(
+            request
+                .into_inner()
+                .try_into()
+                .map_err(|e| Status::invalid_argument(format!("Invalid address: {e}")))?,
+        );
+        let response: AuthNodeQueryResponse = self.app.typed_query(req)?.try_into()?;
+        let AuthNodeQueryResponse::AddressStringToBytes(response) = response else {
+            return Err(Status::internal(ERROR_STATE_MSG));
         };
-
-        Ok(Response::new(AddressStringToBytesResponse {
-            address_bytes,
-        }))
+        Ok(Response::new(response.into()))
     }
 }
 
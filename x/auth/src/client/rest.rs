@@ -1,5 +1,9 @@
 use crate::{
-    query::{QueryAccountRequest, QueryAccountsRequest, QueryParamsRequest},
+    query::{
+        AddressBytesToStringRequest, AddressStringToBytesRequest, Bech32PrefixRequest,
+        QueryAccountInfoRequest, QueryAccountRequest, QueryAccountsRequest,
+        QueryModuleAccountByNameRequest, QueryModuleAccountsRequest, QueryParamsRequest,
+    },
     AuthNodeQueryRequest, AuthNodeQueryResponse,
 };
 use axum::{
@@ -59,6 +63,91 @@ pub async fn params<
     Ok(Json(res))
 }
 
+/// Get all module accounts.
+pub async fn module_accounts<
+    QReq: QueryRequest + From<AuthNodeQueryRequest>,
+    QRes: QueryResponse + TryInto<AuthNodeQueryResponse>,
+    App: NodeQueryHandler<QReq, QRes>,
+>(
+    State(rest_state): State<RestState<QReq, QRes, App>>,
+) -> Result<Json<QRes>, HTTPError> {
+    let req = AuthNodeQueryRequest::ModuleAccounts(QueryModuleAccountsRequest {});
+    let res = rest_state.app.typed_query(req)?;
+    Ok(Json(res))
+}
+
+/// Get module account by its name.
+pub async fn module_account_by_name<
+    QReq: QueryRequest + From<AuthNodeQueryRequest>,
+    QRes: QueryResponse + TryInto<AuthNodeQueryResponse>,
+    App: NodeQueryHandler<QReq, QRes>,
+>(
+    Path(name): Path<String>,
+    State(rest_state): State<RestState<QReq, QRes, App>>,
+) -> Result<Json<QRes>, HTTPError> {
+    let req = AuthNodeQueryRequest::ModuleAccountByName(QueryModuleAccountByNameRequest { name });
+    let res = rest_state.app.typed_query(req)?;
+    Ok(Json(res))
+}
+
+/// Get bech32 prefix of account addresses.
+pub async fn bech32_prefix<
+    QReq: QueryRequest + From<AuthNodeQueryRequest>,
+    QRes: QueryResponse + TryInto<AuthNodeQueryResponse>,
+    App: NodeQueryHandler<QReq, QRes>,
+>(
+    State(rest_state): State<RestState<QReq, QRes, App>>,
+) -> Result<Json<QRes>, HTTPError> {
+    let req = AuthNodeQueryRequest::Bech32Prefix(Bech32PrefixRequest {});
+    let res = rest_state.app.typed_query(req)?;
+    Ok(Json(res))
+}
+
+/// Convert bech32 address to bytes or base64 encoded address bytes to bech32 string.
+pub async fn bech32_address<
+    QReq: QueryRequest + From<AuthNodeQueryRequest>,
+    QRes: QueryResponse + TryInto<AuthNodeQueryResponse>,
+    App: NodeQueryHandler<QReq, QRes>,
+>(
+    Path(address): Path<String>,
+    State(rest_state): State<RestState<QReq, QRes, App>>,
+) -> Result<Json<QRes>, HTTPError> {
+    let req = match AccAddress::from_bech32(&address) {
+        Ok(address_string) => {
+            AuthNodeQueryRequest::AddressStringToBytes(AddressStringToBytesRequest {
+                address_string,
+            })
+        }
+        Err(_) => {
+            let address_bytes = data_encoding::BASE64
+                .decode(address.as_bytes())
+                .ok()
+                .and_then(|bytes| AccAddress::try_from(bytes).ok())
+                .ok_or_else(|| HTTPError::bad_request(format!("invalid address: {address}")))?;
+
+            AuthNodeQueryRequest::AddressBytesToString(AddressBytesToStringRequest {
+                address_bytes,
+            })
+        }
+    };
+    let res = rest_state.app.typed_query(req)?;
+    Ok(Json(res))
+}
+
+/// Get account info of address.
+pub async fn account_info<
+    QReq: QueryRequest + From<AuthNodeQueryRequest>,
+    QRes: QueryResponse + TryInto<AuthNodeQueryResponse>,
+    App: NodeQueryHandler<QReq, QRes>,
+>(
+    Path(address): Path<AccAddress>,
+    State(rest_state): State<RestState<QReq, QRes, App>>,
+) -> Result<Json<QRes>, HTTPError> {
+    let req = AuthNodeQueryRequest::AccountInfo(QueryAccountInfoRequest { address });
+    let res = rest_state.app.typed_query(req)?;
+    Ok(Json(res))
+}
+
 pub fn get_router<
     QReq: QueryRequest + From<AuthNodeQueryRequest>,
     QRes: QueryResponse + TryInto<AuthNodeQueryResponse>,
@@ -68,4 +157,12 @@ pub fn get_router<
         .route("/v1beta1/accounts/:address", get(account))
         .route("/v1beta1/accounts", get(accounts))
         .route("/v1beta1/params", get(params))
+        .route("/v1beta1/module_accounts", get(module_accounts))
+        .route(
+            "/v1beta1/module_accounts/:name",
+            get(module_account_by_name),
+        )
+        .route("/v1beta1/bech32", get(bech32_prefix))
+        .route("/v1beta1/bech32/:address", get(bech32_address))
+        .route("/v1beta1/account_info/:address", get(account_info))
 }
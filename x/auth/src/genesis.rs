@@ -22,6 +22,7 @@ impl Default for GenesisState {
                 tx_size_cost_per_byte: 10,
                 sig_verify_cost_ed25519: 590,
                 sig_verify_cost_secp256k1: 1000,
+                sig_verify_cost_secp256r1: 500,
             },
         }
     }
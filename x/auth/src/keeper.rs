@@ -177,6 +177,26 @@ impl<SK: StoreKey, PSK: ParamsSubspaceKey, M: Module> Keeper<SK, PSK, M> {
                 .collect(),
         )
     }
+
+    /// Returns all module accounts which are stored in auth store
+    pub fn module_accounts<DB: Database>(&self, ctx: &QueryContext<DB, SK>) -> Vec<Account> {
+        let (_, accounts) = self.accounts(ctx, None);
+
+        accounts
+            .into_iter()
+            .filter(|account| matches!(account, Account::Module(_)))
+            .collect()
+    }
+
+    pub fn module_account_by_name<DB: Database>(
+        &self,
+        ctx: &QueryContext<DB, SK>,
+        name: &str,
+    ) -> Option<Account> {
+        self.module_accounts(ctx)
+            .into_iter()
+            .find(|account| matches!(account, Account::Module(module) if module.name == name))
+    }
 }
 
 fn create_auth_store_key(address: AccAddress) -> Vec<u8> {
@@ -15,6 +15,7 @@ const KEY_TX_SIG_LIMIT: &str = "TxSigLimit";
 const KEY_TX_SIZE_COST_PER_BYTE: &str = "TxSizeCostPerByte";
 const KEY_SIG_VERIFY_COST_ED25519: &str = "SigVerifyCostED25519";
 const KEY_SIG_VERIFY_COST_SECP256K1: &str = "SigVerifyCostSecp256k1";
+const KEY_SIG_VERIFY_COST_SECP256R1: &str = "SigVerifyCostSecp256r1";
 
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct AuthsParams {
@@ -33,6 +34,15 @@ pub struct AuthsParams {
     #[serde(serialize_with = "serialize_number_to_string")]
     #[serde(deserialize_with = "deserialize_number_from_string")]
     pub sig_verify_cost_secp256k1: u64,
+    /// Not a part of cosmos sdk params, so it's optional in genesis and isn't sent via grpc
+    #[serde(serialize_with = "serialize_number_to_string")]
+    #[serde(deserialize_with = "deserialize_number_from_string")]
+    #[serde(default = "default_sig_verify_cost_secp256r1")]
+    pub sig_verify_cost_secp256r1: u64,
+}
+
+fn default_sig_verify_cost_secp256r1() -> u64 {
+    DEFAULT_PARAMS.sig_verify_cost_secp256r1
 }
 
 impl From<ibc_proto::cosmos::auth::v1beta1::Params> for AuthsParams {
@@ -43,6 +53,7 @@ impl From<ibc_proto::cosmos::auth::v1beta1::Params> for AuthsParams {
             tx_size_cost_per_byte: value.tx_size_cost_per_byte,
             sig_verify_cost_ed25519: value.sig_verify_cost_ed25519,
             sig_verify_cost_secp256k1: value.sig_verify_cost_secp256k1,
+            sig_verify_cost_secp256r1: default_sig_verify_cost_secp256r1(),
         }
     }
 }
@@ -73,13 +84,14 @@ impl ParamsSerialize for AuthsParams {
             KEY_TX_SIZE_COST_PER_BYTE,
             KEY_SIG_VERIFY_COST_ED25519,
             KEY_SIG_VERIFY_COST_SECP256K1,
+            KEY_SIG_VERIFY_COST_SECP256R1,
         ]
         .into_iter()
         .collect()
     }
 
     fn to_raw(&self) -> Vec<(&'static str, Vec<u8>)> {
-        let mut hash_map = Vec::with_capacity(5);
+        let mut hash_map = Vec::with_capacity(6);
 
         hash_map.push((
             KEY_MAX_MEMO_CHARACTERS,
@@ -106,11 +118,25 @@ impl ParamsSerialize for AuthsParams {
             format!("\"{}\"", self.sig_verify_cost_secp256k1).into_bytes(),
         ));
 
+        // not a cosmos sdk param so keep the store (and app hash) of chains
+        // which don't change it identical to the sdk one
+        if self.sig_verify_cost_secp256r1 != DEFAULT_PARAMS.sig_verify_cost_secp256r1 {
+            hash_map.push((
+                KEY_SIG_VERIFY_COST_SECP256R1,
+                format!("\"{}\"", self.sig_verify_cost_secp256r1).into_bytes(),
+            ));
+        }
+
         hash_map
     }
 }
 
 impl ParamsDeserialize for AuthsParams {
+    fn optional_keys() -> HashSet<&'static str> {
+        // chains started before secp256r1 keys were supported don't have the key
+        [KEY_SIG_VERIFY_COST_SECP256R1].into_iter().collect()
+    }
+
     fn from_raw(mut fields: HashMap<&'static str, Vec<u8>>) -> Self {
         // THIS IS AWFUL
         Self {
@@ -142,6 +168,15 @@ impl ParamsDeserialize for AuthsParams {
                 )
                 .unsigned_64()
                 .unwrap_or_corrupt(),
+            sig_verify_cost_secp256r1: fields
+                .remove(KEY_SIG_VERIFY_COST_SECP256R1)
+                .map(|value| {
+                    ParamKind::U64
+                        .parse_param(value)
+                        .unsigned_64()
+                        .unwrap_or_corrupt()
+                })
+                .unwrap_or_else(default_sig_verify_cost_secp256r1),
         }
     }
 }
@@ -151,10 +186,22 @@ impl AuthParams for AuthsParams {
         self.max_memo_characters
     }
 
+    fn tx_sig_limit(&self) -> u64 {
+        self.tx_sig_limit
+    }
+
+    fn sig_verify_cost_ed25519(&self) -> u64 {
+        self.sig_verify_cost_ed25519
+    }
+
     fn sig_verify_cost_secp256k1(&self) -> u64 {
         self.sig_verify_cost_secp256k1
     }
 
+    fn sig_verify_cost_secp256r1(&self) -> u64 {
+        self.sig_verify_cost_secp256r1
+    }
+
     fn tx_cost_per_byte(&self) -> u64 {
         self.tx_size_cost_per_byte
     }
@@ -166,6 +213,8 @@ pub const DEFAULT_PARAMS: AuthsParams = AuthsParams {
     tx_size_cost_per_byte: 10,
     sig_verify_cost_ed25519: 590,
     sig_verify_cost_secp256k1: 1000,
+    // verification of secp256r1 is about 2 times faster than secp256k1
+    sig_verify_cost_secp256r1: 500,
 };
 
 #[derive(Debug, Clone)]
@@ -202,6 +251,10 @@ impl<PSK: ParamsSubspaceKey> ParamsKeeper<PSK> for AuthParamsKeeper<PSK> {
                 .parse_param(value.as_ref().to_vec())
                 .unsigned_64()
                 .is_some(),
+            KEY_SIG_VERIFY_COST_SECP256R1 => ParamKind::U64
+                .parse_param(value.as_ref().to_vec())
+                .unsigned_64()
+                .is_some(),
             _ => false,
         }
     }
@@ -250,6 +303,53 @@ mod tests {
         assert_eq!(expected_hash, after_hash);
     }
 
+    #[test]
+    fn default_secp256r1_cost_is_not_stored() {
+        let keeper = AuthParamsKeeper {
+            params_subspace_key: SubspaceKey::Auth,
+        };
+
+        let mut default_store =
+            ApplicationMultiBank::<_, SubspaceKey>::new(Arc::new(MemDB::new())).unwrap_test();
+        let mut ctx = build_init_ctx(&mut default_store, ConsensusParams::default());
+        keeper.set(&mut ctx, DEFAULT_PARAMS.clone());
+        default_store.commit();
+
+        let mut reset_store =
+            ApplicationMultiBank::<_, SubspaceKey>::new(Arc::new(MemDB::new())).unwrap_test();
+        let mut ctx = build_init_ctx(&mut reset_store, ConsensusParams::default());
+        keeper.set(
+            &mut ctx,
+            AuthsParams {
+                sig_verify_cost_secp256r1: 42,
+                ..DEFAULT_PARAMS
+            },
+        );
+        keeper.set(&mut ctx, DEFAULT_PARAMS.clone());
+        reset_store.commit();
+
+        assert_eq!(
+            default_store.head_commit_hash(),
+            reset_store.head_commit_hash()
+        );
+    }
+
+    #[test]
+    fn from_raw_defaults_missing_secp256r1_cost() {
+        let params = AuthsParams {
+            sig_verify_cost_secp256r1: 42,
+            ..DEFAULT_PARAMS
+        };
+
+        let fields = params
+            .to_raw()
+            .into_iter()
+            .filter(|(key, _)| *key != KEY_SIG_VERIFY_COST_SECP256R1)
+            .collect();
+
+        assert_eq!(AuthsParams::from_raw(fields), DEFAULT_PARAMS);
+    }
+
     #[derive(strum::EnumIter, Debug, PartialEq, Eq, Hash, Clone, ParamsKeys, StoreKeys)]
     #[skey(params = Params)]
     enum SubspaceKey {
@@ -1,7 +1,8 @@
 use gears::{
+    core::serializers::Base64Standard,
     derive::{Protobuf, Query},
     types::{
-        account::Account,
+        account::{Account, BaseAccount},
         address::AccAddress,
         pagination::{request::PaginationRequest, response::PaginationResponse},
     },
@@ -18,6 +19,24 @@ pub mod inner {
     pub use ibc_proto::cosmos::auth::v1beta1::QueryAccountsResponse;
     pub use ibc_proto::cosmos::auth::v1beta1::QueryParamsRequest;
     pub use ibc_proto::cosmos::auth::v1beta1::QueryParamsResponse;
+    pub use ibc_proto::cosmos::auth::v1beta1::{
+        AddressBytesToStringRequest, AddressBytesToStringResponse, AddressStringToBytesRequest,
+        AddressStringToBytesResponse, BaseAccount, Bech32PrefixRequest, Bech32PrefixResponse,
+        QueryModuleAccountByNameRequest, QueryModuleAccountByNameResponse,
+        QueryModuleAccountsRequest, QueryModuleAccountsResponse,
+    };
+
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct QueryAccountInfoRequest {
+        #[prost(string, tag = "1")]
+        pub address: String,
+    }
+
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct QueryAccountInfoResponse {
+        #[prost(message, optional, tag = "1")]
+        pub info: Option<BaseAccount>,
+    }
 }
 
 #[derive(Clone, PartialEq, Message, Query, Protobuf)]
@@ -72,3 +91,94 @@ pub struct QueryParamsResponse {
     #[proto(optional)]
     pub params: AuthsParams,
 }
+
+/// QueryModuleAccountsRequest is the request type for the Query/ModuleAccounts RPC method.
+#[derive(Clone, PartialEq, Message, Query, Protobuf)]
+#[query(url = "/cosmos.auth.v1beta1.Query/ModuleAccounts")]
+#[proto(raw = "inner::QueryModuleAccountsRequest")]
+pub struct QueryModuleAccountsRequest {}
+
+/// QueryModuleAccountsResponse is the response type for the Query/ModuleAccounts RPC method.
+#[derive(Clone, PartialEq, Serialize, Deserialize, Debug, Query, Protobuf)]
+#[proto(raw = "inner::QueryModuleAccountsResponse")]
+pub struct QueryModuleAccountsResponse {
+    #[proto(repeated)]
+    pub accounts: Vec<Account>,
+}
+
+/// QueryModuleAccountByNameRequest is the request type for the Query/ModuleAccountByName RPC method.
+#[derive(Clone, PartialEq, Debug, Query, Protobuf)]
+#[query(url = "/cosmos.auth.v1beta1.Query/ModuleAccountByName")]
+#[proto(raw = "inner::QueryModuleAccountByNameRequest")]
+pub struct QueryModuleAccountByNameRequest {
+    pub name: String,
+}
+
+/// QueryModuleAccountByNameResponse is the response type for the Query/ModuleAccountByName RPC method.
+#[derive(Clone, PartialEq, Serialize, Deserialize, Debug, Query, Protobuf)]
+#[proto(raw = "inner::QueryModuleAccountByNameResponse")]
+pub struct QueryModuleAccountByNameResponse {
+    #[proto(optional)]
+    pub account: Option<Account>,
+}
+
+/// Bech32PrefixRequest is the request type for Bech32Prefix rpc method.
+#[derive(Clone, PartialEq, Message, Query, Protobuf)]
+#[query(url = "/cosmos.auth.v1beta1.Query/Bech32Prefix")]
+#[proto(raw = "inner::Bech32PrefixRequest")]
+pub struct Bech32PrefixRequest {}
+
+/// Bech32PrefixResponse is the response type for Bech32Prefix rpc method.
+#[derive(Clone, PartialEq, Serialize, Deserialize, Debug, Query, Protobuf)]
+#[proto(raw = "inner::Bech32PrefixResponse")]
+pub struct Bech32PrefixResponse {
+    pub bech32_prefix: String,
+}
+
+/// AddressBytesToStringRequest is the request type for AddressString rpc method.
+#[derive(Clone, PartialEq, Debug, Query, Protobuf)]
+#[query(url = "/cosmos.auth.v1beta1.Query/AddressBytesToString")]
+#[proto(raw = "inner::AddressBytesToStringRequest")]
+pub struct AddressBytesToStringRequest {
+    pub address_bytes: AccAddress,
+}
+
+/// AddressBytesToStringResponse is the response type for AddressString rpc method.
+#[derive(Clone, PartialEq, Serialize, Deserialize, Debug, Query, Protobuf)]
+#[proto(raw = "inner::AddressBytesToStringResponse")]
+pub struct AddressBytesToStringResponse {
+    pub address_string: AccAddress,
+}
+
+/// AddressStringToBytesRequest is the request type for AccountBytes rpc method.
+#[derive(Clone, PartialEq, Debug, Query, Protobuf)]
+#[query(url = "/cosmos.auth.v1beta1.Query/AddressStringToBytes")]
+#[proto(raw = "inner::AddressStringToBytesRequest")]
+pub struct AddressStringToBytesRequest {
+    pub address_string: AccAddress,
+}
+
+/// AddressStringToBytesResponse is the response type for AddressBytes rpc method.
+#[derive(Clone, PartialEq, Serialize, Deserialize, Debug, Query, Protobuf)]
+#[proto(raw = "inner::AddressStringToBytesResponse")]
+pub struct AddressStringToBytesResponse {
+    #[serde(with = "Base64Standard")]
+    pub address_bytes: Vec<u8>,
+}
+
+/// QueryAccountInfoRequest is the request type for the Query/AccountInfo RPC method.
+#[derive(Clone, PartialEq, Debug, Query, Protobuf)]
+#[query(url = "/cosmos.auth.v1beta1.Query/AccountInfo")]
+#[proto(raw = "inner::QueryAccountInfoRequest")]
+pub struct QueryAccountInfoRequest {
+    pub address: AccAddress,
+}
+
+/// QueryAccountInfoResponse is the response type for the Query/AccountInfo RPC method.
+#[derive(Clone, PartialEq, Serialize, Deserialize, Debug, Query, Protobuf)]
+#[proto(raw = "inner::QueryAccountInfoResponse")]
+pub struct QueryAccountInfoResponse {
+    /// info is the account info which is represented by BaseAccount.
+    #[proto(optional)]
+    pub info: Option<BaseAccount>,
+}
@@ -0,0 +1,377 @@
+use std::{
+    cell::RefCell,
+    num::NonZero,
+    sync::{Arc, Mutex},
+};
+
+use gears::{
+    application::handlers::node::{ModuleInfo, TxError},
+    baseapp::{options::NodeOptions, ConsensusParams},
+    context::{tx::TxContext, TransactionalContext},
+    core::{any::google::Any, errors::CoreError},
+    crypto::info::{create_signed_transaction_direct, SigningInfo},
+    derive::StoreKeys,
+    extensions::{infallible::UnwrapInfallible, testing::UnwrapTesting},
+    gas::metering::{kind::TxKind, GasMeter},
+    params::gas::schedule::GasSchedule,
+    store::{
+        database::{Database, MemDB},
+        StoreKey,
+    },
+    tendermint::types::{chain_id::ChainId, proto::header::Header},
+    types::{
+        address::AccAddress,
+        auth::fee::Fee,
+        tx::{body::TxBody, raw::TxWithRaw, Messages, TxMessage},
+    },
+    utils::node::{build_store, User},
+    x::{
+        ante::{
+            AnteDecorator, AnteEnv, AnteHandlerBuilder, DefaultAnteDecorators,
+            DefaultSignGasConsumer, SigGasConsumeDecorator, ValidateSigCountDecorator,
+        },
+        keepers::mocks::{
+            auth::{MockAuthKeeper, MockAuthParams},
+            bank::MockBankKeeper,
+        },
+        module::Module,
+    },
+};
+
+#[test]
+/// Decorators run in order they were added to builder
+fn builder_runs_decorators_in_order() {
+    let log = Log::default();
+
+    let ante_handler = AnteHandlerBuilder::new()
+        .decorator(RecordDecorator::ok("first", &log))
+        .decorator(RecordDecorator::ok("second", &log))
+        .decorator(RecordDecorator::ok("third", &log))
+        .build();
+
+    let tx = tx(1);
+    with_tx_ctx(|ctx| {
+        ante_handler
+            .run(ctx, &tx, false, NodeOptions::default(), gas_meter())
+            .unwrap_test()
+    });
+
+    assert_eq!(log.entries(), ["first", "second", "third"]);
+}
+
+#[test]
+/// Chain stops on first failed decorator and returns its error
+fn chain_stops_on_first_failure() {
+    let log = Log::default();
+
+    let ante_handler = AnteHandlerBuilder::new()
+        .decorator(RecordDecorator::ok("first", &log))
+        .decorator(RecordDecorator::failing("second", &log))
+        .decorator(RecordDecorator::failing("third", &log))
+        .decorator(RecordDecorator::ok("fourth", &log))
+        .build();
+
+    let tx = tx(1);
+    let err = with_tx_ctx(|ctx| {
+        ante_handler
+            .run(ctx, &tx, false, NodeOptions::default(), gas_meter())
+            .expect_err("second decorator fails")
+    });
+
+    assert_eq!(err.msg, "second failed");
+    assert_eq!(log.entries(), ["first", "second"]);
+}
+
+#[test]
+/// Default decorators could be added to builder in other order with decorators inserted between
+/// them, replaced or removed
+fn default_decorators_could_be_rearranged() {
+    let log = Log::default();
+
+    let DefaultAnteDecorators {
+        mempool_fee,
+        tx_timeout_height,
+        validate_memo,
+        ..
+    } = DefaultAnteDecorators::<_, _, SpaceKey, _, AuthModules>::new(
+        MockAuthKeeper::former().form(),
+        MockBankKeeper::former().form(),
+        DefaultSignGasConsumer,
+        AuthModules::FeeCollector,
+    );
+
+    let ante_handler = AnteHandlerBuilder::new()
+        .decorator(mempool_fee)
+        .decorator(RecordDecorator::ok("validate_basic", &log))
+        .decorator(tx_timeout_height)
+        .decorator(RecordDecorator::ok("inserted", &log))
+        .decorator(validate_memo)
+        .build();
+
+    // default validate basic decorator would reject tx with two signatures of the only signer
+    let tx = tx(2);
+    with_tx_ctx(|ctx| {
+        ante_handler
+            .run(ctx, &tx, false, NodeOptions::default(), gas_meter())
+            .unwrap_test()
+    });
+
+    assert_eq!(log.entries(), ["validate_basic", "inserted"]);
+}
+
+#[test]
+/// Tx with different number of signers and signatures is rejected instead of panic
+fn sig_gas_consume_checks_signatures_count() {
+    // two signatures of the only signer
+    let tx = tx(2);
+
+    let decorator = SigGasConsumeDecorator::<_, _, SpaceKey, AuthModules>::new(
+        MockAuthKeeper {
+            get_auth_params: MockAuthParams::default(),
+            has_account: true,
+            get_account: Vec::new(),
+        },
+        DefaultSignGasConsumer,
+    );
+
+    let env = AnteEnv {
+        is_check: false,
+        simulate: false,
+        node_opt: NodeOptions::default(),
+        gas_meter: gas_meter(),
+    };
+
+    let err = with_tx_ctx(|ctx| {
+        decorator
+            .ante_handle(ctx, &tx, &env)
+            .expect_err("signatures count doesn't match")
+    });
+
+    assert_eq!(err.msg, "wrong number of signatures; expected 1, got 2");
+}
+
+#[test]
+/// Tx with more signer keys than `tx_sig_limit` is rejected
+fn validate_sig_count_checks_limit() {
+    let tx = tx(2);
+
+    let decorator = |tx_sig_limit| {
+        ValidateSigCountDecorator::<_, SpaceKey, AuthModules>::new(MockAuthKeeper {
+            get_auth_params: MockAuthParams {
+                tx_sig_limit,
+                ..Default::default()
+            },
+            has_account: true,
+            get_account: Vec::new(),
+        })
+    };
+
+    let env = AnteEnv {
+        is_check: false,
+        simulate: false,
+        node_opt: NodeOptions::default(),
+        gas_meter: gas_meter(),
+    };
+
+    with_tx_ctx(|ctx| {
+        decorator(2).ante_handle(ctx, &tx, &env).unwrap_test();
+        decorator(1)
+            .ante_handle(ctx, &tx, &env)
+            .expect_err("too many signatures");
+    });
+}
+
+#[derive(Debug, Clone, Default)]
+struct Log(Arc<Mutex<Vec<&'static str>>>);
+
+impl Log {
+    fn push(&self, name: &'static str) {
+        self.0.lock().expect("poisoned lock").push(name);
+    }
+
+    fn entries(&self) -> Vec<&'static str> {
+        self.0.lock().expect("poisoned lock").clone()
+    }
+}
+
+/// Decorator which records its name to log and fails if configured
+#[derive(Debug, Clone)]
+struct RecordDecorator {
+    name: &'static str,
+    fail: bool,
+    log: Log,
+}
+
+impl RecordDecorator {
+    fn ok(name: &'static str, log: &Log) -> Self {
+        Self {
+            name,
+            fail: false,
+            log: log.clone(),
+        }
+    }
+
+    fn failing(name: &'static str, log: &Log) -> Self {
+        Self {
+            name,
+            fail: true,
+            log: log.clone(),
+        }
+    }
+}
+
+impl<SK: StoreKey, M: TxMessage> AnteDecorator<SK, M> for RecordDecorator {
+    fn ante_handle<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        _ctx: &mut CTX,
+        _tx: &TxWithRaw<M>,
+        _env: &AnteEnv,
+    ) -> Result<(), TxError> {
+        self.log.push(self.name);
+
+        match self.fail {
+            true => Err(TxError::new::<AuthModuleInfo>(
+                format!("{} failed", self.name),
+                NonZero::new(1).expect("hard coded code is not zero"),
+            )),
+            false => Ok(()),
+        }
+    }
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+struct TestMsg {
+    signer: AccAddress,
+}
+
+impl From<TestMsg> for Any {
+    fn from(msg: TestMsg) -> Self {
+        Any {
+            type_url: "/test.TestMsg".to_owned(),
+            value: msg.signer.to_string().into_bytes(),
+        }
+    }
+}
+
+impl TryFrom<Any> for TestMsg {
+    type Error = CoreError;
+
+    fn try_from(_: Any) -> Result<Self, Self::Error> {
+        Err(CoreError::DecodeGeneral(
+            "test message isn't decoded".to_owned(),
+        ))
+    }
+}
+
+impl TxMessage for TestMsg {
+    fn get_signers(&self) -> Vec<&AccAddress> {
+        vec![&self.signer]
+    }
+
+    fn type_url(&self) -> &'static str {
+        "/test.TestMsg"
+    }
+}
+
+/// Tx with `signers` signatures of same user
+fn tx(signers: usize) -> TxWithRaw<TestMsg> {
+    let user = User::from_bech32("race draft rival universe maid cheese steel logic crowd fork comic easy truth drift tomorrow eye buddy head time cash swing swift midnight borrow", 1).unwrap_test();
+
+    let messages = Messages::from(TestMsg {
+        signer: user.address(),
+    });
+
+    let signing_infos = (0..signers)
+        .map(|_| SigningInfo {
+            key: &user.key_pair,
+            sequence: 0,
+            account_number: user.account_number,
+        })
+        .collect();
+
+    let fee = Fee {
+        amount: None,
+        gas_limit: 200_000_u32.into(),
+        payer: None,
+        granter: "".into(),
+    };
+
+    create_signed_transaction_direct(
+        signing_infos,
+        ChainId::default(),
+        fee,
+        None,
+        TxBody::new_with_defaults(messages.into_msgs()),
+    )
+    .unwrap_infallible()
+    .into()
+}
+
+fn with_tx_ctx<T>(f: impl FnOnce(&mut TxContext<'_, MemDB, SpaceKey>) -> T) -> T {
+    let multi_store = build_store::<SpaceKey>();
+    let mut multi_store = multi_store.to_tx_kind();
+    let mut block_gas_meter = GasMeter::infinite();
+
+    let mut ctx = TxContext::new(
+        &mut multi_store,
+        1,
+        Header::default(),
+        ConsensusParams::default(),
+        GasSchedule::default(),
+        GasMeter::infinite(),
+        &mut block_gas_meter,
+        NodeOptions::default(),
+        0,
+        [0; 32],
+    );
+
+    f(&mut ctx)
+}
+
+fn gas_meter() -> Arc<RefCell<GasMeter<TxKind>>> {
+    Arc::new(RefCell::new(GasMeter::infinite()))
+}
+
+#[derive(Debug, Clone)]
+struct AuthModuleInfo;
+
+impl ModuleInfo for AuthModuleInfo {
+    const NAME: &'static str = "auth";
+}
+
+/// Only used as type parameter of decorators
+#[allow(dead_code)]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AuthModules {
+    FeeCollector,
+}
+
+impl Module for AuthModules {
+    fn name(&self) -> String {
+        match self {
+            AuthModules::FeeCollector => "fee_collector".into(),
+        }
+    }
+
+    fn address(&self) -> AccAddress {
+        match self {
+            AuthModules::FeeCollector => {
+                AccAddress::from_bech32("cosmos17xpfvakm2amg962yls6f84z3kell8c5lserqta")
+                    .expect("hard coded address is valid")
+            }
+        }
+    }
+
+    fn permissions(&self) -> Vec<String> {
+        Vec::new()
+    }
+}
+
+#[derive(strum::EnumIter, Debug, PartialEq, Eq, Hash, Clone, StoreKeys)]
+#[skey(params = Params)]
+pub enum SpaceKey {
+    #[skey(to_string = "acc")]
+    Auth,
+    #[skey(to_string = "params")]
+    Params,
+}
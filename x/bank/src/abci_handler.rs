@@ -1,9 +1,11 @@
 use std::marker::PhantomData;
 
-use gears::application::handlers::node::{ABCIHandler, ModuleInfo, TxError};
+use gears::application::handlers::node::{
+    ABCIHandler, InvariantError, InvariantRoute, ModuleInfo, TxError,
+};
 use gears::baseapp::errors::QueryError;
 use gears::baseapp::QueryRequest;
-use gears::context::{init::InitContext, query::QueryContext, tx::TxContext};
+use gears::context::{init::InitContext, query::QueryContext, tx::TxContext, QueryableContext};
 use gears::core::Protobuf;
 use gears::derive::Query;
 use gears::extensions::gas::GasResultExt;
@@ -19,10 +21,13 @@ use gears::x::module::Module;
 use serde::Serialize;
 
 use crate::errors::BankTxError;
+use crate::invariants::TOTAL_SUPPLY_INVARIANT;
 use crate::types::query::{
     QueryAllBalancesRequest, QueryAllBalancesResponse, QueryBalanceRequest, QueryBalanceResponse,
-    QueryDenomMetadataRequest, QueryDenomMetadataResponse, QueryDenomsMetadataRequest,
-    QueryDenomsMetadataResponse, QueryParamsRequest, QueryParamsResponse,
+    QueryDenomMetadataRequest, QueryDenomMetadataResponse, QueryDenomOwnersRequest,
+    QueryDenomOwnersResponse, QueryDenomsMetadataRequest, QueryDenomsMetadataResponse,
+    QueryParamsRequest, QueryParamsResponse, QuerySendEnabledRequest, QuerySendEnabledResponse,
+    QuerySpendableBalanceByDenomRequest, QuerySpendableBalanceByDenomResponse,
     QuerySpendableBalancesRequest, QuerySpendableBalancesResponse, QuerySupplyOfRequest,
     QuerySupplyOfResponse, QueryTotalSupplyRequest, QueryTotalSupplyResponse,
 };
@@ -50,6 +55,9 @@ pub enum BankNodeQueryRequest {
     Params(QueryParamsRequest),
     SupplyOf(QuerySupplyOfRequest),
     Spendable(QuerySpendableBalancesRequest),
+    SpendableByDenom(QuerySpendableBalanceByDenomRequest),
+    DenomOwners(QueryDenomOwnersRequest),
+    SendEnabled(QuerySendEnabledRequest),
 }
 
 impl QueryRequest for BankNodeQueryRequest {
@@ -69,6 +77,9 @@ pub enum BankNodeQueryResponse {
     Params(QueryParamsResponse),
     SupplyOf(QuerySupplyOfResponse),
     Spendable(QuerySpendableBalancesResponse),
+    SpendableByDenom(QuerySpendableBalanceByDenomResponse),
+    DenomOwners(QueryDenomOwnersResponse),
+    SendEnabled(QuerySendEnabledResponse),
 }
 
 impl<
@@ -124,6 +135,15 @@ impl<
                 let balance = self.query_spendable(ctx, req);
                 BankNodeQueryResponse::Spendable(balance)
             }
+            BankNodeQueryRequest::SpendableByDenom(req) => {
+                BankNodeQueryResponse::SpendableByDenom(self.query_spendable_by_denom(ctx, req))
+            }
+            BankNodeQueryRequest::DenomOwners(req) => {
+                BankNodeQueryResponse::DenomOwners(self.query_denom_owners(ctx, req))
+            }
+            BankNodeQueryRequest::SendEnabled(req) => {
+                BankNodeQueryResponse::SendEnabled(self.query_send_enabled(ctx, req))
+            }
         }
     }
 
@@ -165,6 +185,21 @@ impl<
         Vec::new()
     }
 
+    fn invariant_routes(&self) -> Vec<InvariantRoute> {
+        vec![InvariantRoute::new(MI::NAME, TOTAL_SUPPLY_INVARIANT)]
+    }
+
+    fn check_invariant<DB: Database, CTX: QueryableContext<DB, Self::StoreKey>>(
+        &self,
+        ctx: &CTX,
+        route: &InvariantRoute,
+    ) -> Result<(), InvariantError> {
+        match route.route {
+            TOTAL_SUPPLY_INVARIANT => self.keeper.total_supply_invariant(ctx),
+            _ => Err(InvariantError::NotRegistered),
+        }
+    }
+
     fn query<DB: Database + Send + Sync>(
         &self,
         ctx: &QueryContext<DB, Self::StoreKey>,
@@ -206,6 +241,21 @@ impl<
                 let params = self.keeper.params(ctx);
                 Ok(QueryParamsResponse { params }.encode_vec())
             }
+            QuerySpendableBalanceByDenomRequest::QUERY_URL => {
+                let req = QuerySpendableBalanceByDenomRequest::decode(query.data)?;
+
+                Ok(self.query_spendable_by_denom(ctx, req).encode_vec())
+            }
+            QueryDenomOwnersRequest::QUERY_URL => {
+                let req = QueryDenomOwnersRequest::decode(query.data)?;
+
+                Ok(self.query_denom_owners(ctx, req).encode_vec())
+            }
+            QuerySendEnabledRequest::QUERY_URL => {
+                let req = QuerySendEnabledRequest::decode(query.data)?;
+
+                Ok(self.query_send_enabled(ctx, req).encode_vec())
+            }
             _ => Err(QueryError::PathNotFound),
         }
     }
@@ -248,6 +298,49 @@ impl<
         }
     }
 
+    fn query_spendable_by_denom<DB: Database>(
+        &self,
+        ctx: &QueryContext<DB, SK>,
+        QuerySpendableBalanceByDenomRequest { address, denom }: QuerySpendableBalanceByDenomRequest,
+    ) -> QuerySpendableBalanceByDenomResponse {
+        let balance = self
+            .keeper
+            .spendable_balance_by_denom(ctx, &address, &denom)
+            .ok();
+
+        QuerySpendableBalanceByDenomResponse { balance }
+    }
+
+    fn query_denom_owners<DB: Database>(
+        &self,
+        ctx: &QueryContext<DB, SK>,
+        QueryDenomOwnersRequest { denom, pagination }: QueryDenomOwnersRequest,
+    ) -> QueryDenomOwnersResponse {
+        let (p_result, denom_owners) =
+            self.keeper
+                .denom_owners(ctx, &denom, pagination.map(Pagination::from));
+
+        QueryDenomOwnersResponse {
+            denom_owners,
+            pagination: p_result.map(PaginationResponse::from),
+        }
+    }
+
+    fn query_send_enabled<DB: Database>(
+        &self,
+        ctx: &QueryContext<DB, SK>,
+        QuerySendEnabledRequest { denoms, pagination }: QuerySendEnabledRequest,
+    ) -> QuerySendEnabledResponse {
+        let (p_result, send_enabled) =
+            self.keeper
+                .send_enabled_entries(ctx, &denoms, pagination.map(Pagination::from));
+
+        QuerySendEnabledResponse {
+            send_enabled,
+            pagination: p_result.map(PaginationResponse::from),
+        }
+    }
+
     fn query_balances<DB: Database>(
         &self,
         ctx: &QueryContext<DB, SK>,
@@ -1,4 +1,7 @@
-use gears::baseapp::{NodeQueryHandler, QueryRequest, QueryResponse};
+use gears::{
+    baseapp::{NodeQueryHandler, QueryRequest, QueryResponse},
+    grpc::service::{from_raw, unary},
+};
 use ibc_proto::cosmos::bank::v1beta1::{
     query_server::{Query, QueryServer},
     QueryAllBalancesRequest, QueryAllBalancesResponse,
@@ -9,14 +12,28 @@ use ibc_proto::cosmos::bank::v1beta1::{
     QuerySpendableBalancesResponse, QuerySupplyOfRequest, QuerySupplyOfResponse,
     QueryTotalSupplyRequest, QueryTotalSupplyResponse,
 };
-use std::marker::PhantomData;
-use tonic::{Request, Response, Status};
+use std::{convert::Infallible, marker::PhantomData};
+use tonic::{
+    body::BoxBody,
+    codegen::{http, Body, BoxFuture, Context, Poll, Service, StdError},
+    server::NamedService,
+    Request, Response, Status,
+};
 use tracing::info;
 
-use crate::{BankNodeQueryRequest, BankNodeQueryResponse};
+use crate::{
+    types::query::{
+        inner::{
+            QuerySendEnabledRequest, QuerySendEnabledResponse, QuerySpendableBalanceByDenomRequest,
+            QuerySpendableBalanceByDenomResponse,
+        },
+        QuerySendEnabledRequest as DomainQuerySendEnabledRequest,
+        QuerySpendableBalanceByDenomRequest as DomainQuerySpendableBalanceByDenomRequest,
+    },
+    BankNodeQueryRequest, BankNodeQueryResponse,
+};
 
 const ERROR_STATE_MSG: &str = "An internal error occurred while querying the application state.";
-const UNIMPLEMENTED_MSG: &str = "Unimplemented";
 
 #[derive(Debug, Default)]
 pub struct BankService<QH, QReq, QRes> {
@@ -149,21 +166,102 @@ where
 
     async fn denom_owners(
         &self,
-        _request: Request<QueryDenomOwnersRequest>,
+        request: Request<QueryDenomOwnersRequest>,
     ) -> Result<Response<QueryDenomOwnersResponse>, Status> {
-        Err(Status::internal(UNIMPLEMENTED_MSG))
+        let req = BankNodeQueryRequest::DenomOwners(request.into_inner().try_into()?);
+        let response: BankNodeQueryResponse = self.app.typed_query(req)?.try_into()?;
+
+        if let BankNodeQueryResponse::DenomOwners(response) = response {
+            Ok(Response::new(response.into()))
+        } else {
+            Err(Status::internal(ERROR_STATE_MSG))
+        }
+    }
+}
+
+/// Bank query server. Serves methods which are missing in generated [`QueryServer`]
+/// and delegates the rest to it
+#[derive(Debug)]
+pub struct BankQueryServer<QH, QReq, QRes> {
+    app: QH,
+    inner: QueryServer<BankService<QH, QReq, QRes>>,
+}
+
+impl<QH: Clone, QReq, QRes> Clone for BankQueryServer<QH, QReq, QRes> {
+    fn clone(&self) -> Self {
+        Self {
+            app: self.app.clone(),
+            inner: self.inner.clone(),
+        }
+    }
+}
+
+impl<QH, QReq, QRes> NamedService for BankQueryServer<QH, QReq, QRes> {
+    const NAME: &'static str = "cosmos.bank.v1beta1.Query";
+}
+
+impl<QH, QReq, QRes, B> Service<http::Request<B>> for BankQueryServer<QH, QReq, QRes>
+where
+    QReq: QueryRequest + Send + Sync + 'static + From<BankNodeQueryRequest>,
+    QRes: QueryResponse + Send + Sync + 'static + TryInto<BankNodeQueryResponse, Error = Status>,
+    QH: NodeQueryHandler<QReq, QRes>,
+    B: Body + Send + 'static,
+    B::Error: Into<StdError> + Send + 'static,
+{
+    type Response = http::Response<BoxBody>;
+    type Error = Infallible;
+    type Future = BoxFuture<Self::Response, Self::Error>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, req: http::Request<B>) -> Self::Future {
+        let app = self.app.clone();
+
+        match req.uri().path() {
+            DomainQuerySpendableBalanceByDenomRequest::QUERY_URL => {
+                unary(req, move |raw: QuerySpendableBalanceByDenomRequest| {
+                    info!("Received a gRPC request bank::spendable_balance_by_denom");
+                    let req = BankNodeQueryRequest::SpendableByDenom(from_raw(raw)?);
+                    let response: BankNodeQueryResponse = app.typed_query(req)?.try_into()?;
+
+                    if let BankNodeQueryResponse::SpendableByDenom(response) = response {
+                        Ok(QuerySpendableBalanceByDenomResponse::from(response))
+                    } else {
+                        Err(Status::internal(ERROR_STATE_MSG))
+                    }
+                })
+            }
+            DomainQuerySendEnabledRequest::QUERY_URL => {
+                unary(req, move |raw: QuerySendEnabledRequest| {
+                    let req = BankNodeQueryRequest::SendEnabled(from_raw(raw)?);
+                    let response: BankNodeQueryResponse = app.typed_query(req)?.try_into()?;
+
+                    if let BankNodeQueryResponse::SendEnabled(response) = response {
+                        Ok(QuerySendEnabledResponse::from(response))
+                    } else {
+                        Err(Status::internal(ERROR_STATE_MSG))
+                    }
+                })
+            }
+            _ => self.inner.call(req),
+        }
     }
 }
 
-pub fn new<QH, QReq, QRes>(app: QH) -> QueryServer<BankService<QH, QReq, QRes>>
+pub fn new<QH, QReq, QRes>(app: QH) -> BankQueryServer<QH, QReq, QRes>
 where
     QReq: QueryRequest + Send + Sync + 'static + From<BankNodeQueryRequest>,
     QRes: QueryResponse + Send + Sync + 'static + TryInto<BankNodeQueryResponse, Error = Status>,
     QH: NodeQueryHandler<QReq, QRes>,
 {
     let bank_service = BankService {
-        app,
+        app: app.clone(),
         _phantom: Default::default(),
     };
-    QueryServer::new(bank_service)
+    BankQueryServer {
+        app,
+        inner: QueryServer::new(bank_service),
+    }
 }
@@ -1,7 +1,8 @@
 use crate::{
     types::query::{
         QueryAllBalancesRequest, QueryBalanceRequest, QueryDenomMetadataRequest,
-        QueryParamsRequest, QuerySupplyOfRequest, QueryTotalSupplyRequest,
+        QueryDenomOwnersRequest, QueryParamsRequest, QuerySendEnabledRequest,
+        QuerySpendableBalanceByDenomRequest, QuerySupplyOfRequest, QueryTotalSupplyRequest,
     },
     BankNodeQueryRequest, BankNodeQueryResponse,
 };
@@ -124,6 +125,75 @@ pub async fn get_denom_metadata<
     Ok(Json(res))
 }
 
+/// Get spendable balance for a given address and denom
+pub async fn get_spendable_balances_by_denom<
+    QReq: QueryRequest + From<BankNodeQueryRequest>,
+    QRes: QueryResponse + TryInto<BankNodeQueryResponse>,
+    App: NodeQueryHandler<QReq, QRes>,
+>(
+    Path(address): Path<AccAddress>,
+    query: Query<QueryData>,
+    State(rest_state): State<RestState<QReq, QRes, App>>,
+) -> Result<Json<QRes>, HTTPError> {
+    let req = BankNodeQueryRequest::SpendableByDenom(QuerySpendableBalanceByDenomRequest {
+        address,
+        denom: query.0.denom,
+    });
+    let res = rest_state.app.typed_query(req)?;
+    Ok(Json(res))
+}
+
+/// Get all account holders of a given denom
+pub async fn get_denom_owners<
+    QReq: QueryRequest + From<BankNodeQueryRequest>,
+    QRes: QueryResponse + TryInto<BankNodeQueryResponse>,
+    App: NodeQueryHandler<QReq, QRes>,
+>(
+    Path(denom): Path<Denom>,
+    pagination: Query<Pagination>,
+    State(rest_state): State<RestState<QReq, QRes, App>>,
+) -> Result<Json<QRes>, HTTPError> {
+    let req = BankNodeQueryRequest::DenomOwners(QueryDenomOwnersRequest {
+        denom,
+        pagination: Some(pagination.0.into()),
+    });
+    let res = rest_state.app.typed_query(req)?;
+    Ok(Json(res))
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SendEnabledQuery {
+    /// comma separated list of denoms
+    denoms: Option<String>,
+}
+
+/// send_enabled queries for SendEnabled entries. Returns all entries if denoms are empty.
+pub async fn send_enabled<
+    QReq: QueryRequest + From<BankNodeQueryRequest>,
+    QRes: QueryResponse + TryInto<BankNodeQueryResponse>,
+    App: NodeQueryHandler<QReq, QRes>,
+>(
+    query: Query<SendEnabledQuery>,
+    pagination: Query<Pagination>,
+    State(rest_state): State<RestState<QReq, QRes, App>>,
+) -> Result<Json<QRes>, HTTPError> {
+    let denoms = match query.0.denoms {
+        Some(denoms) => denoms
+            .split(',')
+            .map(Denom::try_from)
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|e| HTTPError::bad_request(e.to_string()))?,
+        None => Vec::new(),
+    };
+
+    let req = BankNodeQueryRequest::SendEnabled(QuerySendEnabledRequest {
+        denoms,
+        pagination: Some(pagination.0.into()),
+    });
+    let res = rest_state.app.typed_query(req)?;
+    Ok(Json(res))
+}
+
 /// params queries the module parameters.
 pub async fn params<
     QReq: QueryRequest + From<BankNodeQueryRequest>,
@@ -151,6 +221,12 @@ pub fn get_router<
             "/v1beta1/balances/:address/by_denom",
             get(get_balances_by_denom),
         )
+        .route(
+            "/v1beta1/spendable_balances/:address/by_denom",
+            get(get_spendable_balances_by_denom),
+        )
         .route("/v1beta1/denoms_metadata/:denom", get(get_denom_metadata))
+        .route("/v1beta1/denom_owners/:denom", get(get_denom_owners))
+        .route("/v1beta1/send_enabled", get(send_enabled))
         .route("/v1beta1/params", get(params))
 }
@@ -0,0 +1,76 @@
+use std::collections::BTreeMap;
+
+use gears::application::handlers::node::InvariantError;
+
+use super::*;
+
+/// Route of invariant which checks that total supply equals sum of all balances
+pub const TOTAL_SUPPLY_INVARIANT: &str = "total-supply";
+
+impl<
+        SK: StoreKey,
+        PSK: ParamsSubspaceKey,
+        AK: AuthKeeper<SK, M> + Send + Sync + 'static,
+        M: Module,
+    > Keeper<SK, PSK, AK, M>
+{
+    /// Checks that total supply of every denom equals sum of balances of all accounts
+    pub fn total_supply_invariant<DB: Database, CTX: QueryableContext<DB, SK>>(
+        &self,
+        ctx: &CTX,
+    ) -> Result<(), InvariantError> {
+        let mut balances = BTreeMap::<Denom, Uint256>::new();
+        let balances_store = ctx
+            .kv_store(&self.store_key)
+            .prefix_store(ADDRESS_BALANCES_STORE_PREFIX);
+        for raw_coin in balances_store.into_range(..) {
+            let (_, raw_coin) = raw_coin?;
+            let coin = UnsignedCoin::decode::<Bytes>(raw_coin.into_owned().into())
+                .ok()
+                .unwrap_or_corrupt();
+
+            *balances.entry(coin.denom).or_default() += coin.amount;
+        }
+        balances.retain(|_, amount| !amount.is_zero());
+
+        let mut supply = BTreeMap::<Denom, Uint256>::new();
+        let supply_store = ctx.kv_store(&self.store_key).prefix_store(SUPPLY_KEY);
+        for raw_coin in supply_store.into_range(..) {
+            let (denom, amount) = raw_coin?;
+            let denom = Denom::from_str(&String::from_utf8_lossy(&denom))
+                .ok()
+                .unwrap_or_corrupt();
+            let amount = Uint256::from_str(&String::from_utf8_lossy(&amount))
+                .ok()
+                .unwrap_or_corrupt();
+
+            if !amount.is_zero() {
+                supply.insert(denom, amount);
+            }
+        }
+
+        if balances == supply {
+            return Ok(());
+        }
+
+        let mismatches = supply
+            .keys()
+            .chain(balances.keys())
+            .collect::<std::collections::BTreeSet<_>>()
+            .into_iter()
+            .filter(|denom| supply.get(*denom) != balances.get(*denom))
+            .map(|denom| {
+                format!(
+                    "{denom}: total supply {}, sum of balances {}",
+                    supply.get(denom).copied().unwrap_or_default(),
+                    balances.get(denom).copied().unwrap_or_default()
+                )
+            })
+            .collect::<Vec<_>>();
+
+        Err(InvariantError::Broken(format!(
+            "total supply is not equal to sum of balances: {}",
+            mismatches.join("; ")
+        )))
+    }
+}
@@ -0,0 +1,51 @@
+use gears::context::InfallibleContextMut;
+
+use super::*;
+
+/// Version of bank state. Version 2 adds index of balance owners by denom, so upgrade
+/// handlers should migrate bank from version 1 with [Keeper::migrate_v1_to_v2]
+pub const CONSENSUS_VERSION: u64 = 2;
+
+impl<
+        SK: StoreKey,
+        PSK: ParamsSubspaceKey,
+        AK: AuthKeeper<SK, M> + Send + Sync + 'static,
+        M: Module,
+    > Keeper<SK, PSK, AK, M>
+{
+    /// Indexes owners of every stored balance by denom
+    pub fn migrate_v1_to_v2<DB: Database, CTX: InfallibleContextMut<DB, SK>>(&self, ctx: &mut CTX) {
+        let balances = ctx
+            .infallible_store(&self.store_key)
+            .prefix_store(ADDRESS_BALANCES_STORE_PREFIX)
+            .into_range(..)
+            .map(|(key, _)| {
+                // key is length prefixed address followed by denom
+                let addr_len = key.first().copied().unwrap_or_corrupt() as usize;
+                let address =
+                    AccAddress::try_from(key.get(1..addr_len + 1).unwrap_or_corrupt().to_vec())
+                        .ok()
+                        .unwrap_or_corrupt();
+                let denom = Denom::from_str(&String::from_utf8_lossy(
+                    key.get(addr_len + 1..).unwrap_or_corrupt(),
+                ))
+                .ok()
+                .unwrap_or_corrupt();
+
+                (address, denom)
+            })
+            .collect::<Vec<_>>();
+
+        let mut store = ctx.infallible_store_mut(&self.store_key);
+        for (address, denom) in balances {
+            store.set(
+                [
+                    create_denom_address_prefix(&denom),
+                    denom_address_key(&address),
+                ]
+                .concat(),
+                [0],
+            );
+        }
+    }
+}
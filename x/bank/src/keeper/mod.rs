@@ -1,9 +1,10 @@
 mod mint;
-use crate::{Balance, BankParams, BankParamsKeeper};
+use crate::types::query::DenomOwner;
+use crate::{Balance, BankParams, BankParamsKeeper, SendEnabled};
 use bytes::Bytes;
 use gears::application::keepers::params::ParamsKeeper;
 use gears::context::{init::InitContext, query::QueryContext};
-use gears::context::{QueryableContext, TransactionalContext};
+use gears::context::{InfallibleContext, QueryableContext, TransactionalContext};
 use gears::core::Protobuf;
 use gears::extensions::corruption::UnwrapCorrupt;
 use gears::extensions::gas::GasResultExt;
@@ -35,11 +36,15 @@ use std::{collections::HashMap, str::FromStr};
 pub mod balances;
 pub mod bank;
 pub mod gov;
+pub mod invariants;
+pub mod migrations;
 pub mod staking;
 
 const SUPPLY_KEY: [u8; 1] = [0];
 const ADDRESS_BALANCES_STORE_PREFIX: [u8; 1] = [2];
 const DENOM_METADATA_PREFIX: [u8; 1] = [1];
+/// Reverse index of balances: denom to addresses which hold it
+const DENOM_ADDRESS_PREFIX: [u8; 1] = [3];
 
 pub(crate) fn account_key(addr: &AccAddress) -> Vec<u8> {
     [
@@ -63,6 +68,15 @@ fn create_denom_balance_prefix(addr: AccAddress) -> Vec<u8> {
     .concat()
 }
 
+fn create_denom_address_prefix(denom: &Denom) -> Vec<u8> {
+    // null byte separates denom from address as denom could be prefix of another one
+    [DENOM_ADDRESS_PREFIX.as_slice(), denom.as_ref(), &[0]].concat()
+}
+
+fn denom_address_key(addr: &AccAddress) -> Vec<u8> {
+    [&[addr.len()], addr.as_ref()].concat()
+}
+
 #[derive(Debug, Clone)]
 pub struct Keeper<SK: StoreKey, PSK: ParamsSubspaceKey, AK: AuthKeeper<SK, M>, M: Module> {
     store_key: SK,
@@ -108,12 +122,9 @@ impl<
 
         let mut total_supply: HashMap<Denom, Uint256> = HashMap::new();
         for balance in balances {
-            let prefix = create_denom_balance_prefix(balance.address);
-            let mut denom_balance_store =
-                ctx.kv_store_mut(&self.store_key).prefix_store_mut(prefix);
-
             for coin in balance.coins {
-                denom_balance_store.set(coin.denom.to_string().into_bytes(), coin.encode_vec());
+                self.set_balance(ctx, &balance.address, coin.clone())
+                    .unwrap_gas();
                 let zero = Uint256::zero();
                 let current_balance = total_supply.get(&coin.denom).unwrap_or(&zero);
                 total_supply.insert(coin.denom, coin.amount + current_balance);
@@ -162,18 +173,26 @@ impl<
         address: &AccAddress,
         amount: UnsignedCoin,
     ) -> Result<(), GasStoreErrors> {
-        let bank_store = ctx.kv_store_mut(&self.store_key);
-        let prefix = create_denom_balance_prefix(address.clone());
+        let denom_address_prefix = create_denom_address_prefix(&amount.denom);
 
-        let mut account_store = bank_store.prefix_store_mut(prefix);
+        let mut account_store = self.address_balances_store(ctx, address);
         if amount.amount.is_zero() {
             account_store.delete(amount.denom.to_string().as_bytes())?;
-            Ok(())
         } else {
             account_store.set(
                 amount.denom.to_string().as_bytes().to_vec(),
                 amount.encode_vec(),
-            )
+            )?;
+        }
+
+        let mut denom_address_store = ctx
+            .kv_store_mut(&self.store_key)
+            .prefix_store_mut(denom_address_prefix);
+        if amount.amount.is_zero() {
+            denom_address_store.delete(&denom_address_key(address))?;
+            Ok(())
+        } else {
+            denom_address_store.set(denom_address_key(address), [0])
         }
     }
 
@@ -246,6 +265,81 @@ impl<
         (p_result, store)
     }
 
+    /// Gets all accounts which hold a non zero balance of `denom`
+    pub fn denom_owners<DB: Database, CTX: InfallibleContext<DB, SK>>(
+        &self,
+        ctx: &CTX,
+        denom: &Denom,
+        pagination: Option<Pagination>,
+    ) -> (Option<PaginationResult>, Vec<DenomOwner>) {
+        let denom_address_store = ctx
+            .infallible_store(&self.store_key)
+            .prefix_store(create_denom_address_prefix(denom));
+
+        // key is length prefixed address
+        let (p_result, iter) = denom_address_store
+            .into_range(..)
+            .maybe_paginate(pagination);
+
+        let owners = iter
+            .map(|(key, _)| {
+                let address = AccAddress::try_from(key.get(1..).unwrap_or_corrupt().to_vec())
+                    .ok()
+                    .unwrap_or_corrupt();
+                let balance = self
+                    .balance(ctx, &address, denom)
+                    .unwrap_gas()
+                    .unwrap_or_corrupt();
+
+                DenomOwner { address, balance }
+            })
+            .collect();
+
+        (p_result, owners)
+    }
+
+    /// Gets the spendable balance of `denom` for the `address`. Zero coin is returned
+    /// if the account doesn't hold any spendable coins of the `denom`
+    pub fn spendable_balance_by_denom<DB: Database, CTX: QueryableContext<DB, SK>>(
+        &self,
+        ctx: &CTX,
+        address: &AccAddress,
+        denom: &Denom,
+    ) -> Result<UnsignedCoin, BankKeeperError> {
+        let (spendable, _, _) = self.spendable_coins(ctx, address, None)?;
+
+        Ok(UnsignedCoin {
+            denom: denom.clone(),
+            amount: spendable
+                .map(|coins| coins.amount_of(denom))
+                .unwrap_or_else(Uint256::zero),
+        })
+    }
+
+    /// Gets the `SendEnabled` entries for the `denoms`. All entries are returned
+    /// if `denoms` is empty
+    pub fn send_enabled_entries<DB: Database>(
+        &self,
+        ctx: &QueryContext<DB, SK>,
+        denoms: &[Denom],
+        pagination: Option<Pagination>,
+    ) -> (Option<PaginationResult>, Vec<SendEnabled>) {
+        let BankParams { send_enabled, .. } = self.params(ctx);
+
+        if denoms.is_empty() {
+            let (p_result, iter) = send_enabled.into_iter().maybe_paginate(pagination);
+            (p_result, iter.collect())
+        } else {
+            (
+                None,
+                send_enabled
+                    .into_iter()
+                    .filter(|this| denoms.contains(&this.denom))
+                    .collect(),
+            )
+        }
+    }
+
     fn send_coins<DB: Database, CTX: TransactionalContext<DB, SK>>(
         &self,
         ctx: &mut CTX,
@@ -262,7 +356,7 @@ impl<
         let mut events = vec![];
 
         for send_coin in amount.inner() {
-            let mut from_account_store = self.address_balances_store(ctx, &from_address);
+            let from_account_store = self.address_balances_store(ctx, &from_address);
             let from_balance = from_account_store
                 .get(send_coin.denom.to_string().as_bytes())?
                 .ok_or(InsufficientFundsError::RequiredActual {
@@ -284,19 +378,12 @@ impl<
 
             from_balance.amount -= send_coin.amount;
 
-            // if balance == 0 then denom should be removed from store
-            if from_balance.amount.is_zero() {
-                from_account_store.delete(send_coin.denom.to_string().as_bytes())?;
-            } else {
-                from_account_store.set(
-                    send_coin.denom.clone().to_string().into_bytes(),
-                    from_balance.encode_vec(),
-                )?;
-            }
+            // if balance == 0 then denom is removed from store
+            self.set_balance(ctx, &from_address, from_balance)?;
         }
 
         for send_coin in amount.inner() {
-            let mut to_account_store = self.address_balances_store(ctx, &to_address);
+            let to_account_store = self.address_balances_store(ctx, &to_address);
             let to_balance = to_account_store.get(send_coin.denom.to_string().as_bytes())?;
 
             let mut to_balance: UnsignedCoin = match to_balance {
@@ -311,10 +398,7 @@ impl<
 
             to_balance.amount += send_coin.amount;
 
-            to_account_store.set(
-                send_coin.denom.to_string().into_bytes(),
-                to_balance.encode_vec(),
-            )?;
+            self.set_balance(ctx, &to_address, to_balance)?;
 
             events.push(Event::new(
                 "transfer",
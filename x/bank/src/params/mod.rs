@@ -1,9 +1,11 @@
 use gears::application::keepers::params::ParamsKeeper;
 use gears::derive::Protobuf;
 use gears::extensions::corruption::UnwrapCorrupt;
+use gears::extensions::pagination::PaginationKey;
 use gears::params::{ParamKind, ParamsDeserialize, ParamsSerialize, ParamsSubspaceKey};
 use gears::types::denom::Denom;
 use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
 use std::collections::{HashMap, HashSet};
 
 const KEY_SEND_ENABLED: &str = "SendEnabled";
@@ -23,6 +25,12 @@ pub struct SendEnabled {
     pub enabled: bool,
 }
 
+impl PaginationKey for SendEnabled {
+    fn iterator_key(&self) -> Cow<'_, [u8]> {
+        Cow::Borrowed(self.denom.as_ref())
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Deserialize, Serialize, Protobuf)]
 #[proto(raw = "inner::Params")]
 pub struct BankParams {
@@ -11,7 +11,7 @@ use gears::{
 use prost::Message;
 use serde::{Deserialize, Serialize};
 
-use crate::BankParams;
+use crate::{BankParams, SendEnabled};
 
 pub mod inner {
     pub use ibc_proto::cosmos::bank::v1beta1::QueryAllBalancesRequest;
@@ -20,9 +20,44 @@ pub mod inner {
     pub use ibc_proto::cosmos::bank::v1beta1::QueryBalanceResponse;
     pub use ibc_proto::cosmos::bank::v1beta1::QueryDenomMetadataRequest;
     pub use ibc_proto::cosmos::bank::v1beta1::QueryDenomMetadataResponse;
+    pub use ibc_proto::cosmos::bank::v1beta1::QueryDenomOwnersRequest;
+    pub use ibc_proto::cosmos::bank::v1beta1::QueryDenomOwnersResponse;
     pub use ibc_proto::cosmos::bank::v1beta1::QueryDenomsMetadataRequest;
     pub use ibc_proto::cosmos::bank::v1beta1::QueryTotalSupplyRequest;
     pub use ibc_proto::cosmos::bank::v1beta1::QueryTotalSupplyResponse;
+    pub use ibc_proto::cosmos::bank::v1beta1::{DenomOwner, SendEnabled};
+    pub use ibc_proto::cosmos::base::query::v1beta1::{PageRequest, PageResponse};
+    pub use ibc_proto::cosmos::base::v1beta1::Coin;
+
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct QuerySpendableBalanceByDenomRequest {
+        #[prost(string, tag = "1")]
+        pub address: String,
+        #[prost(string, tag = "2")]
+        pub denom: String,
+    }
+
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct QuerySpendableBalanceByDenomResponse {
+        #[prost(message, optional, tag = "1")]
+        pub balance: Option<Coin>,
+    }
+
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct QuerySendEnabledRequest {
+        #[prost(string, repeated, tag = "1")]
+        pub denoms: Vec<String>,
+        #[prost(message, optional, tag = "99")]
+        pub pagination: Option<PageRequest>,
+    }
+
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct QuerySendEnabledResponse {
+        #[prost(message, repeated, tag = "1")]
+        pub send_enabled: Vec<SendEnabled>,
+        #[prost(message, optional, tag = "99")]
+        pub pagination: Option<PageResponse>,
+    }
 }
 
 #[derive(Clone, PartialEq, Debug, Query, Protobuf)]
@@ -174,3 +209,80 @@ pub struct QuerySpendableBalancesResponse {
     #[proto(optional)]
     pub pagination: Option<PaginationResponse>,
 }
+
+/// QueryDenomOwnersRequest defines the request type for the DenomOwners RPC query,
+/// which queries for a paginated set of all account holders of a particular
+/// denomination.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, Query, Protobuf)]
+#[proto(raw = "inner::QueryDenomOwnersRequest")]
+#[query(url = "/cosmos.bank.v1beta1.Query/DenomOwners")]
+pub struct QueryDenomOwnersRequest {
+    /// denom defines the coin denomination to query all account holders for.
+    pub denom: Denom,
+    #[proto(optional)]
+    pub pagination: Option<PaginationRequest>,
+}
+
+/// DenomOwner defines structure representing an account that owns or holds a
+/// particular denominated token. It contains the account address and account
+/// balance of the denominated token.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, Protobuf)]
+#[proto(raw = "inner::DenomOwner")]
+pub struct DenomOwner {
+    /// address defines the address that owns a particular denomination.
+    pub address: AccAddress,
+    /// balance is the balance of the denominated coin for an account.
+    #[proto(optional)]
+    pub balance: UnsignedCoin,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, Query, Protobuf)]
+#[proto(raw = "inner::QueryDenomOwnersResponse")]
+pub struct QueryDenomOwnersResponse {
+    #[proto(repeated)]
+    pub denom_owners: Vec<DenomOwner>,
+    #[proto(optional)]
+    pub pagination: Option<PaginationResponse>,
+}
+
+/// QuerySpendableBalanceByDenomRequest defines the gRPC request structure for
+/// querying an account's spendable balance for a specific denom.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, Query, Protobuf)]
+#[proto(raw = "inner::QuerySpendableBalanceByDenomRequest")]
+#[query(url = "/cosmos.bank.v1beta1.Query/SpendableBalanceByDenom")]
+pub struct QuerySpendableBalanceByDenomRequest {
+    pub address: AccAddress,
+    pub denom: Denom,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, Query, Protobuf)]
+#[proto(raw = "inner::QuerySpendableBalanceByDenomResponse")]
+pub struct QuerySpendableBalanceByDenomResponse {
+    #[proto(optional)]
+    pub balance: Option<UnsignedCoin>,
+}
+
+/// QuerySendEnabledRequest defines the RPC request for looking up SendEnabled entries.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, Query, Protobuf)]
+#[proto(raw = "inner::QuerySendEnabledRequest")]
+#[query(url = "/cosmos.bank.v1beta1.Query/SendEnabled")]
+pub struct QuerySendEnabledRequest {
+    /// denoms is the specific denoms you want look up. Leave empty to get all entries.
+    #[proto(repeated)]
+    pub denoms: Vec<Denom>,
+    /// pagination defines an optional pagination for the request. This field is
+    /// only read if the denoms field is empty.
+    #[proto(optional)]
+    pub pagination: Option<PaginationRequest>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, Query, Protobuf)]
+#[proto(raw = "inner::QuerySendEnabledResponse")]
+pub struct QuerySendEnabledResponse {
+    #[proto(repeated)]
+    pub send_enabled: Vec<SendEnabled>,
+    /// pagination defines the pagination in the response. This field is only
+    /// populated if the denoms field in the request is empty.
+    #[proto(optional)]
+    pub pagination: Option<PaginationResponse>,
+}
@@ -1,11 +1,24 @@
 use std::str::FromStr;
 
-use bank::{BankABCIHandler, GenesisState, Keeper, Message};
+use bank::{
+    invariants::TOTAL_SUPPLY_INVARIANT,
+    migrations::CONSENSUS_VERSION,
+    types::query::{QueryDenomOwnersRequest, QueryDenomOwnersResponse},
+    BankABCIHandler, GenesisState, Keeper, Message,
+};
 use gears::{
-    application::handlers::node::ModuleInfo,
+    application::handlers::node::{ABCIHandler, InvariantError, InvariantRoute, ModuleInfo},
+    baseapp::{options::NodeOptions, ConsensusParams},
+    context::{init::InitContext, tx::TxContext, InfallibleContextMut},
+    core::Protobuf,
     derive::{ParamsKeys, StoreKeys},
     extensions::testing::UnwrapTesting,
-    tendermint::types::time::timestamp::Timestamp,
+    gas::metering::GasMeter,
+    params::gas::schedule::GasSchedule,
+    store::database::MemDB,
+    tendermint::types::{
+        proto::header::Header, request::query::RequestQuery, time::timestamp::Timestamp,
+    },
     types::{
         address::AccAddress,
         base::{
@@ -14,9 +27,13 @@ use gears::{
         },
         msg::send::MsgSend,
     },
-    utils::node::{acc_address, generate_tx, init_node, GenesisSource, MockOptionsFormer, User},
+    utils::node::{
+        acc_address, build_init_ctx, build_store, generate_tx, init_node, GenesisSource,
+        MockOptionsFormer, User,
+    },
     x::{keepers::mocks::auth::MockAuthKeeper, module::Module},
 };
+use upgrade::migrations::Migrator;
 
 #[test]
 /// In this scenario, we test the initialization of the application and execute a few blocks
@@ -108,6 +125,271 @@ fn test_init_and_sending_tx() {
     );
 }
 
+#[test]
+/// In this scenario, we assert invariants on every block while a tx moves balances
+fn test_invariants_checked_on_every_block() {
+    let mut genesis = GenesisState::default();
+
+    genesis.add_genesis_account(
+        acc_address(),
+        UnsignedCoins::new(vec![UnsignedCoin::from_str("30uatom").unwrap_test()]).unwrap_test(),
+    );
+
+    let opt: MockOptionsFormer<
+        SubspaceKey,
+        BankABCIHandler<SpaceKey, SubspaceKey, MockAuthKeeper, BankModules, BankModuleInfo>,
+        GenesisState,
+    > = MockOptionsFormer::new()
+        .abci_handler(BankABCIHandler::new(Keeper::new(
+            SpaceKey::Auth,
+            SubspaceKey::Auth,
+            MockAuthKeeper::former().form(),
+        )))
+        .baseapp_sbs_key(SubspaceKey::BaseApp)
+        .node_opt(NodeOptions::default().with_inv_check_period(1))
+        .genesis(GenesisSource::Genesis(genesis));
+
+    let mut node = init_node(opt);
+
+    let user = User::from_bech32("race draft rival universe maid cheese steel logic crowd fork comic easy truth drift tomorrow eye buddy head time cash swing swift midnight borrow", 1).unwrap_test();
+
+    node.step(vec![], Timestamp::UNIX_EPOCH);
+
+    let msg = Message::Send(MsgSend {
+        from_address: user.address(),
+        to_address: "cosmos180tr8wmsk8ugt32yynj8efqwg3yglmpwp22rut"
+            .parse()
+            .expect("hard coded address is valid"),
+        amount: Coins::new(vec!["10uatom".parse().expect("hard coded coin is valid")])
+            .expect("hard coded coins are valid"),
+    });
+
+    let txs = generate_tx(vec1::vec1![msg], 0, &user, node.chain_id().clone());
+
+    // a broken invariant halts the node
+    node.step(vec![txs], Timestamp::UNIX_EPOCH);
+    node.step(vec![], Timestamp::UNIX_EPOCH);
+}
+
+#[test]
+/// In this scenario, we check that total supply invariant holds after a tx moves balances
+fn test_invariants_hold_after_tx() {
+    let (handler, _) = invariant_handler();
+    let route = InvariantRoute::new(BankModuleInfo::NAME, TOTAL_SUPPLY_INVARIANT);
+    assert_eq!(handler.invariant_routes(), vec![route]);
+
+    with_genesis_tx_ctx(&handler, |ctx| {
+        assert_eq!(handler.check_invariant(ctx, &route), Ok(()));
+
+        let msg = Message::Send(MsgSend {
+            from_address: acc_address(),
+            to_address: "cosmos180tr8wmsk8ugt32yynj8efqwg3yglmpwp22rut"
+                .parse()
+                .expect("hard coded address is valid"),
+            amount: Coins::new(vec!["10uatom".parse().expect("hard coded coin is valid")])
+                .expect("hard coded coins are valid"),
+        });
+        handler.msg(ctx, &msg).unwrap_test();
+
+        assert_eq!(handler.check_invariant(ctx, &route), Ok(()));
+    });
+}
+
+#[test]
+/// In this scenario, we check that total supply invariant is broken by a balance which isn't in supply
+fn test_invariants_broken_by_balance_out_of_supply() {
+    let (handler, keeper) = invariant_handler();
+    let route = InvariantRoute::new(BankModuleInfo::NAME, TOTAL_SUPPLY_INVARIANT);
+
+    with_genesis_tx_ctx(&handler, |ctx| {
+        keeper
+            .set_balance(
+                ctx,
+                &acc_address(),
+                UnsignedCoin::from_str("31uatom").unwrap_test(),
+            )
+            .unwrap_test();
+
+        assert!(matches!(
+            handler.check_invariant(ctx, &route),
+            Err(InvariantError::Broken(_))
+        ));
+    });
+}
+
+type TestKeeper = Keeper<SpaceKey, SubspaceKey, MockAuthKeeper, BankModules>;
+type TestHandler =
+    BankABCIHandler<SpaceKey, SubspaceKey, MockAuthKeeper, BankModules, BankModuleInfo>;
+
+fn invariant_handler() -> (TestHandler, TestKeeper) {
+    let keeper = Keeper::new(
+        SpaceKey::Auth,
+        SubspaceKey::Auth,
+        MockAuthKeeper::former().form(),
+    );
+
+    (BankABCIHandler::new(keeper.clone()), keeper)
+}
+
+/// Runs `f` in tx context over state with `30uatom` of [acc_address] in genesis
+fn with_genesis_tx_ctx(handler: &TestHandler, f: impl FnOnce(&mut TxContext<'_, MemDB, SpaceKey>)) {
+    let mut genesis = GenesisState::default();
+    genesis.add_genesis_account(
+        acc_address(),
+        UnsignedCoins::new(vec![UnsignedCoin::from_str("30uatom").unwrap_test()]).unwrap_test(),
+    );
+
+    let mut multi_store = build_store::<SpaceKey>();
+    handler.init_genesis(
+        &mut build_init_ctx(&mut multi_store, ConsensusParams::default()),
+        genesis,
+    );
+
+    let mut multi_store = multi_store.to_tx_kind();
+    let mut block_gas_meter = GasMeter::infinite();
+    let mut ctx = TxContext::new(
+        &mut multi_store,
+        1,
+        Header::default(),
+        ConsensusParams::default(),
+        GasSchedule::default(),
+        GasMeter::infinite(),
+        &mut block_gas_meter,
+        NodeOptions::default(),
+        0,
+        [0; 32],
+    );
+
+    f(&mut ctx)
+}
+
+#[test]
+/// In this scenario, we check that denom owners index follows balances changed by tx
+fn test_denom_owners_after_tx() {
+    let mut genesis = GenesisState::default();
+
+    genesis.add_genesis_account(
+        acc_address(),
+        UnsignedCoins::new(vec![UnsignedCoin::from_str("30uatom").unwrap_test()]).unwrap_test(),
+    );
+
+    let opt: MockOptionsFormer<
+        SubspaceKey,
+        BankABCIHandler<SpaceKey, SubspaceKey, MockAuthKeeper, BankModules, BankModuleInfo>,
+        GenesisState,
+    > = MockOptionsFormer::new()
+        .abci_handler(BankABCIHandler::new(Keeper::new(
+            SpaceKey::Auth,
+            SubspaceKey::Auth,
+            MockAuthKeeper::former().form(),
+        )))
+        .baseapp_sbs_key(SubspaceKey::BaseApp)
+        .genesis(GenesisSource::Genesis(genesis));
+
+    let mut node = init_node(opt);
+
+    let user = User::from_bech32("race draft rival universe maid cheese steel logic crowd fork comic easy truth drift tomorrow eye buddy head time cash swing swift midnight borrow", 1).unwrap_test();
+
+    node.step(vec![], Timestamp::UNIX_EPOCH);
+
+    let query_owners = |node: &gears::utils::node::MockNode<_, _>| {
+        let res = node.query(RequestQuery {
+            data: QueryDenomOwnersRequest {
+                denom: "uatom".parse().expect("hard coded denom is valid"),
+                pagination: None,
+            }
+            .encode_vec()
+            .into(),
+            path: QueryDenomOwnersRequest::QUERY_URL.to_owned(),
+            height: node.height() as i64,
+            prove: false,
+        });
+        assert_eq!(res.code, 0, "{}", res.log);
+
+        QueryDenomOwnersResponse::decode_vec(&res.value)
+            .unwrap_test()
+            .denom_owners
+            .into_iter()
+            .map(|owner| (owner.address, owner.balance))
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(
+        query_owners(&node),
+        vec![(
+            user.address(),
+            UnsignedCoin::from_str("30uatom").unwrap_test()
+        )]
+    );
+
+    let to_address: AccAddress = "cosmos180tr8wmsk8ugt32yynj8efqwg3yglmpwp22rut"
+        .parse()
+        .expect("hard coded address is valid");
+
+    let msg = Message::Send(MsgSend {
+        from_address: user.address(),
+        to_address: to_address.clone(),
+        amount: Coins::new(vec!["30uatom".parse().expect("hard coded coin is valid")])
+            .expect("hard coded coins are valid"),
+    });
+
+    let txs = generate_tx(vec1::vec1![msg], 0, &user, node.chain_id().clone());
+    node.step(vec![txs], Timestamp::UNIX_EPOCH);
+
+    // sender spent whole balance, so only recipient holds the denom
+    assert_eq!(
+        query_owners(&node),
+        vec![(to_address, UnsignedCoin::from_str("30uatom").unwrap_test())]
+    );
+}
+
+#[test]
+/// In this scenario, we check that migration from version 1 indexes owners of existing balances
+fn test_migrate_denom_owners_index() {
+    let (_, keeper) = invariant_handler();
+    let address = acc_address();
+    let denom = "uatom".parse().expect("hard coded denom is valid");
+
+    let mut multi_store = build_store::<SpaceKey>();
+    let mut ctx = build_init_ctx(&mut multi_store, ConsensusParams::default());
+
+    // balance stored by version 1 of bank without index entry
+    ctx.infallible_store_mut(&SpaceKey::Auth).set(
+        [
+            [2, address.len()].as_slice(),
+            address.as_ref(),
+            b"uatom".as_slice(),
+        ]
+        .concat(),
+        UnsignedCoin::from_str("30uatom").unwrap_test().encode_vec(),
+    );
+    assert!(keeper.denom_owners(&ctx, &denom, None).1.is_empty());
+
+    let mut migrator = Migrator::<InitContext<'_, MemDB, SpaceKey>>::new();
+    migrator
+        .register(BankModuleInfo::NAME, 1, |ctx| {
+            keeper.migrate_v1_to_v2(ctx);
+            Ok(())
+        })
+        .unwrap_test();
+
+    let versions = migrator
+        .run_migrations(&mut ctx, [UpgradeModule::Bank], [(UpgradeModule::Bank, 1)])
+        .unwrap_test();
+    assert_eq!(versions[&UpgradeModule::Bank], CONSENSUS_VERSION);
+
+    let owners = keeper
+        .denom_owners(&ctx, &denom, None)
+        .1
+        .into_iter()
+        .map(|owner| (owner.address, owner.balance))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        owners,
+        vec![(address, UnsignedCoin::from_str("30uatom").unwrap_test())]
+    );
+}
+
 #[derive(Debug, Clone)]
 struct BankModuleInfo;
 
@@ -143,6 +425,36 @@ impl Module for BankModules {
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum UpgradeModule {
+    Bank,
+}
+
+impl TryFrom<Vec<u8>> for UpgradeModule {
+    type Error = String;
+
+    fn try_from(value: Vec<u8>) -> Result<Self, Self::Error> {
+        match value.as_slice() {
+            b"bank" => Ok(Self::Bank),
+            _ => Err("unknown module".to_owned()),
+        }
+    }
+}
+
+impl upgrade::Module for UpgradeModule {
+    fn name(&self) -> &'static str {
+        match self {
+            Self::Bank => BankModuleInfo::NAME,
+        }
+    }
+
+    fn consensus_version(&self) -> u64 {
+        match self {
+            Self::Bank => CONSENSUS_VERSION,
+        }
+    }
+}
+
 #[derive(strum::EnumIter, Debug, PartialEq, Eq, Hash, Clone, StoreKeys)]
 #[skey(params = Params)]
 pub enum SpaceKey {
@@ -0,0 +1,54 @@
+use std::marker::PhantomData;
+
+use gears::{
+    application::handlers::node::{ABCIHandler, ModuleInfo, TxError},
+    context::{init::InitContext, tx::TxContext},
+    params::ParamsSubspaceKey,
+    store::{database::Database, StoreKey},
+    x::{keepers::bank::BankKeeper, module::Module},
+};
+
+use crate::{
+    errors::CrisisTxError, genesis::CrisisGenesis, keeper::CrisisKeeper, message::Message,
+};
+
+#[derive(Debug, Clone)]
+pub struct CrisisAbciHandler<SK, PSK, BK, M, MI> {
+    keeper: CrisisKeeper<SK, PSK, BK, M>,
+    _marker: PhantomData<MI>,
+}
+
+impl<SK: StoreKey, PSK: ParamsSubspaceKey, BK: BankKeeper<SK, M>, M: Module, MI: ModuleInfo>
+    CrisisAbciHandler<SK, PSK, BK, M, MI>
+{
+    pub fn new(keeper: CrisisKeeper<SK, PSK, BK, M>) -> Self {
+        Self {
+            keeper,
+            _marker: PhantomData,
+        }
+    }
+
+    pub fn init_genesis<DB: Database>(
+        &self,
+        ctx: &mut InitContext<'_, DB, SK>,
+        genesis: CrisisGenesis,
+    ) {
+        self.keeper.init_genesis(ctx, genesis)
+    }
+
+    /// Handle message of the module. Invariants are checked with routes
+    /// registered in application `handler`.
+    pub fn msg<DB: Database, H: ABCIHandler<StoreKey = SK>>(
+        &self,
+        ctx: &mut TxContext<'_, DB, SK>,
+        msg: &Message,
+        handler: &H,
+    ) -> Result<(), TxError> {
+        match msg {
+            Message::VerifyInvariant(msg) => self
+                .keeper
+                .verify_invariant(ctx, handler, msg)
+                .map_err(|e| CrisisTxError::from(e).into::<MI>()),
+        }
+    }
+}
@@ -0,0 +1,35 @@
+use anyhow::Result;
+use clap::{Args, Subcommand};
+use gears::types::address::AccAddress;
+
+use crate::message::{Message, MsgVerifyInvariant};
+
+#[derive(Args, Debug, Clone)]
+pub struct CrisisTxCli {
+    #[command(subcommand)]
+    pub command: CrisisCommands,
+}
+
+#[derive(Subcommand, Debug, Clone)]
+pub enum CrisisCommands {
+    /// Submit proof that an invariant broken to halt the chain
+    InvariantBroken {
+        /// Name of the module which registered the invariant, e.g. `bank`
+        module_name: String,
+        /// Route of the invariant, e.g. `total-supply`
+        invariant_route: String,
+    },
+}
+
+pub fn run_crisis_tx_command(args: CrisisTxCli, from_address: AccAddress) -> Result<Message> {
+    match args.command {
+        CrisisCommands::InvariantBroken {
+            module_name,
+            invariant_route,
+        } => Ok(Message::VerifyInvariant(MsgVerifyInvariant {
+            sender: from_address,
+            invariant_module_name: module_name,
+            invariant_route,
+        })),
+    }
+}
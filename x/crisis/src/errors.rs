@@ -0,0 +1,35 @@
+use gears::{
+    application::handlers::node::{ModuleInfo, TxError},
+    gas::store::errors::GasStoreErrors,
+    types::base::errors::CoinsError,
+    x::errors::BankKeeperError,
+};
+
+#[derive(thiserror::Error, Debug)]
+pub enum CrisisTxError {
+    #[error(transparent)]
+    Keeper(#[from] CrisisKeeperError),
+}
+
+impl CrisisTxError {
+    pub fn into<MI: ModuleInfo>(self) -> TxError {
+        let code = match &self {
+            CrisisTxError::Keeper(CrisisKeeperError::UnknownInvariant { .. }) => nz::u16!(3),
+            CrisisTxError::Keeper(_) => nz::u16!(1),
+        };
+
+        TxError::new::<MI>(self.to_string(), code)
+    }
+}
+
+#[derive(thiserror::Error, Debug, Clone)]
+pub enum CrisisKeeperError {
+    #[error("unknown invariant {module}/{route}")]
+    UnknownInvariant { module: String, route: String },
+    #[error("{0}")]
+    Bank(#[from] BankKeeperError),
+    #[error("{0}")]
+    Coins(#[from] CoinsError),
+    #[error("{0}")]
+    Gas(#[from] GasStoreErrors),
+}
@@ -0,0 +1,31 @@
+use gears::{
+    baseapp::genesis::{Genesis, GenesisError},
+    types::{address::AccAddress, base::coin::UnsignedCoin, base::coins::UnsignedCoins},
+};
+use serde::{Deserialize, Serialize};
+
+use crate::params::CrisisParams;
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CrisisGenesis {
+    /// fee paid by sender of `MsgVerifyInvariant`
+    pub constant_fee: UnsignedCoin,
+}
+
+impl Default for CrisisGenesis {
+    fn default() -> Self {
+        Self {
+            constant_fee: CrisisParams::default().constant_fee,
+        }
+    }
+}
+
+impl Genesis for CrisisGenesis {
+    fn add_genesis_account(
+        &mut self,
+        _address: AccAddress,
+        _coins: UnsignedCoins,
+    ) -> Result<(), GenesisError> {
+        Ok(())
+    }
+}
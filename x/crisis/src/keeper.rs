@@ -0,0 +1,121 @@
+use std::marker::PhantomData;
+
+use gears::{
+    application::{
+        handlers::node::{ABCIHandler, InvariantError},
+        keepers::params::ParamsKeeper,
+    },
+    context::{init::InitContext, tx::TxContext, TransactionalContext},
+    params::ParamsSubspaceKey,
+    store::{database::Database, StoreKey},
+    tendermint::types::proto::event::{Event, EventAttribute},
+    types::base::coins::UnsignedCoins,
+    x::{keepers::bank::BankKeeper, module::Module},
+};
+
+use crate::{
+    errors::CrisisKeeperError,
+    genesis::CrisisGenesis,
+    message::MsgVerifyInvariant,
+    params::{CrisisParams, CrisisParamsKeeper},
+};
+
+#[derive(Debug, Clone)]
+pub struct CrisisKeeper<SK, PSK, BK, M> {
+    params_keeper: CrisisParamsKeeper<PSK>,
+    bank_keeper: BK,
+    fee_collector: M,
+    _marker: PhantomData<SK>,
+}
+
+impl<SK, PSK, BK, M> CrisisKeeper<SK, PSK, BK, M> {
+    pub fn new(params_subspace_key: PSK, bank_keeper: BK, fee_collector: M) -> Self {
+        Self {
+            params_keeper: CrisisParamsKeeper {
+                params_subspace_key,
+            },
+            bank_keeper,
+            fee_collector,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<SK: StoreKey, PSK: ParamsSubspaceKey, BK: BankKeeper<SK, M>, M: Module>
+    CrisisKeeper<SK, PSK, BK, M>
+{
+    pub fn init_genesis<DB: Database>(
+        &self,
+        ctx: &mut InitContext<'_, DB, SK>,
+        CrisisGenesis { constant_fee }: CrisisGenesis,
+    ) {
+        self.params_keeper.set(ctx, CrisisParams { constant_fee });
+    }
+
+    /// Charge sender with constant fee and check requested invariant of `handler`.
+    /// A broken invariant halts the chain.
+    pub fn verify_invariant<DB: Database, H: ABCIHandler<StoreKey = SK>>(
+        &self,
+        ctx: &mut TxContext<'_, DB, SK>,
+        handler: &H,
+        msg: &MsgVerifyInvariant,
+    ) -> Result<(), CrisisKeeperError> {
+        let CrisisParams { constant_fee } = self.params_keeper.try_get(ctx)?;
+        if !constant_fee.amount.is_zero() {
+            self.bank_keeper.send_coins_from_account_to_module(
+                ctx,
+                msg.sender.clone(),
+                &self.fee_collector,
+                UnsignedCoins::new([constant_fee])?,
+            )?;
+        }
+
+        let route = handler
+            .invariant_routes()
+            .into_iter()
+            .find(|route| {
+                route.module == msg.invariant_module_name && route.route == msg.invariant_route
+            })
+            .ok_or_else(|| CrisisKeeperError::UnknownInvariant {
+                module: msg.invariant_module_name.clone(),
+                route: msg.invariant_route.clone(),
+            })?;
+
+        match handler.check_invariant(ctx, &route) {
+            Ok(()) => (),
+            // state is corrupted and there is no way to continue
+            Err(InvariantError::Broken(e)) => panic!("invariant {route} is broken: {e}"),
+            Err(InvariantError::NotRegistered) => Err(CrisisKeeperError::UnknownInvariant {
+                module: msg.invariant_module_name.clone(),
+                route: msg.invariant_route.clone(),
+            })?,
+            Err(InvariantError::Gas(e)) => Err(e)?,
+        }
+
+        ctx.push_event(Event {
+            r#type: "invariant".to_owned(),
+            attributes: vec![EventAttribute {
+                key: "route".into(),
+                value: msg.invariant_route.clone().into(),
+                index: false,
+            }],
+        });
+        ctx.push_event(Event {
+            r#type: "message".to_owned(),
+            attributes: vec![
+                EventAttribute {
+                    key: "module".into(),
+                    value: "crisis".into(),
+                    index: false,
+                },
+                EventAttribute {
+                    key: "sender".into(),
+                    value: msg.sender.to_string().into(),
+                    index: false,
+                },
+            ],
+        });
+
+        Ok(())
+    }
+}
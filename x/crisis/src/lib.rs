@@ -0,0 +1,7 @@
+pub mod abci_handler;
+pub mod client;
+pub mod errors;
+pub mod genesis;
+pub mod keeper;
+pub mod message;
+pub mod params;
@@ -0,0 +1,58 @@
+use gears::{
+    core::Protobuf,
+    derive::AppMessage,
+    types::address::{AccAddress, AddressError},
+};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, AppMessage)]
+pub enum Message {
+    #[serde(rename = "/cosmos.crisis.v1beta1.MsgVerifyInvariant")]
+    #[msg(url(path = MsgVerifyInvariant::TYPE_URL))]
+    VerifyInvariant(MsgVerifyInvariant),
+}
+
+#[derive(Clone, PartialEq, Serialize, Deserialize, prost::Message)]
+pub struct MsgVerifyInvariantRaw {
+    #[prost(string, tag = "1")]
+    pub sender: String,
+    #[prost(string, tag = "2")]
+    pub invariant_module_name: String,
+    #[prost(string, tag = "3")]
+    pub invariant_route: String,
+}
+
+impl From<MsgVerifyInvariant> for MsgVerifyInvariantRaw {
+    fn from(value: MsgVerifyInvariant) -> Self {
+        Self {
+            sender: value.sender.to_string(),
+            invariant_module_name: value.invariant_module_name,
+            invariant_route: value.invariant_route,
+        }
+    }
+}
+
+/// MsgVerifyInvariant represents a message to verify a particular invariance.
+/// Sender pays the constant fee for the check.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, AppMessage)]
+#[msg(url = "/cosmos.crisis.v1beta1.MsgVerifyInvariant")]
+pub struct MsgVerifyInvariant {
+    #[msg(signer)]
+    pub sender: AccAddress,
+    pub invariant_module_name: String,
+    pub invariant_route: String,
+}
+
+impl TryFrom<MsgVerifyInvariantRaw> for MsgVerifyInvariant {
+    type Error = AddressError;
+
+    fn try_from(value: MsgVerifyInvariantRaw) -> Result<Self, Self::Error> {
+        Ok(Self {
+            sender: AccAddress::from_bech32(&value.sender)?,
+            invariant_module_name: value.invariant_module_name,
+            invariant_route: value.invariant_route,
+        })
+    }
+}
+
+impl Protobuf<MsgVerifyInvariantRaw> for MsgVerifyInvariant {}
@@ -0,0 +1,74 @@
+use std::{
+    collections::{HashMap, HashSet},
+    str::FromStr,
+};
+
+use gears::{
+    application::keepers::params::ParamsKeeper,
+    extensions::corruption::UnwrapCorrupt,
+    params::{ParamsDeserialize, ParamsSerialize, ParamsSubspaceKey},
+    types::{base::coin::UnsignedCoin, denom::Denom, uint::Uint256},
+};
+use serde::{Deserialize, Serialize};
+
+const KEY_CONSTANT_FEE: &str = "ConstantFee";
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct CrisisParams {
+    /// fee paid by sender of `MsgVerifyInvariant`
+    pub constant_fee: UnsignedCoin,
+}
+
+impl Default for CrisisParams {
+    fn default() -> Self {
+        Self {
+            constant_fee: UnsignedCoin {
+                denom: Denom::from_str(env!("XMOD_STAKING_PARAMS_BOND_DENOM"))
+                    .expect("default denom for constant fee is invalid"),
+                amount: Uint256::from(1000u64),
+            },
+        }
+    }
+}
+
+impl ParamsSerialize for CrisisParams {
+    fn keys() -> HashSet<&'static str> {
+        HashSet::from_iter([KEY_CONSTANT_FEE])
+    }
+
+    fn to_raw(&self) -> Vec<(&'static str, Vec<u8>)> {
+        vec![(
+            KEY_CONSTANT_FEE,
+            serde_json::to_vec(&self.constant_fee).expect("conversion to json shouldn't fail"),
+        )]
+    }
+}
+
+impl ParamsDeserialize for CrisisParams {
+    fn from_raw(fields: HashMap<&'static str, Vec<u8>>) -> Self {
+        Self {
+            constant_fee: serde_json::from_slice(fields.get(KEY_CONSTANT_FEE).unwrap_or_corrupt())
+                .unwrap_or_corrupt(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct CrisisParamsKeeper<PSK> {
+    pub(super) params_subspace_key: PSK,
+}
+
+impl<PSK: ParamsSubspaceKey> ParamsKeeper<PSK> for CrisisParamsKeeper<PSK> {
+    type Param = CrisisParams;
+
+    fn psk(&self) -> &PSK {
+        &self.params_subspace_key
+    }
+
+    fn validate(key: impl AsRef<[u8]>, value: impl AsRef<[u8]>) -> bool {
+        match String::from_utf8_lossy(key.as_ref()).as_ref() {
+            KEY_CONSTANT_FEE => serde_json::from_slice::<UnsignedCoin>(value.as_ref()).is_ok(),
+            _ => false,
+        }
+    }
+}
@@ -1,12 +1,17 @@
+use crate::invariants::{CAN_WITHDRAW_INVARIANT, MODULE_ACCOUNT_INVARIANT};
 use crate::{
     errors::DistributionTxError, GenesisState, Keeper, Message, QueryCommunityPoolRequest,
-    QueryCommunityPoolResponse, QueryDelegationRewardsRequest, QueryDelegatorParams,
-    QueryDelegatorTotalRewardsResponse, QueryParamsRequest, QueryParamsResponse,
+    QueryCommunityPoolResponse, QueryDelegationRewardsRequest, QueryDelegationRewardsResponse,
+    QueryDelegatorParams, QueryDelegatorTotalRewardsResponse, QueryDelegatorWithdrawAddressRequest,
+    QueryDelegatorWithdrawAddressResponse, QueryParamsRequest, QueryParamsResponse,
     QueryValidatorCommissionRequest, QueryValidatorCommissionResponse,
+    QueryValidatorDistributionInfoRequest, QueryValidatorDistributionInfoResponse,
     QueryValidatorOutstandingRewardsRequest, QueryValidatorOutstandingRewardsResponse,
     QueryValidatorSlashesRequest, QueryValidatorSlashesResponse, QueryWithdrawAllRewardsRequest,
+    QueryWithdrawAllRewardsResponse,
 };
 use gears::{
+    application::handlers::node::{InvariantError, InvariantRoute, ModuleInfo},
     baseapp::errors::QueryError,
     context::{
         block::BlockContext, init::InitContext, query::QueryContext, tx::TxContext,
@@ -16,7 +21,7 @@ use gears::{
     params::ParamsSubspaceKey,
     store::{database::Database, StoreKey},
     tendermint::{request::RequestBeginBlock, types::request::query::RequestQuery},
-    types::address::ConsAddress,
+    types::address::{AccAddress, ConsAddress},
     x::{
         keepers::{
             auth::AuthKeeper,
@@ -33,6 +38,10 @@ pub enum DistributionNodeQueryRequest {
     ValidatorCommission(QueryValidatorCommissionRequest),
     ValidatorSlashes(QueryValidatorSlashesRequest),
     DelegatorTotalRewards(QueryDelegatorParams),
+    DelegationRewards(QueryDelegationRewardsRequest),
+    DelegatorValidators(QueryWithdrawAllRewardsRequest),
+    DelegatorWithdrawAddress(QueryDelegatorWithdrawAddressRequest),
+    ValidatorDistributionInfo(QueryValidatorDistributionInfoRequest),
     CommunityPool(QueryCommunityPoolRequest),
     Params(QueryParamsRequest),
 }
@@ -42,6 +51,10 @@ pub enum DistributionNodeQueryResponse {
     ValidatorCommission(QueryValidatorCommissionResponse),
     ValidatorSlashes(QueryValidatorSlashesResponse),
     DelegatorTotalRewards(QueryDelegatorTotalRewardsResponse),
+    DelegationRewards(QueryDelegationRewardsResponse),
+    DelegatorValidators(QueryWithdrawAllRewardsResponse),
+    DelegatorWithdrawAddress(QueryDelegatorWithdrawAddressResponse),
+    ValidatorDistributionInfo(QueryValidatorDistributionInfoResponse),
     CommunityPool(QueryCommunityPoolResponse),
     Params(QueryParamsResponse),
 }
@@ -88,6 +101,9 @@ impl<
                 .withdraw_delegator_reward_and_commission(ctx, msg)?),
             Message::SetWithdrawAddr(msg) => Ok(self.keeper.set_withdraw_address(ctx, msg)?),
             Message::FundCommunityPool(msg) => Ok(self.keeper.fund_community_pool_cmd(ctx, msg)?),
+            Message::CommunityPoolSpend(msg) => {
+                Ok(self.keeper.community_pool_spend_cmd(ctx, msg)?)
+            }
         }
     }
 
@@ -151,6 +167,24 @@ impl<
                     .encode_vec()
                     .into())
             }
+            "/cosmos.distribution.v1beta1.Query/DelegatorWithdrawAddress" => {
+                let req = QueryDelegatorWithdrawAddressRequest::decode(query.data)?;
+
+                Ok(self
+                    .keeper
+                    .query_delegator_withdraw_address(ctx, req)
+                    .encode_vec()
+                    .into())
+            }
+            "/cosmos.distribution.v1beta1.Query/ValidatorDistributionInfo" => {
+                let req = QueryValidatorDistributionInfoRequest::decode(query.data)?;
+
+                Ok(self
+                    .keeper
+                    .query_validator_distribution_info(ctx, req)?
+                    .encode_vec()
+                    .into())
+            }
             "/cosmos.distribution.v1beta1.Query/CommunityPool" => {
                 let req = QueryCommunityPoolRequest::decode(query.data)?;
 
@@ -195,6 +229,35 @@ impl<
                     self.keeper.query_delegator_total_rewards(ctx, req),
                 )
             }
+            DistributionNodeQueryRequest::DelegationRewards(req) => {
+                DistributionNodeQueryResponse::DelegationRewards(
+                    self.keeper
+                        .query_delegation_rewards(ctx, req)
+                        .unwrap_or(QueryDelegationRewardsResponse { rewards: None }),
+                )
+            }
+            DistributionNodeQueryRequest::DelegatorValidators(req) => {
+                DistributionNodeQueryResponse::DelegatorValidators(
+                    self.keeper.query_delegator_validators(ctx, req),
+                )
+            }
+            DistributionNodeQueryRequest::DelegatorWithdrawAddress(req) => {
+                DistributionNodeQueryResponse::DelegatorWithdrawAddress(
+                    self.keeper.query_delegator_withdraw_address(ctx, req),
+                )
+            }
+            DistributionNodeQueryRequest::ValidatorDistributionInfo(req) => {
+                let operator_address = AccAddress::from(req.validator_address.clone());
+                DistributionNodeQueryResponse::ValidatorDistributionInfo(
+                    self.keeper
+                        .query_validator_distribution_info(ctx, req)
+                        .unwrap_or(QueryValidatorDistributionInfoResponse {
+                            operator_address,
+                            self_bond_rewards: None,
+                            commission: None,
+                        }),
+                )
+            }
             DistributionNodeQueryRequest::CommunityPool(req) => {
                 DistributionNodeQueryResponse::CommunityPool(
                     self.keeper.query_community_pool(ctx, req),
@@ -250,4 +313,23 @@ impl<
         };
         self.keeper.set_previous_proposer_cons_addr(ctx, &cons_addr);
     }
+
+    pub fn invariant_routes<MI: ModuleInfo>(&self) -> Vec<InvariantRoute> {
+        vec![
+            InvariantRoute::new(MI::NAME, MODULE_ACCOUNT_INVARIANT),
+            InvariantRoute::new(MI::NAME, CAN_WITHDRAW_INVARIANT),
+        ]
+    }
+
+    pub fn check_invariant<DB: Database, CTX: QueryableContext<DB, SK>>(
+        &self,
+        ctx: &CTX,
+        route: &InvariantRoute,
+    ) -> Result<(), InvariantError> {
+        match route.route {
+            MODULE_ACCOUNT_INVARIANT => self.keeper.module_account_invariant(ctx),
+            CAN_WITHDRAW_INVARIANT => self.keeper.can_withdraw_invariant(ctx),
+            _ => Err(InvariantError::NotRegistered),
+        }
+    }
 }
@@ -1,45 +1,215 @@
-// TODO: uncomment and add impl for new version of ibc_proto
-// use crate::{DistributionNodeQueryRequest, DistributionNodeQueryResponse};
-// use gears::baseapp::{NodeQueryHandler, QueryRequest, QueryResponse};
-// use ibc_proto::cosmos::distribution::v1beta1::{
-//     query_server::{Query, QueryServer},
-//     QueryParamsRequest, QueryParamsResponse,
-// };
-// use std::marker::PhantomData;
-// use tonic::{Request, Response, Status};
-//
-// #[derive(Debug, Default)]
-// pub struct DistributionService<QH, QReq, QRes> {
-//     app: QH,
-//     _phantom: PhantomData<(QReq, QRes)>,
-// }
-//
-// #[tonic::async_trait]
-// impl<
-//         QReq: Send + Sync + 'static,
-//         QRes: Send + Sync + 'static,
-//         QH: NodeQueryHandler<QReq, QRes>,
-//     > Query for DistributionService<QH, QReq, QRes>
-// where
-//     QReq: QueryRequest + From<DistributionNodeQueryRequest>,
-//     QRes: QueryResponse + TryInto<DistributionNodeQueryResponse, Error = Status>,
-// {
-//     // fill
-// }
-//
-// pub fn new<QH, QReq, QRes>(app: QH) -> QueryServer<DistributionService<QH, QReq, QRes>>
-// where
-//     QReq: QueryRequest + Send + Sync + 'static + From<DistributionNodeQueryRequest>,
-//     QRes: QueryResponse
-//         + Send
-//         + Sync
-//         + 'static
-//         + TryInto<DistributionNodeQueryResponse, Error = Status>,
-//     QH: NodeQueryHandler<QReq, QRes>,
-// {
-//     let distribution_service = DistributionService {
-//         app,
-//         _phantom: Default::default(),
-//     };
-//     QueryServer::new(distribution_service)
-// }
+use crate::{
+    DistributionNodeQueryRequest, DistributionNodeQueryResponse, QueryDelegationRewardsResponseRaw,
+    QueryValidatorCommissionRequestRaw, QueryValidatorCommissionResponseRaw,
+    QueryValidatorOutstandingRewardsRequestRaw, QueryValidatorOutstandingRewardsResponseRaw,
+    QueryValidatorSlashesRequestRaw, QueryValidatorSlashesResponseRaw,
+    QueryWithdrawAllRewardsRequestRaw, QueryWithdrawAllRewardsResponseRaw,
+    RawQueryCommunityPoolRequest, RawQueryCommunityPoolResponse, RawQueryDelegationRewardsRequest,
+    RawQueryDelegatorParams, RawQueryDelegatorTotalRewardsResponse,
+    RawQueryDelegatorWithdrawAddressRequest, RawQueryDelegatorWithdrawAddressResponse,
+    RawQueryParamsRequest, RawQueryParamsResponse, RawQueryValidatorDistributionInfoRequest,
+    RawQueryValidatorDistributionInfoResponse,
+};
+use gears::{
+    baseapp::{NodeQueryHandler, QueryRequest, QueryResponse},
+    grpc::service::{from_raw, unary, unimplemented},
+};
+use std::{convert::Infallible, marker::PhantomData};
+use tonic::{
+    body::BoxBody,
+    codegen::{http, Body, BoxFuture, Context, Poll, Service, StdError},
+    server::NamedService,
+    Status,
+};
+
+const ERROR_STATE_MSG: &str = "An internal error occurred while querying the application state.";
+
+#[derive(Debug)]
+pub struct DistributionService<QH, QReq, QRes> {
+    app: QH,
+    _phantom: PhantomData<(QReq, QRes)>,
+}
+
+impl<QH: Clone, QReq, QRes> Clone for DistributionService<QH, QReq, QRes> {
+    fn clone(&self) -> Self {
+        Self {
+            app: self.app.clone(),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<QH, QReq, QRes> NamedService for DistributionService<QH, QReq, QRes> {
+    const NAME: &'static str = "cosmos.distribution.v1beta1.Query";
+}
+
+fn query<QH, QReq, QRes>(
+    app: &QH,
+    req: DistributionNodeQueryRequest,
+) -> Result<DistributionNodeQueryResponse, Status>
+where
+    QReq: QueryRequest + From<DistributionNodeQueryRequest>,
+    QRes: QueryResponse + TryInto<DistributionNodeQueryResponse, Error = Status>,
+    QH: NodeQueryHandler<QReq, QRes>,
+{
+    app.typed_query(req)?.try_into()
+}
+
+impl<QH, QReq, QRes, B> Service<http::Request<B>> for DistributionService<QH, QReq, QRes>
+where
+    QReq: QueryRequest + Send + Sync + 'static + From<DistributionNodeQueryRequest>,
+    QRes: QueryResponse
+        + Send
+        + Sync
+        + 'static
+        + TryInto<DistributionNodeQueryResponse, Error = Status>,
+    QH: NodeQueryHandler<QReq, QRes>,
+    B: Body + Send + 'static,
+    B::Error: Into<StdError> + Send + 'static,
+{
+    type Response = http::Response<BoxBody>;
+    type Error = Infallible;
+    type Future = BoxFuture<Self::Response, Self::Error>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, req: http::Request<B>) -> Self::Future {
+        let app = self.app.clone();
+
+        match req.uri().path() {
+            "/cosmos.distribution.v1beta1.Query/Params" => {
+                unary(req, move |raw: RawQueryParamsRequest| {
+                    let req = DistributionNodeQueryRequest::Params(from_raw(raw)?);
+                    match query::<_, QReq, QRes>(&app, req)? {
+                        DistributionNodeQueryResponse::Params(res) => {
+                            Ok(RawQueryParamsResponse::from(res))
+                        }
+                        _ => Err(Status::internal(ERROR_STATE_MSG)),
+                    }
+                })
+            }
+            "/cosmos.distribution.v1beta1.Query/ValidatorDistributionInfo" => {
+                unary(req, move |raw: RawQueryValidatorDistributionInfoRequest| {
+                    let req =
+                        DistributionNodeQueryRequest::ValidatorDistributionInfo(from_raw(raw)?);
+                    match query::<_, QReq, QRes>(&app, req)? {
+                        DistributionNodeQueryResponse::ValidatorDistributionInfo(res) => {
+                            Ok(RawQueryValidatorDistributionInfoResponse::from(res))
+                        }
+                        _ => Err(Status::internal(ERROR_STATE_MSG)),
+                    }
+                })
+            }
+            "/cosmos.distribution.v1beta1.Query/ValidatorOutstandingRewards" => unary(
+                req,
+                move |raw: QueryValidatorOutstandingRewardsRequestRaw| {
+                    let req =
+                        DistributionNodeQueryRequest::ValidatorOutstandingRewards(from_raw(raw)?);
+                    match query::<_, QReq, QRes>(&app, req)? {
+                        DistributionNodeQueryResponse::ValidatorOutstandingRewards(res) => {
+                            Ok(QueryValidatorOutstandingRewardsResponseRaw::from(res))
+                        }
+                        _ => Err(Status::internal(ERROR_STATE_MSG)),
+                    }
+                },
+            ),
+            "/cosmos.distribution.v1beta1.Query/ValidatorCommission" => {
+                unary(req, move |raw: QueryValidatorCommissionRequestRaw| {
+                    let req = DistributionNodeQueryRequest::ValidatorCommission(from_raw(raw)?);
+                    match query::<_, QReq, QRes>(&app, req)? {
+                        DistributionNodeQueryResponse::ValidatorCommission(res) => {
+                            Ok(QueryValidatorCommissionResponseRaw::from(res))
+                        }
+                        _ => Err(Status::internal(ERROR_STATE_MSG)),
+                    }
+                })
+            }
+            "/cosmos.distribution.v1beta1.Query/ValidatorSlashes" => {
+                unary(req, move |raw: QueryValidatorSlashesRequestRaw| {
+                    let req = DistributionNodeQueryRequest::ValidatorSlashes(from_raw(raw)?);
+                    match query::<_, QReq, QRes>(&app, req)? {
+                        DistributionNodeQueryResponse::ValidatorSlashes(res) => {
+                            Ok(QueryValidatorSlashesResponseRaw::from(res))
+                        }
+                        _ => Err(Status::internal(ERROR_STATE_MSG)),
+                    }
+                })
+            }
+            "/cosmos.distribution.v1beta1.Query/DelegationRewards" => {
+                unary(req, move |raw: RawQueryDelegationRewardsRequest| {
+                    let req = DistributionNodeQueryRequest::DelegationRewards(from_raw(raw)?);
+                    match query::<_, QReq, QRes>(&app, req)? {
+                        DistributionNodeQueryResponse::DelegationRewards(res) => {
+                            Ok(QueryDelegationRewardsResponseRaw::from(res))
+                        }
+                        _ => Err(Status::internal(ERROR_STATE_MSG)),
+                    }
+                })
+            }
+            "/cosmos.distribution.v1beta1.Query/DelegationTotalRewards" => {
+                unary(req, move |raw: RawQueryDelegatorParams| {
+                    let req = DistributionNodeQueryRequest::DelegatorTotalRewards(from_raw(raw)?);
+                    match query::<_, QReq, QRes>(&app, req)? {
+                        DistributionNodeQueryResponse::DelegatorTotalRewards(res) => {
+                            Ok(RawQueryDelegatorTotalRewardsResponse::from(res))
+                        }
+                        _ => Err(Status::internal(ERROR_STATE_MSG)),
+                    }
+                })
+            }
+            "/cosmos.distribution.v1beta1.Query/DelegatorValidators" => {
+                unary(req, move |raw: QueryWithdrawAllRewardsRequestRaw| {
+                    let req = DistributionNodeQueryRequest::DelegatorValidators(from_raw(raw)?);
+                    match query::<_, QReq, QRes>(&app, req)? {
+                        DistributionNodeQueryResponse::DelegatorValidators(res) => {
+                            Ok(QueryWithdrawAllRewardsResponseRaw::from(res))
+                        }
+                        _ => Err(Status::internal(ERROR_STATE_MSG)),
+                    }
+                })
+            }
+            "/cosmos.distribution.v1beta1.Query/DelegatorWithdrawAddress" => {
+                unary(req, move |raw: RawQueryDelegatorWithdrawAddressRequest| {
+                    let req =
+                        DistributionNodeQueryRequest::DelegatorWithdrawAddress(from_raw(raw)?);
+                    match query::<_, QReq, QRes>(&app, req)? {
+                        DistributionNodeQueryResponse::DelegatorWithdrawAddress(res) => {
+                            Ok(RawQueryDelegatorWithdrawAddressResponse::from(res))
+                        }
+                        _ => Err(Status::internal(ERROR_STATE_MSG)),
+                    }
+                })
+            }
+            "/cosmos.distribution.v1beta1.Query/CommunityPool" => {
+                unary(req, move |raw: RawQueryCommunityPoolRequest| {
+                    let req = DistributionNodeQueryRequest::CommunityPool(from_raw(raw)?);
+                    match query::<_, QReq, QRes>(&app, req)? {
+                        DistributionNodeQueryResponse::CommunityPool(res) => {
+                            Ok(RawQueryCommunityPoolResponse::from(res))
+                        }
+                        _ => Err(Status::internal(ERROR_STATE_MSG)),
+                    }
+                })
+            }
+            _ => unimplemented(),
+        }
+    }
+}
+
+pub fn new<QH, QReq, QRes>(app: QH) -> DistributionService<QH, QReq, QRes>
+where
+    QReq: QueryRequest + Send + Sync + 'static + From<DistributionNodeQueryRequest>,
+    QRes: QueryResponse
+        + Send
+        + Sync
+        + 'static
+        + TryInto<DistributionNodeQueryResponse, Error = Status>,
+    QH: NodeQueryHandler<QReq, QRes>,
+{
+    DistributionService {
+        app,
+        _phantom: Default::default(),
+    }
+}
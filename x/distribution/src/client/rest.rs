@@ -1,8 +1,10 @@
 use crate::{
     DistributionNodeQueryRequest, DistributionNodeQueryResponse, DistributionParams,
-    QueryCommunityPoolRequest, QueryCommunityPoolResponse, QueryDelegatorParams,
-    QueryParamsRequest, QueryParamsResponse, QueryValidatorCommissionRequest,
+    QueryCommunityPoolRequest, QueryCommunityPoolResponse, QueryDelegationRewardsRequest,
+    QueryDelegatorParams, QueryDelegatorWithdrawAddressRequest, QueryParamsRequest,
+    QueryParamsResponse, QueryValidatorCommissionRequest, QueryValidatorDistributionInfoRequest,
     QueryValidatorOutstandingRewardsRequest, QueryValidatorSlashesRequest,
+    QueryWithdrawAllRewardsRequest,
 };
 use axum::{
     extract::{Path, Query, State},
@@ -33,6 +35,52 @@ pub async fn delegation_delegator_rewards<
     Ok(Json(res))
 }
 
+pub async fn delegation_rewards<
+    QReq: QueryRequest + From<DistributionNodeQueryRequest>,
+    QRes: QueryResponse + TryInto<DistributionNodeQueryResponse>,
+    App: NodeQueryHandler<QReq, QRes>,
+>(
+    Path((delegator_address, validator_address)): Path<(AccAddress, ValAddress)>,
+    State(rest_state): State<RestState<QReq, QRes, App>>,
+) -> Result<Json<QRes>, HTTPError> {
+    let req = DistributionNodeQueryRequest::DelegationRewards(QueryDelegationRewardsRequest {
+        delegator_address,
+        validator_address,
+    });
+    let res = rest_state.app.typed_query(req)?;
+    Ok(Json(res))
+}
+
+pub async fn delegator_validators<
+    QReq: QueryRequest + From<DistributionNodeQueryRequest>,
+    QRes: QueryResponse + TryInto<DistributionNodeQueryResponse>,
+    App: NodeQueryHandler<QReq, QRes>,
+>(
+    Path(delegator_address): Path<AccAddress>,
+    State(rest_state): State<RestState<QReq, QRes, App>>,
+) -> Result<Json<QRes>, HTTPError> {
+    let req = DistributionNodeQueryRequest::DelegatorValidators(QueryWithdrawAllRewardsRequest {
+        delegator_address,
+    });
+    let res = rest_state.app.typed_query(req)?;
+    Ok(Json(res))
+}
+
+pub async fn delegator_withdraw_address<
+    QReq: QueryRequest + From<DistributionNodeQueryRequest>,
+    QRes: QueryResponse + TryInto<DistributionNodeQueryResponse>,
+    App: NodeQueryHandler<QReq, QRes>,
+>(
+    Path(delegator_address): Path<AccAddress>,
+    State(rest_state): State<RestState<QReq, QRes, App>>,
+) -> Result<Json<QRes>, HTTPError> {
+    let req = DistributionNodeQueryRequest::DelegatorWithdrawAddress(
+        QueryDelegatorWithdrawAddressRequest { delegator_address },
+    );
+    let res = rest_state.app.typed_query(req)?;
+    Ok(Json(res))
+}
+
 pub async fn community_pool<
     QReq: QueryRequest + From<DistributionNodeQueryRequest>,
     QRes: QueryResponse + TryInto<DistributionNodeQueryResponse>,
@@ -74,6 +122,21 @@ pub async fn validator_commission<
     Ok(Json(res))
 }
 
+pub async fn validator_distribution_info<
+    QReq: QueryRequest + From<DistributionNodeQueryRequest>,
+    QRes: QueryResponse + TryInto<DistributionNodeQueryResponse>,
+    App: NodeQueryHandler<QReq, QRes>,
+>(
+    Path(validator_address): Path<ValAddress>,
+    State(rest_state): State<RestState<QReq, QRes, App>>,
+) -> Result<Json<QRes>, HTTPError> {
+    let req = DistributionNodeQueryRequest::ValidatorDistributionInfo(
+        QueryValidatorDistributionInfoRequest { validator_address },
+    );
+    let res = rest_state.app.typed_query(req)?;
+    Ok(Json(res))
+}
+
 pub async fn validator_outstanding_rewards<
     QReq: QueryRequest + From<DistributionNodeQueryRequest>,
     QRes: QueryResponse + TryInto<DistributionNodeQueryResponse>,
@@ -144,6 +207,22 @@ pub fn get_router<
             "/v1beta1/delegators/:delegator_address/rewards",
             get(delegation_delegator_rewards),
         )
+        .route(
+            "/v1beta1/delegators/:delegator_address/rewards/:validator_address",
+            get(delegation_rewards),
+        )
+        .route(
+            "/v1beta1/delegators/:delegator_address/validators",
+            get(delegator_validators),
+        )
+        .route(
+            "/v1beta1/delegators/:delegator_address/withdraw_address",
+            get(delegator_withdraw_address),
+        )
+        .route(
+            "/v1beta1/validators/:validator_address",
+            get(validator_distribution_info),
+        )
         .route(
             "/v1beta1/validators/:validator_address/commission",
             get(validator_commission),
@@ -1,8 +1,14 @@
+use std::num::NonZero;
+
 use gears::{
-    error::NumericError, gas::store::errors::GasStoreErrors, types::{
+    application::handlers::node::{ModuleInfo, TxError},
+    error::NumericError,
+    gas::store::errors::GasStoreErrors,
+    types::{
         address::{AccAddress, ValAddress},
         base::errors::CoinsError,
-    }, x::errors::{AccountNotFound, BankKeeperError}
+    },
+    x::errors::{AccountNotFound, BankKeeperError, CommunityPoolError},
 };
 
 #[derive(Debug, Clone, thiserror::Error)]
@@ -11,6 +17,12 @@ pub enum DistributionTxError {
     DelegatorValidator(#[from] DistributionError),
 }
 
+impl DistributionTxError {
+    pub fn into<MI: ModuleInfo>(self) -> TxError {
+        TxError::new::<MI>(self.to_string(), NonZero::<u16>::MIN)
+    }
+}
+
 #[derive(Debug, Clone, thiserror::Error)]
 pub enum TokenAllocationError {
     #[error(transparent)]
@@ -59,6 +71,13 @@ pub enum DistributionError {
     Numeric(#[from] NumericError),
     #[error("{0}")]
     Gas(#[from] GasStoreErrors),
+    #[error("invalid authority; expected {expected}, got {got}")]
+    InvalidAuthority {
+        expected: AccAddress,
+        got: AccAddress,
+    },
+    #[error("{0}")]
+    CommunityPool(#[from] CommunityPoolError),
 }
 
 #[derive(Debug, Clone, thiserror::Error)]
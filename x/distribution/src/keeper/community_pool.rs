@@ -0,0 +1,59 @@
+use gears::{
+    context::TransactionalContext,
+    types::base::coins::{DecimalCoins, UnsignedCoins},
+    x::{errors::CommunityPoolError, keepers::distribution::CommunityPoolKeeper},
+};
+
+use super::*;
+
+impl<
+        SK: StoreKey,
+        PSK: ParamsSubspaceKey,
+        AK: AuthKeeper<SK, M>,
+        BK: BankKeeper<SK, M>,
+        DSK: DistributionStakingKeeper<SK, M>,
+        M: Module,
+    > CommunityPoolKeeper<SK> for Keeper<SK, PSK, AK, BK, DSK, M>
+{
+    /// distribute_from_fee_pool distributes funds from the distribution module account to
+    /// a receiver address while updating the community pool
+    fn distribute_from_fee_pool<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        amount: UnsignedCoins,
+        recipient: &AccAddress,
+    ) -> Result<(), CommunityPoolError> {
+        if self
+            .blocked_addrs
+            .get(&recipient.to_string())
+            .copied()
+            .unwrap_or_default()
+        {
+            return Err(CommunityPoolError::Blocked(recipient.clone()));
+        }
+
+        let mut fee_pool = self
+            .fee_pool(ctx)?
+            .ok_or_else(|| CommunityPoolError::Other(DistributionError::FeePoolNone.to_string()))?;
+
+        let spend = DecimalCoins::try_from(amount.clone().into_inner())
+            .map_err(|e| CommunityPoolError::Other(e.to_string()))?;
+        if !fee_pool.community_pool.is_all_gte(spend.inner()) {
+            return Err(CommunityPoolError::InsufficientFunds);
+        }
+        fee_pool.community_pool = fee_pool
+            .community_pool
+            .checked_sub(&spend)
+            .map_err(|e| CommunityPoolError::Other(e.to_string()))?;
+
+        self.bank_keeper.send_coins_from_module_to_account(
+            ctx,
+            recipient,
+            &self.distribution_module,
+            amount,
+        )?;
+        self.set_fee_pool(ctx, &fee_pool)?;
+
+        Ok(())
+    }
+}
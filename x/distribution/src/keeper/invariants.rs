@@ -0,0 +1,133 @@
+use super::*;
+use crate::{keys::delegator_starting_info_validator_prefix, VALIDATOR_OUTSTANDING_REWARDS_PREFIX};
+use gears::{
+    application::handlers::node::InvariantError, context::QueryableContext, core::Protobuf,
+    extensions::corruption::UnwrapCorrupt, x::types::delegation::StakingDelegation,
+};
+
+/// Route of invariant which checks that distribution module account holds all outstanding rewards
+pub const MODULE_ACCOUNT_INVARIANT: &str = "module-account";
+/// Route of invariant which checks that outstanding rewards of validators cover rewards of delegations
+pub const CAN_WITHDRAW_INVARIANT: &str = "can-withdraw";
+
+impl<
+        SK: StoreKey,
+        PSK: ParamsSubspaceKey,
+        AK: AuthKeeper<SK, M>,
+        BK: BankKeeper<SK, M>,
+        DSK: DistributionStakingKeeper<SK, M>,
+        M: Module,
+    > Keeper<SK, PSK, AK, BK, DSK, M>
+{
+    /// Checks that balance of distribution module account equals sum of outstanding
+    /// rewards of all validators and community pool, truncated to integer amounts
+    pub fn module_account_invariant<DB: Database, CTX: QueryableContext<DB, SK>>(
+        &self,
+        ctx: &CTX,
+    ) -> Result<(), InvariantError> {
+        let mut expected: Option<DecimalCoins> =
+            self.fee_pool(ctx)?.map(|fee_pool| fee_pool.community_pool);
+
+        let store = ctx.kv_store(&self.store_key);
+        for raw_rewards in store
+            .prefix_store(VALIDATOR_OUTSTANDING_REWARDS_PREFIX)
+            .into_range(..)
+        {
+            let (_, raw_rewards) = raw_rewards?;
+            let outstanding =
+                ValidatorOutstandingRewards::decode_vec(&raw_rewards).unwrap_or_corrupt();
+
+            expected = match expected {
+                Some(expected) => Some(
+                    expected
+                        .checked_add(&outstanding.rewards)
+                        .map_err(|e| InvariantError::Broken(e.to_string()))?,
+                ),
+                None => Some(outstanding.rewards),
+            };
+        }
+
+        let expected = expected
+            .and_then(|expected| expected.truncate_decimal().0)
+            .map(UnsignedCoins::into_inner)
+            .unwrap_or_default();
+
+        let (_, balance) =
+            self.bank_keeper
+                .balance_all(ctx, self.distribution_module.address(), None)?;
+
+        if balance == expected {
+            return Ok(());
+        }
+
+        Err(InvariantError::Broken(format!(
+            "distribution module balance {balance:?} is not equal to outstanding rewards and community pool {expected:?}"
+        )))
+    }
+
+    /// Checks that outstanding rewards of every validator cover its accumulated commission
+    /// and rewards of all its delegations up to the last finished period
+    pub fn can_withdraw_invariant<DB: Database, CTX: QueryableContext<DB, SK>>(
+        &self,
+        ctx: &CTX,
+    ) -> Result<(), InvariantError> {
+        for validator in self.staking_keeper.validators_iter(ctx)? {
+            let validator = validator?;
+            let operator = validator.operator();
+
+            let (Some(outstanding), Some(current)) = (
+                self.validator_outstanding_rewards(ctx, operator)?,
+                self.validator_current_rewards(ctx, operator)?,
+            ) else {
+                continue;
+            };
+            let Some(ending_period) = current.period.checked_sub(1) else {
+                continue;
+            };
+
+            let mut owed = self
+                .validator_accumulated_commission(ctx, operator)?
+                .map(|commission| commission.commission);
+
+            let store = ctx.kv_store(&self.store_key);
+            let starting_infos =
+                store.prefix_store(delegator_starting_info_validator_prefix(operator.clone()));
+            for raw_key in starting_infos.into_range(..) {
+                let (key, _) = raw_key?;
+                // key is length prefixed delegator address
+                let delegator = AccAddress::try_from(key[1..].to_vec()).unwrap_or_corrupt();
+
+                let Some(delegation) = self.staking_keeper.delegation(ctx, &delegator, operator)?
+                else {
+                    continue;
+                };
+                let tokens = validator
+                    .tokens_from_shares(*delegation.shares())
+                    .map_err(|e| InvariantError::Broken(e.to_string()))?;
+
+                let rewards = self
+                    .calculate_delegation_rewards(ctx, operator, &delegator, tokens, ending_period)
+                    .map_err(|e| InvariantError::Broken(e.to_string()))?;
+
+                owed = match (owed, rewards) {
+                    (Some(owed), Some(rewards)) => Some(
+                        owed.checked_add(&rewards)
+                            .map_err(|e| InvariantError::Broken(e.to_string()))?,
+                    ),
+                    (owed, rewards) => owed.or(rewards),
+                };
+            }
+
+            if let Some(owed) = owed {
+                if !outstanding.rewards.is_all_gte(owed.inner()) {
+                    return Err(InvariantError::Broken(format!(
+                        "outstanding rewards {:?} of validator {operator} are less than its commission and delegation rewards {owed:?}",
+                        outstanding.rewards
+                    )));
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -29,7 +29,9 @@ use gears::{
 use std::collections::HashMap;
 
 mod allocation;
+mod community_pool;
 mod delegation;
+pub mod invariants;
 mod query;
 mod store;
 mod tx;
@@ -53,6 +55,8 @@ pub struct Keeper<
     staking_keeper: DSK,
     fee_collector_module: M,
     distribution_module: M,
+    /// module which account is allowed to spend community pool
+    authority_module: M,
     blocked_addrs: HashMap<String, bool>,
 }
 
@@ -73,6 +77,7 @@ impl<
         staking_keeper: DSK,
         fee_collector_module: M,
         distribution_module: M,
+        authority_module: M,
         blocked_addrs: HashMap<String, bool>,
     ) -> Self {
         Self {
@@ -85,6 +90,7 @@ impl<
             staking_keeper,
             fee_collector_module,
             distribution_module,
+            authority_module,
             blocked_addrs,
         }
     }
@@ -102,15 +108,13 @@ impl<
                 .unwrap_gas()
         });
 
-        let previous_proposer = if !genesis.previous_proposer.is_empty() {
-            ConsAddress::from_bech32(&genesis.previous_proposer)?
-        } else {
-            todo!("the sdk doesn't have this branch. It may use a default value");
-        };
-
-        self.set_previous_proposer_cons_addr(ctx, &previous_proposer);
+        // genesis of a new chain doesn't have a previous proposer
+        if !genesis.previous_proposer.is_empty() {
+            let previous_proposer = ConsAddress::from_bech32(&genesis.previous_proposer)?;
+            self.set_previous_proposer_cons_addr(ctx, &previous_proposer);
+        }
 
-        let mut module_holdings = vec![];
+        let mut module_holdings = genesis.fee_pool.community_pool.clone();
         for rew in genesis.outstanding_rewards {
             self.set_validator_outstanding_rewards(
                 ctx,
@@ -118,13 +122,8 @@ impl<
                 &rew.outstanding_rewards,
             )
             .unwrap_gas();
-            module_holdings.push(rew.outstanding_rewards.rewards);
+            module_holdings = module_holdings.checked_add(&rew.outstanding_rewards.rewards)?;
         }
-        let start = module_holdings[0].clone();
-        let module_holdings = module_holdings
-            .into_iter()
-            .take(1)
-            .try_fold(start, |acc, holdings| acc.checked_add(&holdings))?;
 
         genesis
             .validator_accumulated_commissions
@@ -173,7 +172,6 @@ impl<
             )
         });
 
-        let module_holdings = module_holdings.checked_add(&genesis.fee_pool.community_pool)?;
         let (module_holdings_int, _) = module_holdings.truncate_decimal();
 
         // check if the module account exists
@@ -185,7 +183,7 @@ impl<
             .unwrap_gas()
             .1;
 
-        if module_holdings_int != Some(UnsignedCoins::new(balances)?) {
+        if module_holdings_int != UnsignedCoins::new(balances).ok() {
             return Err(anyhow!(
                 "distribution module balance does not match the module holdings".to_string(),
             ));
@@ -2,8 +2,10 @@ use super::*;
 use crate::{
     DelegationDelegatorReward, QueryCommunityPoolRequest, QueryCommunityPoolResponse,
     QueryDelegationRewardsRequest, QueryDelegationRewardsResponse, QueryDelegatorParams,
-    QueryDelegatorTotalRewardsResponse, QueryParamsRequest, QueryParamsResponse,
+    QueryDelegatorTotalRewardsResponse, QueryDelegatorWithdrawAddressRequest,
+    QueryDelegatorWithdrawAddressResponse, QueryParamsRequest, QueryParamsResponse,
     QueryValidatorCommissionRequest, QueryValidatorCommissionResponse,
+    QueryValidatorDistributionInfoRequest, QueryValidatorDistributionInfoResponse,
     QueryValidatorOutstandingRewardsRequest, QueryValidatorOutstandingRewardsResponse,
     QueryValidatorSlashesRequest, QueryValidatorSlashesResponse, QueryWithdrawAllRewardsRequest,
     QueryWithdrawAllRewardsResponse, SlashEventIterator,
@@ -225,6 +227,68 @@ impl<
         QueryWithdrawAllRewardsResponse { validators }
     }
 
+    pub fn query_delegator_withdraw_address<DB: Database>(
+        &self,
+        ctx: &QueryContext<DB, SK>,
+        QueryDelegatorWithdrawAddressRequest { delegator_address }: QueryDelegatorWithdrawAddressRequest,
+    ) -> QueryDelegatorWithdrawAddressResponse {
+        let withdraw_address = self
+            .delegator_withdraw_addr(ctx, &delegator_address)
+            .unwrap_gas()
+            .unwrap_or(delegator_address);
+
+        QueryDelegatorWithdrawAddressResponse { withdraw_address }
+    }
+
+    pub fn query_validator_distribution_info<DB: Database>(
+        &self,
+        ctx: &QueryContext<DB, SK>,
+        QueryValidatorDistributionInfoRequest { validator_address }: QueryValidatorDistributionInfoRequest,
+    ) -> Result<QueryValidatorDistributionInfoResponse, QueryError> {
+        let validator = self
+            .staking_keeper
+            .validator(ctx, &validator_address)
+            .unwrap_gas()
+            .ok_or(QueryError::TODO(anyhow!("validator does not exist")))?;
+
+        let operator_address = AccAddress::from(validator_address.clone());
+        let delegation = self
+            .staking_keeper
+            .delegation(ctx, &operator_address, &validator_address)
+            .unwrap_gas()
+            .ok_or(QueryError::TODO(anyhow!("delegation is not found")))?;
+
+        // TODO: original logic increments validator period, see `query_delegation_rewards`
+        let self_bond_rewards = match self
+            .validator_current_rewards(ctx, &validator_address)
+            .unwrap_gas()
+        {
+            Some(rew) => self
+                .calculate_delegation_rewards(
+                    ctx,
+                    &validator_address,
+                    &operator_address,
+                    validator
+                        .tokens_from_shares(*delegation.shares())
+                        .map_err(|e| QueryError::TODO(anyhow!(e.to_string())))?,
+                    rew.period,
+                )
+                .map_err(|e| QueryError::TODO(anyhow!(e.to_string())))?,
+            None => None,
+        };
+
+        let commission = self
+            .validator_accumulated_commission(ctx, &validator_address)
+            .unwrap_gas()
+            .map(|this| this.commission);
+
+        Ok(QueryValidatorDistributionInfoResponse {
+            operator_address,
+            self_bond_rewards,
+            commission,
+        })
+    }
+
     pub fn query_community_pool<DB: Database>(
         &self,
         ctx: &QueryContext<DB, SK>,
@@ -1,7 +1,13 @@
-use gears::tendermint::types::proto::event::{Event, EventAttribute};
+use gears::{
+    tendermint::types::proto::event::{Event, EventAttribute},
+    types::base::coins::SimpleCoins,
+    x::keepers::distribution::CommunityPoolKeeper,
+};
 
 use super::*;
-use crate::{MsgFundCommunityPool, MsgSetWithdrawAddr, MsgWithdrawDelegatorReward};
+use crate::{
+    MsgCommunityPoolSpend, MsgFundCommunityPool, MsgSetWithdrawAddr, MsgWithdrawDelegatorReward,
+};
 
 impl<
         SK: StoreKey,
@@ -108,4 +114,38 @@ impl<
 
         Ok(())
     }
+
+    pub fn community_pool_spend_cmd<DB: Database>(
+        &self,
+        ctx: &mut TxContext<'_, DB, SK>,
+        msg: &MsgCommunityPoolSpend,
+    ) -> Result<(), DistributionError> {
+        let authority = self.authority_module.address();
+        if msg.authority != authority {
+            return Err(DistributionError::InvalidAuthority {
+                expected: authority,
+                got: msg.authority.clone(),
+            });
+        }
+
+        self.distribute_from_fee_pool(ctx, msg.amount.clone(), &msg.recipient)?;
+
+        ctx.push_event(Event {
+            r#type: "community_pool_spend".to_string(),
+            attributes: vec![
+                EventAttribute {
+                    key: "recipient".into(),
+                    value: msg.recipient.to_string().into(),
+                    index: false,
+                },
+                EventAttribute {
+                    key: "amount".into(),
+                    value: SimpleCoins::new(msg.amount.clone()).to_string_bytes(),
+                    index: false,
+                },
+            ],
+        });
+
+        Ok(())
+    }
 }
@@ -29,6 +29,15 @@ pub fn delegator_starting_info_key(
     .concat()
 }
 
+/// delegator_starting_info_validator_prefix creates the prefix of starting infos of all delegators of a validator
+pub fn delegator_starting_info_validator_prefix(validator_address: ValAddress) -> Vec<u8> {
+    [
+        DELEGATOR_STARTING_INFO_PREFIX.to_vec(),
+        length_prefixed(validator_address.len(), validator_address),
+    ]
+    .concat()
+}
+
 /// validator_outstanding_rewards_key creates the outstanding rewards key for a validator
 pub fn validator_outstanding_rewards_key(addr: ValAddress) -> Vec<u8> {
     [
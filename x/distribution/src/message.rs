@@ -1,7 +1,9 @@
 use gears::derive::AppMessage;
 use serde::Serialize;
 
-use crate::{MsgFundCommunityPool, MsgSetWithdrawAddr, MsgWithdrawDelegatorReward};
+use crate::{
+    MsgCommunityPoolSpend, MsgFundCommunityPool, MsgSetWithdrawAddr, MsgWithdrawDelegatorReward,
+};
 
 #[derive(Debug, Clone, Serialize, AppMessage)]
 pub enum Message {
@@ -14,4 +16,7 @@ pub enum Message {
     #[serde(rename = "/cosmos.distribution.v1beta1.FundCommunityPool")]
     #[msg(url(path = MsgFundCommunityPool::TYPE_URL))]
     FundCommunityPool(MsgFundCommunityPool),
+    #[serde(rename = "/cosmos.distribution.v1beta1.MsgCommunityPoolSpend")]
+    #[msg(url(path = MsgCommunityPoolSpend::TYPE_URL))]
+    CommunityPoolSpend(MsgCommunityPoolSpend),
 }
@@ -223,6 +223,24 @@ pub struct QueryDelegatorParams {
     pub delegator_address: AccAddress,
 }
 
+/// QueryDelegatorWithdrawAddressRequest is the request type for the
+/// Query/DelegatorWithdrawAddress RPC method.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Raw, Protobuf)]
+pub struct QueryDelegatorWithdrawAddressRequest {
+    /// delegator_address defines the delegator address to query for.
+    #[raw(kind(string), raw = String)]
+    pub delegator_address: AccAddress,
+}
+
+/// QueryValidatorDistributionInfoRequest is the request type for the
+/// Query/ValidatorDistributionInfo RPC method.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Raw, Protobuf)]
+pub struct QueryValidatorDistributionInfoRequest {
+    /// validator_address defines the validator address to query for.
+    #[raw(kind(string), raw = String)]
+    pub validator_address: ValAddress,
+}
+
 #[derive(Clone, PartialEq, Message, Raw, Protobuf)]
 pub struct QueryCommunityPoolRequest {}
 
@@ -465,6 +483,32 @@ pub struct QueryDelegatorTotalRewardsResponse {
     pub total: Option<DecimalCoins>,
 }
 
+/// QueryDelegatorWithdrawAddressResponse is the response type for the
+/// Query/DelegatorWithdrawAddress RPC method.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, Raw, Protobuf)]
+pub struct QueryDelegatorWithdrawAddressResponse {
+    /// withdraw_address defines the delegator address to query for.
+    #[raw(kind(string), raw = String)]
+    pub withdraw_address: AccAddress,
+}
+
+/// QueryValidatorDistributionInfoResponse is the response type for the
+/// Query/ValidatorDistributionInfo RPC method.
+#[derive(Debug, Clone, PartialEq, Deserialize, Serialize, Raw, Protobuf)]
+pub struct QueryValidatorDistributionInfoResponse {
+    /// operator_address defines the validator operator address.
+    #[raw(kind(string), raw = String)]
+    pub operator_address: AccAddress,
+    /// self_bond_rewards defines the self delegations rewards.
+    #[proto(optional)]
+    #[raw(kind(message), optional, raw = DecimalCoinsRaw)]
+    pub self_bond_rewards: Option<DecimalCoins>,
+    /// commission defines the commission the validator received.
+    #[proto(optional)]
+    #[raw(kind(message), optional, raw = DecimalCoinsRaw)]
+    pub commission: Option<DecimalCoins>,
+}
+
 #[derive(Clone, Serialize, Message)]
 pub struct QueryCommunityPoolResponseRaw {
     #[prost(bytes, optional, tag = "1")]
@@ -3,7 +3,7 @@ use gears::{
     derive::AppMessage,
     types::{
         address::{AccAddress, AddressError, ValAddress},
-        base::coins::UnsignedCoins,
+        base::{coins::UnsignedCoins, errors::CoinError},
     },
 };
 use prost::Message;
@@ -159,3 +159,71 @@ impl TryFrom<MsgFundCommunityPoolRaw> for MsgFundCommunityPool {
 }
 
 impl Protobuf<MsgFundCommunityPoolRaw> for MsgFundCommunityPool {}
+
+#[derive(Clone, PartialEq, Serialize, Deserialize, Message)]
+pub struct MsgCommunityPoolSpendRaw {
+    #[prost(string, tag = "1")]
+    pub authority: String,
+    #[prost(string, tag = "2")]
+    pub recipient: String,
+    #[prost(message, repeated, tag = "3")]
+    pub amount: Vec<ibc_proto::cosmos::base::v1beta1::Coin>,
+}
+
+impl From<MsgCommunityPoolSpend> for MsgCommunityPoolSpendRaw {
+    fn from(
+        MsgCommunityPoolSpend {
+            authority,
+            recipient,
+            amount,
+        }: MsgCommunityPoolSpend,
+    ) -> Self {
+        Self {
+            authority: authority.to_string(),
+            recipient: recipient.to_string(),
+            amount: amount.into_inner().into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+/// MsgCommunityPoolSpend spends `amount` from community pool to `recipient`.
+/// `authority` must be the module account which is allowed to spend community pool
+/// (gov module account).
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, AppMessage)]
+#[msg(url = "/cosmos.distribution.v1beta1.MsgCommunityPoolSpend")]
+pub struct MsgCommunityPoolSpend {
+    #[msg(signer)]
+    pub authority: AccAddress,
+    pub recipient: AccAddress,
+    pub amount: UnsignedCoins,
+}
+
+impl TryFrom<MsgCommunityPoolSpendRaw> for MsgCommunityPoolSpend {
+    type Error = CoreError;
+
+    fn try_from(
+        MsgCommunityPoolSpendRaw {
+            authority,
+            recipient,
+            amount,
+        }: MsgCommunityPoolSpendRaw,
+    ) -> Result<Self, Self::Error> {
+        let mut coins = Vec::with_capacity(amount.len());
+        for coin in amount {
+            coins.push(
+                coin.try_into()
+                    .map_err(|e: CoinError| CoreError::Coin(e.to_string()))?,
+            );
+        }
+
+        Ok(Self {
+            authority: AccAddress::from_bech32(&authority)
+                .map_err(|e| CoreError::DecodeAddress(e.to_string()))?,
+            recipient: AccAddress::from_bech32(&recipient)
+                .map_err(|e| CoreError::DecodeAddress(e.to_string()))?,
+            amount: UnsignedCoins::new(coins).map_err(|e| CoreError::Coins(e.to_string()))?,
+        })
+    }
+}
+
+impl Protobuf<MsgCommunityPoolSpendRaw> for MsgCommunityPoolSpend {}
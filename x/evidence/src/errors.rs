@@ -1,5 +1,9 @@
+use std::num::NonZero;
+
 use gears::{
-    gas::store::errors::GasStoreErrors, tendermint::informal::hash::Hash,
+    application::handlers::node::{ModuleInfo, TxError},
+    gas::store::errors::GasStoreErrors,
+    tendermint::informal::hash::Hash,
     types::address::ConsAddress,
 };
 
@@ -23,6 +27,12 @@ pub enum TxEvidenceError {
     AlreadyExists(#[from] EvidenceAlreadyExistsError),
 }
 
+impl TxEvidenceError {
+    pub fn into<MI: ModuleInfo>(self) -> TxError {
+        TxError::new::<MI>(self.to_string(), NonZero::<u16>::MIN)
+    }
+}
+
 #[derive(Debug, thiserror::Error)]
 #[error("failure in conversion of any type into concrete evidence")]
 pub struct DecodeError;
@@ -10,4 +10,8 @@ pub use abci_handler::*;
 pub use client::*;
 pub use genesis::*;
 pub use keeper::*;
-pub use types::Evidence;
+pub use message::*;
+pub use types::{
+    Evidence, QueryAllEvidenceRequest, QueryAllEvidenceResponse, QueryEvidenceRequest,
+    QueryEvidenceResponse, RawEquivocation,
+};
@@ -11,15 +11,15 @@ use gears::{
         tx::{raw::TxWithRaw, NullTxMsg},
     },
     x::{
-        ante::{BaseAnteHandler, SignGasConsumer},
+        ante::TxAnteHandler,
         keepers::{
             auth::AuthKeeper,
-            bank::BankKeeper,
             staking::{KeeperHooks, StakingBankKeeper},
         },
         module::Module,
     },
 };
+use staking::CreateValidator;
 
 use crate::genesis::GenutilGenesis;
 
@@ -28,13 +28,13 @@ pub struct GenutilAbciHandler<
     SK: StoreKey,
     PSK: ParamsSubspaceKey,
     AK: AuthKeeper<SK, M>,
-    BK: StakingBankKeeper<SK, M> + BankKeeper<SK, M>,
+    BK: StakingBankKeeper<SK, M>,
     KH: KeeperHooks<SK, AK, M>,
     M: Module,
-    GC: SignGasConsumer,
+    AH: TxAnteHandler<SK, CreateValidator>,
 > {
     staking: staking::Keeper<SK, PSK, AK, BK, KH, M>,
-    ante_handler: BaseAnteHandler<BK, AK, SK, GC, M>,
+    ante_handler: AH,
 }
 
 impl<
@@ -44,13 +44,10 @@ impl<
         BK: StakingBankKeeper<SK, M>,
         KH: KeeperHooks<SK, AK, M>,
         M: Module,
-        GC: SignGasConsumer,
-    > GenutilAbciHandler<SK, PSK, AK, BK, KH, M, GC>
+        AH: TxAnteHandler<SK, CreateValidator>,
+    > GenutilAbciHandler<SK, PSK, AK, BK, KH, M, AH>
 {
-    pub fn new(
-        staking: staking::Keeper<SK, PSK, AK, BK, KH, M>,
-        ante_handler: BaseAnteHandler<BK, AK, SK, GC, M>,
-    ) -> Self {
+    pub fn new(staking: staking::Keeper<SK, PSK, AK, BK, KH, M>, ante_handler: AH) -> Self {
         Self {
             staking,
             ante_handler,
@@ -65,8 +62,8 @@ impl<
         BK: StakingBankKeeper<SK, M>,
         KH: KeeperHooks<SK, AK, M>,
         M: Module,
-        GC: SignGasConsumer,
-    > ABCIHandler for GenutilAbciHandler<SK, PSK, AK, BK, KH, M, GC>
+        AH: TxAnteHandler<SK, CreateValidator>,
+    > ABCIHandler for GenutilAbciHandler<SK, PSK, AK, BK, KH, M, AH>
 {
     type Message = NullTxMsg;
 
@@ -206,7 +206,7 @@ impl TxHandler for GentxTxHandler {
             min_self_delegation,
             delegator_address: from_address.clone(),
             validator_address: from_address.into(),
-            pubkey: pub_key.into(),
+            pubkey: pub_key.try_into()?,
             value: amount,
         });
 
@@ -15,13 +15,10 @@ use gears::{
     params::ParamsSubspaceKey,
     store::{database::Database, StoreKey},
     tendermint::types::{
-        proto::{
-            event::{Event, EventAttribute},
-            validator::ValidatorUpdate,
-        },
+        proto::event::{Event, EventAttribute},
         request::query::RequestQuery,
     },
-    types::tx::raw::TxWithRaw,
+    types::tx::TxMessage,
     x::{
         keepers::{gov::GovernanceBankKeeper, staking::GovStakingKeeper},
         module::Module,
@@ -41,7 +38,7 @@ use crate::{
             QueryProposalRequest, QueryProposalsRequest, QueryProposerRequest,
             QueryTallyResultRequest, QueryVoteRequest, QueryVotesRequest,
         },
-        GovQuery, GovQueryResponse,
+        v1, GovQuery, GovQueryResponse,
     },
 };
 
@@ -89,41 +86,24 @@ impl<
         AK: AuthKeeper<SK, M>,
         STK: GovStakingKeeper<SK, M>,
         P: Proposal + DeserializeOwned,
-        PH: ProposalHandler<P, SK> + Clone + Send + Sync + 'static,
-        MI: ModuleInfo + Clone + Send + Sync + 'static,
-    > ABCIHandler for GovAbciHandler<SK, PSK, M, BK, AK, STK, P, PH, MI>
+        PH: ProposalHandler<P, SK>,
+        MI: ModuleInfo,
+    > GovAbciHandler<SK, PSK, M, BK, AK, STK, P, PH, MI>
 {
-    type Message = GovMsg;
-
-    type Genesis = GovGenesisState<P>;
-
-    type StoreKey = SK;
-
-    type QReq = GovQuery;
-
-    type QRes = GovQueryResponse<P>;
-
-    fn typed_query<DB: Database>(
+    pub fn typed_query<DB: Database>(
         &self,
-        ctx: &QueryContext<DB, Self::StoreKey>,
-        query: Self::QReq,
-    ) -> Self::QRes {
+        ctx: &QueryContext<DB, SK>,
+        query: GovQuery,
+    ) -> GovQueryResponse<P> {
         self.keeper.query(ctx, query).unwrap_gas()
     }
 
-    fn run_ante_checks<DB: Database>(
+    /// Handle message of gov module. `AM` is message of application
+    /// which executes messages of `v1` proposals.
+    pub fn msg<DB: Database, AM: TxMessage>(
         &self,
-        _ctx: &mut TxContext<'_, DB, Self::StoreKey>,
-        _tx: &TxWithRaw<Self::Message>,
-        _: bool,
-    ) -> Result<(), TxError> {
-        Ok(())
-    }
-
-    fn msg<DB: Database>(
-        &self,
-        ctx: &mut TxContext<'_, DB, Self::StoreKey>,
-        msg: &Self::Message,
+        ctx: &mut TxContext<'_, DB, SK>,
+        msg: &GovMsg,
     ) -> Result<(), TxError> {
         enum EmitEvent {
             Regular,
@@ -178,6 +158,39 @@ impl<
                         ),
                     })
             }
+            GovMsg::ProposalV1(msg) => {
+                let proposal_id = self
+                    .keeper
+                    .submit_proposal_v1::<_, AM>(ctx, msg.clone())
+                    .map_err(GovTxError::from)
+                    .map_err(|e| e.into::<MI>())?;
+
+                let proposal_messages = msg
+                    .messages
+                    .iter()
+                    .map(|this| this.type_url.as_str())
+                    .collect::<Vec<_>>()
+                    .join(",");
+
+                self.keeper
+                    .deposit_add(
+                        ctx,
+                        Deposit {
+                            proposal_id,
+                            depositor: msg.proposer.clone(),
+                            amount: msg.initial_deposit.clone(),
+                        },
+                    )
+                    .map(|is_voting_started| {
+                        (
+                            msg.proposer.to_string(),
+                            EmitEvent::Proposal((
+                                proposal_messages,
+                                is_voting_started.then_some(proposal_id),
+                            )),
+                        )
+                    })
+            }
         }
         .map_err(GovTxError::from)
         .map_err(|e| e.into::<MI>())?;
@@ -231,18 +244,17 @@ impl<
         Ok(())
     }
 
-    fn init_genesis<DB: Database>(
+    pub fn init_genesis<DB: Database>(
         &self,
-        ctx: &mut InitContext<'_, DB, Self::StoreKey>,
-        genesis: Self::Genesis,
-    ) -> Vec<ValidatorUpdate> {
+        ctx: &mut InitContext<'_, DB, SK>,
+        genesis: GovGenesisState<P>,
+    ) {
         self.keeper.init_genesis(ctx, genesis);
-        vec![]
     }
 
-    fn query<DB: Database>(
+    pub fn query<DB: Database>(
         &self,
-        ctx: &QueryContext<DB, Self::StoreKey>,
+        ctx: &QueryContext<DB, SK>,
         RequestQuery {
             data,
             path,
@@ -273,6 +285,15 @@ impl<
             QueryProposerRequest::QUERY_URL => {
                 GovQuery::Proposer(QueryProposerRequest::decode(data)?)
             }
+            v1::QueryProposalRequest::QUERY_URL => {
+                GovQuery::ProposalV1(v1::QueryProposalRequest::decode(data)?)
+            }
+            v1::QueryProposalsRequest::QUERY_URL => {
+                GovQuery::ProposalsV1(v1::QueryProposalsRequest::decode(data)?)
+            }
+            v1::QueryParamsRequest::QUERY_URL => {
+                GovQuery::ParamsV1(v1::QueryParamsRequest::decode(data)?)
+            }
             _ => Err(QueryError::PathNotFound)?,
         };
 
@@ -281,15 +302,16 @@ impl<
         Ok(result.into_bytes())
     }
 
-    fn end_block<'a, DB: Database>(
+    /// Finish deposit and voting periods of proposals. Messages of passed proposals
+    /// are executed by `handler` of application.
+    pub fn end_block<DB: Database, H: ABCIHandler<StoreKey = SK>>(
         &self,
-        ctx: &mut BlockContext<'_, DB, Self::StoreKey>,
+        ctx: &mut BlockContext<'_, DB, SK>,
         _request: RequestEndBlock,
-    ) -> Vec<ValidatorUpdate> {
-        let events = self.keeper.end_block(ctx);
+        handler: &H,
+    ) {
+        let events = self.keeper.end_block(ctx, handler);
 
         ctx.append_events(events);
-
-        Vec::new()
     }
 }
@@ -17,6 +17,7 @@ pub enum GovTxCommands {
     Vote(VoteCliCommand),
     WeightedVote(WeightedVoteCliCommand),
     SubmitProposal(ProposalCliCommand<ProposalCliSubcommand>),
+    SubmitProposalV1(ProposalV1CliCommand),
 }
 
 /// Deposit tokens for an active proposal
@@ -52,12 +53,31 @@ pub struct ProposalCliCommand<T: Subcommand> {
     pub command: T,
 }
 
+/// Submit a gov v1 proposal which executes messages after it passes
+#[derive(Args, Debug, Clone)]
+pub struct ProposalV1CliCommand {
+    pub initial_deposit: UnsignedCoins,
+    /// JSON file with list of proposal messages encoded as `Any`
+    #[arg(long)]
+    pub messages: Option<PathBuf>,
+    #[arg(long, default_value_t)]
+    pub title: String,
+    #[arg(long, default_value_t)]
+    pub summary: String,
+    #[arg(long, default_value_t)]
+    pub metadata: String,
+    /// Vote on proposal during shorter voting period with higher threshold
+    #[arg(long)]
+    pub expedited: bool,
+}
+
 #[derive(Subcommand, Debug, Clone)]
 pub enum ProposalCliSubcommand {
     Text(TextProposalCliCommand),
     ParamChange(ParamChangeProposalCliCommand),
     SoftwareUpgrade(SoftwareUpgradeProposalCliCommand),
     CancelSoftwareUpgrade(CancelSoftwareUpgradeProposalCliCommand),
+    CommunityPoolSpend(CommunityPoolSpendProposalCliCommand),
 }
 
 #[derive(Args, Debug, Clone)]
@@ -81,3 +101,9 @@ pub struct CancelSoftwareUpgradeProposalCliCommand {
     pub title: String,
     pub description: String,
 }
+
+/// Spend coins of community pool. File contains title, description, recipient and amount
+#[derive(Args, Debug, Clone)]
+pub struct CommunityPoolSpendProposalCliCommand {
+    pub file: PathBuf,
+}
@@ -5,7 +5,7 @@ use crate::{
             ParamsQuery, QueryDepositsRequest, QueryParamsRequest, QueryProposalRequest,
             QueryProposalsRequest, QueryTallyResultRequest, QueryVoteRequest, QueryVotesRequest,
         },
-        GovQuery, GovQueryResponse,
+        v1, GovQuery, GovQueryResponse,
     },
 };
 use axum::{
@@ -161,6 +161,52 @@ pub async fn params_deposit<
     Ok(Json(res))
 }
 
+pub async fn proposals_v1<
+    QReq: QueryRequest + From<GovQuery>,
+    QRes: QueryResponse + TryInto<GovQueryResponse<P>>,
+    App: NodeQueryHandler<QReq, QRes>,
+    P: Proposal,
+>(
+    Query(pagination): Query<Pagination>,
+    State(rest_state): State<RestState<QReq, QRes, App>>,
+) -> Result<Json<QRes>, HTTPError> {
+    let req = GovQuery::ProposalsV1(v1::QueryProposalsRequest {
+        voter: None,
+        depositor: None,
+        proposal_status: None,
+        pagination: Some(PaginationRequest::from(pagination)),
+    });
+    let res = rest_state.app.typed_query(req)?;
+    Ok(Json(res))
+}
+
+pub async fn proposals_proposal_id_v1<
+    QReq: QueryRequest + From<GovQuery>,
+    QRes: QueryResponse + TryInto<GovQueryResponse<P>>,
+    App: NodeQueryHandler<QReq, QRes>,
+    P: Proposal,
+>(
+    Path(proposal_id): Path<u64>,
+    State(rest_state): State<RestState<QReq, QRes, App>>,
+) -> Result<Json<QRes>, HTTPError> {
+    let req = GovQuery::ProposalV1(v1::QueryProposalRequest { proposal_id });
+    let res = rest_state.app.typed_query(req)?;
+    Ok(Json(res))
+}
+
+pub async fn params_v1<
+    QReq: QueryRequest + From<GovQuery>,
+    QRes: QueryResponse + TryInto<GovQueryResponse<P>>,
+    App: NodeQueryHandler<QReq, QRes>,
+    P: Proposal,
+>(
+    State(rest_state): State<RestState<QReq, QRes, App>>,
+) -> Result<Json<QRes>, HTTPError> {
+    let req = GovQuery::ParamsV1(v1::QueryParamsRequest);
+    let res = rest_state.app.typed_query(req)?;
+    Ok(Json(res))
+}
+
 pub fn get_router<
     QReq: QueryRequest + From<GovQuery>,
     QRes: QueryResponse + TryInto<GovQueryResponse<P>>,
@@ -192,4 +238,7 @@ pub fn get_router<
         .route("/v1beta1/params/voting", get(params_voting))
         .route("/v1beta1/params/tallying", get(params_tally))
         .route("/v1beta1/params/deposit", get(params_deposit))
+        .route("/v1/proposals", get(proposals_v1))
+        .route("/v1/proposals/:proposal_id", get(proposals_proposal_id_v1))
+        .route("/v1/params/:params_type", get(params_v1))
 }
@@ -1,21 +1,24 @@
 use std::{fs::File, io::Read};
 
 use gears::{
-    application::handlers::client::TxHandler, commands::client::tx::ClientTxContext,
-    crypto::public::PublicKey, types::tx::Messages,
+    application::handlers::client::TxHandler,
+    commands::client::tx::ClientTxContext,
+    crypto::public::PublicKey,
+    types::{address::AccAddress, tx::Messages},
 };
 
 use crate::{
     client::cli::tx::{
         DepositCliCommand, GovTxCli, GovTxCommands, ParamChangeProposalCliCommand,
-        ProposalCliCommand, ProposalCliSubcommand, TextProposalCliCommand, VoteCliCommand,
-        WeightedVoteCliCommand,
+        ProposalCliCommand, ProposalCliSubcommand, ProposalV1CliCommand, TextProposalCliCommand,
+        VoteCliCommand, WeightedVoteCliCommand,
     },
     msg::{
-        deposit::Deposit, proposal::MsgSubmitProposal, vote::Vote, weighted_vote::MsgVoteWeighted,
-        GovMsg,
+        deposit::Deposit, proposal::MsgSubmitProposal, v1, vote::Vote,
+        weighted_vote::MsgVoteWeighted, GovMsg,
     },
     proposal::{
+        community_pool::CommunityPoolSpendProposal,
         param::RawParameterChangeProposal,
         text::TextProposal,
         upgrade::{CancelSoftwareUpgradeProposal, SoftwareUpgradeProposal},
@@ -23,7 +26,10 @@ use crate::{
 };
 
 use super::{
-    cli::tx::{CancelSoftwareUpgradeProposalCliCommand, SoftwareUpgradeProposalCliCommand},
+    cli::tx::{
+        CancelSoftwareUpgradeProposalCliCommand, CommunityPoolSpendProposalCliCommand,
+        SoftwareUpgradeProposalCliCommand,
+    },
     GovClientHandler,
 };
 
@@ -38,73 +44,107 @@ impl<T> TxHandler for GovClientHandler<T> {
         command: Self::TxCommands,
         pubkey: PublicKey,
     ) -> anyhow::Result<Messages<Self::Message>> {
-        let command = match command.command {
-            GovTxCommands::Deposit(DepositCliCommand {
-                proposal_id,
-                amount,
-            }) => GovMsg::Deposit(Deposit {
-                proposal_id,
-                depositor: pubkey.get_address(),
-                amount,
-            }),
-            GovTxCommands::Vote(VoteCliCommand {
-                proposal_id,
-                option,
-            }) => GovMsg::Vote(Vote {
-                proposal_id,
-                voter: pubkey.get_address(),
-                option,
-            }),
-            GovTxCommands::WeightedVote(WeightedVoteCliCommand {
-                proposal_id,
-                options,
-            }) => GovMsg::Weighted(MsgVoteWeighted {
-                proposal_id,
-                voter: pubkey.get_address(),
-                options,
-            }),
-            GovTxCommands::SubmitProposal(ProposalCliCommand {
-                initial_deposit,
-                command,
-            }) => match command {
-                ProposalCliSubcommand::Text(TextProposalCliCommand { title, description }) => {
-                    GovMsg::Proposal(MsgSubmitProposal {
-                        content: TextProposal { title, description }.into(),
-                        initial_deposit,
-                        proposer: pubkey.get_address(),
-                    })
-                }
-                ProposalCliSubcommand::ParamChange(ParamChangeProposalCliCommand { file }) => {
-                    let mut buf = String::new();
-                    File::open(file)?.read_to_string(&mut buf)?;
+        run_gov_tx_command(command, pubkey.get_address()).map(Into::into)
+    }
+}
 
-                    let proposal = serde_json::from_str::<RawParameterChangeProposal>(&buf)?;
+/// Build gov message from tx subcommand signed by `from_address`
+pub fn run_gov_tx_command(command: GovTxCli, from_address: AccAddress) -> anyhow::Result<GovMsg> {
+    let command = match command.command {
+        GovTxCommands::Deposit(DepositCliCommand {
+            proposal_id,
+            amount,
+        }) => GovMsg::Deposit(Deposit {
+            proposal_id,
+            depositor: from_address,
+            amount,
+        }),
+        GovTxCommands::Vote(VoteCliCommand {
+            proposal_id,
+            option,
+        }) => GovMsg::Vote(Vote {
+            proposal_id,
+            voter: from_address,
+            option,
+        }),
+        GovTxCommands::WeightedVote(WeightedVoteCliCommand {
+            proposal_id,
+            options,
+        }) => GovMsg::Weighted(MsgVoteWeighted {
+            proposal_id,
+            voter: from_address,
+            options,
+        }),
+        GovTxCommands::SubmitProposal(ProposalCliCommand {
+            initial_deposit,
+            command,
+        }) => match command {
+            ProposalCliSubcommand::Text(TextProposalCliCommand { title, description }) => {
+                GovMsg::Proposal(MsgSubmitProposal {
+                    content: TextProposal { title, description }.into(),
+                    initial_deposit,
+                    proposer: from_address,
+                })
+            }
+            ProposalCliSubcommand::ParamChange(ParamChangeProposalCliCommand { file }) => {
+                let mut buf = String::new();
+                File::open(file)?.read_to_string(&mut buf)?;
 
-                    GovMsg::Proposal(MsgSubmitProposal {
-                        content: proposal.into(),
-                        initial_deposit,
-                        proposer: pubkey.get_address(),
-                    })
-                }
-                ProposalCliSubcommand::SoftwareUpgrade(SoftwareUpgradeProposalCliCommand {
-                    file,
-                }) => GovMsg::Proposal(MsgSubmitProposal {
+                let proposal = serde_json::from_str::<RawParameterChangeProposal>(&buf)?;
+
+                GovMsg::Proposal(MsgSubmitProposal {
+                    content: proposal.into(),
+                    initial_deposit,
+                    proposer: from_address,
+                })
+            }
+            ProposalCliSubcommand::SoftwareUpgrade(SoftwareUpgradeProposalCliCommand { file }) => {
+                GovMsg::Proposal(MsgSubmitProposal {
                     content: serde_json::from_slice::<SoftwareUpgradeProposal>(&std::fs::read(
                         file,
                     )?)?
                     .into(),
                     initial_deposit,
-                    proposer: pubkey.get_address(),
-                }),
-                ProposalCliSubcommand::CancelSoftwareUpgrade(
-                    CancelSoftwareUpgradeProposalCliCommand { title, description },
-                ) => GovMsg::Proposal(MsgSubmitProposal {
-                    content: CancelSoftwareUpgradeProposal { title, description }.into(),
-                    initial_deposit,
-                    proposer: pubkey.get_address(),
-                }),
+                    proposer: from_address,
+                })
+            }
+            ProposalCliSubcommand::CancelSoftwareUpgrade(
+                CancelSoftwareUpgradeProposalCliCommand { title, description },
+            ) => GovMsg::Proposal(MsgSubmitProposal {
+                content: CancelSoftwareUpgradeProposal { title, description }.into(),
+                initial_deposit,
+                proposer: from_address,
+            }),
+            ProposalCliSubcommand::CommunityPoolSpend(CommunityPoolSpendProposalCliCommand {
+                file,
+            }) => GovMsg::Proposal(MsgSubmitProposal {
+                content: serde_json::from_slice::<CommunityPoolSpendProposal>(&std::fs::read(
+                    file,
+                )?)?
+                .into(),
+                initial_deposit,
+                proposer: from_address,
+            }),
+        },
+        GovTxCommands::SubmitProposalV1(ProposalV1CliCommand {
+            initial_deposit,
+            messages,
+            title,
+            summary,
+            metadata,
+            expedited,
+        }) => GovMsg::ProposalV1(v1::MsgSubmitProposal {
+            messages: match messages {
+                Some(file) => serde_json::from_slice(&std::fs::read(file)?)?,
+                None => Vec::new(),
             },
-        };
-        Ok(command.into())
-    }
+            initial_deposit,
+            proposer: from_address,
+            metadata,
+            title,
+            summary,
+            expedited,
+        }),
+    };
+    Ok(command)
 }
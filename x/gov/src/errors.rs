@@ -1,7 +1,7 @@
 use gears::{
     application::handlers::node::{ModuleInfo, TxError},
     gas::store::errors::GasStoreErrors,
-    types::base::errors::CoinsError,
+    types::{address::AccAddress, base::errors::CoinsError},
     x::errors::BankKeeperError,
 };
 
@@ -32,6 +32,12 @@ pub enum TallyError {
 pub enum GovKeeperError {
     #[error("gov: no handler exists for proposal type")]
     NoHandler,
+    #[error("gov: expected gov account as only signer for proposal message, got {0}")]
+    InvalidSigner(AccAddress),
+    #[error("gov: metadata too long: {0} bytes")]
+    MetadataTooLong(usize),
+    #[error("gov: title too long: {0} bytes")]
+    TitleTooLong(usize),
     #[error("{0}")]
     Bank(#[from] BankKeeperError),
     #[error("{0}")]
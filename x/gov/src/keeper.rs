@@ -5,7 +5,7 @@ use gears::extensions::gas::GasResultExt;
 use gears::gas::store::errors::GasStoreErrors;
 use gears::x::keepers::auth::AuthKeeper;
 use gears::{
-    application::keepers::params::ParamsKeeper,
+    application::{handlers::node::ABCIHandler, keepers::params::ParamsKeeper},
     context::{
         block::BlockContext, init::InitContext, tx::TxContext, QueryableContext,
         TransactionalContext,
@@ -15,7 +15,9 @@ use gears::{
     tendermint::types::proto::event::{Event, EventAttribute},
     types::{
         address::{AccAddress, ValAddress},
+        base::coins::UnsignedCoins,
         decimal256::Decimal256,
+        tx::TxMessage,
     },
     x::{
         keepers::{gov::GovernanceBankKeeper, staking::GovStakingKeeper},
@@ -23,17 +25,19 @@ use gears::{
         types::{delegation::StakingDelegation, validator::StakingValidator},
     },
 };
+use ibc_proto::google::protobuf::Any;
 use serde::de::DeserializeOwned;
 use serde::Serialize;
 use strum::IntoEnumIterator;
 
-use crate::proposal::{Proposal, ProposalHandler};
+use crate::proposal::{Proposal, ProposalHandler, ProposalHandlingError};
 use crate::{
     errors::{GovKeeperError, TallyError, SERDE_JSON_CONVERSION},
     genesis::GovGenesisState,
     msg::{
         deposit::Deposit,
         proposal::MsgSubmitProposal,
+        v1::{MsgExecLegacyContent, MsgSubmitProposal as MsgSubmitProposalV1},
         vote::VoteOption,
         weighted_vote::{MsgVoteWeighted, VoteOptionWeighted},
     },
@@ -49,7 +53,7 @@ use crate::{
             QueryParamsResponse, QueryProposalResponse, QueryProposalsResponse,
             QueryTallyResultResponse, QueryVoteResponse, QueryVotesResponse,
         },
-        GovQuery, GovQueryResponse,
+        v1, GovQuery, GovQueryResponse,
     },
     types::{
         deposit_iter::DepositIterator,
@@ -64,6 +68,10 @@ use crate::{
 
 const PROPOSAL_ID_KEY: [u8; 1] = [0x03];
 pub(crate) const KEY_PROPOSAL_PREFIX: [u8; 1] = [0x00];
+/// Maximum length of proposal metadata
+const MAX_METADATA_LEN: usize = 255;
+/// Maximum length of proposal title
+const MAX_TITLE_LEN: usize = 255;
 
 #[derive(Debug, Clone)]
 pub struct GovKeeper<
@@ -136,6 +144,9 @@ impl<
             let mut store = ctx.kv_store_mut(&self.store_key);
             store.set(PROPOSAL_ID_KEY, starting_proposal_id.to_be_bytes())
         }
+        if !params.is_valid() {
+            panic!("invalid gov params: {:?}", params)
+        }
         self.gov_params_keeper.set(ctx, params);
 
         self.auth_keeper
@@ -179,7 +190,10 @@ impl<
                     ProposalStatus::VotingPeriod => store_mut.set(
                         ProposalModel::<P>::active_queue_key(
                             proposal.proposal_id,
-                            &proposal.deposit_end_time,
+                            proposal
+                                .voting_end_time
+                                .as_ref()
+                                .unwrap_or(&proposal.deposit_end_time),
                         ),
                         proposal.proposal_id.to_be_bytes(),
                     ),
@@ -286,42 +300,10 @@ impl<
                 depositor,
                 proposal_status,
                 pagination: _,
-            }) => {
-                let iterator = ProposalsIterator::new(ctx.kv_store(&self.store_key))
-                    .map(|this| this.map(|(_key, value)| value))
-                    .filter_map(|this| this.ok());
-
-                let mut proposals = Vec::new();
-                for proposal in iterator {
-                    if let Some(voter) = &voter {
-                        let vote = vote_get(ctx, &self.store_key, proposal.proposal_id, voter)?;
-                        if vote.is_none() {
-                            continue;
-                        }
-                    }
-
-                    if let Some(depositor) = &depositor {
-                        let deposit =
-                            deposit_get(ctx, &self.store_key, proposal.proposal_id, depositor)?;
-                        if deposit.is_none() {
-                            continue;
-                        }
-                    }
-
-                    if let Some(proposal_status) = proposal_status {
-                        if proposal.status != proposal_status {
-                            continue;
-                        }
-                    }
-
-                    proposals.push(proposal);
-                }
-
-                GovQueryResponse::Proposals(QueryProposalsResponse {
-                    proposals,
-                    pagination: None,
-                })
-            }
+            }) => GovQueryResponse::Proposals(QueryProposalsResponse {
+                proposals: self.proposals_filtered(ctx, voter, depositor, proposal_status)?,
+                pagination: None,
+            }),
             GovQuery::Tally(QueryTallyResultRequest { proposal_id }) => {
                 let proposal = proposal_get::<_, _, _, P>(ctx, &self.store_key, proposal_id)?;
 
@@ -349,11 +331,90 @@ impl<
                 })
             }
             GovQuery::Proposer(QueryProposerRequest { proposal_id: _ }) => unimplemented!(), // TODO:NOW I couldn't find where this query handles or what method
+            GovQuery::ProposalV1(v1::QueryProposalRequest { proposal_id }) => {
+                GovQueryResponse::ProposalV1(v1::QueryProposalResponse {
+                    proposal: proposal_get(ctx, &self.store_key, proposal_id)?
+                        .map(|this| self.proposal_to_v1(this)),
+                })
+            }
+            GovQuery::ProposalsV1(v1::QueryProposalsRequest {
+                voter,
+                depositor,
+                proposal_status,
+                pagination: _,
+            }) => GovQueryResponse::ProposalsV1(v1::QueryProposalsResponse {
+                proposals: self
+                    .proposals_filtered(ctx, voter, depositor, proposal_status)?
+                    .into_iter()
+                    .map(|this| self.proposal_to_v1(this))
+                    .collect(),
+                pagination: None,
+            }),
+            GovQuery::ParamsV1(v1::QueryParamsRequest) => {
+                GovQueryResponse::ParamsV1(v1::QueryParamsResponse {
+                    params: self.gov_params_keeper.try_get(ctx)?,
+                })
+            }
         };
 
         Ok(result)
     }
 
+    fn proposals_filtered<CTX: QueryableContext<DB, SK>, DB: Database>(
+        &self,
+        ctx: &CTX,
+        voter: Option<AccAddress>,
+        depositor: Option<AccAddress>,
+        proposal_status: Option<ProposalStatus>,
+    ) -> Result<Vec<ProposalModel<P>>, GasStoreErrors> {
+        let iterator = ProposalsIterator::new(ctx.kv_store(&self.store_key))
+            .map(|this| this.map(|(_key, value)| value))
+            .filter_map(|this| this.ok());
+
+        let mut proposals = Vec::new();
+        for proposal in iterator {
+            if let Some(voter) = &voter {
+                let vote = vote_get(ctx, &self.store_key, proposal.proposal_id, voter)?;
+                if vote.is_none() {
+                    continue;
+                }
+            }
+
+            if let Some(depositor) = &depositor {
+                let deposit = deposit_get(ctx, &self.store_key, proposal.proposal_id, depositor)?;
+                if deposit.is_none() {
+                    continue;
+                }
+            }
+
+            if let Some(proposal_status) = proposal_status {
+                if proposal.status != proposal_status {
+                    continue;
+                }
+            }
+
+            proposals.push(proposal);
+        }
+
+        Ok(proposals)
+    }
+
+    /// Legacy content is represented by `MsgExecLegacyContent` in `v1` proposals
+    fn proposal_to_v1(&self, mut proposal: ProposalModel<P>) -> ProposalModel<P> {
+        if let Some(content) = proposal.content.take() {
+            proposal.messages.insert(
+                0,
+                MsgExecLegacyContent {
+                    content: content.into(),
+                    authority: self.gov_mod.address(),
+                }
+                .into(),
+            );
+        }
+
+        proposal
+    }
+
     pub fn deposit_add<DB: Database>(
         &self,
         ctx: &mut TxContext<'_, DB, SK>,
@@ -379,13 +440,17 @@ impl<
         )?;
 
         proposal.total_deposit = proposal.total_deposit.checked_add(&amount)?;
-        proposal_set(ctx, &self.store_key, &proposal)?;
 
         let deposit_params = self.gov_params_keeper.try_get(ctx)?.deposit;
 
         let activated_voting_period = matches!(proposal.status, ProposalStatus::DepositPeriod if proposal
                    .total_deposit
-                   .is_all_gte(Vec::from(deposit_params.min_deposit.clone()).iter()));
+                   .is_all_gte(Vec::from(deposit_params.min_deposit_for(proposal.expedited)).iter()));
+
+        if activated_voting_period {
+            self.voting_period_activate(ctx, &mut proposal)?;
+        }
+        proposal_set(ctx, &self.store_key, &proposal)?;
 
         let deposit = match deposit_get(ctx, &self.store_key, proposal_id, &depositor)? {
             Some(mut deposit) => {
@@ -463,6 +528,101 @@ impl<
             proposer: _proposer,
         }: MsgSubmitProposal,
     ) -> Result<u64, GovKeeperError> {
+        let content: P = content
+            .try_into()
+            .map_err(|e: CoreError| GovKeeperError::Custom(e.to_string()))?; // TODO: Better way. Generic or smth else
+
+        if !PH::check(&content) {
+            return Err(GovKeeperError::NoHandler);
+        }
+
+        let mut proposal = self.proposal_new(ctx, initial_deposit)?;
+        proposal.content = Some(content);
+
+        self.proposal_submit(ctx, proposal)
+    }
+
+    /// Submit gov `v1` proposal. Every message of proposal should be a message of application `AM`
+    /// with gov module account as its only signer
+    pub fn submit_proposal_v1<DB: Database, AM: TxMessage>(
+        &self,
+        ctx: &mut TxContext<'_, DB, SK>,
+        MsgSubmitProposalV1 {
+            messages,
+            initial_deposit,
+            proposer,
+            metadata,
+            title,
+            summary,
+            expedited,
+        }: MsgSubmitProposalV1,
+    ) -> Result<u64, GovKeeperError> {
+        if metadata.len() > MAX_METADATA_LEN {
+            return Err(GovKeeperError::MetadataTooLong(metadata.len()));
+        }
+
+        if title.len() > MAX_TITLE_LEN {
+            return Err(GovKeeperError::TitleTooLong(title.len()));
+        }
+
+        for msg in &messages {
+            self.proposal_msg_check::<AM>(msg)?;
+        }
+
+        let mut proposal = self.proposal_new(ctx, initial_deposit)?;
+        proposal.messages = messages;
+        proposal.metadata = metadata;
+        proposal.title = title;
+        proposal.summary = summary;
+        proposal.proposer = Some(proposer);
+        proposal.expedited = expedited;
+
+        self.proposal_submit(ctx, proposal)
+    }
+
+    fn proposal_msg_check<AM: TxMessage>(&self, msg: &Any) -> Result<(), GovKeeperError> {
+        let authority = self.gov_mod.address();
+
+        match msg.type_url.as_str() {
+            MsgExecLegacyContent::TYPE_URL => {
+                let MsgExecLegacyContent {
+                    content,
+                    authority: signer,
+                } = msg
+                    .clone()
+                    .try_into()
+                    .map_err(|e: CoreError| GovKeeperError::Custom(e.to_string()))?;
+
+                if signer != authority {
+                    return Err(GovKeeperError::InvalidSigner(signer));
+                }
+
+                let content: P = content
+                    .try_into()
+                    .map_err(|e: CoreError| GovKeeperError::Custom(e.to_string()))?;
+
+                match PH::check(&content) {
+                    true => Ok(()),
+                    false => Err(GovKeeperError::NoHandler),
+                }
+            }
+            _ => {
+                let msg = AM::try_from(msg.clone())
+                    .map_err(|e: CoreError| GovKeeperError::Custom(e.to_string()))?;
+
+                match foreign_signer(&msg, &authority) {
+                    Some(signer) => Err(GovKeeperError::InvalidSigner(signer)),
+                    None => Ok(()),
+                }
+            }
+        }
+    }
+
+    fn proposal_new<DB: Database>(
+        &self,
+        ctx: &mut TxContext<'_, DB, SK>,
+        initial_deposit: UnsignedCoins,
+    ) -> Result<ProposalModel<P>, GovKeeperError> {
         let proposal_id = proposal_id_get(ctx, &self.store_key)?;
         let submit_time = ctx.header().time;
         let deposit_period = self
@@ -471,11 +631,9 @@ impl<
             .deposit
             .max_deposit_period;
 
-        let proposal = ProposalModel {
+        Ok(ProposalModel {
             proposal_id,
-            content: content
-                .try_into()
-                .map_err(|e: CoreError| GovKeeperError::Custom(e.to_string()))?, // TODO: Better way. Generic or smth else
+            content: None,
             status: ProposalStatus::DepositPeriod,
             final_tally_result: None,
             submit_time,
@@ -485,21 +643,29 @@ impl<
             total_deposit: initial_deposit,
             voting_start_time: None,
             voting_end_time: None,
-        };
+            messages: Vec::new(),
+            metadata: String::new(),
+            title: String::new(),
+            summary: String::new(),
+            proposer: None,
+            expedited: false,
+            failed_reason: String::new(),
+        })
+    }
 
-        if !PH::check(&proposal.content) {
-            return Err(GovKeeperError::NoHandler);
-        }
+    fn proposal_submit<DB: Database>(
+        &self,
+        ctx: &mut TxContext<'_, DB, SK>,
+        proposal: ProposalModel<P>,
+    ) -> Result<u64, GovKeeperError> {
+        let proposal_id = proposal.proposal_id;
 
         proposal_set(ctx, &self.store_key, &proposal)?;
         let mut store = ctx.kv_store_mut(&self.store_key);
 
         store.set(
-            ProposalModel::<P>::inactive_queue_key(
-                proposal.proposal_id,
-                &proposal.deposit_end_time,
-            ),
-            proposal.proposal_id.to_be_bytes(),
+            ProposalModel::<P>::inactive_queue_key(proposal_id, &proposal.deposit_end_time),
+            proposal_id.to_be_bytes(),
         )?;
 
         store.set(PROPOSAL_ID_KEY, (proposal_id + 1).to_be_bytes())?;
@@ -515,10 +681,85 @@ impl<
             )],
         ));
 
-        Ok(proposal.proposal_id)
+        Ok(proposal_id)
+    }
+
+    fn voting_period_activate<DB: Database>(
+        &self,
+        ctx: &mut TxContext<'_, DB, SK>,
+        proposal: &mut ProposalModel<P>,
+    ) -> Result<(), GovKeeperError> {
+        let voting_start_time = ctx.header().time;
+        let voting_period = self
+            .gov_params_keeper
+            .try_get(ctx)?
+            .voting
+            .voting_period_for(proposal.expedited);
+        let voting_end_time = voting_start_time
+            .checked_add(voting_period)
+            .ok_or(GovKeeperError::Time("Voting end time overflow".to_owned()))?;
+
+        proposal.status = ProposalStatus::VotingPeriod;
+        proposal.voting_start_time = Some(voting_start_time);
+        proposal.voting_end_time = Some(voting_end_time);
+
+        let mut store = ctx.kv_store_mut(&self.store_key);
+        store.delete(&ProposalModel::<P>::inactive_queue_key(
+            proposal.proposal_id,
+            &proposal.deposit_end_time,
+        ))?;
+        store.set(
+            ProposalModel::<P>::active_queue_key(proposal.proposal_id, &voting_end_time),
+            proposal.proposal_id.to_be_bytes(),
+        )?;
+
+        Ok(())
+    }
+
+    /// Execute content of legacy proposal or messages of `v1` proposal. Messages of application
+    /// are executed by `handler`. State changes are kept only if all messages succeed.
+    fn proposal_execute<DB: Database, H: ABCIHandler<StoreKey = SK>>(
+        &self,
+        ctx: &mut BlockContext<'_, DB, SK>,
+        proposal: &ProposalModel<P>,
+        handler: &H,
+    ) -> Result<(), ProposalHandlingError> {
+        let authority = self.gov_mod.address();
+
+        ctx.cached(|ctx| -> Result<(), ProposalHandlingError> {
+            if let Some(content) = &proposal.content {
+                self.proposal_handler.handle(content.clone(), ctx)?;
+            }
+
+            for msg in &proposal.messages {
+                match msg.type_url.as_str() {
+                    MsgExecLegacyContent::TYPE_URL => {
+                        let MsgExecLegacyContent { content, .. } = msg.clone().try_into()?;
+                        let content: P = content.try_into()?;
+
+                        self.proposal_handler.handle(content, ctx)?;
+                    }
+                    _ => {
+                        let msg = H::Message::try_from(msg.clone())?;
+                        if let Some(signer) = foreign_signer(&msg, &authority) {
+                            return Err(ProposalHandlingError::InvalidSigner(signer));
+                        }
+
+                        ctx.with_tx_context(|ctx| handler.msg(ctx, &msg))
+                            .map_err(|e| ProposalHandlingError::Msg(e.to_string()))?;
+                    }
+                }
+            }
+
+            Ok(())
+        })
     }
 
-    pub fn end_block<DB: Database>(&self, ctx: &mut BlockContext<'_, DB, SK>) -> Vec<Event> {
+    pub fn end_block<DB: Database, H: ABCIHandler<StoreKey = SK>>(
+        &self,
+        ctx: &mut BlockContext<'_, DB, SK>,
+        handler: &H,
+    ) -> Vec<Event> {
         let mut events = Vec::new();
 
         {
@@ -557,16 +798,20 @@ impl<
         {
             let active_iter = {
                 let store = ctx.kv_store(&self.store_key).into();
-                ActiveProposalIterator::new(store, &ctx.header.time)
-                    .map(|this| this.map(|((_, _), proposal)| proposal))
+                ActiveProposalIterator::<'_, _, P>::new(store, &ctx.header.time)
+                    .map(|this| this.map(|((proposal_id, _), _)| proposal_id))
                     .collect::<Vec<_>>()
             };
 
-            for proposal in active_iter {
-                let mut proposal: ProposalModel<P> = proposal.unwrap_gas();
+            for var in active_iter {
+                let proposal_id = var.unwrap_gas();
+                let mut proposal: ProposalModel<P> =
+                    proposal_get(ctx, &self.store_key, proposal_id)
+                        .unwrap_gas()
+                        .expect("proposal in active queue exists");
 
                 let (passes, burn_deposit, tally_result) =
-                    match self.tally(ctx, proposal.proposal_id) {
+                    match self.tally(ctx, proposal.proposal_id, proposal.expedited) {
                         Ok(var) => var,
                         Err(err) => match err {
                             TallyError::Gas(_) => unreachable!("block ctx doesn't have any gas"),
@@ -574,6 +819,61 @@ impl<
                         },
                     };
 
+                let voting_end_time = proposal
+                    .voting_end_time
+                    .expect("proposal in active queue has voting end time");
+                ctx.kv_store_mut(&self.store_key)
+                    .delete(&ProposalModel::<P>::active_queue_key(
+                        proposal.proposal_id,
+                        &voting_end_time,
+                    ));
+
+                // Expedited proposal which didn't pass continues voting as a regular one,
+                // so its deposits are neither refunded nor burned yet
+                if proposal.expedited && !passes {
+                    let voting_period = self
+                        .gov_params_keeper
+                        .try_get(ctx)
+                        .unwrap_gas()
+                        .voting
+                        .voting_period;
+                    let voting_end_time = proposal
+                        .voting_start_time
+                        .expect("proposal in active queue has voting start time")
+                        .checked_add(voting_period)
+                        .expect("voting end time of regular proposal is valid");
+
+                    proposal.expedited = false;
+                    proposal.voting_end_time = Some(voting_end_time);
+
+                    proposal_set(ctx, &self.store_key, &proposal).unwrap_gas();
+                    ctx.kv_store_mut(&self.store_key).set(
+                        ProposalModel::<P>::active_queue_key(
+                            proposal.proposal_id,
+                            &voting_end_time,
+                        ),
+                        proposal.proposal_id.to_be_bytes(),
+                    );
+
+                    events.push(Event::new(
+                        "active_proposal",
+                        vec![
+                            EventAttribute::new(
+                                "proposal_id".into(),
+                                proposal.proposal_id.to_string().into(),
+                                false,
+                            ),
+                            EventAttribute::new(
+                                "proposal_result".into(),
+                                "expedited_proposal_rejected".into(),
+                                false,
+                            ),
+                        ],
+                    ));
+
+                    continue;
+                }
+
                 if burn_deposit {
                     deposit_del(ctx, self, proposal.proposal_id).unwrap_gas();
                 } else {
@@ -581,25 +881,19 @@ impl<
                 }
 
                 match passes {
-                    true if self
-                        .proposal_handler
-                        .handle(proposal.content.clone(), ctx)
-                        .is_ok() =>
-                    {
-                        proposal.status = ProposalStatus::Passed
-                    }
-                    true => proposal.status = ProposalStatus::Failed,
+                    true => match self.proposal_execute(ctx, &proposal, handler) {
+                        Ok(_) => proposal.status = ProposalStatus::Passed,
+                        Err(e) => {
+                            proposal.status = ProposalStatus::Failed;
+                            proposal.failed_reason = e.to_string();
+                        }
+                    },
                     false => proposal.status = ProposalStatus::Rejected,
                 }
 
                 proposal.final_tally_result = Some(tally_result);
 
                 proposal_set(ctx, &self.store_key, &proposal).unwrap_gas();
-                ctx.kv_store_mut(&self.store_key)
-                    .delete(&ProposalModel::<P>::active_queue_key(
-                        proposal.proposal_id,
-                        &proposal.deposit_end_time,
-                    ));
 
                 // TODO: HOOKS https://github.com/cosmos/cosmos-sdk/blob/d3f09c222243bb3da3464969f0366330dcb977a8/x/gov/abci.go#L97
 
@@ -624,6 +918,7 @@ impl<
         &self,
         ctx: &mut CTX,
         proposal_id: u64,
+        expedited: bool,
     ) -> Result<(bool, bool, TallyResult), TallyError> {
         let mut curr_validators = HashMap::<ValAddress, ValidatorGovInfo>::new();
 
@@ -761,7 +1056,7 @@ impl<
         // If more than 1/2 of non-abstaining voters vote Yes, proposal passes
         if *tally_results.get_mut(&VoteOption::Yes)
             / (total_voting_power - *tally_results.get_mut(&VoteOption::Abstain))
-            > tally_params.threshold
+            > tally_params.threshold_for(expedited)
         {
             return Ok((true, false, tally_results.into_result()));
         }
@@ -818,6 +1113,14 @@ impl TallyResultMap {
     }
 }
 
+/// Returns signer of proposal message which isn't gov module account
+fn foreign_signer<AM: TxMessage>(msg: &AM, authority: &AccAddress) -> Option<AccAddress> {
+    msg.get_signers()
+        .into_iter()
+        .find(|signer| *signer != authority)
+        .cloned()
+}
+
 fn proposal_id_get<DB: Database, SK: StoreKey, CTX: QueryableContext<DB, SK>>(
     ctx: &CTX,
     store_key: &SK,
@@ -889,10 +1192,12 @@ fn proposal_del<
             &proposal.deposit_end_time,
         ))?;
 
-        store.delete(&ProposalModel::<P>::active_queue_key(
-            proposal_id,
-            &proposal.deposit_end_time,
-        ))?;
+        if let Some(voting_end_time) = &proposal.voting_end_time {
+            store.delete(&ProposalModel::<P>::active_queue_key(
+                proposal_id,
+                voting_end_time,
+            ))?;
+        }
 
         store.delete(&proposal.key())?;
 
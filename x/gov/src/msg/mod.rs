@@ -14,6 +14,7 @@ use weighted_vote::MsgVoteWeighted;
 
 pub mod deposit;
 pub mod proposal;
+pub mod v1;
 pub mod vote;
 pub mod weighted_vote;
 
@@ -27,6 +28,8 @@ pub enum GovMsg {
     Weighted(MsgVoteWeighted),
     #[msg(url(path = MsgSubmitProposal::TYPE_URL))]
     Proposal(MsgSubmitProposal),
+    #[msg(url(path = v1::MsgSubmitProposal::TYPE_URL))]
+    ProposalV1(v1::MsgSubmitProposal),
 }
 
 impl ValueRenderer for GovMsg {
@@ -0,0 +1,166 @@
+//! Messages of gov `v1` which submit proposals with arbitrary messages
+
+use bytes::Bytes;
+use gears::{
+    core::{any::google::Any, errors::CoreError, Protobuf},
+    derive::{AppMessage, Protobuf, Raw},
+    types::{
+        address::AccAddress,
+        base::{coins::UnsignedCoins, errors::CoinError},
+        tx::TxMessage,
+    },
+};
+use serde::{Deserialize, Serialize};
+
+mod inner {
+    pub use ibc_proto::cosmos::base::v1beta1::Coin;
+    pub use ibc_proto::google::protobuf::Any;
+
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct MsgSubmitProposal {
+        #[prost(message, repeated, tag = "1")]
+        pub messages: Vec<Any>,
+        #[prost(message, repeated, tag = "2")]
+        pub initial_deposit: Vec<Coin>,
+        #[prost(string, tag = "3")]
+        pub proposer: String,
+        #[prost(string, tag = "4")]
+        pub metadata: String,
+        #[prost(string, tag = "5")]
+        pub title: String,
+        #[prost(string, tag = "6")]
+        pub summary: String,
+        #[prost(bool, tag = "7")]
+        pub expedited: bool,
+    }
+}
+
+/// Submit proposal which executes `messages` with gov module account as their signer
+/// once it passes
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MsgSubmitProposal {
+    pub messages: Vec<Any>,
+    pub initial_deposit: UnsignedCoins,
+    pub proposer: AccAddress,
+    pub metadata: String,
+    pub title: String,
+    pub summary: String,
+    pub expedited: bool,
+}
+
+impl MsgSubmitProposal {
+    pub const TYPE_URL: &'static str = "/cosmos.gov.v1.MsgSubmitProposal";
+}
+
+impl TxMessage for MsgSubmitProposal {
+    fn get_signers(&self) -> Vec<&AccAddress> {
+        vec![&self.proposer]
+    }
+
+    fn type_url(&self) -> &'static str {
+        MsgSubmitProposal::TYPE_URL
+    }
+}
+
+impl Protobuf<inner::MsgSubmitProposal> for MsgSubmitProposal {}
+
+impl TryFrom<inner::MsgSubmitProposal> for MsgSubmitProposal {
+    type Error = CoreError;
+
+    fn try_from(
+        inner::MsgSubmitProposal {
+            messages,
+            initial_deposit,
+            proposer,
+            metadata,
+            title,
+            summary,
+            expedited,
+        }: inner::MsgSubmitProposal,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            messages,
+            initial_deposit: UnsignedCoins::new({
+                let mut coins = Vec::with_capacity(initial_deposit.len());
+                for coin in initial_deposit {
+                    coins.push(
+                        coin.try_into()
+                            .map_err(|e: CoinError| CoreError::Coin(e.to_string()))?,
+                    )
+                }
+
+                coins
+            })
+            .map_err(|e| CoreError::Coins(e.to_string()))?,
+            proposer: AccAddress::from_bech32(&proposer)
+                .map_err(|e| CoreError::DecodeAddress(e.to_string()))?,
+            metadata,
+            title,
+            summary,
+            expedited,
+        })
+    }
+}
+
+impl From<MsgSubmitProposal> for inner::MsgSubmitProposal {
+    fn from(
+        MsgSubmitProposal {
+            messages,
+            initial_deposit,
+            proposer,
+            metadata,
+            title,
+            summary,
+            expedited,
+        }: MsgSubmitProposal,
+    ) -> Self {
+        Self {
+            messages,
+            initial_deposit: initial_deposit
+                .into_inner()
+                .into_iter()
+                .map(|e| e.into())
+                .collect(),
+            proposer: proposer.to_string(),
+            metadata,
+            title,
+            summary,
+            expedited,
+        }
+    }
+}
+
+impl TryFrom<Any> for MsgSubmitProposal {
+    type Error = CoreError;
+
+    fn try_from(value: Any) -> Result<Self, Self::Error> {
+        if value.type_url != Self::TYPE_URL {
+            Err(CoreError::DecodeGeneral(
+                "message type not recognized".into(),
+            ))?
+        }
+        MsgSubmitProposal::decode::<Bytes>(value.value.into())
+            .map_err(|e| CoreError::DecodeProtobuf(e.to_string()))
+    }
+}
+
+impl From<MsgSubmitProposal> for Any {
+    fn from(msg: MsgSubmitProposal) -> Self {
+        Any {
+            type_url: MsgSubmitProposal::TYPE_URL.to_string(),
+            value: msg.encode_vec(),
+        }
+    }
+}
+
+/// Wraps legacy `v1beta1` proposal content so it could be executed by `v1` proposal.
+/// `authority` must be the gov module account
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, Raw, Protobuf, AppMessage)]
+#[msg(url = "/cosmos.gov.v1.MsgExecLegacyContent")]
+pub struct MsgExecLegacyContent {
+    #[raw(kind(message), raw = Any)]
+    pub content: Any,
+    #[raw(kind(string), raw = String)]
+    #[msg(signer)]
+    pub authority: AccAddress,
+}
@@ -4,7 +4,8 @@ use std::{
 };
 
 use gears::tendermint::types::time::duration::serde_with::{
-    deserialize_duration_from_nanos_string, serialize_duration_to_nanos_string,
+    deserialize_duration_from_nanos_string, deserialize_duration_opt_from_nanos_string,
+    serialize_duration_opt_to_nanos_string, serialize_duration_to_nanos_string,
 };
 use gears::{
     application::keepers::params::ParamsKeeper,
@@ -30,6 +31,8 @@ const KEY_VOTING_PARAMS: &str = "votingparams";
 const KEY_TALLY_PARAMS: &str = "tallyparams";
 
 const DEFAULT_PERIOD: Duration = Duration::new_from_secs(172800); // 2 days
+const DEFAULT_EXPEDITED_PERIOD: Duration = Duration::new_from_secs(86400); // 1 day
+const DEFAULT_EXPEDITED_MIN_DEPOSIT_MULTIPLIER: u8 = 5;
 
 mod environment;
 
@@ -40,6 +43,26 @@ pub struct DepositParams {
     #[serde(serialize_with = "serialize_duration_to_nanos_string")]
     #[serde(deserialize_with = "deserialize_duration_from_nanos_string")]
     pub max_deposit_period: Duration, // ?
+    /// Minimum deposit for an expedited proposal. Defaults to five times `min_deposit`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expedited_min_deposit: Option<UnsignedCoins>,
+}
+
+impl DepositParams {
+    /// Deposit which is required to start voting period of proposal
+    pub fn min_deposit_for(&self, expedited: bool) -> UnsignedCoins {
+        match (expedited, &self.expedited_min_deposit) {
+            (false, _) => self.min_deposit.clone(),
+            (true, Some(expedited_min_deposit)) => expedited_min_deposit.clone(),
+            (true, None) => UnsignedCoins::new(self.min_deposit.clone().into_iter().map(
+                |UnsignedCoin { denom, amount }| UnsignedCoin {
+                    denom,
+                    amount: amount.saturating_mul(DEFAULT_EXPEDITED_MIN_DEPOSIT_MULTIPLIER.into()),
+                },
+            ))
+            .expect("multiplied coins are still valid"),
+        }
+    }
 }
 
 impl Default for DepositParams {
@@ -51,6 +74,7 @@ impl Default for DepositParams {
             .expect("default is valid")])
             .expect("default is valid"),
             max_deposit_period: DEFAULT_PERIOD,
+            expedited_min_deposit: None,
         }
     }
 }
@@ -61,12 +85,36 @@ pub struct VotingParams {
     #[serde(serialize_with = "serialize_duration_to_nanos_string")]
     #[serde(deserialize_with = "deserialize_duration_from_nanos_string")]
     pub voting_period: Duration,
+    /// Voting period of an expedited proposal. Defaults to one day, but no longer than `voting_period`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[serde(serialize_with = "serialize_duration_opt_to_nanos_string")]
+    #[serde(deserialize_with = "deserialize_duration_opt_from_nanos_string")]
+    pub expedited_voting_period: Option<Duration>,
+}
+
+impl VotingParams {
+    pub fn voting_period_for(&self, expedited: bool) -> Duration {
+        match (expedited, self.expedited_voting_period) {
+            (false, _) => self.voting_period,
+            (true, Some(expedited_voting_period)) => expedited_voting_period,
+            (true, None) => DEFAULT_EXPEDITED_PERIOD.min(self.voting_period),
+        }
+    }
+
+    /// Voting period of an expedited proposal, if set, must be shorter than `voting_period`
+    pub fn is_valid(&self) -> bool {
+        match self.expedited_voting_period {
+            Some(expedited_voting_period) => expedited_voting_period < self.voting_period,
+            None => true,
+        }
+    }
 }
 
 impl Default for VotingParams {
     fn default() -> Self {
         Self {
             voting_period: DEFAULT_PERIOD,
+            expedited_voting_period: None,
         }
     }
 }
@@ -76,6 +124,27 @@ pub struct TallyParams {
     pub quorum: Decimal256,
     pub threshold: Decimal256,
     pub veto_threshold: Decimal256,
+    /// Minimum proportion of Yes votes for an expedited proposal to pass. Defaults to 0.667
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expedited_threshold: Option<Decimal256>,
+}
+
+impl TallyParams {
+    pub fn threshold_for(&self, expedited: bool) -> Decimal256 {
+        match (expedited, self.expedited_threshold) {
+            (false, _) => self.threshold,
+            (true, Some(expedited_threshold)) => expedited_threshold,
+            (true, None) => Decimal256::from_atomics(667_u16, 3).expect("Default should be valid"),
+        }
+    }
+
+    /// Threshold of an expedited proposal, if set, must be greater than `threshold`
+    pub fn is_valid(&self) -> bool {
+        match self.expedited_threshold {
+            Some(expedited_threshold) => expedited_threshold > self.threshold,
+            None => true,
+        }
+    }
 }
 
 impl Default for TallyParams {
@@ -92,17 +161,24 @@ impl Default for TallyParams {
             //veto_threshold: Decimal256::from_atomics(334_u16, 3).expect("Default should be valid"),
             veto_threshold: Decimal256::from_atomics(334000000000000001_u64, 18)
                 .expect("Default should be valid"),
+            expedited_threshold: None,
         }
     }
 }
 
-#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, Serialize, Deserialize, PartialEq)]
 pub struct GovParams {
     pub deposit: DepositParams,
     pub voting: VotingParams,
     pub tally: TallyParams,
 }
 
+impl GovParams {
+    pub fn is_valid(&self) -> bool {
+        self.voting.is_valid() && self.tally.is_valid()
+    }
+}
+
 impl ParamsSerialize for GovParams {
     fn keys() -> HashSet<&'static str> {
         [KEY_DEPOSIT_PARAMS, KEY_VOTING_PARAMS, KEY_TALLY_PARAMS]
@@ -156,8 +232,10 @@ impl<PSK: ParamsSubspaceKey> ParamsKeeper<PSK> for GovParamsKeeper<PSK> {
     fn validate(key: impl AsRef<[u8]>, value: impl AsRef<[u8]>) -> bool {
         match String::from_utf8_lossy(key.as_ref()).as_ref() {
             KEY_DEPOSIT_PARAMS => serde_json::from_slice::<DepositParams>(value.as_ref()).is_ok(),
-            KEY_VOTING_PARAMS => serde_json::from_slice::<VotingParams>(value.as_ref()).is_ok(),
-            KEY_TALLY_PARAMS => serde_json::from_slice::<TallyParams>(value.as_ref()).is_ok(),
+            KEY_VOTING_PARAMS => serde_json::from_slice::<VotingParams>(value.as_ref())
+                .is_ok_and(|params| params.is_valid()),
+            KEY_TALLY_PARAMS => serde_json::from_slice::<TallyParams>(value.as_ref())
+                .is_ok_and(|params| params.is_valid()),
             _ => false,
         }
     }
@@ -203,6 +281,7 @@ impl TryFrom<inner::DepositParams> for DepositParams {
                     ))
                 })?
             },
+            expedited_min_deposit: None,
         })
     }
 }
@@ -212,6 +291,7 @@ impl From<DepositParams> for inner::DepositParams {
         DepositParams {
             min_deposit,
             max_deposit_period,
+            expedited_min_deposit: _,
         }: DepositParams,
     ) -> Self {
         Self {
@@ -240,6 +320,7 @@ impl TryFrom<inner::TallyParams> for TallyParams {
             quorum: Decimal256::one(),
             threshold: Decimal256::one(),
             veto_threshold: Decimal256::one(),
+            expedited_threshold: None,
         }) // TODO:NOW
     }
 }
@@ -267,12 +348,18 @@ impl TryFrom<inner::VotingParams> for VotingParams {
                 Duration::try_new(duration.seconds, duration.nanos)
                     .map_err(|err| anyhow::anyhow!("failed to map duration: {err}"))?
             },
+            expedited_voting_period: None,
         })
     }
 }
 
 impl From<VotingParams> for inner::VotingParams {
-    fn from(VotingParams { voting_period }: VotingParams) -> Self {
+    fn from(
+        VotingParams {
+            voting_period,
+            expedited_voting_period: _,
+        }: VotingParams,
+    ) -> Self {
         Self {
             voting_period: Some(inner::Duration {
                 seconds: voting_period.duration_seconds().into(),
@@ -283,3 +370,46 @@ impl From<VotingParams> for inner::VotingParams {
 }
 
 impl Protobuf<inner::VotingParams> for VotingParams {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn expedited_voting_period_shorter_than_voting_period() {
+        let params = VotingParams {
+            voting_period: DEFAULT_PERIOD,
+            expedited_voting_period: Some(DEFAULT_EXPEDITED_PERIOD),
+        };
+        assert!(params.is_valid());
+
+        let params = VotingParams {
+            voting_period: DEFAULT_PERIOD,
+            expedited_voting_period: Some(DEFAULT_PERIOD),
+        };
+        assert!(!params.is_valid());
+
+        let params = VotingParams {
+            voting_period: DEFAULT_EXPEDITED_PERIOD,
+            expedited_voting_period: None,
+        };
+        assert!(params.is_valid());
+    }
+
+    #[test]
+    fn expedited_threshold_greater_than_threshold() {
+        let params = TallyParams {
+            expedited_threshold: Some(Decimal256::from_atomics(667_u16, 3).expect("valid")),
+            ..Default::default()
+        };
+        assert!(params.is_valid());
+
+        let params = TallyParams {
+            expedited_threshold: Some(Decimal256::from_atomics(5_u8, 1).expect("valid")),
+            ..Default::default()
+        };
+        assert!(!params.is_valid());
+
+        assert!(TallyParams::default().is_valid());
+    }
+}
@@ -0,0 +1,225 @@
+use gears::{
+    context::InfallibleContextMut,
+    core::{errors::CoreError, Protobuf},
+    derive::AppMessage,
+    signing::{
+        handler::MetadataGetter,
+        renderer::value_renderer::{
+            DefaultPrimitiveRenderer, PrimitiveValueRenderer, RenderError,
+            TryPrimitiveValueRenderer, TryPrimitiveValueRendererWithMetadata, ValueRenderer,
+        },
+    },
+    store::{database::Database, StoreKey},
+    tendermint::types::proto::event::{Event, EventAttribute},
+    types::{
+        address::AccAddress,
+        base::{
+            coins::{SimpleCoins, UnsignedCoins},
+            errors::CoinError,
+        },
+        rendering::screen::{Indent, Screen},
+    },
+    x::keepers::distribution::CommunityPoolKeeper,
+};
+use serde::{Deserialize, Serialize};
+
+use super::handler::{ProposalHandler, ProposalHandlingError};
+
+mod inner {
+    pub use ibc_proto::cosmos::base::v1beta1::Coin;
+
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct CommunityPoolSpendProposal {
+        #[prost(string, tag = "1")]
+        pub title: String,
+        #[prost(string, tag = "2")]
+        pub description: String,
+        #[prost(string, tag = "3")]
+        pub recipient: String,
+        #[prost(message, repeated, tag = "4")]
+        pub amount: Vec<Coin>,
+    }
+}
+
+const MAX_TITLE_LEN: usize = 140;
+const MAX_DESCRIPTION_LEN: usize = 10000;
+
+/// Spend `amount` from community pool to `recipient` once proposal passes
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, AppMessage)]
+#[msg(url = "/cosmos.distribution.v1beta1.CommunityPoolSpendProposal")]
+pub struct CommunityPoolSpendProposal {
+    pub title: String,
+    pub description: String,
+    pub recipient: AccAddress,
+    pub amount: UnsignedCoins,
+}
+
+impl TryFrom<inner::CommunityPoolSpendProposal> for CommunityPoolSpendProposal {
+    type Error = CoreError;
+
+    fn try_from(
+        inner::CommunityPoolSpendProposal {
+            title,
+            description,
+            recipient,
+            amount,
+        }: inner::CommunityPoolSpendProposal,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            title,
+            description,
+            recipient: AccAddress::from_bech32(&recipient)
+                .map_err(|e| CoreError::DecodeAddress(e.to_string()))?,
+            amount: coins_from_raw(amount)?,
+        })
+    }
+}
+
+impl From<CommunityPoolSpendProposal> for inner::CommunityPoolSpendProposal {
+    fn from(
+        CommunityPoolSpendProposal {
+            title,
+            description,
+            recipient,
+            amount,
+        }: CommunityPoolSpendProposal,
+    ) -> Self {
+        Self {
+            title,
+            description,
+            recipient: recipient.to_string(),
+            amount: amount.into_inner().into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl Protobuf<inner::CommunityPoolSpendProposal> for CommunityPoolSpendProposal {}
+
+impl ValueRenderer for CommunityPoolSpendProposal {
+    fn format<MG: MetadataGetter>(&self, get_metadata: &MG) -> Result<Vec<Screen>, RenderError> {
+        let mut screens = vec![
+            Screen {
+                title: "Title".to_string(),
+                content: DefaultPrimitiveRenderer::try_format(self.title.clone())?,
+                indent: Some(Indent::two()),
+                expert: false,
+            },
+            Screen {
+                title: "Description".to_string(),
+                content: DefaultPrimitiveRenderer::try_format(self.description.clone())?,
+                indent: Some(Indent::two()),
+                expert: false,
+            },
+        ];
+        screens.extend(spend_screens(&self.recipient, &self.amount, get_metadata)?);
+
+        Ok(screens)
+    }
+}
+
+fn coins_from_raw(amount: Vec<inner::Coin>) -> Result<UnsignedCoins, CoreError> {
+    let mut coins = Vec::with_capacity(amount.len());
+    for coin in amount {
+        coins.push(
+            coin.try_into()
+                .map_err(|e: CoinError| CoreError::Coin(e.to_string()))?,
+        );
+    }
+
+    UnsignedCoins::new(coins).map_err(|e| CoreError::Coins(e.to_string()))
+}
+
+fn spend_screens<MG: MetadataGetter>(
+    recipient: &AccAddress,
+    amount: &UnsignedCoins,
+    get_metadata: &MG,
+) -> Result<Vec<Screen>, RenderError> {
+    Ok(vec![
+        Screen {
+            title: "Recipient".to_string(),
+            content: DefaultPrimitiveRenderer::format(recipient.clone()),
+            indent: Some(Indent::two()),
+            expert: false,
+        },
+        Screen {
+            title: "Amount".to_string(),
+            content: DefaultPrimitiveRenderer::try_format_with_metadata(
+                amount.to_owned(),
+                get_metadata,
+            )?,
+            indent: Some(Indent::two()),
+            expert: false,
+        },
+    ])
+}
+
+#[derive(Debug, Clone)]
+pub struct CommunityPoolSpendProposalHandler<DK> {
+    keeper: DK,
+}
+
+impl<DK> CommunityPoolSpendProposalHandler<DK> {
+    pub fn new(keeper: DK) -> Self {
+        Self { keeper }
+    }
+
+    fn spend<SK: StoreKey, CTX: InfallibleContextMut<DB, SK>, DB: Database>(
+        &self,
+        ctx: &mut CTX,
+        recipient: AccAddress,
+        amount: UnsignedCoins,
+    ) -> Result<(), ProposalHandlingError>
+    where
+        DK: CommunityPoolKeeper<SK>,
+    {
+        self.keeper
+            .distribute_from_fee_pool(ctx, amount.clone(), &recipient)
+            .map_err(|e| ProposalHandlingError::Other(e.to_string()))?;
+
+        ctx.push_event(Event::new(
+            "community_pool_spend",
+            [
+                EventAttribute::new("recipient".into(), String::from(recipient).into(), true),
+                EventAttribute::new(
+                    "amount".into(),
+                    SimpleCoins::new(amount).to_string_bytes(),
+                    true,
+                ),
+            ],
+        ));
+
+        Ok(())
+    }
+}
+
+impl<SK: StoreKey, DK: CommunityPoolKeeper<SK>> ProposalHandler<CommunityPoolSpendProposal, SK>
+    for CommunityPoolSpendProposalHandler<DK>
+{
+    fn handle<CTX: InfallibleContextMut<DB, SK>, DB: Database>(
+        &self,
+        CommunityPoolSpendProposal {
+            title: _,
+            description: _,
+            recipient,
+            amount,
+        }: CommunityPoolSpendProposal,
+        ctx: &mut CTX,
+    ) -> Result<(), ProposalHandlingError> {
+        self.spend::<SK, CTX, DB>(ctx, recipient, amount)
+    }
+
+    fn check(
+        CommunityPoolSpendProposal {
+            title,
+            description,
+            recipient: _,
+            amount,
+        }: &CommunityPoolSpendProposal,
+    ) -> bool {
+        !amount.is_empty()
+            && !title.trim().is_empty()
+            && title.len() <= MAX_TITLE_LEN
+            && !description.trim().is_empty()
+            && description.len() <= MAX_DESCRIPTION_LEN
+    }
+}
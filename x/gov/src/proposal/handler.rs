@@ -3,6 +3,7 @@ use gears::core::errors::CoreError;
 use gears::gas::store::errors::GasStoreErrors;
 use gears::store::database::Database;
 use gears::store::StoreKey;
+use gears::types::address::AccAddress;
 
 pub trait ProposalHandler<P, SK: StoreKey> {
     fn handle<CTX: InfallibleContextMut<DB, SK>, DB: Database>(
@@ -26,6 +27,10 @@ pub enum ProposalHandlingError {
     InvalidProposal,
     #[error("Can't handle this proposal: {0}")]
     Gas(#[from] GasStoreErrors),
+    #[error("Can't handle this proposal: message signer {0} isn't gov module account")]
+    InvalidSigner(AccAddress),
+    #[error("Can't handle this proposal: message failed: {0}")]
+    Msg(String),
     #[error("{0}")]
     Other(String),
 }
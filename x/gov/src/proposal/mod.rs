@@ -1,12 +1,14 @@
+pub mod community_pool;
 mod handler;
 pub mod param;
 pub mod text;
 pub mod upgrade;
 
 use ::upgrade::{keeper::UpgradeKeeper, UpgradeHandler};
+use community_pool::{CommunityPoolSpendProposal, CommunityPoolSpendProposalHandler};
 use gears::{
     application::keepers::params::ParamsKeeper, core::errors::CoreError, derive::AppMessage,
-    params::ParamsSubspaceKey, store::StoreKey,
+    params::ParamsSubspaceKey, store::StoreKey, x::keepers::distribution::CommunityPoolKeeper,
 };
 pub use handler::*;
 use ibc_proto::google::protobuf::Any;
@@ -37,6 +39,8 @@ pub enum Proposals<PSK: ParamsSubspaceKey> {
     Upgrade(SoftwareUpgradeProposal),
     #[msg(url(path = CancelSoftwareUpgradeProposal::TYPE_URL))]
     CancelUpgrade(CancelSoftwareUpgradeProposal),
+    #[msg(url(path = CommunityPoolSpendProposal::TYPE_URL))]
+    CommunityPoolSpend(CommunityPoolSpendProposal),
 }
 
 impl<PSK: ParamsSubspaceKey> Serialize for Proposals<PSK> {
@@ -49,6 +53,7 @@ impl<PSK: ParamsSubspaceKey> Serialize for Proposals<PSK> {
             Proposals::Params(inner) => inner.serialize(serializer),
             Proposals::Upgrade(inner) => inner.serialize(serializer),
             Proposals::CancelUpgrade(inner) => inner.serialize(serializer),
+            Proposals::CommunityPoolSpend(inner) => inner.serialize(serializer),
         }
     }
 }
@@ -56,16 +61,18 @@ impl<PSK: ParamsSubspaceKey> Serialize for Proposals<PSK> {
 impl<PSK: ParamsSubspaceKey> Proposal for Proposals<PSK> {}
 
 #[derive(Debug, Clone)]
-pub struct ProposalsHandler<SK, PSK, PK, M, UH> {
+pub struct ProposalsHandler<SK, PSK, PK, M, UH, DK> {
     params_handler: ParamChangeProposalHandler<PK, SK, PSK>,
     upgrade_handler: UpgradeProposalHandler<SK, M, UH>,
+    community_pool_handler: CommunityPoolSpendProposalHandler<DK>,
 }
 
-impl<SK, PSK, PK, M, UH> ProposalsHandler<SK, PSK, PK, M, UH> {
-    pub fn new(keeper: UpgradeKeeper<SK, M, UH>) -> Self {
+impl<SK, PSK, PK, M, UH, DK> ProposalsHandler<SK, PSK, PK, M, UH, DK> {
+    pub fn new(keeper: UpgradeKeeper<SK, M, UH>, distribution_keeper: DK) -> Self {
         Self {
             params_handler: ParamChangeProposalHandler::new(),
             upgrade_handler: UpgradeProposalHandler::new(keeper),
+            community_pool_handler: CommunityPoolSpendProposalHandler::new(distribution_keeper),
         }
     }
 }
@@ -76,7 +83,8 @@ impl<
         PK: ParamsKeeper<PSK>,
         M: ::upgrade::Module + TryFrom<Vec<u8>, Error = anyhow::Error>,
         UH: UpgradeHandler,
-    > ProposalHandler<Proposals<PSK>, SK> for ProposalsHandler<SK, PSK, PK, M, UH>
+        DK: CommunityPoolKeeper<SK>,
+    > ProposalHandler<Proposals<PSK>, SK> for ProposalsHandler<SK, PSK, PK, M, UH, DK>
 {
     fn handle<
         CTX: gears::context::InfallibleContextMut<DB, SK>,
@@ -91,6 +99,9 @@ impl<
             Proposals::Params(proposal) => self.params_handler.handle(proposal, ctx),
             Proposals::Upgrade(proposal) => self.upgrade_handler.handle(proposal, ctx),
             Proposals::CancelUpgrade(proposal) => self.upgrade_handler.handle(proposal, ctx),
+            Proposals::CommunityPoolSpend(proposal) => {
+                self.community_pool_handler.handle(proposal, ctx)
+            }
         }
     }
 
@@ -99,6 +110,9 @@ impl<
             Proposals::Params(proposal) => {
                 ParamChangeProposalHandler::<PK, SK, PSK>::check(proposal)
             }
+            Proposals::CommunityPoolSpend(proposal) => {
+                <CommunityPoolSpendProposalHandler<DK> as ProposalHandler<_, SK>>::check(proposal)
+            }
             _ => true,
         }
     }
@@ -15,6 +15,7 @@ use crate::proposal::Proposal;
 
 pub mod request;
 pub mod response;
+pub mod v1;
 
 #[derive(Debug, Clone, Query)]
 #[query(request)]
@@ -29,6 +30,9 @@ pub enum GovQuery {
     Vote(QueryVoteRequest),
     Votes(QueryVotesRequest),
     Proposer(QueryProposerRequest),
+    ProposalV1(v1::QueryProposalRequest),
+    ProposalsV1(v1::QueryProposalsRequest),
+    ParamsV1(v1::QueryParamsRequest),
 }
 
 impl QueryRequest for GovQuery {
@@ -50,4 +54,7 @@ pub enum GovQueryResponse<T: Proposal> {
     Vote(QueryVoteResponse),
     Votes(QueryVotesResponse),
     Proposer(QueryProposerResponse),
+    ProposalV1(v1::QueryProposalResponse<T>),
+    ProposalsV1(v1::QueryProposalsResponse<T>),
+    ParamsV1(v1::QueryParamsResponse),
 }
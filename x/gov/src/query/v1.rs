@@ -0,0 +1,520 @@
+//! Queries of gov `v1` which expose messages and other `v1` fields of proposals
+
+use std::str::FromStr;
+
+use gears::{
+    core::{errors::CoreError, Protobuf},
+    derive::{Protobuf, Query},
+    error::ProtobufError,
+    tendermint::types::time::{duration::Duration, timestamp::Timestamp},
+    types::{
+        address::AccAddress,
+        base::{coins::UnsignedCoins, errors::CoinError},
+        decimal256::Decimal256,
+        pagination::{request::PaginationRequest, response::PaginationResponse},
+        uint::Uint256,
+    },
+};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    params::{DepositParams, GovParams, TallyParams, VotingParams},
+    proposal::Proposal,
+    types::proposal::{ProposalModel, ProposalStatus, TallyResult},
+};
+
+use super::request;
+
+mod inner {
+    pub use ibc_proto::cosmos::base::query::v1beta1::PageResponse;
+    pub use ibc_proto::cosmos::base::v1beta1::Coin;
+    pub use ibc_proto::cosmos::gov::v1beta1::QueryProposalRequest;
+    pub use ibc_proto::cosmos::gov::v1beta1::QueryProposalsRequest;
+    pub use ibc_proto::google::protobuf::{Any, Duration, Timestamp};
+
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct QueryParamsRequest {}
+
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct TallyResult {
+        #[prost(string, tag = "1")]
+        pub yes_count: String,
+        #[prost(string, tag = "2")]
+        pub abstain_count: String,
+        #[prost(string, tag = "3")]
+        pub no_count: String,
+        #[prost(string, tag = "4")]
+        pub no_with_veto_count: String,
+    }
+
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Proposal {
+        #[prost(uint64, tag = "1")]
+        pub id: u64,
+        #[prost(message, repeated, tag = "2")]
+        pub messages: Vec<Any>,
+        #[prost(int32, tag = "3")]
+        pub status: i32,
+        #[prost(message, optional, tag = "4")]
+        pub final_tally_result: Option<TallyResult>,
+        #[prost(message, optional, tag = "5")]
+        pub submit_time: Option<Timestamp>,
+        #[prost(message, optional, tag = "6")]
+        pub deposit_end_time: Option<Timestamp>,
+        #[prost(message, repeated, tag = "7")]
+        pub total_deposit: Vec<Coin>,
+        #[prost(message, optional, tag = "8")]
+        pub voting_start_time: Option<Timestamp>,
+        #[prost(message, optional, tag = "9")]
+        pub voting_end_time: Option<Timestamp>,
+        #[prost(string, tag = "10")]
+        pub metadata: String,
+        #[prost(string, tag = "11")]
+        pub title: String,
+        #[prost(string, tag = "12")]
+        pub summary: String,
+        #[prost(string, tag = "13")]
+        pub proposer: String,
+        #[prost(bool, tag = "14")]
+        pub expedited: bool,
+        #[prost(string, tag = "15")]
+        pub failed_reason: String,
+    }
+
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct Params {
+        #[prost(message, repeated, tag = "1")]
+        pub min_deposit: Vec<Coin>,
+        #[prost(message, optional, tag = "2")]
+        pub max_deposit_period: Option<Duration>,
+        #[prost(message, optional, tag = "3")]
+        pub voting_period: Option<Duration>,
+        #[prost(string, tag = "4")]
+        pub quorum: String,
+        #[prost(string, tag = "5")]
+        pub threshold: String,
+        #[prost(string, tag = "6")]
+        pub veto_threshold: String,
+        #[prost(message, optional, tag = "10")]
+        pub expedited_voting_period: Option<Duration>,
+        #[prost(string, tag = "11")]
+        pub expedited_threshold: String,
+        #[prost(message, repeated, tag = "12")]
+        pub expedited_min_deposit: Vec<Coin>,
+    }
+
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct QueryProposalResponse {
+        #[prost(message, optional, tag = "1")]
+        pub proposal: Option<Proposal>,
+    }
+
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct QueryProposalsResponse {
+        #[prost(message, repeated, tag = "1")]
+        pub proposals: Vec<Proposal>,
+        #[prost(message, optional, tag = "2")]
+        pub pagination: Option<PageResponse>,
+    }
+
+    #[derive(Clone, PartialEq, ::prost::Message)]
+    pub struct QueryParamsResponse {
+        #[prost(message, optional, tag = "4")]
+        pub params: Option<Params>,
+    }
+}
+
+#[derive(Clone, PartialEq, Debug, Query, Protobuf)]
+#[query(url = "/cosmos.gov.v1.Query/Proposal")]
+#[proto(raw = "inner::QueryProposalRequest")]
+pub struct QueryProposalRequest {
+    pub proposal_id: u64,
+}
+
+#[derive(Clone, PartialEq, Debug, Query)]
+#[query(url = "/cosmos.gov.v1.Query/Proposals")]
+pub struct QueryProposalsRequest {
+    pub voter: Option<AccAddress>,
+    pub depositor: Option<AccAddress>,
+    pub proposal_status: Option<ProposalStatus>,
+    pub pagination: Option<PaginationRequest>,
+}
+
+// Request has same fields as in `v1beta1`
+impl TryFrom<inner::QueryProposalsRequest> for QueryProposalsRequest {
+    type Error = CoreError;
+
+    fn try_from(value: inner::QueryProposalsRequest) -> Result<Self, Self::Error> {
+        let request::QueryProposalsRequest {
+            voter,
+            depositor,
+            proposal_status,
+            pagination,
+        } = value.try_into()?;
+
+        Ok(Self {
+            voter,
+            depositor,
+            proposal_status,
+            pagination,
+        })
+    }
+}
+
+impl From<QueryProposalsRequest> for inner::QueryProposalsRequest {
+    fn from(
+        QueryProposalsRequest {
+            voter,
+            depositor,
+            proposal_status,
+            pagination,
+        }: QueryProposalsRequest,
+    ) -> Self {
+        request::QueryProposalsRequest {
+            voter,
+            depositor,
+            proposal_status,
+            pagination,
+        }
+        .into()
+    }
+}
+
+impl Protobuf<inner::QueryProposalsRequest> for QueryProposalsRequest {}
+
+/// Request all gov params. `params_type` of request is ignored
+#[derive(Clone, PartialEq, Debug, Query, Protobuf)]
+#[query(url = "/cosmos.gov.v1.Query/Params")]
+#[proto(raw = "inner::QueryParamsRequest")]
+pub struct QueryParamsRequest;
+
+#[derive(Clone, PartialEq, Serialize, Deserialize, Debug, Query)]
+pub struct QueryProposalResponse<T: Proposal> {
+    pub proposal: Option<ProposalModel<T>>,
+}
+
+impl<T: Proposal> TryFrom<inner::QueryProposalResponse> for QueryProposalResponse<T> {
+    type Error = ProtobufError;
+
+    fn try_from(
+        inner::QueryProposalResponse { proposal }: inner::QueryProposalResponse,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            proposal: match proposal {
+                Some(proposal) => Some(proposal_from_raw(proposal)?),
+                None => None,
+            },
+        })
+    }
+}
+
+impl<T: Proposal> From<QueryProposalResponse<T>> for inner::QueryProposalResponse {
+    fn from(QueryProposalResponse { proposal }: QueryProposalResponse<T>) -> Self {
+        Self {
+            proposal: proposal.map(proposal_into_raw),
+        }
+    }
+}
+
+impl<T: Proposal> Protobuf<inner::QueryProposalResponse> for QueryProposalResponse<T> {}
+
+#[derive(Clone, PartialEq, Serialize, Deserialize, Debug, Query)]
+pub struct QueryProposalsResponse<T: Proposal> {
+    pub proposals: Vec<ProposalModel<T>>,
+    pub pagination: Option<PaginationResponse>,
+}
+
+impl<T: Proposal> TryFrom<inner::QueryProposalsResponse> for QueryProposalsResponse<T> {
+    type Error = ProtobufError;
+
+    fn try_from(
+        inner::QueryProposalsResponse {
+            proposals,
+            pagination,
+        }: inner::QueryProposalsResponse,
+    ) -> Result<Self, Self::Error> {
+        Ok(Self {
+            proposals: {
+                let mut result = Vec::with_capacity(proposals.len());
+
+                for proposal in proposals {
+                    result.push(proposal_from_raw(proposal)?)
+                }
+
+                result
+            },
+            pagination: pagination.map(|e| e.into()),
+        })
+    }
+}
+
+impl<T: Proposal> From<QueryProposalsResponse<T>> for inner::QueryProposalsResponse {
+    fn from(
+        QueryProposalsResponse {
+            proposals,
+            pagination,
+        }: QueryProposalsResponse<T>,
+    ) -> Self {
+        Self {
+            proposals: proposals.into_iter().map(proposal_into_raw).collect(),
+            pagination: pagination.map(|e| e.into()),
+        }
+    }
+}
+
+impl<T: Proposal> Protobuf<inner::QueryProposalsResponse> for QueryProposalsResponse<T> {}
+
+#[derive(Clone, PartialEq, Serialize, Deserialize, Debug, Query)]
+pub struct QueryParamsResponse {
+    pub params: GovParams,
+}
+
+impl TryFrom<inner::QueryParamsResponse> for QueryParamsResponse {
+    type Error = CoreError;
+
+    fn try_from(
+        inner::QueryParamsResponse { params }: inner::QueryParamsResponse,
+    ) -> Result<Self, Self::Error> {
+        let inner::Params {
+            min_deposit,
+            max_deposit_period,
+            voting_period,
+            quorum,
+            threshold,
+            veto_threshold,
+            expedited_voting_period,
+            expedited_threshold,
+            expedited_min_deposit,
+        } = params.ok_or(CoreError::MissingField(
+            "QueryParamsResponse: field `params`".to_owned(),
+        ))?;
+
+        Ok(Self {
+            params: GovParams {
+                deposit: DepositParams {
+                    min_deposit: coins_from_raw(min_deposit)?,
+                    max_deposit_period: duration_from_raw(
+                        max_deposit_period,
+                        "max_deposit_period",
+                    )?,
+                    expedited_min_deposit: Some(coins_from_raw(expedited_min_deposit)?),
+                },
+                voting: VotingParams {
+                    voting_period: duration_from_raw(voting_period, "voting_period")?,
+                    expedited_voting_period: Some(duration_from_raw(
+                        expedited_voting_period,
+                        "expedited_voting_period",
+                    )?),
+                },
+                tally: TallyParams {
+                    quorum: decimal_from_raw(&quorum)?,
+                    threshold: decimal_from_raw(&threshold)?,
+                    veto_threshold: decimal_from_raw(&veto_threshold)?,
+                    expedited_threshold: Some(decimal_from_raw(&expedited_threshold)?),
+                },
+            },
+        })
+    }
+}
+
+impl From<QueryParamsResponse> for inner::QueryParamsResponse {
+    fn from(
+        QueryParamsResponse {
+            params:
+                GovParams {
+                    deposit,
+                    voting,
+                    tally,
+                },
+        }: QueryParamsResponse,
+    ) -> Self {
+        // unset expedited params are reported with their default values
+        Self {
+            params: Some(inner::Params {
+                min_deposit: deposit
+                    .min_deposit_for(false)
+                    .into_iter()
+                    .map(Into::into)
+                    .collect(),
+                max_deposit_period: Some(deposit.max_deposit_period.into()),
+                voting_period: Some(voting.voting_period_for(false).into()),
+                quorum: tally.quorum.to_string(),
+                threshold: tally.threshold_for(false).to_string(),
+                veto_threshold: tally.veto_threshold.to_string(),
+                expedited_voting_period: Some(voting.voting_period_for(true).into()),
+                expedited_threshold: tally.threshold_for(true).to_string(),
+                expedited_min_deposit: deposit
+                    .min_deposit_for(true)
+                    .into_iter()
+                    .map(Into::into)
+                    .collect(),
+            }),
+        }
+    }
+}
+
+impl Protobuf<inner::QueryParamsResponse> for QueryParamsResponse {}
+
+fn proposal_from_raw<T: Proposal>(
+    inner::Proposal {
+        id,
+        messages,
+        status,
+        final_tally_result,
+        submit_time,
+        deposit_end_time,
+        total_deposit,
+        voting_start_time,
+        voting_end_time,
+        metadata,
+        title,
+        summary,
+        proposer,
+        expedited,
+        failed_reason,
+    }: inner::Proposal,
+) -> Result<ProposalModel<T>, CoreError> {
+    Ok(ProposalModel {
+        proposal_id: id,
+        content: None,
+        status: status.try_into()?,
+        final_tally_result: match final_tally_result {
+            Some(var) => Some(tally_result_from_raw(var)?),
+            None => None,
+        },
+        submit_time: timestamp_from_raw(submit_time, "submit_time")?,
+        deposit_end_time: timestamp_from_raw(deposit_end_time, "deposit_end_time")?,
+        total_deposit: coins_from_raw(total_deposit)?,
+        voting_start_time: match voting_start_time {
+            Some(var) => Some(timestamp_from_raw(Some(var), "voting_start_time")?),
+            None => None,
+        },
+        voting_end_time: match voting_end_time {
+            Some(var) => Some(timestamp_from_raw(Some(var), "voting_end_time")?),
+            None => None,
+        },
+        messages,
+        metadata,
+        title,
+        summary,
+        proposer: match proposer.is_empty() {
+            true => None,
+            false => Some(
+                AccAddress::from_bech32(&proposer)
+                    .map_err(|e| CoreError::DecodeAddress(e.to_string()))?,
+            ),
+        },
+        expedited,
+        failed_reason,
+    })
+}
+
+fn proposal_into_raw<T>(
+    ProposalModel {
+        proposal_id,
+        content: _,
+        status,
+        final_tally_result,
+        submit_time,
+        deposit_end_time,
+        total_deposit,
+        voting_start_time,
+        voting_end_time,
+        messages,
+        metadata,
+        title,
+        summary,
+        proposer,
+        expedited,
+        failed_reason,
+    }: ProposalModel<T>,
+) -> inner::Proposal {
+    inner::Proposal {
+        id: proposal_id,
+        messages,
+        status: status.into(),
+        final_tally_result: final_tally_result.map(
+            |TallyResult {
+                 yes,
+                 abstain,
+                 no,
+                 no_with_veto,
+             }| inner::TallyResult {
+                yes_count: yes.to_string(),
+                abstain_count: abstain.to_string(),
+                no_count: no.to_string(),
+                no_with_veto_count: no_with_veto.to_string(),
+            },
+        ),
+        submit_time: Some(submit_time.into()),
+        deposit_end_time: Some(deposit_end_time.into()),
+        total_deposit: total_deposit.into_iter().map(Into::into).collect(),
+        voting_start_time: voting_start_time.map(Into::into),
+        voting_end_time: voting_end_time.map(Into::into),
+        metadata,
+        title,
+        summary,
+        proposer: proposer.map(|this| this.to_string()).unwrap_or_default(),
+        expedited,
+        failed_reason,
+    }
+}
+
+fn tally_result_from_raw(
+    inner::TallyResult {
+        yes_count,
+        abstain_count,
+        no_count,
+        no_with_veto_count,
+    }: inner::TallyResult,
+) -> Result<TallyResult, CoreError> {
+    let parse = |votes: &str, name: &str| {
+        Uint256::from_str(votes)
+            .map_err(|e| CoreError::DecodeGeneral(format!("{name} votes parse error: {e}")))
+    };
+
+    Ok(TallyResult {
+        yes: parse(&yes_count, "Yes")?,
+        abstain: parse(&abstain_count, "Abstain")?,
+        no: parse(&no_count, "No")?,
+        no_with_veto: parse(&no_with_veto_count, "NoWithVeto")?,
+    })
+}
+
+fn timestamp_from_raw(
+    timestamp: Option<inner::Timestamp>,
+    field: &str,
+) -> Result<Timestamp, CoreError> {
+    timestamp
+        .ok_or(CoreError::MissingField(format!(
+            "Proposal: field `{field}`"
+        )))?
+        .try_into()
+        .map_err(|e| CoreError::DecodeGeneral(format!("Proposal: invalid `{field}`: {e}")))
+}
+
+fn duration_from_raw(
+    duration: Option<inner::Duration>,
+    field: &str,
+) -> Result<Duration, CoreError> {
+    duration
+        .ok_or(CoreError::MissingField(format!("Params: field `{field}`")))?
+        .try_into()
+        .map_err(|e| CoreError::DecodeGeneral(format!("Params: invalid `{field}`: {e}")))
+}
+
+fn decimal_from_raw(decimal: &str) -> Result<Decimal256, CoreError> {
+    Decimal256::from_str(decimal).map_err(|e| CoreError::DecodeGeneral(e.to_string()))
+}
+
+fn coins_from_raw(coins: Vec<inner::Coin>) -> Result<UnsignedCoins, CoreError> {
+    let mut result = Vec::with_capacity(coins.len());
+    for coin in coins {
+        result.push(
+            coin.try_into()
+                .map_err(|e: CoinError| CoreError::Coin(e.to_string()))?,
+        );
+    }
+
+    UnsignedCoins::new(result).map_err(|e| CoreError::Coins(e.to_string()))
+}
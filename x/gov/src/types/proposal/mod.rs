@@ -1,6 +1,6 @@
 use std::{marker::PhantomData, str::FromStr, sync::OnceLock};
 
-use chrono::{DateTime, SubsecRound, Utc};
+use chrono::{DateTime, NaiveDateTime, SubsecRound, Utc};
 use gears::{
     core::{errors::CoreError, Protobuf},
     error::ProtobufError,
@@ -8,14 +8,19 @@ use gears::{
     store::database::Database,
     tendermint::types::time::timestamp::Timestamp,
     types::{
+        address::AccAddress,
         base::coins::UnsignedCoins,
         store::{kv::Store, range::VectoredStoreRange},
         uint::Uint256,
     },
 };
+use ibc_proto::google::protobuf::Any;
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 
-use crate::{errors::SERDE_JSON_CONVERSION, keeper::KEY_PROPOSAL_PREFIX, proposal::Proposal};
+use crate::{
+    errors::SERDE_JSON_CONVERSION, keeper::KEY_PROPOSAL_PREFIX, msg::v1::MsgExecLegacyContent,
+    proposal::Proposal,
+};
 
 pub mod active_iter;
 pub mod inactive_iter;
@@ -26,12 +31,17 @@ mod inner {
 }
 
 // Slight modification of the RFC3339Nano but it right pads all zeros and drops the time zone info
-const SORTABLE_DATE_TIME_FORMAT: &str = "%Y-%m-%dT&H:%M:%S.000000000";
+const SORTABLE_DATE_TIME_FORMAT: &str = "%Y-%m-%dT%H:%M:%S.000000000";
 
+/// Proposal stored by gov module.
+///
+/// Legacy proposals submitted with `v1beta1` message have `content` while `v1` proposals
+/// carry `messages` which are executed after proposal passes. Fields introduced by `v1`
+/// are omitted from serialization of legacy proposals.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ProposalModel<T> {
     pub proposal_id: u64,
-    pub content: T,
+    pub content: Option<T>,
     pub status: ProposalStatus,
     pub final_tally_result: Option<TallyResult>,
     pub submit_time: Timestamp,
@@ -39,6 +49,21 @@ pub struct ProposalModel<T> {
     pub total_deposit: UnsignedCoins,
     pub voting_start_time: Option<Timestamp>,
     pub voting_end_time: Option<Timestamp>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub messages: Vec<Any>,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub metadata: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub title: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub summary: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub proposer: Option<AccAddress>,
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub expedited: bool,
+    /// Reason of failed execution of passed proposal
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    pub failed_reason: String,
 }
 
 impl<T: Proposal> TryFrom<inner::Proposal> for ProposalModel<T> {
@@ -67,11 +92,10 @@ impl<T: Proposal> TryFrom<inner::Proposal> for ProposalModel<T> {
 
         Ok(Self {
             proposal_id,
-            content: content
-                .ok_or(CoreError::MissingField(
-                    "Proposal: field `content`".to_owned(),
-                ))?
-                .try_into()?,
+            content: match content {
+                Some(content) => Some(content.try_into()?),
+                None => None,
+            },
             status: status.try_into()?,
             final_tally_result: match final_tally_result {
                 Some(var) => Some(var.try_into()?),
@@ -109,6 +133,13 @@ impl<T: Proposal> TryFrom<inner::Proposal> for ProposalModel<T> {
                 })?),
                 None => None,
             },
+            messages: Vec::new(),
+            metadata: String::new(),
+            title: String::new(),
+            summary: String::new(),
+            proposer: None,
+            expedited: false,
+            failed_reason: String::new(),
         })
     }
 }
@@ -125,11 +156,18 @@ impl<T: Proposal> From<ProposalModel<T>> for inner::Proposal {
             total_deposit,
             voting_start_time,
             voting_end_time,
+            messages,
+            metadata: _,
+            title: _,
+            summary: _,
+            proposer: _,
+            expedited: _,
+            failed_reason: _,
         }: ProposalModel<T>,
     ) -> Self {
         Self {
             proposal_id,
-            content: Some(content.into()),
+            content: content.map(Into::into).or_else(|| legacy_content(messages)),
             status: status as i32,
             final_tally_result: final_tally_result.map(|e| e.into()),
             submit_time: Some(ibc_proto::google::protobuf::Timestamp {
@@ -157,6 +195,16 @@ impl<T: Proposal> From<ProposalModel<T>> for inner::Proposal {
 
 impl<T: Proposal> Protobuf<inner::Proposal> for ProposalModel<T> {}
 
+/// Content of `v1` proposal which only executes legacy content
+fn legacy_content(messages: Vec<Any>) -> Option<Any> {
+    match <[Any; 1]>::try_from(messages) {
+        Ok([msg]) => MsgExecLegacyContent::try_from(msg)
+            .ok()
+            .map(|this| this.content),
+        Err(_) => None,
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
 pub struct TallyResult {
     pub yes: Uint256,
@@ -297,14 +345,18 @@ fn parse_proposal_key_bytes(bytes: impl AsRef<[u8]>) -> (u64, DateTime<Utc>) {
             .len()
     });
 
-    let time = DateTime::parse_from_rfc3339(
+    let time = NaiveDateTime::parse_from_str(
         core::str::from_utf8(&bytes.as_ref()[1..1 + length_time])
             .expect("We serialize date as String so conversion is save"),
+        SORTABLE_DATE_TIME_FORMAT,
     )
-    .unwrap() // TODO
-    .to_utc();
-    let proposal = u64::from_be_bytes(bytes.as_ref()[1 + length_time..].try_into().unwrap());
-    // TODO
+    .expect("queue keys are formatted with the same format")
+    .and_utc();
+    let proposal = u64::from_be_bytes(
+        bytes.as_ref()[1 + length_time..]
+            .try_into()
+            .expect("queue key ends with proposal id"),
+    );
 
     (proposal, time)
 }
@@ -323,13 +375,17 @@ impl<'a, DB: Database, P: Proposal> ProposalsIterator<'a, DB, P> {
 }
 
 impl<'a, DB: Database, P: Proposal + DeserializeOwned> Iterator for ProposalsIterator<'a, DB, P> {
-    type Item = Result<((u64, DateTime<Utc>), ProposalModel<P>), GasStoreErrors>;
+    type Item = Result<(u64, ProposalModel<P>), GasStoreErrors>;
 
     fn next(&mut self) -> Option<Self::Item> {
         if let Some(var) = self.0.next() {
             match var {
                 Ok((key, value)) => Some(Ok((
-                    parse_proposal_key_bytes(key.as_ref()),
+                    u64::from_be_bytes(
+                        key.as_ref()
+                            .try_into()
+                            .expect("proposal key is proposal id"),
+                    ),
                     serde_json::from_slice(&value).expect(SERDE_JSON_CONVERSION),
                 ))),
                 Err(err) => Some(Err(err)),
@@ -6,7 +6,7 @@ use gears::{
         keepers::params::ParamsKeeper,
     },
     baseapp::{errors::QueryError, QueryResponse},
-    context::{query::QueryContext, InfallibleContext, TransactionalContext},
+    context::{query::QueryContext, InfallibleContext, QueryableContext, TransactionalContext},
     core::Protobuf,
     params::ParamsSubspaceKey,
     store::{database::Database, StoreKey},
@@ -14,11 +14,7 @@ use gears::{
         proto::event::{Event, EventAttribute},
         request::query::RequestQuery,
     },
-    types::{
-        base::coins::UnsignedCoins,
-        decimal256::{CosmosDecimalProtoString, Decimal256},
-        tx::NullTxMsg,
-    },
+    types::{base::coins::UnsignedCoins, decimal256::CosmosDecimalProtoString, tx::NullTxMsg},
     x::{
         keepers::mint::{MintingBankKeeper, MintingStakingKeeper},
         module::Module,
@@ -27,6 +23,10 @@ use gears::{
 
 use crate::{
     genesis::MintGenesis,
+    inflation::{
+        apply_capped_provision, cap_to_max_supply, BondedRatioInflation, InflationCalculator,
+        InflationInfo,
+    },
     keeper::MintKeeper,
     params::MintParamsKeeper,
     types::query::{
@@ -45,14 +45,14 @@ const MISSING_MINTER_ERR_MSG: &str =
     "Failed to get minter. Minter should be set during init genesis";
 
 #[derive(Debug, Clone)]
-pub struct MintAbciHandler<SK, PSK, BK, STK, M, MI> {
-    keeper: MintKeeper<SK, BK, STK, M>,
+pub struct MintAbciHandler<SK, PSK, BK, STK, M, MI, IC = BondedRatioInflation> {
+    keeper: MintKeeper<SK, BK, STK, M, IC>,
     params_keeper: MintParamsKeeper<PSK>,
     _marker: PhantomData<MI>,
 }
 
-impl<SK, PSK, BK, STK, M, MI> MintAbciHandler<SK, PSK, BK, STK, M, MI> {
-    pub fn new(keeper: MintKeeper<SK, BK, STK, M>, params_subspace_key: PSK) -> Self {
+impl<SK, PSK, BK, STK, M, MI, IC> MintAbciHandler<SK, PSK, BK, STK, M, MI, IC> {
+    pub fn new(keeper: MintKeeper<SK, BK, STK, M, IC>, params_subspace_key: PSK) -> Self {
         Self {
             keeper,
             params_keeper: MintParamsKeeper {
@@ -70,7 +70,8 @@ impl<
         STK: MintingStakingKeeper<SK, M>,
         M: Module,
         MI: ModuleInfo,
-    > MintAbciHandler<SK, PSK, BK, STK, M, MI>
+        IC: InflationCalculator,
+    > MintAbciHandler<SK, PSK, BK, STK, M, MI, IC>
 {
     pub fn query_params<CTX: InfallibleContext<DB, SK>, DB: Database>(
         &self,
@@ -113,7 +114,8 @@ impl<
         STK: MintingStakingKeeper<SK, M>,
         M: Module,
         MI: ModuleInfo,
-    > ABCIHandler for MintAbciHandler<SK, PSK, BK, STK, M, MI>
+        IC: InflationCalculator,
+    > ABCIHandler for MintAbciHandler<SK, PSK, BK, STK, M, MI, IC>
 {
     type Message = NullTxMsg;
 
@@ -200,8 +202,15 @@ impl<
 
         let bonded_ration = self.keeper.bonded_ratio(ctx);
 
+        let inflation_info = InflationInfo {
+            height: ctx.height(),
+            bonded_ratio: bonded_ration,
+            total_supply: total_staking_supply,
+        };
+        let calculator = self.keeper.inflation_calculator();
+
         //
-        minter.inflation = match minter.next_inflation_rate(&params, bonded_ration) {
+        minter.inflation = match calculator.next_inflation_rate(&minter, &params, &inflation_info) {
             Some(inflation) => inflation,
             None => panic!(
                 "Failed to `begin_block` in {} Reason: overflow while calculate inflation",
@@ -210,7 +219,7 @@ impl<
         };
 
         minter.annual_provisions =
-            match minter.next_annual_provision(Decimal256::new(total_staking_supply)) {
+            match calculator.next_annual_provisions(&minter, &params, &inflation_info) {
                 Some(provisions) => provisions,
                 None => panic!(
                     "Failed to `begin_block` in {} Reason: overflow while calculate next annual provision",
@@ -218,13 +227,37 @@ impl<
                 ),
             };
 
-        self.keeper.minter_set(ctx, &minter);
-
         //
-        let minted_coin = minter.block_provision(&params).expect("overflow");
+        let minted_coin = calculator
+            .block_provision(&minter, &params, &inflation_info)
+            .expect("overflow");
+        let block_provision = minted_coin.amount;
+        let mint_denom_supply = self
+            .keeper
+            .supply(ctx, &params.mint_denom)
+            .map(|this| this.amount)
+            .unwrap_or_default();
+        let minted_coin = cap_to_max_supply(minted_coin, &params, mint_denom_supply);
         let minted_attribute =
             EventAttribute::new("amount".into(), minted_coin.amount.to_string().into(), true);
 
+        if apply_capped_provision(
+            &mut minter,
+            block_provision,
+            minted_coin.amount,
+            &params,
+            &inflation_info,
+        )
+        .is_none()
+        {
+            panic!(
+                "Failed to `begin_block` in {} Reason: overflow while calculate capped provision",
+                MI::NAME
+            )
+        }
+
+        self.keeper.minter_set(ctx, &minter);
+
         let minted_coins = match UnsignedCoins::new([minted_coin]) {
             Ok(minted_coins) => minted_coins,
             Err(_) => {
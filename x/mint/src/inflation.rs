@@ -0,0 +1,400 @@
+//! Inflation schedules which define amount of coins minted each block
+
+use gears::types::{base::coin::UnsignedCoin, decimal256::Decimal256, uint::Uint256};
+
+use crate::{params::MintParams, types::minter::Minter};
+
+/// State of the chain which is used to calculate inflation of the block
+#[derive(Debug, Clone, PartialEq)]
+pub struct InflationInfo {
+    /// height of the block
+    pub height: u32,
+    /// ratio of bonded tokens to total supply of staking denom
+    pub bonded_ratio: Decimal256,
+    /// total supply of staking denom
+    pub total_supply: Uint256,
+}
+
+/// Calculates inflation rate, annual provisions and provision of each block.
+///
+/// `begin_block` of mint module calls [InflationCalculator::next_inflation_rate],
+/// then [InflationCalculator::next_annual_provisions] with minter which has updated inflation
+/// and at last [InflationCalculator::block_provision] with updated minter.
+pub trait InflationCalculator: Clone + Send + Sync + 'static {
+    /// Inflation rate for the block
+    fn next_inflation_rate(
+        &self,
+        minter: &Minter,
+        params: &MintParams,
+        info: &InflationInfo,
+    ) -> Option<Decimal256>;
+
+    /// Annual provisions for the block
+    fn next_annual_provisions(
+        &self,
+        minter: &Minter,
+        params: &MintParams,
+        info: &InflationInfo,
+    ) -> Option<Decimal256>;
+
+    /// Amount of coins to mint in the block
+    fn block_provision(
+        &self,
+        minter: &Minter,
+        params: &MintParams,
+        _info: &InflationInfo,
+    ) -> Option<UnsignedCoin> {
+        minter.block_provision(params)
+    }
+}
+
+/// Default inflation of cosmos sdk. Inflation rate changes depending on ratio of bonded tokens
+/// with goal to keep it close to `goal_bonded` param.
+#[derive(Debug, Clone, Default)]
+pub struct BondedRatioInflation;
+
+impl InflationCalculator for BondedRatioInflation {
+    fn next_inflation_rate(
+        &self,
+        minter: &Minter,
+        params: &MintParams,
+        info: &InflationInfo,
+    ) -> Option<Decimal256> {
+        minter.next_inflation_rate(params, info.bonded_ratio)
+    }
+
+    fn next_annual_provisions(
+        &self,
+        minter: &Minter,
+        _params: &MintParams,
+        info: &InflationInfo,
+    ) -> Option<Decimal256> {
+        minter.next_annual_provision(Decimal256::new(info.total_supply))
+    }
+}
+
+/// Provision of each block halves every year. Year length is defined by `blocks_per_year` param.
+#[derive(Debug, Clone)]
+pub struct HalvingInflation {
+    /// amount minted each block during the first year
+    pub initial_block_provision: Uint256,
+}
+
+impl HalvingInflation {
+    pub fn new(initial_block_provision: Uint256) -> Self {
+        Self {
+            initial_block_provision,
+        }
+    }
+
+    fn provision_at(&self, params: &MintParams, height: u32) -> Uint256 {
+        let halvings = match params.blocks_per_year {
+            0 => 0,
+            blocks_per_year => height.saturating_sub(1) / blocks_per_year,
+        };
+
+        self.initial_block_provision
+            .checked_shr(halvings)
+            .unwrap_or(Uint256::zero())
+    }
+}
+
+impl InflationCalculator for HalvingInflation {
+    fn next_inflation_rate(
+        &self,
+        _minter: &Minter,
+        params: &MintParams,
+        info: &InflationInfo,
+    ) -> Option<Decimal256> {
+        inflation_rate(
+            self.provision_at(params, info.height),
+            params,
+            info.total_supply,
+        )
+    }
+
+    fn next_annual_provisions(
+        &self,
+        _minter: &Minter,
+        params: &MintParams,
+        info: &InflationInfo,
+    ) -> Option<Decimal256> {
+        annual_provisions(self.provision_at(params, info.height), params)
+    }
+
+    fn block_provision(
+        &self,
+        _minter: &Minter,
+        params: &MintParams,
+        info: &InflationInfo,
+    ) -> Option<UnsignedCoin> {
+        Some(UnsignedCoin {
+            denom: params.mint_denom.clone(),
+            amount: self.provision_at(params, info.height),
+        })
+    }
+}
+
+/// Same amount of coins minted each block
+#[derive(Debug, Clone)]
+pub struct FixedEmission {
+    /// amount minted each block
+    pub block_provision: Uint256,
+}
+
+impl FixedEmission {
+    pub fn new(block_provision: Uint256) -> Self {
+        Self { block_provision }
+    }
+}
+
+impl InflationCalculator for FixedEmission {
+    fn next_inflation_rate(
+        &self,
+        _minter: &Minter,
+        params: &MintParams,
+        info: &InflationInfo,
+    ) -> Option<Decimal256> {
+        inflation_rate(self.block_provision, params, info.total_supply)
+    }
+
+    fn next_annual_provisions(
+        &self,
+        _minter: &Minter,
+        params: &MintParams,
+        _info: &InflationInfo,
+    ) -> Option<Decimal256> {
+        annual_provisions(self.block_provision, params)
+    }
+
+    fn block_provision(
+        &self,
+        _minter: &Minter,
+        params: &MintParams,
+        _info: &InflationInfo,
+    ) -> Option<UnsignedCoin> {
+        Some(UnsignedCoin {
+            denom: params.mint_denom.clone(),
+            amount: self.block_provision,
+        })
+    }
+}
+
+/// Reduce `provision` so total supply won't exceed `max_supply` param. Zero `max_supply`
+/// means that supply is unlimited.
+pub fn cap_to_max_supply(
+    mut provision: UnsignedCoin,
+    params: &MintParams,
+    current_supply: Uint256,
+) -> UnsignedCoin {
+    if !params.max_supply.is_zero() {
+        provision.amount = std::cmp::min(
+            provision.amount,
+            params.max_supply.saturating_sub(current_supply),
+        );
+    }
+
+    provision
+}
+
+/// Update inflation and annual provisions of `minter` to match `provision` which was reduced
+/// by [cap_to_max_supply]. Does nothing if `provision` isn't less than `block_provision`.
+pub fn apply_capped_provision(
+    minter: &mut Minter,
+    block_provision: Uint256,
+    provision: Uint256,
+    params: &MintParams,
+    info: &InflationInfo,
+) -> Option<()> {
+    if provision < block_provision {
+        minter.annual_provisions = annual_provisions(provision, params)?;
+        minter.inflation = inflation_rate(provision, params, info.total_supply)?;
+    }
+
+    Some(())
+}
+
+fn annual_provisions(block_provision: Uint256, params: &MintParams) -> Option<Decimal256> {
+    let annual = block_provision
+        .checked_mul(Uint256::from(params.blocks_per_year))
+        .ok()?;
+
+    Decimal256::from_atomics(annual, 0).ok()
+}
+
+fn inflation_rate(
+    block_provision: Uint256,
+    params: &MintParams,
+    total_supply: Uint256,
+) -> Option<Decimal256> {
+    if total_supply.is_zero() {
+        return Some(Decimal256::zero());
+    }
+
+    annual_provisions(block_provision, params)?
+        .checked_div(Decimal256::from_atomics(total_supply, 0).ok()?)
+        .ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn params(blocks_per_year: u32, max_supply: u64) -> MintParams {
+        MintParams {
+            blocks_per_year,
+            max_supply: Uint256::from(max_supply),
+            ..Default::default()
+        }
+    }
+
+    fn info(height: u32, total_supply: u64) -> InflationInfo {
+        InflationInfo {
+            height,
+            bonded_ratio: Decimal256::zero(),
+            total_supply: Uint256::from(total_supply),
+        }
+    }
+
+    #[test]
+    fn halving_provision_halves_every_year() {
+        let schedule = HalvingInflation::new(Uint256::from(1000_u64));
+        let params = params(10, 0);
+        let minter = Minter::default();
+
+        let provision_at = |height| {
+            schedule
+                .block_provision(&minter, &params, &info(height, 0))
+                .expect("no overflow")
+                .amount
+        };
+
+        assert_eq!(provision_at(1), Uint256::from(1000_u64));
+        assert_eq!(provision_at(10), Uint256::from(1000_u64));
+        assert_eq!(provision_at(11), Uint256::from(500_u64));
+        assert_eq!(provision_at(21), Uint256::from(250_u64));
+        assert_eq!(provision_at(10 * 300), Uint256::zero());
+    }
+
+    #[test]
+    fn halving_inflation_rate() {
+        let schedule = HalvingInflation::new(Uint256::from(1000_u64));
+        let params = params(10, 0);
+        let minter = Minter::default();
+
+        let first_year = schedule
+            .next_inflation_rate(&minter, &params, &info(1, 100_000))
+            .expect("no overflow");
+        let second_year = schedule
+            .next_inflation_rate(&minter, &params, &info(11, 100_000))
+            .expect("no overflow");
+
+        assert_eq!(
+            first_year,
+            Decimal256::from_atomics(1_u8, 1).expect("valid")
+        );
+        assert_eq!(
+            second_year,
+            Decimal256::from_atomics(5_u8, 2).expect("valid")
+        );
+        assert_eq!(
+            schedule.next_inflation_rate(&minter, &params, &info(1, 0)),
+            Some(Decimal256::zero())
+        );
+    }
+
+    #[test]
+    fn fixed_emission_is_constant() {
+        let schedule = FixedEmission::new(Uint256::from(42_u64));
+        let params = params(100, 0);
+        let minter = Minter::default();
+
+        for height in [1, 100, 101, 100_000] {
+            let provision = schedule
+                .block_provision(&minter, &params, &info(height, 1_000_000))
+                .expect("no overflow");
+
+            assert_eq!(provision.amount, Uint256::from(42_u64));
+            assert_eq!(provision.denom, params.mint_denom);
+        }
+
+        assert_eq!(
+            schedule.next_annual_provisions(&minter, &params, &info(1, 0)),
+            Some(Decimal256::from_atomics(4200_u64, 0).expect("valid"))
+        );
+    }
+
+    #[test]
+    fn provision_capped_to_max_supply() {
+        let coin = |amount: u64| UnsignedCoin {
+            denom: MintParams::default().mint_denom,
+            amount: Uint256::from(amount),
+        };
+
+        assert_eq!(
+            cap_to_max_supply(coin(100), &params(10, 1000), Uint256::from(950_u64)),
+            coin(50)
+        );
+        assert_eq!(
+            cap_to_max_supply(coin(100), &params(10, 1000), Uint256::from(1000_u64)),
+            coin(0)
+        );
+        assert_eq!(
+            cap_to_max_supply(coin(100), &params(10, 1000), Uint256::from(2000_u64)),
+            coin(0)
+        );
+        assert_eq!(
+            cap_to_max_supply(coin(100), &params(10, 0), Uint256::from(u64::MAX)),
+            coin(100)
+        );
+    }
+
+    #[test]
+    fn capped_provision_updates_minter() {
+        let params = params(10, 1000);
+        let info = info(1, 1000);
+        let initial = Minter {
+            inflation: Decimal256::from_atomics(1_u8, 1).expect("valid"),
+            annual_provisions: Decimal256::from_atomics(100_u8, 0).expect("valid"),
+        };
+
+        let mut minter = initial.clone();
+        apply_capped_provision(
+            &mut minter,
+            Uint256::from(10_u64),
+            Uint256::from(10_u64),
+            &params,
+            &info,
+        )
+        .expect("no overflow");
+        assert_eq!(minter, initial);
+
+        apply_capped_provision(
+            &mut minter,
+            Uint256::from(10_u64),
+            Uint256::from(5_u64),
+            &params,
+            &info,
+        )
+        .expect("no overflow");
+        assert_eq!(
+            minter.annual_provisions,
+            Decimal256::from_atomics(50_u8, 0).expect("valid")
+        );
+        assert_eq!(
+            minter.inflation,
+            Decimal256::from_atomics(5_u8, 2).expect("valid")
+        );
+
+        apply_capped_provision(
+            &mut minter,
+            Uint256::from(10_u64),
+            Uint256::zero(),
+            &params,
+            &info,
+        )
+        .expect("no overflow");
+        assert_eq!(minter.annual_provisions, Decimal256::zero());
+        assert_eq!(minter.inflation, Decimal256::zero());
+    }
+}
@@ -6,6 +6,7 @@ use gears::{
     types::{
         base::{coin::UnsignedCoin, coins::UnsignedCoins},
         decimal256::Decimal256,
+        denom::Denom,
         uint::Uint256,
     },
     x::{
@@ -15,17 +16,21 @@ use gears::{
     },
 };
 
-use crate::types::minter::Minter;
+use crate::{
+    inflation::{BondedRatioInflation, InflationCalculator},
+    types::minter::Minter,
+};
 
 const MINTER_KEY: [u8; 1] = [0x00];
 
 #[derive(Debug, Clone)]
-pub struct MintKeeper<SK, BK, STK, M> {
+pub struct MintKeeper<SK, BK, STK, M, IC = BondedRatioInflation> {
     store_key: SK,
     staking_keeper: STK,
     bank_keeper: BK,
     module: M,
     fee_collector: M,
+    inflation_calculator: IC,
 }
 
 impl<SK, BK, STK, M> MintKeeper<SK, BK, STK, M> {
@@ -35,6 +40,26 @@ impl<SK, BK, STK, M> MintKeeper<SK, BK, STK, M> {
         bank_keeper: BK,
         module: M,
         fee_collector: M,
+    ) -> Self {
+        Self::new_with_inflation(
+            store_key,
+            staking_keeper,
+            bank_keeper,
+            module,
+            fee_collector,
+            BondedRatioInflation,
+        )
+    }
+}
+
+impl<SK, BK, STK, M, IC> MintKeeper<SK, BK, STK, M, IC> {
+    pub fn new_with_inflation(
+        store_key: SK,
+        staking_keeper: STK,
+        bank_keeper: BK,
+        module: M,
+        fee_collector: M,
+        inflation_calculator: IC,
     ) -> Self {
         Self {
             store_key,
@@ -42,13 +67,23 @@ impl<SK, BK, STK, M> MintKeeper<SK, BK, STK, M> {
             bank_keeper,
             module,
             fee_collector,
+            inflation_calculator,
         }
     }
 }
 
-impl<SK: StoreKey, BK: MintingBankKeeper<SK, M>, STK: MintingStakingKeeper<SK, M>, M: Module>
-    MintKeeper<SK, BK, STK, M>
+impl<
+        SK: StoreKey,
+        BK: MintingBankKeeper<SK, M>,
+        STK: MintingStakingKeeper<SK, M>,
+        M: Module,
+        IC: InflationCalculator,
+    > MintKeeper<SK, BK, STK, M, IC>
 {
+    pub fn inflation_calculator(&self) -> &IC {
+        &self.inflation_calculator
+    }
+
     pub fn minter<CTX: InfallibleContext<DB, SK>, DB: Database>(
         &self,
         ctx: &CTX,
@@ -76,6 +111,14 @@ impl<SK: StoreKey, BK: MintingBankKeeper<SK, M>, STK: MintingStakingKeeper<SK, M
             .unwrap_gas()
     }
 
+    pub fn supply<CTX: InfallibleContext<DB, SK>, DB: Database>(
+        &self,
+        ctx: &CTX,
+        denom: &Denom,
+    ) -> Option<UnsignedCoin> {
+        self.bank_keeper.supply(ctx, denom).unwrap_gas()
+    }
+
     pub fn bonded_ratio<CTX: InfallibleContext<DB, SK>, DB: Database>(
         &self,
         ctx: &CTX,
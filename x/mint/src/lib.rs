@@ -1,6 +1,7 @@
 pub mod abci_handler;
 pub mod client;
 pub mod genesis;
+pub mod inflation;
 pub mod keeper;
 pub mod params;
 pub mod types;
@@ -11,6 +11,7 @@ use gears::{
     types::{
         decimal256::{CosmosDecimalProtoString, Decimal256},
         denom::Denom,
+        uint::Uint256,
     },
 };
 use serde::{Deserialize, Serialize};
@@ -21,8 +22,9 @@ const INFLATION_MAX_KEY: &str = "InflationMax";
 const INFLATION_MIN_KEY: &str = "InflationMin";
 const GOAL_BONDED_KEY: &str = "GoalBonded";
 const BLOCKS_PER_YEAR_KEY: &str = "BlocksPerYear";
+const MAX_SUPPLY_KEY: &str = "MaxSupply";
 
-#[derive(Debug, Clone, Serialize, Deserialize, Raw, Protobuf)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Raw, Protobuf)]
 pub struct MintParams {
     /// denom of coin to mint
     #[raw(kind(string), raw = String)]
@@ -66,6 +68,16 @@ pub struct MintParams {
     /// expected blocks per year
     #[raw(kind(uint32))]
     pub blocks_per_year: u32,
+    /// maximum supply of minted denom. Zero means that supply is unlimited
+    #[raw(kind(string), raw = String)]
+    #[proto(
+        from = "Uint256::from_str",
+        from_ref,
+        into = "Uint256::to_string",
+        into_ref
+    )]
+    #[serde(default)]
+    pub max_supply: Uint256,
 }
 
 impl Default for MintParams {
@@ -78,6 +90,7 @@ impl Default for MintParams {
             inflation_min: Decimal256::from_atomics(7_u8, 2).expect("default is valid"),
             goal_bonded: Decimal256::from_atomics(67_u8, 2).expect("default is valid"),
             blocks_per_year: 60 * 60 * 8766 / 5, // assuming 5 second block times
+            max_supply: Uint256::zero(),
         }
     }
 }
@@ -91,11 +104,12 @@ impl ParamsSerialize for MintParams {
             INFLATION_MIN_KEY,
             GOAL_BONDED_KEY,
             BLOCKS_PER_YEAR_KEY,
+            MAX_SUPPLY_KEY,
         ])
     }
 
     fn to_raw(&self) -> Vec<(&'static str, Vec<u8>)> {
-        vec![
+        let mut raw = vec![
             (MINT_DENOM_KEY, self.mint_denom.to_string().into_bytes()),
             (
                 INFLATION_RATE_CHANGE_KEY,
@@ -119,11 +133,23 @@ impl ParamsSerialize for MintParams {
                 BLOCKS_PER_YEAR_KEY,
                 self.blocks_per_year.to_string().into_bytes(),
             ),
-        ]
+        ];
+
+        // unlimited supply isn't stored so chains without max supply keep the same state
+        if !self.max_supply.is_zero() {
+            raw.push((MAX_SUPPLY_KEY, self.max_supply.to_string().into_bytes()));
+        }
+
+        raw
     }
 }
 
 impl ParamsDeserialize for MintParams {
+    fn optional_keys() -> HashSet<&'static str> {
+        // chains started before max supply was introduced don't have the key
+        HashSet::from_iter([MAX_SUPPLY_KEY])
+    }
+
     fn from_raw(fields: HashMap<&'static str, Vec<u8>>) -> Self {
         Self {
             mint_denom: Denom::from_str(&String::from_utf8_lossy(
@@ -150,6 +176,10 @@ impl ParamsDeserialize for MintParams {
                 fields.get(BLOCKS_PER_YEAR_KEY).unwrap_or_corrupt(),
             ))
             .unwrap_or_corrupt(),
+            max_supply: fields
+                .get(MAX_SUPPLY_KEY)
+                .map(|value| Uint256::from_str(&String::from_utf8_lossy(value)).unwrap_or_corrupt())
+                .unwrap_or_default(),
         }
     }
 }
@@ -186,7 +216,45 @@ impl<PSK: ParamsSubspaceKey> ParamsKeeper<PSK> for MintParamsKeeper<PSK> {
                     .is_ok()
             }
             BLOCKS_PER_YEAR_KEY => u32::from_str(&String::from_utf8_lossy(value.as_ref())).is_ok(),
+            MAX_SUPPLY_KEY => Uint256::from_str(&String::from_utf8_lossy(value.as_ref())).is_ok(),
             _ => false,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unlimited_max_supply_is_not_serialized() {
+        let params = MintParams::default();
+
+        let fields = params.to_raw().into_iter().collect::<HashMap<_, _>>();
+
+        assert!(!fields.contains_key(MAX_SUPPLY_KEY));
+        assert_eq!(MintParams::from_raw(fields), params);
+    }
+
+    #[test]
+    fn from_raw_defaults_missing_max_supply() {
+        let params = MintParams {
+            max_supply: Uint256::from(1000_u64),
+            blocks_per_year: 42,
+            ..Default::default()
+        };
+
+        let fields = params
+            .to_raw()
+            .into_iter()
+            .filter(|(key, _)| *key != MAX_SUPPLY_KEY)
+            .collect::<HashMap<_, _>>();
+        assert_eq!(
+            MintParams::from_raw(fields),
+            MintParams {
+                max_supply: Uint256::zero(),
+                ..params
+            }
+        );
+    }
+}
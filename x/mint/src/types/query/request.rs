@@ -4,15 +4,15 @@ use gears::{
 };
 
 #[derive(Debug, Clone, Raw, Query, Protobuf, serde::Serialize, serde::Deserialize)]
-#[query(url = "/cosmos.mint.v1beta1.QueryParamsRequest")]
+#[query(url = "/cosmos.mint.v1beta1.Query/Params")]
 pub struct QueryParamsRequest {}
 
 #[derive(Debug, Clone, Raw, Query, Protobuf, serde::Serialize, serde::Deserialize)]
-#[query(url = "/cosmos.mint.v1beta1.QueryInflationRequest")]
+#[query(url = "/cosmos.mint.v1beta1.Query/Inflation")]
 pub struct QueryInflationRequest {}
 
 #[derive(Debug, Clone, Raw, Query, Protobuf, serde::Serialize, serde::Deserialize)]
-#[query(url = "/cosmos.mint.v1beta1.QueryAnnualProvisionsRequest")]
+#[query(url = "/cosmos.mint.v1beta1.Query/AnnualProvisions")]
 pub struct QueryAnnualProvisionsRequest {}
 
 #[derive(Debug, Clone, Query)]
@@ -5,13 +5,13 @@ use gears::{
 
 use crate::params::{MintParams, RawMintParams};
 
-#[derive(Debug, Clone, Raw, Query, Protobuf, serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, PartialEq, Raw, Query, Protobuf, serde::Serialize, serde::Deserialize)]
 pub struct QueryParamsResponse {
     #[raw(kind(message), raw = RawMintParams)]
     pub params: MintParams,
 }
 
-#[derive(Debug, Clone, Raw, Query, Protobuf, serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, PartialEq, Raw, Query, Protobuf, serde::Serialize, serde::Deserialize)]
 pub struct QueryInflationResponse {
     #[raw(kind(string), raw = String)]
     #[proto(
@@ -23,7 +23,7 @@ pub struct QueryInflationResponse {
     pub inflation: Decimal256,
 }
 
-#[derive(Debug, Clone, Raw, Query, Protobuf, serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, PartialEq, Raw, Query, Protobuf, serde::Serialize, serde::Deserialize)]
 pub struct QueryAnnualProvisionsResponse {
     #[raw(kind(string), raw = String)]
     #[proto(
@@ -35,7 +35,7 @@ pub struct QueryAnnualProvisionsResponse {
     pub annual_provisions: Decimal256,
 }
 
-#[derive(Debug, Clone, Query, serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, PartialEq, Query, serde::Serialize, serde::Deserialize)]
 pub enum MintQueryResponse {
     Params(QueryParamsResponse),
     Inflation(QueryInflationResponse),
@@ -0,0 +1,80 @@
+use std::str::FromStr;
+
+use gears::{
+    core::Protobuf,
+    extensions::testing::UnwrapTesting,
+    tendermint::types::{
+        request::query::RequestQuery, response::ResponseQuery, time::timestamp::Timestamp,
+    },
+    types::{
+        base::coin::UnsignedCoin,
+        decimal256::{CosmosDecimalProtoString, Decimal256},
+        uint::Uint256,
+    },
+    utils::node::StepResponse,
+};
+use mint::types::query::{
+    request::QueryAnnualProvisionsRequest, response::QueryAnnualProvisionsResponse,
+};
+use utils::{set_node, MockBankKeeper, MockStakingKeeper};
+
+const TEST_BLOCKS_PER_YEAR: u32 = 60;
+
+#[path = "./utils.rs"]
+mod utils;
+
+#[test]
+fn mint_event_emitted_with_indexed_attributes() {
+    let mut node = set_node(
+        Some(MockBankKeeper::new(
+            UnsignedCoin::from_str("1000000000000uatom").unwrap_test(),
+            None,
+        )),
+        Some(MockStakingKeeper::new(Decimal256::new(Uint256::from(
+            1000000000_u64,
+        )))),
+        Some(TEST_BLOCKS_PER_YEAR),
+    );
+
+    let StepResponse { events, .. } = node.step(vec![], Timestamp::UNIX_EPOCH);
+
+    let q = QueryAnnualProvisionsRequest {};
+    let ResponseQuery { value, .. } = node.query(RequestQuery {
+        data: q.encode_vec().into(),
+        path: QueryAnnualProvisionsRequest::QUERY_URL.to_owned(),
+        height: node.height() as i64,
+        prove: false,
+    });
+    let QueryAnnualProvisionsResponse { annual_provisions } =
+        QueryAnnualProvisionsResponse::decode_vec(&value).unwrap_test();
+
+    let minted = events
+        .iter()
+        .filter(|event| event.r#type == "mint")
+        .collect::<Vec<_>>();
+    assert_eq!(minted.len(), 1);
+    let attribute = |key: &str| {
+        let attribute = minted[0]
+            .attributes
+            .iter()
+            .find(|attribute| attribute.key.as_ref() == key.as_bytes())
+            .unwrap_test();
+        assert!(attribute.index);
+        String::from_utf8(attribute.value.to_vec()).unwrap_test()
+    };
+
+    assert!(!attribute("bonded_ratio").is_empty());
+    assert_eq!(
+        Decimal256::from_atomics(2_u8, 1)
+            .unwrap_test()
+            .to_cosmos_proto_string(),
+        attribute("inflation")
+    );
+    assert_eq!(
+        annual_provisions.to_cosmos_proto_string(),
+        attribute("annual_provisions")
+    );
+    assert!(!Uint256::from_str(&attribute("amount"))
+        .unwrap_test()
+        .is_zero());
+}
@@ -1,7 +1,7 @@
 use crate::{
     errors::SlashingTxError, GenesisState, Keeper, Message, QueryParamsRequest,
-    QueryParamsResponse, QuerySigningInfoRequest, QuerySigningInfosRequest,
-    QuerySigningInfosResponse,
+    QueryParamsResponse, QuerySigningInfoRequest, QuerySigningInfoResponse,
+    QuerySigningInfosRequest, QuerySigningInfosResponse,
 };
 use gears::{
     baseapp::{errors::QueryError, QueryResponse},
@@ -28,14 +28,13 @@ pub struct ABCIHandler<
 
 #[derive(Clone, Debug)]
 pub enum SlashingNodeQueryRequest {
-    // TODO: check option to change signature of methods and implement typed queries
-    // SigningInfo(QuerySigningInfoRequest),
+    SigningInfo(QuerySigningInfoRequest),
     SigningInfos(QuerySigningInfosRequest),
     Params(QueryParamsRequest),
 }
 #[derive(Clone, Debug, Serialize)]
 pub enum SlashingNodeQueryResponse {
-    // SigningInfo(QuerySigningInfoResponse),
+    SigningInfo(QuerySigningInfoResponse),
     SigningInfos(QuerySigningInfosResponse),
     Params(QueryParamsResponse),
 }
@@ -69,12 +68,16 @@ impl<SK: StoreKey, PSK: ParamsSubspaceKey, SSK: SlashingStakingKeeper<SK, M>, M:
         match query.path.as_str() {
             "/cosmos.slashing.v1beta1.Query/SigningInfo" => {
                 let req = QuerySigningInfoRequest::decode(query.data)?;
+                let cons_address = req.cons_address.clone();
 
-                Ok(self
-                    .keeper
-                    .query_signing_info(ctx, req)?
-                    .into_bytes()
-                    .into())
+                let res = self.keeper.query_signing_info(ctx, req);
+                if res.val_signing_info.is_none() {
+                    return Err(QueryError::TODO(anyhow::anyhow!(
+                        "signing info of validator {cons_address} is not found"
+                    )));
+                }
+
+                Ok(res.into_bytes().into())
             }
             "/cosmos.slashing.v1beta1.Query/SigningInfos" => {
                 let req = QuerySigningInfosRequest::decode(query.data)?;
@@ -96,6 +99,9 @@ impl<SK: StoreKey, PSK: ParamsSubspaceKey, SSK: SlashingStakingKeeper<SK, M>, M:
         query: SlashingNodeQueryRequest,
     ) -> SlashingNodeQueryResponse {
         match query {
+            SlashingNodeQueryRequest::SigningInfo(req) => {
+                SlashingNodeQueryResponse::SigningInfo(self.keeper.query_signing_info(ctx, req))
+            }
             SlashingNodeQueryRequest::SigningInfos(req) => {
                 SlashingNodeQueryResponse::SigningInfos(self.query_signing_infos(ctx, req))
             }
@@ -1 +1,118 @@
+use crate::{
+    QueryParamsRequest, QuerySigningInfoRequest, QuerySigningInfosRequest, RawQueryParamsRequest,
+    RawQueryParamsResponse, RawQuerySigningInfoRequest, RawQuerySigningInfoResponse,
+    RawQuerySigningInfosRequest, RawQuerySigningInfosResponse, SlashingNodeQueryRequest,
+    SlashingNodeQueryResponse,
+};
+use gears::{
+    baseapp::{NodeQueryHandler, QueryRequest, QueryResponse},
+    grpc::service::{from_raw, unary, unimplemented},
+};
+use std::{convert::Infallible, marker::PhantomData};
+use tonic::{
+    body::BoxBody,
+    codegen::{http, Body, BoxFuture, Context, Poll, Service, StdError},
+    server::NamedService,
+    Status,
+};
 
+const ERROR_STATE_MSG: &str = "An internal error occurred while querying the application state.";
+
+#[derive(Debug)]
+pub struct SlashingService<QH, QReq, QRes> {
+    app: QH,
+    _phantom: PhantomData<(QReq, QRes)>,
+}
+
+impl<QH: Clone, QReq, QRes> Clone for SlashingService<QH, QReq, QRes> {
+    fn clone(&self) -> Self {
+        Self {
+            app: self.app.clone(),
+            _phantom: PhantomData,
+        }
+    }
+}
+
+impl<QH, QReq, QRes> NamedService for SlashingService<QH, QReq, QRes> {
+    const NAME: &'static str = "cosmos.slashing.v1beta1.Query";
+}
+
+fn query<QH, QReq, QRes>(
+    app: &QH,
+    req: SlashingNodeQueryRequest,
+) -> Result<SlashingNodeQueryResponse, Status>
+where
+    QReq: QueryRequest + From<SlashingNodeQueryRequest>,
+    QRes: QueryResponse + TryInto<SlashingNodeQueryResponse, Error = Status>,
+    QH: NodeQueryHandler<QReq, QRes>,
+{
+    app.typed_query(req)?.try_into()
+}
+
+impl<QH, QReq, QRes, B> Service<http::Request<B>> for SlashingService<QH, QReq, QRes>
+where
+    QReq: QueryRequest + Send + Sync + 'static + From<SlashingNodeQueryRequest>,
+    QRes:
+        QueryResponse + Send + Sync + 'static + TryInto<SlashingNodeQueryResponse, Error = Status>,
+    QH: NodeQueryHandler<QReq, QRes>,
+    B: Body + Send + 'static,
+    B::Error: Into<StdError> + Send + 'static,
+{
+    type Response = http::Response<BoxBody>;
+    type Error = Infallible;
+    type Future = BoxFuture<Self::Response, Self::Error>;
+
+    fn poll_ready(&mut self, _cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn call(&mut self, req: http::Request<B>) -> Self::Future {
+        let app = self.app.clone();
+
+        match req.uri().path() {
+            QuerySigningInfoRequest::QUERY_URL => {
+                unary(req, move |raw: RawQuerySigningInfoRequest| {
+                    let req = SlashingNodeQueryRequest::SigningInfo(from_raw(raw)?);
+                    match query::<_, QReq, QRes>(&app, req)? {
+                        SlashingNodeQueryResponse::SigningInfo(res) => {
+                            Ok(RawQuerySigningInfoResponse::from(res))
+                        }
+                        _ => Err(Status::internal(ERROR_STATE_MSG)),
+                    }
+                })
+            }
+            QuerySigningInfosRequest::QUERY_URL => {
+                unary(req, move |raw: RawQuerySigningInfosRequest| {
+                    let req = SlashingNodeQueryRequest::SigningInfos(from_raw(raw)?);
+                    match query::<_, QReq, QRes>(&app, req)? {
+                        SlashingNodeQueryResponse::SigningInfos(res) => {
+                            Ok(RawQuerySigningInfosResponse::from(res))
+                        }
+                        _ => Err(Status::internal(ERROR_STATE_MSG)),
+                    }
+                })
+            }
+            QueryParamsRequest::QUERY_URL => unary(req, move |raw: RawQueryParamsRequest| {
+                let req = SlashingNodeQueryRequest::Params(from_raw(raw)?);
+                match query::<_, QReq, QRes>(&app, req)? {
+                    SlashingNodeQueryResponse::Params(res) => Ok(RawQueryParamsResponse::from(res)),
+                    _ => Err(Status::internal(ERROR_STATE_MSG)),
+                }
+            }),
+            _ => unimplemented(),
+        }
+    }
+}
+
+pub fn new<QH, QReq, QRes>(app: QH) -> SlashingService<QH, QReq, QRes>
+where
+    QReq: QueryRequest + Send + Sync + 'static + From<SlashingNodeQueryRequest>,
+    QRes:
+        QueryResponse + Send + Sync + 'static + TryInto<SlashingNodeQueryResponse, Error = Status>,
+    QH: NodeQueryHandler<QReq, QRes>,
+{
+    SlashingService {
+        app,
+        _phantom: Default::default(),
+    }
+}
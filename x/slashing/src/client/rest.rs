@@ -1,17 +1,17 @@
 use axum::{
-    extract::{Query, State},
+    extract::{Path, Query, State},
     routing::get,
     Json, Router,
 };
 use gears::{
     baseapp::{NodeQueryHandler, QueryRequest, QueryResponse},
     rest::{error::HTTPError, Pagination, RestState},
-    types::pagination::request::PaginationRequest,
+    types::{address::ConsAddress, pagination::request::PaginationRequest},
 };
 
 use crate::{
-    QueryParamsRequest, QueryParamsResponse, QuerySigningInfosRequest, SlashingNodeQueryRequest,
-    SlashingNodeQueryResponse, SlashingParams,
+    QueryParamsRequest, QueryParamsResponse, QuerySigningInfoRequest, QuerySigningInfosRequest,
+    SlashingNodeQueryRequest, SlashingNodeQueryResponse, SlashingParams,
 };
 
 pub async fn signing_infos<
@@ -29,6 +29,19 @@ pub async fn signing_infos<
     Ok(Json(res))
 }
 
+pub async fn signing_info<
+    QReq: QueryRequest + From<SlashingNodeQueryRequest>,
+    QRes: QueryResponse + TryInto<SlashingNodeQueryResponse>,
+    App: NodeQueryHandler<QReq, QRes>,
+>(
+    Path(cons_address): Path<ConsAddress>,
+    State(rest_state): State<RestState<QReq, QRes, App>>,
+) -> Result<Json<QRes>, HTTPError> {
+    let req = SlashingNodeQueryRequest::SigningInfo(QuerySigningInfoRequest { cons_address });
+    let res = rest_state.app.typed_query(req)?;
+    Ok(Json(res))
+}
+
 pub async fn params<
     QReq: QueryRequest + From<SlashingNodeQueryRequest>,
     QRes: QueryResponse + TryInto<SlashingNodeQueryResponse>,
@@ -55,6 +68,7 @@ pub fn get_router<
 >() -> Router<RestState<QReq, QRes, App>> {
     Router::new()
         .route("/v1beta1/signing_infos", get(signing_infos))
+        .route("/v1beta1/signing_infos/:cons_address", get(signing_info))
         .route(
             "/v1beta1/params/current", /* "/v1beta1/params" */
             get(params),
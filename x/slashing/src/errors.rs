@@ -1,5 +1,5 @@
 use gears::{
-    application::handlers::node::TxError,
+    application::handlers::node::{ModuleInfo, TxError},
     error::NumericError,
     gas::store::errors::GasStoreErrors,
     types::{
@@ -16,6 +16,12 @@ pub enum SlashingTxError {
     Unjail(#[from] UnjailError),
 }
 
+impl SlashingTxError {
+    pub fn into<MI: ModuleInfo>(self) -> TxError {
+        TxError::new::<MI>(self.to_string(), nz::u16!(1))
+    }
+}
+
 impl From<SlashingTxError> for TxError {
     fn from(value: SlashingTxError) -> Self {
         match value {
@@ -25,7 +25,7 @@ use gears::{
             event::{Event, EventAttribute},
             validator::VotingPower,
         },
-        time::duration::Duration,
+        time::{duration::Duration, timestamp::Timestamp},
     },
     types::{
         address::{AccAddress, ConsAddress, ValAddress},
@@ -33,7 +33,10 @@ use gears::{
     },
     x::{
         errors::AccountNotFound,
-        keepers::staking::{SlashingStakingKeeper, VALIDATOR_UPDATE_DELAY},
+        keepers::{
+            slashing::EvidenceSlashingKeeper,
+            staking::{SlashingStakingKeeper, VALIDATOR_UPDATE_DELAY},
+        },
         module::Module,
         types::{delegation::StakingDelegation, validator::StakingValidator},
     },
@@ -122,6 +125,7 @@ impl<SK: StoreKey, PSK: ParamsSubspaceKey, SSK: SlashingStakingKeeper<SK, M>, M:
 
         // fetch the validator public key
         self.get_pub_key(ctx, &cons_addr)
+            .unwrap_gas()
             .ok_or(ValidatorHandlingError::ConsensusNotFound)?;
 
         // fetch signing info
@@ -329,14 +333,11 @@ impl<SK: StoreKey, PSK: ParamsSubspaceKey, SSK: SlashingStakingKeeper<SK, M>, M:
     pub fn query_signing_info<DB: Database>(
         &self,
         ctx: &QueryContext<DB, SK>,
-        query: QuerySigningInfoRequest,
-    ) -> Result<QuerySigningInfoResponse, anyhow::Error> {
-        self.validator_signing_info(ctx, &query.cons_address)?
-            .ok_or(anyhow::anyhow!(
-                "signing info of validator {} is not found",
-                query.cons_address
-            ))
-            .map(|val_signing_info| QuerySigningInfoResponse { val_signing_info })
+        QuerySigningInfoRequest { cons_address }: QuerySigningInfoRequest,
+    ) -> QuerySigningInfoResponse {
+        QuerySigningInfoResponse {
+            val_signing_info: self.validator_signing_info(ctx, &cons_address).unwrap_gas(),
+        }
     }
 
     pub fn query_params<DB: Database>(
@@ -413,16 +414,16 @@ impl<SK: StoreKey, PSK: ParamsSubspaceKey, SSK: SlashingStakingKeeper<SK, M>, M:
     //
 
     /// get_pub_key returns the pubkey from the adddress-pubkey relation
-    pub fn get_pub_key<DB: Database>(
+    pub fn get_pub_key<DB: Database, CTX: QueryableContext<DB, SK>>(
         &self,
-        ctx: &BlockContext<'_, DB, SK>,
+        ctx: &CTX,
         addr: &ConsAddress,
-    ) -> Option<PublicKey> {
+    ) -> Result<Option<PublicKey>, GasStoreErrors> {
         let store = ctx.kv_store(&self.store_key);
         let key = addr_pubkey_relation_key(addr.clone());
-        store
-            .get(&key)
-            .map(|bytes| serde_json::from_slice(&bytes).unwrap_or_corrupt())
+        Ok(store
+            .get(&key)?
+            .map(|bytes| serde_json::from_slice(&bytes).unwrap_or_corrupt()))
     }
 
     /// add_pub_key sets a address-pubkey relation
@@ -449,9 +450,9 @@ impl<SK: StoreKey, PSK: ParamsSubspaceKey, SSK: SlashingStakingKeeper<SK, M>, M:
     ) -> Result<Option<ValidatorSigningInfo>, GasStoreErrors> {
         let store = ctx.kv_store(&self.store_key);
         let key = validator_signing_info_key(addr.clone());
-        store.get(&key).map(|sign_info| {
-            sign_info.map(|bytes| serde_json::from_slice(&bytes).unwrap_or_corrupt())
-        })
+        Ok(store
+            .get(&key)?
+            .map(|bytes| ValidatorSigningInfo::decode_vec(&bytes).unwrap_or_corrupt()))
     }
 
     pub fn validator_signing_infos<DB: Database>(
@@ -535,3 +536,146 @@ impl<SK: StoreKey, PSK: ParamsSubspaceKey, SSK: SlashingStakingKeeper<SK, M>, M:
         });
     }
 }
+
+impl<SK: StoreKey, PSK: ParamsSubspaceKey, SSK: SlashingStakingKeeper<SK, M>, M: Module>
+    EvidenceSlashingKeeper<SK, M> for Keeper<SK, PSK, SSK, M>
+{
+    fn pubkey<DB: Database, CTX: QueryableContext<DB, SK>>(
+        &self,
+        ctx: &CTX,
+        addr: &ConsAddress,
+    ) -> Result<Option<PublicKey>, GasStoreErrors> {
+        self.get_pub_key(ctx, addr)
+    }
+
+    fn has_validator_signing_info<DB: Database, CTX: QueryableContext<DB, SK>>(
+        &self,
+        ctx: &CTX,
+        addr: &ConsAddress,
+    ) -> Result<bool, GasStoreErrors> {
+        Ok(self.validator_signing_info(ctx, addr)?.is_some())
+    }
+
+    fn is_tombstoned<DB: Database, CTX: QueryableContext<DB, SK>>(
+        &self,
+        ctx: &CTX,
+        addr: &ConsAddress,
+    ) -> Result<bool, GasStoreErrors> {
+        Ok(self
+            .validator_signing_info(ctx, addr)?
+            .map(|info| info.tombstoned)
+            .unwrap_or_default())
+    }
+
+    fn slash_fraction_double_sign<DB: Database, CTX: QueryableContext<DB, SK>>(
+        &self,
+        ctx: &CTX,
+    ) -> Result<Decimal256, GasStoreErrors> {
+        Ok(self
+            .slashing_params_keeper
+            .try_get(ctx)?
+            .slash_fraction_double_sign)
+    }
+
+    /// slash attempts to slash a validator. The slash is delegated to the staking
+    /// module to make the necessary validator changes.
+    fn slash<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        addr: &ConsAddress,
+        amount: Decimal256,
+        validator_power: i64,
+        height: i64,
+    ) -> Result<(), GasStoreErrors> {
+        ctx.push_event(Event {
+            r#type: "slash".to_string(),
+            attributes: vec![
+                EventAttribute {
+                    key: "address".into(),
+                    value: addr.to_string().into(),
+                    index: false,
+                },
+                EventAttribute {
+                    key: "power".into(),
+                    value: validator_power.to_string().into(),
+                    index: false,
+                },
+                EventAttribute {
+                    key: "reason".into(),
+                    value: "double_sign".to_string().into(),
+                    index: false,
+                },
+            ],
+        });
+
+        let power = VotingPower::new(validator_power.try_into().unwrap_or_default())
+            .expect("evidence power is bounded by max validator power");
+        // the height can be negative up to VALIDATOR_UPDATE_DELAY before the genesis block
+        let height = height.try_into().unwrap_or_default();
+
+        self.staking_keeper.slash(ctx, addr, height, power, amount)
+    }
+
+    /// jail attempts to jail a validator. The slash is delegated to the staking module
+    /// to make the necessary validator changes.
+    fn jail<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        addr: &ConsAddress,
+    ) -> Result<(), GasStoreErrors> {
+        ctx.push_event(Event {
+            r#type: "slash".to_string(),
+            attributes: vec![EventAttribute {
+                key: "jailed".into(),
+                value: addr.to_string().into(),
+                index: false,
+            }],
+        });
+
+        self.staking_keeper.jail(ctx, addr)
+    }
+
+    /// jail_until attempts to set a validator's jailed_until attribute in its signing
+    /// info. It will panic if the signing info does not exist for the validator.
+    fn jail_until<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        addr: &ConsAddress,
+        time: Timestamp,
+    ) -> Result<(), GasStoreErrors> {
+        let mut sign_info = self
+            .validator_signing_info(ctx, addr)?
+            .expect("cannot jail validator that does not have any signing information");
+
+        sign_info.jailed_until = time;
+        let mut store = ctx.kv_store_mut(&self.store_key);
+        store.set(
+            validator_signing_info_key(addr.clone()),
+            sign_info.encode_vec(),
+        )
+    }
+
+    /// tombstone attempts to tombstone a validator. It will panic if signing info for
+    /// the given validator does not exist.
+    fn tombstone<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        addr: &ConsAddress,
+    ) -> Result<(), GasStoreErrors> {
+        let mut sign_info = self
+            .validator_signing_info(ctx, addr)?
+            .expect("cannot tombstone validator that does not have any signing information");
+
+        assert!(
+            !sign_info.tombstoned,
+            "cannot tombstone validator that is already tombstoned"
+        );
+
+        sign_info.tombstoned = true;
+        let mut store = ctx.kv_store_mut(&self.store_key);
+        store.set(
+            validator_signing_info_key(addr.clone()),
+            sign_info.encode_vec(),
+        )
+    }
+}
@@ -51,7 +51,7 @@ pub struct QuerySigningInfoResponse {
     /// val_signing_info is the signing info of requested val cons address
     #[proto(optional)]
     #[raw(kind(message), raw = ValidatorSigningInfoRaw, optional)]
-    pub val_signing_info: ValidatorSigningInfo,
+    pub val_signing_info: Option<ValidatorSigningInfo>,
 }
 
 /// QuerySigningInfosResponse is the response type for the Query/SigningInfos RPC
@@ -9,16 +9,21 @@ use crate::{
     RedelegationResponse,
 };
 use crate::{
-    QueryDelegatorValidatorRequest, QueryDelegatorValidatorResponse,
-    QueryDelegatorValidatorsRequest, QueryDelegatorValidatorsResponse, QueryHistoricalInfoRequest,
-    QueryHistoricalInfoResponse, QueryValidatorDelegationsRequest,
-    QueryValidatorDelegationsResponse, QueryValidatorUnbondingDelegationsRequest,
-    QueryValidatorUnbondingDelegationsResponse,
+    invariants::MODULE_ACCOUNTS_INVARIANT, QueryDelegatorValidatorRequest,
+    QueryDelegatorValidatorResponse, QueryDelegatorValidatorsRequest,
+    QueryDelegatorValidatorsResponse, QueryHistoricalInfoRequest, QueryHistoricalInfoResponse,
+    QueryValidatorDelegationsRequest, QueryValidatorDelegationsResponse,
+    QueryValidatorUnbondingDelegationsRequest, QueryValidatorUnbondingDelegationsResponse,
 };
 use gears::{
-    application::handlers::node::{ABCIHandler, ModuleInfo, TxError},
+    application::handlers::node::{
+        ABCIHandler, InvariantError, InvariantRoute, ModuleInfo, TxError,
+    },
     baseapp::{errors::QueryError, QueryRequest, QueryResponse},
-    context::{block::BlockContext, init::InitContext, query::QueryContext, tx::TxContext},
+    context::{
+        block::BlockContext, init::InitContext, query::QueryContext, tx::TxContext,
+        QueryableContext,
+    },
     core::Protobuf,
     derive::Query,
     extensions::{
@@ -303,6 +308,21 @@ impl<
     ) -> Vec<ValidatorUpdate> {
         self.keeper.block_validator_updates(ctx)
     }
+
+    fn invariant_routes(&self) -> Vec<InvariantRoute> {
+        vec![InvariantRoute::new(MI::NAME, MODULE_ACCOUNTS_INVARIANT)]
+    }
+
+    fn check_invariant<DB: Database, CTX: QueryableContext<DB, Self::StoreKey>>(
+        &self,
+        ctx: &CTX,
+        route: &InvariantRoute,
+    ) -> Result<(), InvariantError> {
+        match route.route {
+            MODULE_ACCOUNTS_INVARIANT => self.keeper.module_accounts_invariant(ctx),
+            _ => Err(InvariantError::NotRegistered),
+        }
+    }
 }
 
 impl<
@@ -78,4 +78,15 @@ impl<
             hooks.after_delegation_modified(ctx, del_addr.clone(), val_addr.clone());
         }
     }
+
+    pub fn before_validator_slashed<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        validator: &Validator,
+        fraction: Decimal256,
+    ) {
+        if let Some(ref hooks) = self.hooks_keeper {
+            hooks.before_validator_slashed(ctx, validator.operator_address.clone(), fraction);
+        }
+    }
 }
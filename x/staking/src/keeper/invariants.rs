@@ -0,0 +1,67 @@
+use gears::{
+    application::handlers::node::InvariantError, core::Protobuf,
+    extensions::corruption::UnwrapCorrupt,
+};
+
+use super::*;
+
+/// Route of invariant which checks that module accounts hold tokens of validators and unbonding delegations
+pub const MODULE_ACCOUNTS_INVARIANT: &str = "module-accounts";
+
+impl<
+        SK: StoreKey,
+        PSK: ParamsSubspaceKey,
+        AK: AuthKeeper<SK, M>,
+        BK: StakingBankKeeper<SK, M>,
+        KH: KeeperHooks<SK, AK, M>,
+        M: Module,
+    > Keeper<SK, PSK, AK, BK, KH, M>
+{
+    /// Checks that balance of bonded pool equals tokens of bonded validators and balance of
+    /// not bonded pool equals tokens of other validators and unbonding delegations
+    pub fn module_accounts_invariant<DB: Database, CTX: QueryableContext<DB, SK>>(
+        &self,
+        ctx: &CTX,
+    ) -> Result<(), InvariantError> {
+        let mut bonded = Uint256::zero();
+        let mut not_bonded = Uint256::zero();
+
+        let store = ctx.kv_store(&self.store_key);
+        for raw_validator in store.prefix_store(VALIDATORS_KEY).into_range(..) {
+            let (_, raw_validator) = raw_validator?;
+            let validator: Validator = Protobuf::decode_vec(&raw_validator).unwrap_or_corrupt();
+
+            match validator.status {
+                BondStatus::Bonded => bonded += validator.tokens,
+                BondStatus::Unbonding | BondStatus::Unbonded => not_bonded += validator.tokens,
+                BondStatus::Unspecified => {
+                    return Err(InvariantError::Broken(format!(
+                        "validator {} has unspecified status",
+                        validator.operator_address
+                    )))
+                }
+            }
+        }
+
+        let store = ctx.kv_store(&self.store_key);
+        for raw_ubd in store.prefix_store(UNBONDING_DELEGATION_KEY).into_range(..) {
+            let (_, raw_ubd) = raw_ubd?;
+            let ubd = UnbondingDelegation::decode_vec(&raw_ubd).unwrap_or_corrupt();
+
+            for entry in ubd.entries {
+                not_bonded += entry.balance;
+            }
+        }
+
+        let pool = self.pool(ctx)?;
+
+        if pool.bonded_tokens == bonded && pool.not_bonded_tokens == not_bonded {
+            return Ok(());
+        }
+
+        Err(InvariantError::Broken(format!(
+            "pool balances do not match tokens: bonded pool {}, bonded tokens {bonded}; not bonded pool {}, not bonded tokens {not_bonded}",
+            pool.bonded_tokens, pool.not_bonded_tokens
+        )))
+    }
+}
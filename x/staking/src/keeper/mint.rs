@@ -0,0 +1,39 @@
+use gears::{
+    types::denom::Denom,
+    x::keepers::{
+        gov::GovernanceBankKeeper, mint::MintingStakingKeeper, staking::GovStakingKeeper,
+    },
+};
+
+use super::*;
+
+impl<
+        SK: StoreKey,
+        PSK: ParamsSubspaceKey,
+        AK: AuthKeeper<SK, M> + Send + Sync + 'static,
+        BK: GovernanceBankKeeper<SK, M>,
+        KH: KeeperHooks<SK, AK, M>,
+        M: Module,
+    > MintingStakingKeeper<SK, M> for Keeper<SK, PSK, AK, BK, KH, M>
+{
+    fn staking_denom<DB: Database, CTX: QueryableContext<DB, SK>>(
+        &self,
+        ctx: &CTX,
+    ) -> Result<Denom, GasStoreErrors> {
+        Ok(self
+            .staking_params_keeper
+            .try_get(ctx)?
+            .bond_denom()
+            .clone())
+    }
+
+    fn total_bonded_tokens<DB: Database, CTX: QueryableContext<DB, SK>>(
+        &self,
+        ctx: &CTX,
+    ) -> Result<Decimal256, GasStoreErrors> {
+        let bonded = GovStakingKeeper::total_bonded_tokens(self, ctx)?;
+
+        Ok(Decimal256::from_atomics(bonded.amount, 0)
+            .expect("bonded tokens are bounded by total supply"))
+    }
+}
@@ -47,9 +47,13 @@ mod delegation;
 mod gov;
 mod historical_info;
 mod hooks;
+pub mod invariants;
+mod mint;
 mod mock_hook_keeper;
 mod query;
 mod redelegation;
+mod slash;
+mod slashing;
 mod tx;
 mod unbonded;
 mod unbonding;
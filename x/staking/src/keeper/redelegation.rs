@@ -176,6 +176,38 @@ impl<
             .map(|bytes| Redelegation::decode::<Bytes>(bytes.into()).unwrap_or_corrupt()))
     }
 
+    /// redelegations_from_src_validator returns all redelegations from a
+    /// particular source validator.
+    pub fn redelegations_from_src_validator<DB: Database, CTX: QueryableContext<DB, SK>>(
+        &self,
+        ctx: &CTX,
+        val_src_addr: &ValAddress,
+    ) -> Result<Vec<Redelegation>, GasStoreErrors> {
+        let store = ctx.kv_store(&self.store_key);
+        let src_store =
+            store.prefix_store(keys::redelegations_from_val_src_index_key(val_src_addr));
+
+        // index key postfix is del_addr.len() || del_addr || val_dst_addr.len() || val_dst_addr
+        let mut triplets = vec![];
+        for r in src_store.into_range(..) {
+            let (k, _v) = r?;
+            let del_len = k[0] as usize;
+            let del_addr: AccAddress = k[1..1 + del_len].to_vec().try_into().unwrap_or_corrupt();
+            let val_dst_addr: ValAddress = k[2 + del_len..].to_vec().try_into().unwrap_or_corrupt();
+            triplets.push((del_addr, val_dst_addr));
+        }
+
+        let mut redelegations = vec![];
+        for (del_addr, val_dst_addr) in triplets {
+            let redelegation = self
+                .redelegation(ctx, &del_addr, val_src_addr, &val_dst_addr)?
+                .expect("Expected corresponding key-value pair");
+            redelegations.push(redelegation);
+        }
+
+        Ok(redelegations)
+    }
+
     pub fn set_redelegation<DB: Database, CTX: TransactionalContext<DB, SK>>(
         &self,
         ctx: &mut CTX,
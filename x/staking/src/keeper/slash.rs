@@ -0,0 +1,395 @@
+use super::*;
+use crate::{Redelegation, UnbondingDelegation};
+use gears::types::address::ConsAddress;
+
+impl<
+        SK: StoreKey,
+        PSK: ParamsSubspaceKey,
+        AK: AuthKeeper<SK, M>,
+        BK: StakingBankKeeper<SK, M>,
+        KH: KeeperHooks<SK, AK, M>,
+        M: Module,
+    > Keeper<SK, PSK, AK, BK, KH, M>
+{
+    /// slash a validator for an infraction committed at a known height.
+    /// Find the contributing stake at that height and burn the specified slash_factor
+    /// of it, updating unbonding delegations & redelegations appropriately.
+    ///
+    /// CONTRACT:
+    ///    slash_factor is non-negative
+    /// CONTRACT:
+    ///    Infraction was committed equal to or less than an unbonding period in the past,
+    ///    so all unbonding delegations and redelegations from that height are stored
+    /// CONTRACT:
+    ///    Slash will not slash unbonded validators (for the above reason)
+    /// CONTRACT:
+    ///    Infraction was committed at the current height or at a past height,
+    ///    not at a height in the future
+    pub fn slash_validator<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        cons_addr: &ConsAddress,
+        infraction_height: u32,
+        power: u64,
+        slash_factor: Decimal256,
+    ) -> Result<Uint256, GasStoreErrors> {
+        // Amount of slashing = slash slash_factor * power at time of infraction
+        let amount = Uint256::from(power) * Uint256::from(self.power_reduction(ctx));
+        let slash_amount = Decimal256::from_atomics(amount, 0)
+            .expect("power is bounded by max validator power")
+            .checked_mul(slash_factor)
+            .expect("slash factor is not greater than one")
+            .to_uint_floor();
+
+        let Some(validator) = self.validator_by_cons_addr(ctx, cons_addr)? else {
+            // If not found, the validator must have been overslashed and removed - so we don't need to do anything
+            // NOTE:  Correctness dependent on invariant that unbonding delegations / redelegations must also have been completely
+            //        slashed in this case - which we don't explicitly check, but should be true.
+            // Log the slash attempt for future reference (maybe we should tag it too)
+            tracing::error!(
+                "WARNING: ignored attempt to slash a nonexistent validator; we recommend you investigate immediately, validator: {cons_addr}"
+            );
+            return Ok(Uint256::zero());
+        };
+
+        // should not be slashing an unbonded validator
+        assert!(
+            validator.status != BondStatus::Unbonded,
+            "should not be slashing unbonded validator: {}",
+            validator.operator_address
+        );
+
+        // Track remaining slash amount for the validator
+        // This will decrease when we slash unbondings and
+        // redelegations, as that stake has since unbonded
+        let mut remaining_slash_amount = slash_amount;
+
+        let height = ctx.height();
+        assert!(
+            infraction_height <= height,
+            "impossible attempt to slash future infraction at height {infraction_height} but we are at height {height}"
+        );
+
+        if infraction_height < height {
+            // Iterate through unbonding delegations from slashed validator
+            let unbonding_delegations =
+                self.unbonding_delegations_from_validator(ctx, &validator.operator_address)?;
+            for ubd in unbonding_delegations {
+                let amount_slashed =
+                    self.slash_unbonding_delegation(ctx, ubd, infraction_height, slash_factor)?;
+                remaining_slash_amount = remaining_slash_amount.saturating_sub(amount_slashed);
+            }
+
+            // Iterate through redelegations from slashed source validator
+            let redelegations =
+                self.redelegations_from_src_validator(ctx, &validator.operator_address)?;
+            for redelegation in redelegations {
+                let amount_slashed =
+                    self.slash_redelegation(ctx, redelegation, infraction_height, slash_factor)?;
+                remaining_slash_amount = remaining_slash_amount.saturating_sub(amount_slashed);
+            }
+        }
+
+        // cannot decrease balance below zero
+        let tokens_to_burn = remaining_slash_amount.min(validator.tokens);
+
+        // we need to calculate the *effective* slash fraction for distribution
+        if !validator.tokens.is_zero() {
+            // rounded up the same way as `QuoRoundUp` of cosmos sdk
+            let effective_fraction = Decimal256::new(
+                Decimal256::one()
+                    .atomics()
+                    .mul_ceil((tokens_to_burn, validator.tokens)),
+            );
+            // call the before-slashed hook
+            self.before_validator_slashed(
+                ctx,
+                &validator,
+                effective_fraction.min(Decimal256::one()),
+            );
+        }
+
+        // Deduct from validator's bonded tokens and update the validator.
+        // Burn the slashed tokens from the pool account and decrease the total supply.
+        let validator = self.remove_validator_tokens(ctx, validator, tokens_to_burn)?;
+
+        match validator.status {
+            BondStatus::Bonded => self.burn_bonded_tokens(ctx, tokens_to_burn)?,
+            BondStatus::Unbonding | BondStatus::Unbonded => {
+                self.burn_not_bonded_tokens(ctx, tokens_to_burn)?
+            }
+            BondStatus::Unspecified => panic!("invalid validator status"),
+        }
+
+        tracing::info!(
+            "validator slashed by slash factor, validator: {}, slash_factor: {slash_factor}, burned: {tokens_to_burn}",
+            validator.operator_address
+        );
+
+        Ok(tokens_to_burn)
+    }
+
+    /// jail a validator by consensus address
+    pub fn jail_by_cons_addr<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        cons_addr: &ConsAddress,
+    ) -> Result<(), GasStoreErrors> {
+        let mut validator = self.must_validator_by_cons_addr(ctx, cons_addr)?;
+        self.jail_validator(ctx, &mut validator)?;
+        tracing::info!("validator jailed, validator: {cons_addr}");
+        Ok(())
+    }
+
+    /// unjail a validator by consensus address
+    pub fn unjail_by_cons_addr<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        cons_addr: &ConsAddress,
+    ) -> Result<(), GasStoreErrors> {
+        let mut validator = self.must_validator_by_cons_addr(ctx, cons_addr)?;
+        self.unjail_validator(ctx, &mut validator)?;
+        tracing::info!("validator un-jailed, validator: {cons_addr}");
+        Ok(())
+    }
+
+    /// unjail_validator removes the jailed flag and restores the validator in the power index
+    pub fn unjail_validator<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        validator: &mut Validator,
+    ) -> Result<(), GasStoreErrors> {
+        assert!(
+            validator.jailed,
+            "cannot unjail already unjailed validator, validator: {}",
+            validator.operator_address
+        );
+        validator.jailed = false;
+        self.set_validator(ctx, validator)?;
+        self.set_validator_by_power_index(ctx, validator)?;
+        Ok(())
+    }
+
+    /// slash_unbonding_delegation slashes an unbonding delegation and updates the pool.
+    /// Returns the amount that would have been slashed assuming
+    /// the unbonding delegation had enough stake to slash
+    /// (the amount actually slashed may be less if there's
+    /// insufficient stake remaining).
+    pub fn slash_unbonding_delegation<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        mut unbonding_delegation: UnbondingDelegation,
+        infraction_height: u32,
+        slash_factor: Decimal256,
+    ) -> Result<Uint256, GasStoreErrors> {
+        let now = ctx.get_time();
+        let mut total_slash_amount = Uint256::zero();
+        let mut burned_amount = Uint256::zero();
+
+        // perform slashing on all entries within the unbonding delegation
+        for i in 0..unbonding_delegation.entries.len() {
+            let entry = &unbonding_delegation.entries[i];
+
+            // If unbonding started before this height, stake didn't contribute to infraction
+            if entry.creation_height < infraction_height {
+                continue;
+            }
+
+            if entry.is_mature(&now) {
+                // Unbonding delegation no longer eligible for slashing, skip it
+                continue;
+            }
+
+            // Calculate slash amount proportional to stake contributing to infraction
+            let slash_amount = slash_amount(entry.initial_balance, slash_factor);
+            total_slash_amount += slash_amount;
+
+            // Don't slash more tokens than held
+            // Possible since the unbonding delegation may already
+            // have been slashed, and slash amounts are calculated
+            // according to stake held at time of infraction
+            let unbonding_slash_amount = slash_amount.min(entry.balance);
+
+            // Update unbonding delegation if necessary
+            if unbonding_slash_amount.is_zero() {
+                continue;
+            }
+
+            burned_amount += unbonding_slash_amount;
+            unbonding_delegation.entries[i].balance -= unbonding_slash_amount;
+            self.set_unbonding_delegation(ctx, &unbonding_delegation)?;
+        }
+
+        self.burn_not_bonded_tokens(ctx, burned_amount)?;
+
+        Ok(total_slash_amount)
+    }
+
+    /// slash_redelegation slashes a redelegation and updates the pool.
+    /// Returns the amount that would have been slashed assuming
+    /// the redelegation had enough stake to slash
+    /// (the amount actually slashed may be less if there's
+    /// insufficient stake remaining).
+    /// NOTE this is only slashing for prior infractions from the source validator
+    pub fn slash_redelegation<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        redelegation: Redelegation,
+        infraction_height: u32,
+        slash_factor: Decimal256,
+    ) -> Result<Uint256, GasStoreErrors> {
+        let now = ctx.get_time();
+        let mut total_slash_amount = Uint256::zero();
+        let mut bonded_burned_amount = Uint256::zero();
+        let mut not_bonded_burned_amount = Uint256::zero();
+
+        // perform slashing on all entries within the redelegation
+        for entry in &redelegation.entries {
+            // If redelegation started before this height, stake didn't contribute to infraction
+            if entry.creation_height < infraction_height {
+                continue;
+            }
+
+            if entry.is_mature(&now) {
+                // Redelegation no longer eligible for slashing, skip it
+                continue;
+            }
+
+            // Calculate slash amount proportional to stake contributing to infraction
+            total_slash_amount += slash_amount(entry.initial_balance, slash_factor);
+
+            // Unbond from target validator
+            let mut shares_to_unbond = slash_factor
+                .checked_mul(entry.share_dst)
+                .expect("slash factor is not greater than one");
+            if shares_to_unbond.is_zero() {
+                continue;
+            }
+
+            let Some(delegation) = self.delegation(
+                ctx,
+                &redelegation.delegator_address,
+                &redelegation.validator_dst_address,
+            )?
+            else {
+                // If deleted, delegation has zero shares, and we can't unbond any more
+                continue;
+            };
+
+            if shares_to_unbond > delegation.shares {
+                shares_to_unbond = delegation.shares;
+            }
+
+            let tokens_to_burn = self
+                .unbond(
+                    ctx,
+                    &redelegation.delegator_address,
+                    &redelegation.validator_dst_address,
+                    shares_to_unbond,
+                )
+                .unwrap_or_else(|e| panic!("failed to unbond slashed redelegation: {e}"));
+
+            let dst_validator = self
+                .validator(ctx, &redelegation.validator_dst_address)?
+                .expect("destination validator of redelegation must exist");
+
+            // tokens of a redelegation currently live in the destination validator
+            // therefor we must burn tokens from the destination-validator's bonding status
+            match dst_validator.status {
+                BondStatus::Bonded => bonded_burned_amount += tokens_to_burn,
+                BondStatus::Unbonding | BondStatus::Unbonded => {
+                    not_bonded_burned_amount += tokens_to_burn
+                }
+                BondStatus::Unspecified => panic!("unknown validator status"),
+            }
+        }
+
+        self.burn_bonded_tokens(ctx, bonded_burned_amount)?;
+        self.burn_not_bonded_tokens(ctx, not_bonded_burned_amount)?;
+
+        Ok(total_slash_amount)
+    }
+
+    /// remove_validator_tokens updates the tokens of an existing validator, updates the validators power index key
+    pub fn remove_validator_tokens<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        mut validator: Validator,
+        tokens_to_remove: Uint256,
+    ) -> Result<Validator, GasStoreErrors> {
+        self.delete_validator_by_power_index(ctx, &validator)?;
+        validator.tokens = validator
+            .tokens
+            .checked_sub(tokens_to_remove)
+            .expect("slashed tokens are bounded by validator tokens");
+        self.set_validator(ctx, &validator)?;
+        self.set_validator_by_power_index(ctx, &validator)?;
+        Ok(validator)
+    }
+
+    /// burn_bonded_tokens removes coins from the bonded pool module account
+    fn burn_bonded_tokens<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        amount: Uint256,
+    ) -> Result<(), GasStoreErrors> {
+        self.burn_tokens(ctx, &self.bonded_module, amount)
+    }
+
+    /// burn_not_bonded_tokens removes coins from the not bonded pool module account
+    fn burn_not_bonded_tokens<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        amount: Uint256,
+    ) -> Result<(), GasStoreErrors> {
+        self.burn_tokens(ctx, &self.not_bonded_module, amount)
+    }
+
+    fn burn_tokens<DB: Database, CTX: TransactionalContext<DB, SK>>(
+        &self,
+        ctx: &mut CTX,
+        module: &M,
+        amount: Uint256,
+    ) -> Result<(), GasStoreErrors> {
+        if amount.is_zero() {
+            // skip as no coins need to be burned
+            return Ok(());
+        }
+
+        // original routine is infallible, it means that the amount should be a valid number.
+        // All errors in sdk panics in this method
+        let coins = UnsignedCoins::new(vec![UnsignedCoin {
+            denom: self
+                .staking_params_keeper
+                .try_get(ctx)?
+                .bond_denom()
+                .clone(),
+            amount,
+        }])
+        .expect("amount is not zero");
+
+        self.bank_keeper
+            .coins_burn(ctx, module, &coins)
+            .unwrap_or_else(|e| panic!("failed to burn slashed tokens: {e}"));
+
+        Ok(())
+    }
+
+    fn must_validator_by_cons_addr<DB: Database, CTX: QueryableContext<DB, SK>>(
+        &self,
+        ctx: &CTX,
+        cons_addr: &ConsAddress,
+    ) -> Result<Validator, GasStoreErrors> {
+        Ok(self
+            .validator_by_cons_addr(ctx, cons_addr)?
+            .unwrap_or_else(|| panic!("validator with consensus-Address {cons_addr} not found")))
+    }
+}
+
+fn slash_amount(balance: Uint256, slash_factor: Decimal256) -> Uint256 {
+    Decimal256::from_atomics(balance, 0)
+        .expect("balance is bounded by total supply")
+        .checked_mul(slash_factor)
+        .expect("slash factor is not greater than one")
+        .to_uint_floor()
+}
@@ -438,7 +438,11 @@ impl TryFrom<inner::MsgCreateValidator> for CreateValidator {
                 .map_err(|e| CoreError::DecodeAddress(e.to_string()))?,
             validator_address: ValAddress::from_bech32(&val.validator_address)
                 .map_err(|e| CoreError::DecodeAddress(e.to_string()))?,
-            pubkey: pubkey.into(),
+            pubkey: pubkey
+                .try_into()
+                .map_err(|e: gears::crypto::public::DecodeError| {
+                    CoreError::DecodeGeneral(e.to_string())
+                })?,
             value: val
                 .value
                 .ok_or(CoreError::MissingField("value".into()))?
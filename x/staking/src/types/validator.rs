@@ -421,7 +421,9 @@ impl TryFrom<inner::Validator> for Validator {
                 security_contact: description.security_contact,
                 details: description.details,
             },
-            consensus_pubkey: consensus_pubkey.into(),
+            consensus_pubkey: consensus_pubkey.try_into().map_err(
+                |e: gears::crypto::public::DecodeError| CoreError::DecodeGeneral(e.to_string()),
+            )?,
             jailed: value.jailed,
             tokens: Uint256::from_str(&value.tokens)
                 .map_err(|e| CoreError::DecodeGeneral(e.to_string()))?,
@@ -0,0 +1,66 @@
+use gears::{
+    core::Protobuf,
+    extensions::testing::UnwrapTesting,
+    tendermint::types::{
+        request::query::RequestQuery, response::ResponseQuery, time::timestamp::Timestamp,
+    },
+    utils::node::GenesisSource,
+};
+
+use staking::{QueryHistoricalInfoRequest, QueryHistoricalInfoResponse};
+use utils::set_node;
+
+#[path = "./utils.rs"]
+mod utils;
+
+const FIRST_BLOCK_HASH: [u8; 32] = [1; 32];
+const SECOND_BLOCK_HASH: [u8; 32] = [2; 32];
+const FIRST_VALIDATORS_HASH: [u8; 32] = [3; 32];
+const SECOND_VALIDATORS_HASH: [u8; 32] = [4; 32];
+
+#[test]
+/// Header stored in historical info is built by the app from `FinalizeBlock` request
+/// and state of the previous block
+fn historical_info_header_from_finalize_block() {
+    let mut node = set_node(GenesisSource::Default);
+
+    let _ = node.finalize_step(
+        vec![],
+        Timestamp::UNIX_EPOCH,
+        FIRST_BLOCK_HASH.to_vec().into(),
+        FIRST_VALIDATORS_HASH.to_vec().into(),
+    );
+    let first_app_hash = node.app_hash().to_vec();
+
+    let _ = node.finalize_step(
+        vec![],
+        Timestamp::UNIX_EPOCH,
+        SECOND_BLOCK_HASH.to_vec().into(),
+        SECOND_VALIDATORS_HASH.to_vec().into(),
+    );
+
+    let q = QueryHistoricalInfoRequest {
+        height: node.height() as i64,
+    };
+    let ResponseQuery {
+        code, value, log, ..
+    } = node.query(RequestQuery {
+        data: q.encode_vec().into(),
+        path: QueryHistoricalInfoRequest::QUERY_URL.to_owned(),
+        height: node.height() as i64,
+        prove: false,
+    });
+
+    assert!(code == 0, "{log}");
+
+    let QueryHistoricalInfoResponse { hist } =
+        QueryHistoricalInfoResponse::decode_vec(&value).unwrap_test();
+    let header = hist.unwrap_test().header;
+
+    assert_eq!(header.height, 2);
+    assert_eq!(header.chain_id, *node.chain_id());
+    assert_eq!(header.app_hash, first_app_hash);
+    assert_eq!(header.last_block_id.hash, FIRST_BLOCK_HASH.to_vec());
+    assert_eq!(header.validators_hash, FIRST_VALIDATORS_HASH.to_vec());
+    assert_eq!(header.next_validators_hash, SECOND_VALIDATORS_HASH.to_vec());
+}
@@ -58,6 +58,7 @@ fn create_validator_unbounded() {
         app_hash,
         mut tx_responses,
         height: _,
+        events: _,
     } = node.step(vec![txs], Timestamp::UNIX_EPOCH);
 
     let ResponseDeliverTx { code, log, .. } = tx_responses.pop().unwrap_test();
@@ -73,6 +74,7 @@ fn create_validator_unbounded() {
         app_hash,
         tx_responses: _,
         height: _,
+        events: _,
     } = node.step(vec![], Timestamp::UNIX_EPOCH);
 
     assert_eq!(
@@ -57,6 +57,7 @@ fn create_delegation() {
         app_hash,
         mut tx_responses,
         height: _,
+        events: _,
     } = node.step(vec![txs], Timestamp::UNIX_EPOCH);
 
     let ResponseDeliverTx { code, log, .. } = tx_responses.pop().unwrap_test();
@@ -133,6 +134,7 @@ fn create_delegation_fails_due_non_existed_validator() {
         app_hash,
         mut tx_responses,
         height: _,
+        events: _,
     } = node.step(vec![txs], Timestamp::UNIX_EPOCH);
 
     let ResponseDeliverTx { code, log, .. } = tx_responses.pop().unwrap_test();
@@ -45,6 +45,7 @@ fn edit_validator_unbounded_no_changes() {
         app_hash,
         mut tx_responses,
         height: _,
+        events: _,
     } = node.step(vec![txs], Timestamp::UNIX_EPOCH);
 
     let ResponseDeliverTx { code, log, .. } = tx_responses.pop().unwrap_test();
@@ -60,6 +61,7 @@ fn edit_validator_unbounded_no_changes() {
         app_hash,
         tx_responses: _,
         height: _,
+        events: _,
     } = node.step(vec![], Timestamp::UNIX_EPOCH);
 
     assert_eq!(
@@ -146,6 +148,7 @@ fn edit_validator_unbounded_edit_desc() {
         app_hash,
         mut tx_responses,
         height: _,
+        events: _,
     } = node.step(vec![txs], Timestamp::UNIX_EPOCH);
 
     let ResponseDeliverTx { code, log, .. } = tx_responses.pop().unwrap_test();
@@ -161,6 +164,7 @@ fn edit_validator_unbounded_edit_desc() {
         app_hash,
         tx_responses: _,
         height: _,
+        events: _,
     } = node.step(vec![], Timestamp::UNIX_EPOCH);
 
     assert_eq!(
@@ -266,6 +270,7 @@ fn edit_validator_unbounded_edit_min_self_delegation() {
         app_hash,
         mut tx_responses,
         height: _,
+        events: _,
     } = node.step(vec![txs], Timestamp::UNIX_EPOCH);
 
     let ResponseDeliverTx { code, log, .. } = tx_responses.pop().unwrap_test();
@@ -281,6 +286,7 @@ fn edit_validator_unbounded_edit_min_self_delegation() {
         app_hash,
         tx_responses: _,
         height: _,
+        events: _,
     } = node.step(vec![], Timestamp::UNIX_EPOCH);
 
     assert_eq!(
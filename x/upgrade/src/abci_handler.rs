@@ -9,7 +9,7 @@ use gears::{
     context::{query::QueryContext, QueryableContext},
     core::Protobuf,
     params::ParamsSubspaceKey,
-    store::{database::Database, StoreKey},
+    store::{database::Database, upgrade::StoreUpgrades, StoreKey},
     tendermint::types::request::query::RequestQuery,
     types::tx::NullTxMsg,
 };
@@ -35,6 +35,17 @@ pub struct UpgradeAbciHandler<SK: StoreKey, PSK: ParamsSubspaceKey, M: Module, U
     _marker: PhantomData<(MI, SK, PSK, M)>,
 }
 
+impl<SK: StoreKey, PSK: ParamsSubspaceKey, M: Module, UH, MI>
+    UpgradeAbciHandler<SK, PSK, M, UH, MI>
+{
+    pub fn new(keeper: UpgradeKeeper<SK, M, UH>) -> Self {
+        Self {
+            keeper,
+            _marker: PhantomData,
+        }
+    }
+}
+
 impl<SK: StoreKey, PSK: ParamsSubspaceKey, M: Module, UH: UpgradeHandler, MI: ModuleInfo>
     ABCIHandler for UpgradeAbciHandler<SK, PSK, M, UH, MI>
 where
@@ -83,6 +94,10 @@ where
         Ok(())
     }
 
+    fn store_upgrades(&self, plan_name: &str) -> StoreUpgrades {
+        self.keeper.store_upgrades(plan_name)
+    }
+
     fn init_genesis<DB: gears::store::database::Database>(
         &self,
         _: &mut gears::context::init::InitContext<'_, DB, Self::StoreKey>,
@@ -171,18 +186,20 @@ where
             }
 
             if !self.keeper.has_handler(&plan.name) {
-                // TODO: store info https://github.com/cosmos/cosmos-sdk/blob/d3f09c222243bb3da3464969f0366330dcb977a8/x/upgrade/keeper/keeper.go#L375-L396
+                // We don't have an upgrade handler for this upgrade name, meaning this software is out of date.
+                // Write upgrade info for the new binary and cosmovisor, then stop before the block is processed.
+                // Panic aborts the block, so its changes aren't committed and the new binary replays it.
+                if let Err(e) = self.keeper.dump_upgrade_info(&plan) {
+                    panic!("unable to write upgrade info to filesystem: {e}");
+                }
 
-                // We don't have an upgrade handler for this upgrade name, meaning this software is out of date so shutdown
                 let msg = format!(
                     "UPGRADE `{}` NEEDED at height: {}: {}",
                     plan.name.as_ref(),
                     plan.height,
                     plan.info
                 );
-                let log_msg = msg.clone();
-
-                tracing::error!("{log_msg}");
+                tracing::error!("{msg}");
                 panic!("{msg}");
             }
 
@@ -1,18 +1,25 @@
 use std::{collections::HashMap, fmt::Debug};
 
-use gears::context::InfallibleContextMut;
+use gears::{context::InfallibleContextMut, store::upgrade::StoreUpgrades};
 
-use crate::types::plan::Plan;
+use crate::{types::plan::Plan, Module};
 
 pub trait UpgradeHandler: Debug + Clone + Send + Sync + 'static {
     fn name(&self) -> &'static str;
 
-    fn handle<CTX: InfallibleContextMut<DB, SK>, DB, SK, M>(
+    /// Migrate state of modules from `versions` stored before upgrade and return
+    /// new version map. Use [crate::migrations::Migrator] to run registered migrations.
+    fn handle<CTX: InfallibleContextMut<DB, SK>, DB, SK, M: Module>(
         &self,
         ctx: &mut CTX,
         plan: &Plan,
         versions: impl IntoIterator<Item = (M, u64)>,
     ) -> anyhow::Result<HashMap<M, u64>>;
+
+    /// Stores which should be added, renamed or deleted by the upgrade
+    fn store_upgrades(&self) -> StoreUpgrades {
+        StoreUpgrades::default()
+    }
 }
 
 pub mod dummy {
@@ -27,7 +34,7 @@ pub mod dummy {
             unreachable!()
         }
 
-        fn handle<CTX: InfallibleContextMut<DB, SK>, DB, SK, M>(
+        fn handle<CTX: InfallibleContextMut<DB, SK>, DB, SK, M: Module>(
             &self,
             _ctx: &mut CTX,
             _plan: &Plan,
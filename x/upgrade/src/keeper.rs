@@ -2,13 +2,15 @@ use std::{
     collections::{HashMap, HashSet},
     fmt::{Debug, Display},
     marker::PhantomData,
+    path::PathBuf,
 };
 
 use gears::{
+    baseapp::upgrade::UpgradeInfo,
     context::{InfallibleContext, InfallibleContextMut},
     core::Protobuf,
     extensions::corruption::UnwrapCorrupt,
-    store::{database::Database, StoreKey},
+    store::{database::Database, upgrade::StoreUpgrades, StoreKey},
 };
 use prost::bytes::Bytes;
 
@@ -58,16 +60,23 @@ fn upgraded_const_state_key(height: u32) -> Vec<u8> {
 pub struct UpgradeKeeper<SK, M, UH> {
     store_key: SK,
     upgrade_handlers: HashMap<&'static str, UH>,
-    skip_heights: HashSet<u32>, // TODO: source https://github.com/cosmos/gaia/blob/189b57be735d64d0dbf0945717b49017a1beb11e/cmd/gaiad/cmd/root.go#L192-L195
+    skip_heights: HashSet<u32>,
+    /// Home directory of the node, `upgrade-info.json` is written into its data directory
+    home: PathBuf,
     _modules_marker: PhantomData<M>,
 }
 
 impl<SK, M, UH: strum::IntoEnumIterator + UpgradeHandler> UpgradeKeeper<SK, M, UH> {
-    pub fn new(store_key: SK, skip_heights: impl IntoIterator<Item = u32>) -> Self {
+    pub fn new(
+        store_key: SK,
+        home: impl Into<PathBuf>,
+        skip_heights: impl IntoIterator<Item = u32>,
+    ) -> Self {
         Self {
             store_key,
             upgrade_handlers: UH::iter().map(|this| (this.name(), this)).collect(),
             skip_heights: skip_heights.into_iter().collect(),
+            home: home.into(),
             _modules_marker: PhantomData,
         }
     }
@@ -77,12 +86,14 @@ impl<SK, M, UH: UpgradeHandler> UpgradeKeeper<SK, M, UH> {
     pub fn new_unchecked(
         store_key: SK,
         upgrade_handlers: impl IntoIterator<Item = (&'static str, UH)>,
+        home: impl Into<PathBuf>,
         skip_heights: impl IntoIterator<Item = u32>,
     ) -> Self {
         Self {
             store_key,
             upgrade_handlers: upgrade_handlers.into_iter().collect(),
             skip_heights: skip_heights.into_iter().collect(),
+            home: home.into(),
             _modules_marker: PhantomData,
         }
     }
@@ -265,24 +276,33 @@ where
             .collect::<HashMap<_, _>>()
     }
 
+    /// Replace stored version map with `modules`. Versions of modules which
+    /// aren't in `modules` anymore are removed.
     fn set_modules_version<DB: Database, CTX: InfallibleContextMut<DB, SK>>(
         &self,
         ctx: &mut CTX,
         modules: impl IntoIterator<Item = (M, u64)>,
     ) {
         let modules = modules.into_iter().collect::<HashMap<_, _>>();
+        let stale = self
+            .modules_version(ctx)
+            .into_keys()
+            .filter(|module| !modules.contains_key(module))
+            .collect::<Vec<_>>();
+
+        let mut store = ctx
+            .infallible_store_mut(&self.store_key)
+            .prefix_store_mut(VERSION_MAP_PREFIX);
+
+        for module in stale {
+            store.delete(module.name().as_bytes());
+        }
 
-        if modules.is_empty() {
-            let mut store = ctx
-                .infallible_store_mut(&self.store_key)
-                .prefix_store_mut(VERSION_MAP_PREFIX);
-
-            for (module, version) in modules {
-                store.set(
-                    module.name().as_bytes().to_owned(),
-                    version.to_be_bytes().to_vec(),
-                );
-            }
+        for (module, version) in modules {
+            store.set(
+                module.name().as_bytes().to_owned(),
+                version.to_be_bytes().to_vec(),
+            );
         }
     }
 
@@ -306,9 +326,27 @@ where
         self.skip_heights.contains(&height)
     }
 
+    /// Write `upgrade-info.json` of `plan` which the new binary and cosmovisor read
+    pub fn dump_upgrade_info(&self, plan: &Plan) -> anyhow::Result<()> {
+        UpgradeInfo {
+            name: plan.name.as_ref().to_owned(),
+            height: plan.height.get(),
+            info: plan.info.clone(),
+        }
+        .write(self.home.join("data"))
+    }
+
     pub fn has_handler(&self, name: impl AsRef<str>) -> bool {
         self.upgrade_handlers.contains_key(name.as_ref())
     }
+
+    /// Store upgrades declared by handler of upgrade `name`
+    pub fn store_upgrades(&self, name: impl AsRef<str>) -> StoreUpgrades {
+        self.upgrade_handlers
+            .get(name.as_ref())
+            .map(UpgradeHandler::store_upgrades)
+            .unwrap_or_default()
+    }
 }
 
 mod downgrade_flag {
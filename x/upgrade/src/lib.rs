@@ -2,6 +2,7 @@ pub mod abci_handler;
 pub mod client;
 mod handler;
 pub mod keeper;
+pub mod migrations;
 pub mod types;
 
 pub use crate::handler::*;
@@ -10,4 +11,10 @@ pub trait Module:
     Clone + Send + Sync + TryFrom<Vec<u8>> + std::cmp::Eq + std::hash::Hash + 'static
 {
     fn name(&self) -> &'static str;
+
+    /// Version of module state. Bump it together with registering a migration
+    /// from the previous version in [migrations::Migrator].
+    fn consensus_version(&self) -> u64 {
+        1
+    }
 }
@@ -0,0 +1,242 @@
+//! Registry of state migrations between consensus versions of modules
+
+use std::collections::HashMap;
+
+use tracing::info;
+
+use crate::Module;
+
+/// Migration of module state from one consensus version to the next one
+pub type Migration<'a, CTX> = Box<dyn Fn(&mut CTX) -> anyhow::Result<()> + 'a>;
+
+/// Migrations registered by module name and version which they migrate from.
+///
+/// Upgrade handler registers migrations and runs them with the version map
+/// stored before upgrade:
+///
+/// ```ignore
+/// let mut migrator = Migrator::new();
+/// migrator.register("staking", 1, |ctx| staking_keeper.migrate_v1_to_v2(ctx))?;
+///
+/// Ok(migrator.run_migrations(ctx, GaiaXmodules::iter(), versions)?)
+/// ```
+pub struct Migrator<'a, CTX> {
+    migrations: HashMap<(&'static str, u64), Migration<'a, CTX>>,
+}
+
+impl<CTX> std::fmt::Debug for Migrator<'_, CTX> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Migrator")
+            .field("migrations", &self.migrations.keys())
+            .finish()
+    }
+}
+
+impl<CTX> Default for Migrator<'_, CTX> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, CTX> Migrator<'a, CTX> {
+    pub fn new() -> Self {
+        Self {
+            migrations: HashMap::new(),
+        }
+    }
+
+    /// Register migration of `module` state from `from_version` to `from_version + 1`
+    pub fn register(
+        &mut self,
+        module: &'static str,
+        from_version: u64,
+        migration: impl Fn(&mut CTX) -> anyhow::Result<()> + 'a,
+    ) -> Result<(), MigrationError> {
+        if self.migrations.contains_key(&(module, from_version)) {
+            return Err(MigrationError::Registered {
+                module,
+                version: from_version,
+            });
+        }
+
+        self.migrations
+            .insert((module, from_version), Box::new(migration));
+
+        Ok(())
+    }
+
+    /// Migrate every module from version in `from_versions` to its consensus version.
+    /// Modules are migrated in order of `modules`. Modules which are missing in
+    /// `from_versions` are new, so they start from consensus version without migrations.
+    ///
+    /// Returns version map which should be stored after upgrade
+    pub fn run_migrations<M: Module>(
+        &self,
+        ctx: &mut CTX,
+        modules: impl IntoIterator<Item = M>,
+        from_versions: impl IntoIterator<Item = (M, u64)>,
+    ) -> Result<HashMap<M, u64>, MigrationError> {
+        let from_versions = from_versions.into_iter().collect::<HashMap<_, _>>();
+
+        let mut versions = HashMap::new();
+        for module in modules {
+            let name = module.name();
+            let to_version = module.consensus_version();
+
+            match from_versions.get(&module) {
+                Some(&from_version) if from_version > to_version => {
+                    return Err(MigrationError::Downgrade {
+                        module: name,
+                        from: from_version,
+                        to: to_version,
+                    })
+                }
+                Some(&from_version) => {
+                    for version in from_version..to_version {
+                        let migration = self.migrations.get(&(name, version)).ok_or(
+                            MigrationError::NotRegistered {
+                                module: name,
+                                version,
+                            },
+                        )?;
+
+                        info!(
+                            "migrating module {name} from version {version} to version {}",
+                            version + 1
+                        );
+                        migration(ctx).map_err(|err| MigrationError::Failed {
+                            module: name,
+                            version,
+                            err,
+                        })?;
+                    }
+                }
+                None => info!("adding a new module {name} with version {to_version}"),
+            }
+
+            versions.insert(module, to_version);
+        }
+
+        Ok(versions)
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+pub enum MigrationError {
+    #[error("migration of {module} from version {version} is already registered")]
+    Registered { module: &'static str, version: u64 },
+    #[error("migration of {module} from version {version} isn't registered")]
+    NotRegistered { module: &'static str, version: u64 },
+    #[error("can't downgrade {module} from version {from} to version {to}")]
+    Downgrade {
+        module: &'static str,
+        from: u64,
+        to: u64,
+    },
+    #[error("failed to migrate {module} from version {version}: {err}")]
+    Failed {
+        module: &'static str,
+        version: u64,
+        err: anyhow::Error,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq, Eq, Hash)]
+    enum TestModule {
+        Bank,
+        Staking,
+        Gov,
+    }
+
+    impl TryFrom<Vec<u8>> for TestModule {
+        type Error = String;
+
+        fn try_from(value: Vec<u8>) -> Result<Self, Self::Error> {
+            match value.as_slice() {
+                b"bank" => Ok(Self::Bank),
+                b"staking" => Ok(Self::Staking),
+                b"gov" => Ok(Self::Gov),
+                _ => Err("unknown module".to_owned()),
+            }
+        }
+    }
+
+    impl Module for TestModule {
+        fn name(&self) -> &'static str {
+            match self {
+                Self::Bank => "bank",
+                Self::Staking => "staking",
+                Self::Gov => "gov",
+            }
+        }
+
+        fn consensus_version(&self) -> u64 {
+            match self {
+                Self::Bank => 1,
+                Self::Staking => 3,
+                Self::Gov => 2,
+            }
+        }
+    }
+
+    fn migrator<'a>() -> Migrator<'a, Vec<String>> {
+        let mut migrator = Migrator::new();
+        for (module, version) in [("staking", 1), ("staking", 2), ("gov", 1)] {
+            migrator
+                .register(module, version, move |log: &mut Vec<String>| {
+                    log.push(format!("{module} v{version}"));
+                    Ok(())
+                })
+                .expect("migration is registered once");
+        }
+
+        migrator
+    }
+
+    #[test]
+    fn migrations_run_in_order() {
+        let mut log = Vec::new();
+
+        let versions = migrator()
+            .run_migrations(
+                &mut log,
+                [TestModule::Bank, TestModule::Staking, TestModule::Gov],
+                [(TestModule::Bank, 1), (TestModule::Staking, 1)],
+            )
+            .expect("migrations succeed");
+
+        assert_eq!(log, ["staking v1", "staking v2"]);
+        assert_eq!(
+            versions,
+            HashMap::from([
+                (TestModule::Bank, 1),
+                (TestModule::Staking, 3),
+                (TestModule::Gov, 2),
+            ])
+        );
+    }
+
+    #[test]
+    fn missing_migration_fails() {
+        let mut migrator = Migrator::new();
+        migrator
+            .register("staking", 2, |_: &mut ()| Ok(()))
+            .expect("migration is registered once");
+
+        assert!(matches!(
+            migrator.run_migrations(&mut (), [TestModule::Staking], [(TestModule::Staking, 1)]),
+            Err(MigrationError::NotRegistered {
+                module: "staking",
+                version: 1
+            })
+        ));
+        assert!(matches!(
+            migrator.register("staking", 2, |_| Ok(())),
+            Err(MigrationError::Registered { .. })
+        ));
+    }
+}
@@ -12,11 +12,11 @@ mod inner {
 
 #[nutype::nutype(
     validate(not_empty),
-    derive(Debug, Clone, Serialize, Deserialize, AsRef)
+    derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, AsRef)
 )]
 pub struct PlanName(String);
 
-#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
 pub struct Plan {
     pub name: PlanName,
     pub height: NonZero<u32>,
@@ -19,7 +19,7 @@ impl QueryRequest for UpgradeQueryRequest {
     }
 }
 
-#[derive(Debug, Clone, Query, serde::Serialize, serde::Deserialize)]
+#[derive(Debug, Clone, PartialEq, Query, serde::Serialize, serde::Deserialize)]
 pub enum UpgradeQueryResponse {
     Plan(QueryCurrentPlanResponse),
     Applied(QueryAppliedPlanResponse),
@@ -46,7 +46,7 @@ mod inner {
 #[query(url = "/cosmos.upgrade.v1beta1.QueryCurrentPlanRequest")]
 pub struct QueryCurrentPlanRequest {}
 
-#[derive(Debug, Clone, Query, Protobuf, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Query, Protobuf, Serialize, Deserialize)]
 #[proto(raw = "inner::QueryCurrentPlanResponse")]
 pub struct QueryCurrentPlanResponse {
     #[proto(optional)]
@@ -60,7 +60,7 @@ pub struct QueryAppliedPlanRequest {
     pub name: String,
 }
 
-#[derive(Debug, Clone, Query, Protobuf, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Query, Protobuf, Serialize, Deserialize)]
 #[proto(raw = "inner::QueryAppliedPlanResponse")]
 pub struct QueryAppliedPlanResponse {
     pub height: u32,
@@ -73,7 +73,7 @@ pub struct QueryModuleVersionsRequest {
     pub module_name: String,
 }
 
-#[derive(Debug, Clone, Query, Protobuf, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Query, Protobuf, Serialize, Deserialize)]
 #[proto(raw = "inner::QueryModuleVersionsResponse")]
 pub struct QueryModuleVersionsResponse {
     #[proto(repeated)]